@@ -0,0 +1,137 @@
+//! In-memory activity log of hardware detection steps and fit decisions.
+//!
+//! Entries go into a bounded ring buffer so that a long TUI session can't grow
+//! it without limit. Frontends show it on demand (TUI `L` overlay, CLI
+//! `--verbose`) to explain why a model landed where it did. Warnings can also
+//! be streamed to stderr as they happen, for scripts that need to tell a
+//! failed probe from absent hardware. Every entry is also forwarded to the
+//! `log` facade under the `llmfit::<kind>` target, so a frontend can install
+//! a logger to keep a persistent record.
+
+use std::collections::VecDeque;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Maximum number of retained entries; the oldest are dropped first.
+pub const ACTIVITY_CAPACITY: usize = 4096;
+
+/// What an activity entry is about.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ActivityKind {
+    /// Hardware detection step (GPU probe, RAM, bandwidth, overrides).
+    Detect,
+    /// Per-model fit decision.
+    Fit,
+    /// A detection tool that is present but failed or gave unusable output.
+    Warning,
+}
+
+impl ActivityKind {
+    pub fn label(&self) -> &'static str {
+        match self {
+            ActivityKind::Detect => "detect",
+            ActivityKind::Fit => "fit",
+            ActivityKind::Warning => "warning",
+        }
+    }
+
+    /// Level the entry is forwarded to the `log` facade at.
+    pub fn level(&self) -> log::Level {
+        match self {
+            ActivityKind::Detect => log::Level::Info,
+            ActivityKind::Fit => log::Level::Debug,
+            ActivityKind::Warning => log::Level::Warn,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ActivityEntry {
+    pub kind: ActivityKind,
+    /// Model name for fit decisions, the tool for warnings, `None` for
+    /// detection steps.
+    pub subject: Option<String>,
+    pub message: String,
+}
+
+static LOG: Mutex<VecDeque<ActivityEntry>> = Mutex::new(VecDeque::new());
+static STDERR_WARNINGS: AtomicBool = AtomicBool::new(false);
+
+/// Also print each warning to stderr as `llmfit: warning: <source>: <message>`.
+pub fn set_stderr_warnings(enabled: bool) {
+    STDERR_WARNINGS.store(enabled, Ordering::Relaxed);
+}
+
+fn push(entry: ActivityEntry) {
+    log::log!(
+        target: &format!("llmfit::{}", entry.kind.label()),
+        entry.kind.level(),
+        "{}",
+        entry.message
+    );
+    if let Ok(mut log) = LOG.lock() {
+        if log.len() >= ACTIVITY_CAPACITY {
+            log.pop_front();
+        }
+        log.push_back(entry);
+    }
+}
+
+/// Record a hardware detection step.
+pub fn detect(message: impl Into<String>) {
+    push(ActivityEntry {
+        kind: ActivityKind::Detect,
+        subject: None,
+        message: message.into(),
+    });
+}
+
+/// Record a detection problem reported by `source` (usually a tool name).
+pub fn warn(source: &str, message: impl Into<String>) {
+    let message = format!("{}: {}", source, message.into());
+    if STDERR_WARNINGS.load(Ordering::Relaxed) {
+        eprintln!("llmfit: warning: {}", message);
+    }
+    push(ActivityEntry {
+        kind: ActivityKind::Warning,
+        subject: Some(source.to_string()),
+        message,
+    });
+}
+
+/// Record the fit decision made for `model`.
+pub fn fit(model: &str, message: impl Into<String>) {
+    push(ActivityEntry {
+        kind: ActivityKind::Fit,
+        subject: Some(model.to_string()),
+        message: message.into(),
+    });
+}
+
+/// Copy of all retained entries, oldest first.
+pub fn entries() -> Vec<ActivityEntry> {
+    LOG.lock()
+        .map(|log| log.iter().cloned().collect())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ring_buffer_drops_oldest() {
+        for i in 0..ACTIVITY_CAPACITY + 10 {
+            fit("ring-test", format!("entry {i}"));
+        }
+        let all = entries();
+        assert!(all.len() <= ACTIVITY_CAPACITY);
+        let last = all
+            .iter()
+            .rev()
+            .find(|e| e.subject.as_deref() == Some("ring-test"))
+            .unwrap();
+        assert_eq!(last.message, format!("entry {}", ACTIVITY_CAPACITY + 9));
+        assert!(!all.iter().any(|e| e.message == "entry 0"));
+    }
+}
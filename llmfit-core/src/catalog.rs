@@ -0,0 +1,280 @@
+//! Remote model catalogs fetched over HTTP.
+//!
+//! A remote catalog uses the same JSON schema as the embedded one. The last
+//! download is cached in ~/.config/llmfit/remote_catalog.json together with
+//! its ETag, so later fetches are conditional and an offline start still has
+//! the last copy to fall back on.
+
+use crate::models::{LlmModel, ModelDatabase};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// How long a fetch may take before falling back to the cached copy.
+const FETCH_TIMEOUT: Duration = Duration::from_secs(15);
+/// Catalogs larger than this are rejected (the embedded one is ~350 KB).
+const MAX_CATALOG_BYTES: u64 = 64 * 1024 * 1024;
+
+/// Where a [`ModelCatalog`] came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CatalogSource {
+    /// Downloaded just now.
+    Downloaded,
+    /// The server confirmed the cached copy is current (HTTP 304).
+    NotModified,
+    /// The cached copy, because the server couldn't be reached.
+    Cache,
+}
+
+/// Why a remote catalog couldn't be loaded.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CatalogError {
+    /// The server couldn't be reached or answered with an error, and there
+    /// is no cached copy to fall back on.
+    Fetch { url: String, message: String },
+    /// The download isn't a catalog this build can read.
+    Invalid { url: String, message: String },
+}
+
+impl std::fmt::Display for CatalogError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CatalogError::Fetch { url, message } => {
+                write!(f, "couldn't fetch catalog from {}: {}", url, message)
+            }
+            CatalogError::Invalid { url, message } => {
+                write!(f, "catalog from {} is invalid: {}", url, message)
+            }
+        }
+    }
+}
+
+impl std::error::Error for CatalogError {}
+
+/// A catalog loaded from a URL, or from the cache of its last download.
+#[derive(Debug, Clone)]
+pub struct ModelCatalog {
+    pub url: String,
+    pub models: Vec<LlmModel>,
+    /// When the server last confirmed this copy (downloaded or 304).
+    pub updated_at: SystemTime,
+    pub source: CatalogSource,
+}
+
+/// On-disk cache of the last successful download.
+#[derive(Serialize, Deserialize)]
+struct CacheEntry {
+    url: String,
+    etag: Option<String>,
+    /// Seconds since the Unix epoch.
+    updated_at: u64,
+    body: String,
+}
+
+impl ModelCatalog {
+    /// Download the catalog at `url`, revalidating the cached copy with its
+    /// ETag. Falls back to the cached copy when the server can't be reached.
+    pub fn fetch_remote(url: &str) -> Result<ModelCatalog, CatalogError> {
+        fetch(url, default_cache_path().as_deref())
+    }
+
+    /// The cached copy of the catalog at `url`, without touching the network.
+    pub fn load_cached(url: &str) -> Option<ModelCatalog> {
+        let entry = read_cache(&default_cache_path()?, url)?;
+        from_entry(&entry, CatalogSource::Cache).ok()
+    }
+
+    /// One-line status, e.g. "catalog: 142 models, updated 3h ago".
+    pub fn summary(&self, now: SystemTime) -> String {
+        let age = now
+            .duration_since(self.updated_at)
+            .unwrap_or_default()
+            .as_secs();
+        let offline = if self.source == CatalogSource::Cache {
+            " (offline)"
+        } else {
+            ""
+        };
+        let count = self.models.len();
+        format!(
+            "catalog: {} model{}, updated {}{}",
+            count,
+            if count == 1 { "" } else { "s" },
+            format_age(age),
+            offline
+        )
+    }
+}
+
+/// ~/.config/llmfit/remote_catalog.json
+fn default_cache_path() -> Option<PathBuf> {
+    Some(crate::paths::config_dir()?.join("remote_catalog.json"))
+}
+
+fn fetch(url: &str, cache_path: Option<&Path>) -> Result<ModelCatalog, CatalogError> {
+    let cached = cache_path.and_then(|path| read_cache(path, url));
+    let mut request = ureq::get(url)
+        .config()
+        .timeout_global(Some(FETCH_TIMEOUT))
+        .build();
+    if let Some(etag) = cached.as_ref().and_then(|c| c.etag.as_deref()) {
+        request = request.header("If-None-Match", etag);
+    }
+
+    let mut response = match request.call() {
+        Ok(response) => response,
+        Err(err) => {
+            return match cached {
+                Some(entry) => from_entry(&entry, CatalogSource::Cache),
+                None => Err(CatalogError::Fetch {
+                    url: url.to_string(),
+                    message: err.to_string(),
+                }),
+            };
+        }
+    };
+
+    if response.status() == 304
+        && let Some(mut entry) = cached
+    {
+        entry.updated_at = unix_secs(SystemTime::now());
+        write_cache(cache_path, &entry);
+        return from_entry(&entry, CatalogSource::NotModified);
+    }
+
+    let etag = response
+        .headers()
+        .get("etag")
+        .and_then(|v| v.to_str().ok())
+        .map(str::to_string);
+    let body = response
+        .body_mut()
+        .with_config()
+        .limit(MAX_CATALOG_BYTES)
+        .read_to_string()
+        .map_err(|e| CatalogError::Fetch {
+            url: url.to_string(),
+            message: e.to_string(),
+        })?;
+    let entry = CacheEntry {
+        url: url.to_string(),
+        etag,
+        updated_at: unix_secs(SystemTime::now()),
+        body,
+    };
+    // Only cache what parses, so a bad download never replaces a good copy
+    let catalog = from_entry(&entry, CatalogSource::Downloaded)?;
+    write_cache(cache_path, &entry);
+    Ok(catalog)
+}
+
+fn from_entry(entry: &CacheEntry, source: CatalogSource) -> Result<ModelCatalog, CatalogError> {
+    let db = ModelDatabase::from_json(&entry.body).map_err(|message| CatalogError::Invalid {
+        url: entry.url.clone(),
+        message,
+    })?;
+    Ok(ModelCatalog {
+        url: entry.url.clone(),
+        models: db.into_models(),
+        updated_at: UNIX_EPOCH + Duration::from_secs(entry.updated_at),
+        source,
+    })
+}
+
+/// The cache entry at `path`, if it holds the catalog for `url`.
+fn read_cache(path: &Path, url: &str) -> Option<CacheEntry> {
+    let text = std::fs::read_to_string(path).ok()?;
+    let entry: CacheEntry = serde_json::from_str(&text).ok()?;
+    (entry.url == url).then_some(entry)
+}
+
+fn write_cache(path: Option<&Path>, entry: &CacheEntry) {
+    let Some(path) = path else {
+        return;
+    };
+    let result = path
+        .parent()
+        .map_or(Ok(()), std::fs::create_dir_all)
+        .and_then(|()| {
+            let json = serde_json::to_string(entry).map_err(std::io::Error::other)?;
+            std::fs::write(path, json)
+        });
+    if let Err(e) = result {
+        log::warn!("can't cache remote catalog in {}: {}", path.display(), e);
+    }
+}
+
+fn unix_secs(time: SystemTime) -> u64 {
+    time.duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
+
+/// Coarse age, e.g. "just now", "5m ago", "3h ago", "2d ago".
+fn format_age(secs: u64) -> String {
+    match secs {
+        0..60 => "just now".to_string(),
+        60..3_600 => format!("{}m ago", secs / 60),
+        3_600..86_400 => format!("{}h ago", secs / 3_600),
+        _ => format!("{}d ago", secs / 86_400),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CATALOG: &str = r#"[{
+        "name": "org/test-7b",
+        "provider": "org",
+        "parameter_count": "7B",
+        "min_ram_gb": 4.0,
+        "recommended_ram_gb": 8.0,
+        "min_vram_gb": 4.0,
+        "quantization": "Q4_K_M",
+        "context_length": 8192,
+        "use_case": "General"
+    }]"#;
+
+    #[test]
+    fn test_format_age() {
+        assert_eq!(format_age(5), "just now");
+        assert_eq!(format_age(300), "5m ago");
+        assert_eq!(format_age(3 * 3_600 + 59), "3h ago");
+        assert_eq!(format_age(2 * 86_400), "2d ago");
+    }
+
+    #[test]
+    fn test_fetch_falls_back_to_cache_when_offline() {
+        let path = std::env::temp_dir().join(format!(
+            "llmfit-remote-catalog-test-{}.json",
+            std::process::id()
+        ));
+        // Nothing listens on the discard port, so the connection is refused
+        let url = "http://127.0.0.1:9/catalog.json";
+        assert!(matches!(
+            fetch(url, Some(&path)),
+            Err(CatalogError::Fetch { .. })
+        ));
+
+        let entry = CacheEntry {
+            url: url.to_string(),
+            etag: Some("\"v1\"".to_string()),
+            updated_at: 1_700_000_000,
+            body: CATALOG.to_string(),
+        };
+        write_cache(Some(&path), &entry);
+        let catalog = fetch(url, Some(&path)).unwrap();
+        assert_eq!(catalog.source, CatalogSource::Cache);
+        assert_eq!(catalog.models.len(), 1);
+        let now = UNIX_EPOCH + Duration::from_secs(1_700_000_000 + 3 * 3_600);
+        assert_eq!(
+            catalog.summary(now),
+            "catalog: 1 model, updated 3h ago (offline)"
+        );
+
+        // A cache for another URL isn't used
+        assert!(read_cache(&path, "http://127.0.0.1:9/other.json").is_none());
+        let _ = std::fs::remove_file(&path);
+    }
+}
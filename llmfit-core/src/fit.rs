@@ -1,5 +1,10 @@
-use crate::hardware::{GpuBackend, SystemSpecs};
-use crate::models::{self, LlmModel, UseCase};
+use std::collections::HashMap;
+
+use rayon::prelude::*;
+
+use crate::activity;
+use crate::hardware::{GpuBackend, MemoryUnits, SystemSpecs, fmt_mem};
+use crate::models::{self, LlmModel, QuantTable, UseCase};
 
 /// Inference runtime — the software framework used for inference.
 /// Orthogonal to `GpuBackend` which represents hardware.
@@ -78,6 +83,108 @@ pub enum RunMode {
     CpuOnly,    // Entirely in system RAM, no GPU -- slow
 }
 
+/// How a fully GPU-resident model is laid out on a multi-GPU machine.
+#[derive(Debug, Clone, Copy, PartialEq, serde::Serialize)]
+pub enum GpuPlacement {
+    /// Fits on one card of `vram_gb`.
+    SingleGpu { vram_gb: f64 },
+    /// Has to be split across `gpus` cards of `vram_gb` each.
+    TensorParallel { gpus: u32, vram_gb: f64 },
+}
+
+impl GpuPlacement {
+    pub fn label(&self, units: MemoryUnits) -> String {
+        match self {
+            GpuPlacement::SingleGpu { vram_gb } => {
+                format!("Fits on GPU 0 ({})", fmt_mem(*vram_gb, 0, units))
+            }
+            GpuPlacement::TensorParallel { gpus, vram_gb } => format!(
+                "Needs tensor-parallel across {} GPUs ({} each)",
+                gpus,
+                fmt_mem(*vram_gb, 0, units)
+            ),
+        }
+    }
+}
+
+/// Where `mem_required` GB lands on the primary GPU group. `None` with a
+/// single card or unified memory, where there's no placement to choose.
+pub fn gpu_placement(system: &SystemSpecs, mem_required: f64) -> Option<GpuPlacement> {
+    if !system.has_gpu || system.unified_memory || system.gpu_count < 2 {
+        return None;
+    }
+    let vram_gb = system.gpu_vram_gb.filter(|&v| v > 0.0)?;
+    if mem_required <= vram_gb {
+        Some(GpuPlacement::SingleGpu { vram_gb })
+    } else {
+        let gpus = ((mem_required / vram_gb).ceil() as u32).min(system.gpu_count);
+        Some(GpuPlacement::TensorParallel { gpus, vram_gb })
+    }
+}
+
+/// An even tensor-parallel shard of one model across identical GPUs.
+///
+/// Unlike the pooled-VRAM approximation, each card has to hold its own
+/// slice of the weights and KV cache plus a full copy of the runtime
+/// overhead, which is how vLLM and SGLang actually place a model.
+#[derive(Debug, Clone, Copy, PartialEq, serde::Serialize)]
+pub struct TensorParallelSplit {
+    /// Value to pass as `--tensor-parallel-size`.
+    pub gpus: u32,
+    pub per_gpu_vram_gb: f64,
+    /// Weights/N + KV cache/N + runtime overhead on each card.
+    pub per_gpu_required_gb: f64,
+    pub fits: bool,
+}
+
+impl TensorParallelSplit {
+    pub fn per_gpu_utilization_pct(&self) -> f64 {
+        self.per_gpu_required_gb / self.per_gpu_vram_gb * 100.0
+    }
+
+    pub fn label(&self, units: MemoryUnits) -> String {
+        format!(
+            "{} across {} GPUs ({} of {} each, {:.0}%)",
+            if self.fits { "Fits" } else { "Doesn't fit" },
+            self.gpus,
+            fmt_mem(self.per_gpu_required_gb, 1, units),
+            fmt_mem(self.per_gpu_vram_gb, 0, units),
+            self.per_gpu_utilization_pct()
+        )
+    }
+}
+
+/// Smallest tensor-parallel size that holds the model on `gpu_count` cards
+/// of `per_gpu_vram_gb`. Sizes are powers of two, since the attention heads
+/// have to divide evenly. When none fits, the largest size tried is returned
+/// with `fits: false`; `None` with fewer than two cards.
+pub fn tensor_parallel_split(
+    weights_gb: f64,
+    kv_cache_gb: f64,
+    per_gpu_vram_gb: f64,
+    gpu_count: u32,
+) -> Option<TensorParallelSplit> {
+    let mut best = None;
+    let mut gpus = 2;
+    while gpus <= gpu_count {
+        let split = TensorParallelSplit {
+            gpus,
+            per_gpu_vram_gb,
+            per_gpu_required_gb: (weights_gb + kv_cache_gb) / gpus as f64 + 0.5,
+            fits: false,
+        };
+        if split.per_gpu_required_gb <= per_gpu_vram_gb {
+            return Some(TensorParallelSplit {
+                fits: true,
+                ..split
+            });
+        }
+        best = Some(split);
+        gpus *= 2;
+    }
+    best
+}
+
 /// Multi-dimensional score components (0-100 each).
 #[derive(Debug, Clone, Copy, serde::Serialize)]
 pub struct ScoreComponents {
@@ -97,8 +204,12 @@ pub struct ModelFit {
     pub fit_level: FitLevel,
     pub run_mode: RunMode,
     pub memory_required_gb: f64, // the memory that matters for this run mode
+    /// Typical-case need: same path and quant, but only a short context
+    /// ([`TYPICAL_CONTEXT_TOKENS`]) in use. `memory_required_gb` is the
+    /// worst case at full context and is what `fit_level` is scored on.
+    pub memory_required_low_gb: f64,
     pub memory_available_gb: f64, // the memory pool being used
-    pub utilization_pct: f64,    // memory_required / memory_available * 100
+    pub utilization_pct: f64,     // memory_required / memory_available * 100
     pub notes: Vec<String>,
     pub moe_offloaded_gb: Option<f64>, // GB of inactive experts offloaded to RAM
     pub score: f64,                    // weighted composite score 0-100
@@ -108,6 +219,185 @@ pub struct ModelFit {
     pub use_case: UseCase,         // inferred use case category
     pub runtime: InferenceRuntime, // inference runtime (MLX or llama.cpp)
     pub installed: bool,           // model found in a local runtime provider
+    /// Context length memory was estimated at: the model's maximum, or less
+    /// under a context cap.
+    pub estimated_context: u32,
+    /// Requests the KV cache was sized for; see [`FitOptions::concurrency`].
+    pub concurrency: u32,
+    /// Size of the weights at `best_quant`.
+    pub weights_gb: f64,
+}
+
+/// The limit that decided a fit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BindingConstraint {
+    /// GPU memory (shared memory on unified systems).
+    Vram,
+    /// System RAM.
+    Ram,
+    /// Memory has headroom; speed is bound by memory bandwidth.
+    Compute,
+}
+
+impl BindingConstraint {
+    pub fn label(&self) -> &'static str {
+        match self {
+            BindingConstraint::Vram => "VRAM",
+            BindingConstraint::Ram => "RAM",
+            BindingConstraint::Compute => "compute",
+        }
+    }
+}
+
+/// The terms of the standard memory estimate.
+#[derive(Debug, Clone, Copy, PartialEq, serde::Serialize)]
+pub struct MemoryBreakdown {
+    pub weights_gb: f64,
+    pub kv_cache_gb: f64,
+    pub overhead_gb: f64,
+    /// Context the KV cache is sized for; half the estimated context when
+    /// quant selection had to shrink it to fit.
+    pub context_tokens: u32,
+    /// Requests served at once; `kv_cache_gb` covers all of them.
+    pub requests: u32,
+}
+
+/// Why a model got its fit, from [`ModelFit::explain`].
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+pub struct FitExplanation {
+    pub binding: BindingConstraint,
+    /// The memory pool the fit was measured against (VRAM or RAM), even
+    /// when `binding` is compute.
+    pub pool: BindingConstraint,
+    pub memory_required_gb: f64,
+    pub memory_available_gb: f64,
+    /// Memory left in `pool` after the model; negative when it's over.
+    pub margin_gb: f64,
+    pub quant: String,
+    /// `None` when the requirement doesn't come from the standard formula
+    /// (MoE active experts, or an exact size from a checkpoint).
+    pub breakdown: Option<MemoryBreakdown>,
+    /// Steps that led to the run mode, in order.
+    pub run_mode_path: Vec<String>,
+}
+
+impl FitExplanation {
+    /// "2.1 GiB of VRAM to spare", or "over by 3.4 GiB of RAM".
+    pub fn margin_text(&self, units: MemoryUnits) -> String {
+        if self.margin_gb >= 0.0 {
+            format!(
+                "{} of {} to spare",
+                fmt_mem(self.margin_gb, 1, units),
+                self.pool.label()
+            )
+        } else {
+            format!(
+                "over by {} of {}",
+                fmt_mem(-self.margin_gb, 1, units),
+                self.pool.label()
+            )
+        }
+    }
+}
+
+/// Which memory figure a fit is measured against.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FitTarget {
+    /// The estimated requirement at the chosen quant: the bare floor.
+    #[default]
+    Minimum,
+    /// At least the catalog's `recommended_ram_gb`, so a model only scores
+    /// well when there's headroom for a comfortable experience.
+    Recommended,
+}
+
+impl FitTarget {
+    pub fn label(&self) -> &'static str {
+        match self {
+            FitTarget::Minimum => "min",
+            FitTarget::Recommended => "recommended",
+        }
+    }
+}
+
+/// Assumptions an analysis is made under. The default estimates each
+/// model at its full context, picks the run mode automatically, scores
+/// against the bare minimum, sizes the KV cache for one request, uses the
+/// built-in quantization table and writes notes in binary units.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FitOptions<'a> {
+    /// Cap on the context length memory is estimated at.
+    pub context_limit: Option<u32>,
+    /// Execution path to pin; see [`ModelFit::analyze_with_run_mode`].
+    pub run_mode: Option<RunMode>,
+    /// Memory figure fits are scored against.
+    pub target: FitTarget,
+    /// Requests served at once. Each holds its own KV cache, so the cache
+    /// scales with this while the weights stay the same. Zero is treated
+    /// as one.
+    pub concurrency: u32,
+    /// Bytes per weight for each quantization.
+    pub quants: &'a QuantTable,
+    /// Units memory figures in notes are written in.
+    pub units: MemoryUnits,
+}
+
+impl Default for FitOptions<'_> {
+    fn default() -> Self {
+        FitOptions {
+            context_limit: None,
+            run_mode: None,
+            target: FitTarget::Minimum,
+            concurrency: 1,
+            quants: QuantTable::builtin(),
+            units: MemoryUnits::Binary,
+        }
+    }
+}
+
+impl FitOptions<'_> {
+    pub fn with_context_limit(context_limit: Option<u32>) -> Self {
+        FitOptions {
+            context_limit,
+            ..Self::default()
+        }
+    }
+
+    fn requests(&self) -> u32 {
+        self.concurrency.max(1)
+    }
+}
+
+/// Memory to score against under `target`. MoE offload sizes only the
+/// active experts, which the whole-model recommendation doesn't describe.
+fn target_requirement(
+    target: FitTarget,
+    model: &LlmModel,
+    run_mode: RunMode,
+    mem_required: f64,
+) -> f64 {
+    if target == FitTarget::Recommended && run_mode != RunMode::MoeOffload {
+        mem_required.max(model.recommended_ram_gb)
+    } else {
+        mem_required
+    }
+}
+
+/// Context assumed in use for the low end of the memory range.
+pub const TYPICAL_CONTEXT_TOKENS: u32 = 2048;
+
+/// Bits per cached key/value element: fp16, the llama.cpp, MLX and vLLM
+/// default.
+pub const DEFAULT_KV_BITS: u32 = 16;
+
+/// KV-cache memory in GB: a key and a value vector of `hidden_size`
+/// elements per layer for each of `context_length` tokens, at `kv_bits`
+/// each. For grouped-query attention pass the KV width
+/// (`num_key_value_heads × head_dim`) rather than the model's hidden size.
+pub fn kv_cache_gb(context_length: u32, num_layers: u32, hidden_size: u32, kv_bits: u32) -> f64 {
+    let bytes_per_token = 2.0 * num_layers as f64 * hidden_size as f64 * kv_bits as f64 / 8.0;
+    bytes_per_token * context_length as f64 / 1e9
 }
 
 impl ModelFit {
@@ -120,15 +410,46 @@ impl ModelFit {
         system: &SystemSpecs,
         context_limit: Option<u32>,
     ) -> Self {
+        Self::analyze_with_run_mode(model, system, context_limit, None)
+    }
+
+    /// Like [`ModelFit::analyze_with_context_limit`], but with the execution
+    /// path pinned instead of picked automatically. `Some(RunMode::CpuOnly)`
+    /// ignores the GPU and puts all memory pressure on RAM;
+    /// `Some(RunMode::Gpu)` scores against VRAM alone, with no offload to RAM;
+    /// `Some(RunMode::CpuOffload)` splits layers between a discrete GPU and
+    /// RAM even when the model would fit in VRAM. Other modes (and `None`)
+    /// mean automatic selection.
+    pub fn analyze_with_run_mode(
+        model: &LlmModel,
+        system: &SystemSpecs,
+        context_limit: Option<u32>,
+        forced_run_mode: Option<RunMode>,
+    ) -> Self {
+        let options = FitOptions {
+            context_limit,
+            run_mode: forced_run_mode,
+            ..FitOptions::default()
+        };
+        Self::analyze_with_options(model, system, &options)
+    }
+
+    /// Analyze `model` under every assumption in `options`.
+    pub fn analyze_with_options(
+        model: &LlmModel,
+        system: &SystemSpecs,
+        options: &FitOptions,
+    ) -> Self {
+        let context_limit = options.context_limit;
+        let requests = options.requests();
+        let quants = options.quants;
+        let units = options.units;
         let mut notes = Vec::new();
         let estimation_ctx = context_limit
             .map(|limit| limit.min(model.context_length))
             .unwrap_or(model.context_length);
 
-        let min_vram = model.min_vram_gb.unwrap_or(model.min_ram_gb);
         let use_case = UseCase::from_model(model);
-        let default_mem_required =
-            model.estimate_memory_gb(model.quantization.as_str(), estimation_ctx);
         if estimation_ctx < model.context_length {
             notes.push(format!(
                 "Context capped for estimation: {} -> {} tokens",
@@ -138,83 +459,17 @@ impl ModelFit {
 
         // Determine inference runtime up front so path selection can use
         // the correct quantization hierarchy.
-        let runtime = if model.is_prequantized() {
-            InferenceRuntime::Vllm
-        } else if system.backend == GpuBackend::Metal && system.unified_memory {
-            InferenceRuntime::Mlx
-        } else {
-            InferenceRuntime::LlamaCpp
-        };
-        let choose_quant =
-            |budget: f64| best_quant_for_runtime_budget(model, runtime, budget, estimation_ctx);
+        let runtime = runtime_for(model, system);
 
         // Step 1: pick the best available execution path
         // Step 2: score memory fit purely on headroom in that path's memory pool
-        let (run_mode, mem_required, mem_available) = if system.has_gpu {
-            if system.unified_memory {
-                // Unified memory (Apple Silicon or NVIDIA Tegra/Grace Blackwell):
-                // GPU and CPU share the same memory pool.
-                // No CpuOffload -- there's no separate pool to spill to.
-                if let Some(pool) = system.gpu_vram_gb {
-                    notes.push("Unified memory: GPU and CPU share the same pool".to_string());
-                    if model.is_moe {
-                        notes.push(format!(
-                            "MoE: {}/{} experts active (all share unified memory pool)",
-                            model.active_experts.unwrap_or(0),
-                            model.num_experts.unwrap_or(0)
-                        ));
-                    }
-                    if model.is_moe {
-                        (RunMode::Gpu, min_vram, pool)
-                    } else if let Some((_, best_mem)) = choose_quant(pool) {
-                        (RunMode::Gpu, best_mem, pool)
-                    } else {
-                        (RunMode::Gpu, default_mem_required, pool)
-                    }
-                } else {
-                    cpu_path(model, system, runtime, estimation_ctx, &mut notes)
-                }
-            } else if let Some(system_vram) = system.total_gpu_vram_gb {
-                // Use total VRAM across all same-model GPUs for fit scoring.
-                // Multi-GPU inference (tensor splitting) is supported by llama.cpp, vLLM, etc.
-                if model.is_moe && min_vram <= system_vram {
-                    // Fits in VRAM -- GPU path
-                    notes.push("GPU: model loaded into VRAM".to_string());
-                    if model.is_moe {
-                        notes.push(format!(
-                            "MoE: all {} experts loaded in VRAM (optimal)",
-                            model.num_experts.unwrap_or(0)
-                        ));
-                    }
-                    (RunMode::Gpu, min_vram, system_vram)
-                } else if model.is_moe {
-                    // MoE model: try expert offloading before CPU fallback
-                    moe_offload_path(model, system, system_vram, min_vram, runtime, &mut notes)
-                } else if let Some((_, best_mem)) = choose_quant(system_vram) {
-                    notes.push("GPU: model loaded into VRAM".to_string());
-                    (RunMode::Gpu, best_mem, system_vram)
-                } else if let Some((_, best_mem)) = choose_quant(system.available_ram_gb) {
-                    // Doesn't fit in VRAM, spill to system RAM
-                    notes.push("GPU: insufficient VRAM, spilling to system RAM".to_string());
-                    notes.push("Performance will be significantly reduced".to_string());
-                    (RunMode::CpuOffload, best_mem, system.available_ram_gb)
-                } else {
-                    // Doesn't fit anywhere -- report against VRAM since GPU is preferred
-                    notes.push("Insufficient VRAM and system RAM".to_string());
-                    notes.push(format!(
-                        "Need {:.1} GB VRAM or {:.1} GB system RAM",
-                        min_vram, model.min_ram_gb
-                    ));
-                    (RunMode::Gpu, default_mem_required, system_vram)
-                }
-            } else {
-                // GPU detected but VRAM unknown -- fall through to CPU
-                notes.push("GPU detected but VRAM unknown".to_string());
-                cpu_path(model, system, runtime, estimation_ctx, &mut notes)
-            }
-        } else {
-            cpu_path(model, system, runtime, estimation_ctx, &mut notes)
+        let need = MemoryNeed::Estimated {
+            ctx: estimation_ctx,
         };
+        let (run_mode, mem_required, mem_available) =
+            select_run_path(model, system, runtime, need, options, &mut notes);
+
+        let mem_required = required_for_target(options, model, run_mode, mem_required, &mut notes);
 
         // Score fit purely on memory headroom (Perfect requires GPU)
         let fit_level = score_fit(
@@ -241,10 +496,15 @@ impl ModelFit {
 
         // Compute MoE offloaded amount if applicable
         let moe_offloaded_gb = if run_mode == RunMode::MoeOffload {
-            model.moe_offloaded_ram_gb()
+            model.moe_offloaded_ram_gb(quants)
         } else {
             None
         };
+        let ram_resident = match run_mode {
+            RunMode::MoeOffload => moe_offloaded_gb.unwrap_or(0.0),
+            _ => mem_required,
+        };
+        let fit_level = swap_guard(fit_level, run_mode, ram_resident, system, units, &mut notes);
 
         // Dynamic quantization: find best quant that fits
         // Pre-quantized models (AWQ/GPTQ) have a fixed quantization — skip dynamic selection.
@@ -258,11 +518,17 @@ impl ModelFit {
                 models::QUANT_HIERARCHY
             };
             model
-                .best_quant_for_budget_with(budget, estimation_ctx, hierarchy)
+                .best_quant_for_budget_with(budget, estimation_ctx, hierarchy, requests, quants)
                 .or_else(|| {
                     // Fall back to GGUF hierarchy if MLX quants don't fit
                     if runtime == InferenceRuntime::Mlx {
-                        model.best_quant_for_budget(budget, estimation_ctx)
+                        model.best_quant_for_budget_with(
+                            budget,
+                            estimation_ctx,
+                            models::QUANT_HIERARCHY,
+                            requests,
+                            quants,
+                        )
                     } else {
                         None
                     }
@@ -279,8 +545,21 @@ impl ModelFit {
             model.quantization.clone()
         };
 
+        if run_mode != RunMode::CpuOnly
+            && let Some(note) = cuda_version_note(system, runtime, &best_quant_str)
+        {
+            notes.push(note);
+        }
+        notes.extend(disk_space_note(
+            model,
+            &best_quant_str,
+            system,
+            quants,
+            units,
+        ));
+
         // Speed estimation
-        let estimated_tps = estimate_tps(model, &best_quant_str, system, run_mode, runtime);
+        let estimated_tps = estimate_tps(model, &best_quant_str, system, run_mode, runtime, quants);
 
         // Add runtime comparison note on Apple Silicon
         if runtime == InferenceRuntime::Mlx {
@@ -290,6 +569,7 @@ impl ModelFit {
                 system,
                 run_mode,
                 InferenceRuntime::LlamaCpp,
+                quants,
             );
             if llamacpp_tps > 0.1 {
                 let speedup = ((estimated_tps / llamacpp_tps - 1.0) * 100.0).round();
@@ -302,6 +582,12 @@ impl ModelFit {
             }
         }
 
+        if run_mode == RunMode::Gpu
+            && let Some(placement) = gpu_placement(system, mem_required)
+        {
+            notes.push(placement.label(units));
+        }
+
         // Multi-dimensional scoring
         let score_components = compute_scores(
             model,
@@ -319,12 +605,23 @@ impl ModelFit {
                 estimated_tps
             ));
         }
+        if run_mode == RunMode::CpuOnly {
+            notes.push(cpu_tps_note(model, &best_quant_str, system, quants));
+        }
+
+        // Low end of the range: same path and quant with only a short context in use
+        let typical_ctx = estimation_ctx.min(TYPICAL_CONTEXT_TOKENS);
+        let kv_saved =
+            (model.kv_cache_gb(estimation_ctx) - model.kv_cache_gb(typical_ctx)) * requests as f64;
+        let mem_required_low = (mem_required - kv_saved).max(0.0);
 
-        ModelFit {
+        let weights_gb = model.weights_file_gb(&best_quant_str, quants);
+        let fit = ModelFit {
             model: model.clone(),
             fit_level,
             run_mode,
             memory_required_gb: mem_required,
+            memory_required_low_gb: mem_required_low,
             memory_available_gb: mem_available,
             utilization_pct,
             notes,
@@ -336,7 +633,167 @@ impl ModelFit {
             use_case,
             runtime,
             installed: false, // set later by App after provider detection
+            estimated_context: estimation_ctx,
+            concurrency: requests,
+            weights_gb,
+        };
+        record_decision(&fit, system, units);
+        fit
+    }
+
+    /// Analyze `model` as if run at `quant` instead of a dynamically chosen
+    /// quantization: its catalog figures are rescaled with
+    /// [`crate::models::requantize`] and the memory need estimated at that
+    /// quant and the (optionally capped) context. The run mode and fit
+    /// target in `options` apply as in [`ModelFit::analyze_with_options`].
+    pub fn analyze_at_quant(
+        model: &LlmModel,
+        system: &SystemSpecs,
+        options: &FitOptions,
+        quant: &str,
+    ) -> Self {
+        let quants = options.quants;
+        let model = crate::models::requantize(model, quant, quants);
+        let ctx = options
+            .context_limit
+            .map(|limit| limit.min(model.context_length))
+            .unwrap_or(model.context_length);
+        let mem_required = model.estimate_memory_gb_for(quant, ctx, options.requests(), quants);
+        let mut fit = Self::analyze_fixed_memory(&model, system, mem_required, options);
+        fit.estimated_context = ctx;
+        fit.concurrency = options.requests();
+        fit.notes
+            .insert(0, format!("Quantization pinned to {}", quant));
+        fit
+    }
+
+    /// Analyze a model whose total memory need is already known exactly
+    /// (e.g. derived from a checkpoint's `config.json`) and that runs at its
+    /// own quantization rather than a dynamically chosen one. The run mode
+    /// and fit target in `options` are honored; its context and concurrency
+    /// are taken to be part of `mem_required` already.
+    pub fn analyze_fixed_memory(
+        model: &LlmModel,
+        system: &SystemSpecs,
+        mem_required: f64,
+        options: &FitOptions,
+    ) -> Self {
+        let (quants, units) = (options.quants, options.units);
+        let mut notes = Vec::new();
+        let use_case = UseCase::from_model(model);
+        let runtime = runtime_for(model, system);
+
+        let (run_mode, mem_required, mem_available) = select_run_path(
+            model,
+            system,
+            runtime,
+            MemoryNeed::Fixed(mem_required),
+            options,
+            &mut notes,
+        );
+
+        let mem_required = required_for_target(options, model, run_mode, mem_required, &mut notes);
+
+        let fit_level = score_fit(
+            mem_required,
+            mem_available,
+            model.recommended_ram_gb,
+            run_mode,
+        );
+        let fit_level = swap_guard(fit_level, run_mode, mem_required, system, units, &mut notes);
+        let utilization_pct = if mem_available > 0.0 {
+            (mem_required / mem_available) * 100.0
+        } else {
+            f64::INFINITY
+        };
+
+        let estimated_tps = estimate_tps(
+            model,
+            &model.quantization,
+            system,
+            run_mode,
+            runtime,
+            quants,
+        );
+        let score_components = compute_scores(
+            model,
+            &model.quantization,
+            use_case,
+            estimated_tps,
+            mem_required,
+            mem_available,
+        );
+        let score = weighted_score(score_components, use_case);
+        if estimated_tps > 0.0 {
+            notes.push(format!(
+                "Baseline estimated speed: {:.1} tok/s",
+                estimated_tps
+            ));
+        }
+        if run_mode == RunMode::CpuOnly {
+            notes.push(cpu_tps_note(model, &model.quantization, system, quants));
+        }
+
+        let fit = ModelFit {
+            model: model.clone(),
+            fit_level,
+            run_mode,
+            memory_required_gb: mem_required,
+            memory_required_low_gb: mem_required,
+            memory_available_gb: mem_available,
+            utilization_pct,
+            notes,
+            moe_offloaded_gb: None,
+            score,
+            score_components,
+            estimated_tps,
+            best_quant: model.quantization.clone(),
+            use_case,
+            runtime,
+            installed: false,
+            estimated_context: model.context_length,
+            concurrency: 1,
+            weights_gb: model.weights_file_gb(&model.quantization, quants),
+        };
+        activity::fit(
+            &model.name,
+            format!(
+                "{}: exact {} / {} ({:.0}%) at {} → {}, {}",
+                model.name,
+                fmt_mem(mem_required, 1, units),
+                fmt_mem(mem_available, 1, units),
+                utilization_pct,
+                fit.best_quant,
+                fit.fit_text(),
+                fit.run_mode_text()
+            ),
+        );
+        fit
+    }
+
+    /// Single-card vs tensor-parallel placement, for fits that run on GPU.
+    pub fn gpu_placement(&self, system: &SystemSpecs) -> Option<GpuPlacement> {
+        if self.run_mode == RunMode::Gpu {
+            gpu_placement(system, self.memory_required_gb)
+        } else {
+            None
+        }
+    }
+
+    /// How this model shards across the primary GPU group when it doesn't
+    /// fit on one card. `None` on single-GPU or unified-memory systems.
+    pub fn tensor_parallel_split(&self, system: &SystemSpecs) -> Option<TensorParallelSplit> {
+        if !system.has_gpu || system.unified_memory || system.gpu_count < 2 {
+            return None;
+        }
+        let vram_gb = system.gpu_vram_gb.filter(|&v| v > 0.0)?;
+        if self.memory_required_gb <= vram_gb {
+            return None;
         }
+        // Whatever isn't weights or overhead is KV cache and buffers
+        let sharded = (self.memory_required_gb - 0.5).max(0.0);
+        let weights = self.weights_gb.min(sharded);
+        tensor_parallel_split(weights, sharded - weights, vram_gb, system.gpu_count)
     }
 
     pub fn fit_emoji(&self) -> &str {
@@ -369,8 +826,111 @@ impl ModelFit {
             RunMode::CpuOnly => "CPU",
         }
     }
+
+    /// Weights + KV cache + overhead at the chosen quant, if that's what
+    /// `memory_required_gb` was computed from. Quant selection may have
+    /// halved the context (see `best_quant_for_budget_with`), so both are tried.
+    pub fn memory_breakdown(&self) -> Option<MemoryBreakdown> {
+        [self.estimated_context, self.estimated_context / 2]
+            .into_iter()
+            .map(|ctx| MemoryBreakdown {
+                weights_gb: self.weights_gb,
+                kv_cache_gb: self.model.kv_cache_gb(ctx) * self.concurrency as f64,
+                overhead_gb: 0.5,
+                context_tokens: ctx,
+                requests: self.concurrency,
+            })
+            .find(|b| {
+                (b.weights_gb + b.kv_cache_gb + b.overhead_gb - self.memory_required_gb).abs()
+                    < 0.05
+            })
+    }
+
+    /// Structured rationale for this fit: what bound it, how the memory
+    /// requirement adds up, and how the run mode was chosen. The detail
+    /// view, JSON output and activity log all render this.
+    pub fn explain(&self, system: &SystemSpecs, units: MemoryUnits) -> FitExplanation {
+        let pool = match self.run_mode {
+            RunMode::Gpu | RunMode::MoeOffload => BindingConstraint::Vram,
+            RunMode::CpuOffload | RunMode::CpuOnly => BindingConstraint::Ram,
+        };
+        let binding = if self.fit_level == FitLevel::Perfect {
+            BindingConstraint::Compute
+        } else {
+            pool
+        };
+        FitExplanation {
+            binding,
+            pool,
+            memory_required_gb: self.memory_required_gb,
+            memory_available_gb: self.memory_available_gb,
+            margin_gb: self.memory_available_gb - self.memory_required_gb,
+            quant: self.best_quant.clone(),
+            breakdown: self.memory_breakdown(),
+            run_mode_path: self.run_mode_path(system, units),
+        }
+    }
+
+    fn run_mode_path(&self, system: &SystemSpecs, units: MemoryUnits) -> Vec<String> {
+        let mut path = Vec::new();
+        if let Some(forced) = self.notes.iter().find(|n| n.starts_with("Run mode forced")) {
+            path.push(forced.clone());
+        } else if !system.has_gpu {
+            path.push("No GPU detected; only system RAM is available".to_string());
+        } else if let Some(limit) = system.gpu_wired_limit_gb {
+            path.push(format!(
+                "Unified memory: macOS lets the GPU wire {} of {}",
+                fmt_mem(limit, 1, units),
+                fmt_mem(system.total_ram_gb, 1, units)
+            ));
+        } else if system.unified_memory {
+            path.push(format!(
+                "Unified memory: GPU and CPU share {}",
+                fmt_mem(system.gpu_vram_gb.unwrap_or(system.total_ram_gb), 1, units)
+            ));
+        } else if let Some(vram) = system.total_gpu_vram_gb {
+            path.push(format!(
+                "{} VRAM across {} GPU{}",
+                fmt_mem(vram, 1, units),
+                system.gpu_count,
+                if system.gpu_count == 1 { "" } else { "s" }
+            ));
+        } else {
+            path.push("GPU detected but VRAM unknown; using system RAM".to_string());
+        }
+
+        let need = format!(
+            "{} at {}",
+            fmt_mem(self.memory_required_gb, 1, units),
+            self.best_quant
+        );
+        let fits = self.fit_level != FitLevel::TooTight;
+        path.push(match (self.run_mode, fits) {
+            (RunMode::Gpu, true) => format!("{} fits in GPU memory → GPU", need),
+            (RunMode::Gpu, false) => {
+                format!("{} fits neither VRAM nor RAM; judged against VRAM", need)
+            }
+            (RunMode::MoeOffload, _) => format!(
+                "Full model exceeds VRAM; active experts ({}) fit → MoE offload",
+                need
+            ),
+            (RunMode::CpuOffload, _) => {
+                format!("Exceeds VRAM; {} spills into system RAM → CPU+GPU", need)
+            }
+            (RunMode::CpuOnly, true) => format!("{} runs from system RAM → CPU", need),
+            (RunMode::CpuOnly, false) => format!("{} exceeds system RAM", need),
+        });
+        if let Some(placement) = self.gpu_placement(system) {
+            path.push(placement.label(units));
+        }
+        path
+    }
 }
 
+/// How much more memory than the requirement a GPU or offload run needs
+/// to score Good rather than Marginal.
+const GOOD_HEADROOM: f64 = 1.2;
+
 /// Pure memory headroom scoring.
 /// - GPU (including Apple Silicon unified memory): can reach Perfect.
 /// - CpuOffload: caps at Good.
@@ -389,7 +949,7 @@ fn score_fit(
         RunMode::Gpu => {
             if recommended <= mem_available {
                 FitLevel::Perfect
-            } else if mem_available >= mem_required * 1.2 {
+            } else if mem_available >= mem_required * GOOD_HEADROOM {
                 FitLevel::Good
             } else {
                 FitLevel::Marginal
@@ -398,7 +958,7 @@ fn score_fit(
         RunMode::MoeOffload => {
             // MoE expert offloading -- GPU handles inference, inactive experts in RAM
             // Good performance with some latency on expert switching
-            if mem_available >= mem_required * 1.2 {
+            if mem_available >= mem_required * GOOD_HEADROOM {
                 FitLevel::Good
             } else {
                 FitLevel::Marginal
@@ -406,7 +966,7 @@ fn score_fit(
         }
         RunMode::CpuOffload => {
             // Mixed GPU/CPU -- decent but not ideal
-            if mem_available >= mem_required * 1.2 {
+            if mem_available >= mem_required * GOOD_HEADROOM {
                 FitLevel::Good
             } else {
                 FitLevel::Marginal
@@ -419,30 +979,348 @@ fn score_fit(
     }
 }
 
-/// Determine memory pool for CPU-only inference.
-fn cpu_path(
+/// Explain Metal's cap on the unified pool when it holds back part of RAM.
+fn wired_limit_note(system: &SystemSpecs, units: MemoryUnits) -> Option<String> {
+    let limit = system.gpu_wired_limit_gb?;
+    (limit < system.total_ram_gb).then(|| {
+        format!(
+            "macOS lets the GPU wire only {} of {}; raise it with sudo sysctl iogpu.wired_limit_mb",
+            fmt_mem(limit, 1, units),
+            fmt_mem(system.total_ram_gb, 1, units)
+        )
+    })
+}
+
+/// Warn when the model file at `quant` won't fit on the download disk.
+fn disk_space_note(
     model: &LlmModel,
+    quant: &str,
     system: &SystemSpecs,
+    quants: &QuantTable,
+    units: MemoryUnits,
+) -> Option<String> {
+    let free = system.free_disk_gb?;
+    let size = model.weights_file_gib(quant, quants);
+    (size > free).then(|| {
+        format!(
+            "Download needs ~{} but only {} is free on disk",
+            fmt_mem(size, 1, units),
+            fmt_mem(free, 1, units)
+        )
+    })
+}
+
+/// Demote a fit to Too Tight when the part of the model held in system RAM
+/// is more than the machine physically has, as when `--memory` sets a
+/// unified pool above installed RAM. It would only "run" by paging to swap,
+/// which is far too slow to count. `ram_resident` is the whole requirement
+/// for CPU and unified runs and the offloaded experts for MoE offload.
+fn swap_guard(
+    fit_level: FitLevel,
+    run_mode: RunMode,
+    ram_resident: f64,
+    system: &SystemSpecs,
+    units: MemoryUnits,
+    notes: &mut Vec<String>,
+) -> FitLevel {
+    let in_ram = match run_mode {
+        RunMode::CpuOnly | RunMode::CpuOffload | RunMode::MoeOffload => true,
+        RunMode::Gpu => system.unified_memory,
+    };
+    if fit_level == FitLevel::TooTight
+        || !in_ram
+        || system.total_ram_gb <= 0.0
+        || ram_resident <= system.total_ram_gb
+    {
+        return fit_level;
+    }
+    let need = fmt_mem(ram_resident, 1, units);
+    let physical = fmt_mem(system.total_ram_gb, 1, units);
+    if system.swap_total_gb > 0.0 {
+        notes.push(format!(
+            "Needs {} but only {} is physical RAM: would rely on swap (very slow)",
+            need, physical
+        ));
+    } else {
+        notes.push(format!(
+            "Needs {} but only {} is physical RAM",
+            need, physical
+        ));
+    }
+    FitLevel::TooTight
+}
+
+/// Log how a fit was reached. The weights/KV/overhead split is only shown
+/// when the requirement came from the generic estimate at `ctx`.
+fn record_decision(fit: &ModelFit, system: &SystemSpecs, units: MemoryUnits) {
+    let model = &fit.model;
+    let why = fit.explain(system, units);
+    let breakdown = match why.breakdown {
+        Some(b) => format!(
+            "weights {:.1} + KV {:.1} + overhead {:.1} = ",
+            units.convert(b.weights_gb),
+            units.convert(b.kv_cache_gb),
+            units.convert(b.overhead_gb)
+        ),
+        None => String::new(),
+    };
+    activity::fit(
+        &model.name,
+        format!(
+            "{}: {}{:.1} / {} ({:.0}%) at {}, {} ctx → {}, {} (bound by {})",
+            model.name,
+            breakdown,
+            units.convert(why.memory_required_gb),
+            fmt_mem(why.memory_available_gb, 1, units),
+            fit.utilization_pct,
+            why.quant,
+            why.breakdown
+                .map_or(fit.estimated_context, |b| b.context_tokens),
+            fit.fit_text(),
+            fit.run_mode_text(),
+            why.binding.label()
+        ),
+    );
+}
+
+/// Lowest CUDA version the chosen runtime/quant is likely to need, with the
+/// component that needs it.
+fn min_cuda_requirement(
     runtime: InferenceRuntime,
-    estimation_ctx: u32,
+    quant: &str,
+) -> Option<((u32, u32), &'static str)> {
+    if runtime == InferenceRuntime::Vllm {
+        Some(((12, 1), "vLLM's prebuilt kernels"))
+    } else if quant.to_uppercase().starts_with("FP8") {
+        Some(((12, 0), "FP8 kernels"))
+    } else {
+        None
+    }
+}
+
+/// Warn when the installed CUDA version is older than the model likely needs.
+/// Says nothing when the version is unknown.
+fn cuda_version_note(
+    system: &SystemSpecs,
+    runtime: InferenceRuntime,
+    quant: &str,
+) -> Option<String> {
+    if system.backend != GpuBackend::Cuda {
+        return None;
+    }
+    let installed = system.cuda_version.as_deref()?;
+    let have = crate::hardware::parse_version_major_minor(installed)?;
+    let (need, what) = min_cuda_requirement(runtime, quant)?;
+    (have < need).then(|| {
+        format!(
+            "{} need CUDA {}.{}+, driver supports {}: may not run despite fitting",
+            what, need.0, need.1, installed
+        )
+    })
+}
+
+/// How much memory a model needs, for [`select_run_path`].
+#[derive(Debug, Clone, Copy)]
+enum MemoryNeed {
+    /// Estimated from the catalog at this context: the best quant is picked
+    /// for each memory pool, and MoE models may offload inactive experts.
+    Estimated { ctx: u32 },
+    /// Known exactly, at the model's own quantization.
+    Fixed(f64),
+}
+
+/// Pick the execution path for `model` and the memory pool it's scored
+/// against: the one pinned in `options.run_mode`, or else the best the
+/// hardware offers. Returns the run mode, memory required and memory
+/// available.
+fn select_run_path(
+    model: &LlmModel,
+    system: &SystemSpecs,
+    runtime: InferenceRuntime,
+    need: MemoryNeed,
+    options: &FitOptions,
+    notes: &mut Vec<String>,
+) -> (RunMode, f64, f64) {
+    let (requests, quants, units) = (options.requests(), options.quants, options.units);
+    // Catalog MoE figures don't describe a memory need that is known exactly
+    let is_moe = model.is_moe && matches!(need, MemoryNeed::Estimated { .. });
+    let (min_vram, min_ram, default_mem) = match need {
+        MemoryNeed::Estimated { ctx } => (
+            model.min_vram_gb.unwrap_or(model.min_ram_gb),
+            model.min_ram_gb,
+            model.estimate_memory_gb_for(model.quantization.as_str(), ctx, requests, quants),
+        ),
+        MemoryNeed::Fixed(mem) => (mem, mem, mem),
+    };
+    // Memory needed to run in a pool of `budget` GB, if anything fits
+    let choose = |budget: f64| match need {
+        MemoryNeed::Estimated { ctx } => {
+            best_quant_for_runtime_budget(model, runtime, budget, ctx, requests, quants)
+                .map(|(_, mem)| mem)
+        }
+        MemoryNeed::Fixed(mem) => (mem <= budget).then_some(mem),
+    };
+    let cpu = |notes: &mut Vec<String>| {
+        notes.push("CPU-only: model loaded into system RAM".to_string());
+        if is_moe {
+            notes.push("MoE architecture, but expert offloading requires a GPU".to_string());
+            return (RunMode::CpuOnly, model.min_ram_gb, system.available_ram_gb);
+        }
+        let pool = system.available_ram_gb;
+        (RunMode::CpuOnly, choose(pool).unwrap_or(default_mem), pool)
+    };
+
+    if options.run_mode == Some(RunMode::CpuOnly) {
+        notes.push("Run mode forced: CPU only, GPU ignored".to_string());
+        cpu(notes)
+    } else if options.run_mode == Some(RunMode::Gpu) {
+        notes.push("Run mode forced: GPU only, no offload to RAM".to_string());
+        let pool = if system.unified_memory {
+            system.gpu_vram_gb
+        } else {
+            system.total_gpu_vram_gb
+        }
+        .filter(|_| system.has_gpu)
+        .unwrap_or(0.0);
+        if pool <= 0.0 {
+            notes.push("No GPU memory detected".to_string());
+        }
+        if is_moe {
+            (RunMode::Gpu, min_vram, pool)
+        } else if let Some(best_mem) = choose(pool) {
+            (RunMode::Gpu, best_mem, pool)
+        } else {
+            (RunMode::Gpu, default_mem, pool)
+        }
+    } else if options.run_mode == Some(RunMode::CpuOffload) {
+        notes.push("Run mode forced: layers split between GPU and system RAM".to_string());
+        match system
+            .total_gpu_vram_gb
+            .filter(|&vram| system.has_gpu && !system.unified_memory && vram > 0.0)
+        {
+            Some(vram) if is_moe => {
+                moe_offload_path(model, system, vram, min_vram, runtime, options, notes)
+            }
+            Some(_) => {
+                let pool = system.available_ram_gb;
+                match choose(pool) {
+                    Some(best_mem) => (RunMode::CpuOffload, best_mem, pool),
+                    None => (RunMode::CpuOffload, default_mem, pool),
+                }
+            }
+            None => {
+                notes.push(
+                    "No discrete GPU with known VRAM to offload from; using system RAM".to_string(),
+                );
+                cpu(notes)
+            }
+        }
+    } else if system.has_gpu {
+        if system.unified_memory {
+            // Unified memory (Apple Silicon or NVIDIA Tegra/Grace Blackwell):
+            // GPU and CPU share the same memory pool.
+            // No CpuOffload -- there's no separate pool to spill to.
+            if let Some(pool) = system.gpu_vram_gb {
+                notes.push("Unified memory: GPU and CPU share the same pool".to_string());
+                notes.extend(wired_limit_note(system, units));
+                if is_moe {
+                    notes.push(format!(
+                        "MoE: {}/{} experts active (all share unified memory pool)",
+                        model.active_experts.unwrap_or(0),
+                        model.num_experts.unwrap_or(0)
+                    ));
+                }
+                if is_moe {
+                    (RunMode::Gpu, min_vram, pool)
+                } else if let Some(best_mem) = choose(pool) {
+                    (RunMode::Gpu, best_mem, pool)
+                } else {
+                    (RunMode::Gpu, default_mem, pool)
+                }
+            } else {
+                cpu(notes)
+            }
+        } else if let Some(system_vram) = system.total_gpu_vram_gb {
+            // Use total VRAM across all same-model GPUs for fit scoring.
+            // Multi-GPU inference (tensor splitting) is supported by llama.cpp, vLLM, etc.
+            if is_moe && min_vram <= system_vram {
+                // Fits in VRAM -- GPU path
+                notes.push("GPU: model loaded into VRAM".to_string());
+                if is_moe {
+                    notes.push(format!(
+                        "MoE: all {} experts loaded in VRAM (optimal)",
+                        model.num_experts.unwrap_or(0)
+                    ));
+                }
+                (RunMode::Gpu, min_vram, system_vram)
+            } else if is_moe {
+                // MoE model: try expert offloading before CPU fallback
+                moe_offload_path(
+                    model,
+                    system,
+                    system_vram,
+                    min_vram,
+                    runtime,
+                    options,
+                    notes,
+                )
+            } else if let Some(best_mem) = choose(system_vram) {
+                notes.push("GPU: model loaded into VRAM".to_string());
+                (RunMode::Gpu, best_mem, system_vram)
+            } else if let Some(best_mem) = choose(system.available_ram_gb) {
+                // Doesn't fit in VRAM, spill to system RAM
+                notes.push("GPU: insufficient VRAM, spilling to system RAM".to_string());
+                notes.push("Performance will be significantly reduced".to_string());
+                (RunMode::CpuOffload, best_mem, system.available_ram_gb)
+            } else {
+                // Doesn't fit anywhere -- report against VRAM since GPU is preferred
+                notes.push("Insufficient VRAM and system RAM".to_string());
+                notes.push(format!(
+                    "Need {} VRAM or {} system RAM",
+                    fmt_mem(min_vram, 1, units),
+                    fmt_mem(min_ram, 1, units)
+                ));
+                (RunMode::Gpu, default_mem, system_vram)
+            }
+        } else {
+            // GPU detected but VRAM unknown -- fall through to CPU
+            notes.push("GPU detected but VRAM unknown".to_string());
+            cpu(notes)
+        }
+    } else {
+        cpu(notes)
+    }
+}
+
+/// Memory to score against once `options.target` is applied, noting when
+/// the recommendation raises it.
+fn required_for_target(
+    options: &FitOptions,
+    model: &LlmModel,
+    run_mode: RunMode,
+    mem_required: f64,
     notes: &mut Vec<String>,
-) -> (RunMode, f64, f64) {
-    notes.push("CPU-only: model loaded into system RAM".to_string());
-    if model.is_moe {
-        notes.push("MoE architecture, but expert offloading requires a GPU".to_string());
-        return (RunMode::CpuOnly, model.min_ram_gb, system.available_ram_gb);
+) -> f64 {
+    let target_required = target_requirement(options.target, model, run_mode, mem_required);
+    if target_required > mem_required {
+        notes.push(format!(
+            "Fit target: recommended {} (estimate {})",
+            fmt_mem(target_required, 1, options.units),
+            fmt_mem(mem_required, 1, options.units)
+        ));
     }
+    target_required
+}
 
-    if let Some((_, best_mem)) =
-        best_quant_for_runtime_budget(model, runtime, system.available_ram_gb, estimation_ctx)
-    {
-        (RunMode::CpuOnly, best_mem, system.available_ram_gb)
+/// Runtime a fit assumes: vLLM for pre-quantized checkpoints, MLX on
+/// Apple Silicon, llama.cpp otherwise.
+fn runtime_for(model: &LlmModel, system: &SystemSpecs) -> InferenceRuntime {
+    if model.is_prequantized() {
+        InferenceRuntime::Vllm
+    } else if system.backend == GpuBackend::Metal && system.unified_memory {
+        InferenceRuntime::Mlx
     } else {
-        (
-            RunMode::CpuOnly,
-            model.estimate_memory_gb(model.quantization.as_str(), estimation_ctx),
-            system.available_ram_gb,
-        )
+        InferenceRuntime::LlamaCpp
     }
 }
 
@@ -454,8 +1332,10 @@ fn moe_offload_path(
     system_vram: f64,
     total_vram: f64,
     runtime: InferenceRuntime,
+    options: &FitOptions,
     notes: &mut Vec<String>,
 ) -> (RunMode, f64, f64) {
+    let (quants, units) = (options.quants, options.units);
     let hierarchy: &[&str] = if runtime == InferenceRuntime::Mlx {
         models::MLX_QUANT_HIERARCHY
     } else {
@@ -463,20 +1343,20 @@ fn moe_offload_path(
     };
 
     for &quant in hierarchy {
-        if let Some((moe_vram, offloaded_gb)) = moe_memory_for_quant(model, quant)
+        if let Some((moe_vram, offloaded_gb)) = moe_memory_for_quant(model, quant, quants)
             && moe_vram <= system_vram
             && offloaded_gb <= system.available_ram_gb
         {
             notes.push(format!(
-                "MoE: {}/{} experts active in VRAM ({:.1} GB) at {}",
+                "MoE: {}/{} experts active in VRAM ({}) at {}",
                 model.active_experts.unwrap_or(0),
                 model.num_experts.unwrap_or(0),
-                moe_vram,
+                fmt_mem(moe_vram, 1, units),
                 quant,
             ));
             notes.push(format!(
-                "Inactive experts offloaded to system RAM ({:.1} GB)",
-                offloaded_gb,
+                "Inactive experts offloaded to system RAM ({})",
+                fmt_mem(offloaded_gb, 1, units),
             ));
             return (RunMode::MoeOffload, moe_vram, system_vram);
         }
@@ -485,20 +1365,20 @@ fn moe_offload_path(
     // On MLX, also try GGUF-style quant levels as a fallback.
     if runtime == InferenceRuntime::Mlx {
         for &quant in models::QUANT_HIERARCHY {
-            if let Some((moe_vram, offloaded_gb)) = moe_memory_for_quant(model, quant)
+            if let Some((moe_vram, offloaded_gb)) = moe_memory_for_quant(model, quant, quants)
                 && moe_vram <= system_vram
                 && offloaded_gb <= system.available_ram_gb
             {
                 notes.push(format!(
-                    "MoE: {}/{} experts active in VRAM ({:.1} GB) at {}",
+                    "MoE: {}/{} experts active in VRAM ({}) at {}",
                     model.active_experts.unwrap_or(0),
                     model.num_experts.unwrap_or(0),
-                    moe_vram,
+                    fmt_mem(moe_vram, 1, units),
                     quant,
                 ));
                 notes.push(format!(
-                    "Inactive experts offloaded to system RAM ({:.1} GB)",
-                    offloaded_gb,
+                    "Inactive experts offloaded to system RAM ({})",
+                    fmt_mem(offloaded_gb, 1, units),
                 ));
                 return (RunMode::MoeOffload, moe_vram, system_vram);
             }
@@ -518,23 +1398,27 @@ fn moe_offload_path(
     } else {
         notes.push("Insufficient VRAM and system RAM".to_string());
         notes.push(format!(
-            "Need {:.1} GB VRAM (full) or {:.1} GB (MoE offload) + RAM",
-            total_vram,
-            model.moe_active_vram_gb().unwrap_or(total_vram),
+            "Need {} VRAM (full) or {} (MoE offload) + RAM",
+            fmt_mem(total_vram, 1, units),
+            fmt_mem(
+                model.moe_active_vram_gb(quants).unwrap_or(total_vram),
+                1,
+                units
+            ),
         ));
         (RunMode::Gpu, total_vram, system_vram)
     }
 }
 
 /// Compute MoE active VRAM + offloaded RAM for a specific quantization level.
-fn moe_memory_for_quant(model: &LlmModel, quant: &str) -> Option<(f64, f64)> {
+fn moe_memory_for_quant(model: &LlmModel, quant: &str, quants: &QuantTable) -> Option<(f64, f64)> {
     if !model.is_moe {
         return None;
     }
 
     let active_params = model.active_parameters? as f64;
     let total_params = model.parameters_raw? as f64;
-    let bpp = models::quant_bpp(quant);
+    let bpp = quants.bpp(quant);
 
     let active_vram = ((active_params * bpp) / (1024.0 * 1024.0 * 1024.0) * 1.1).max(0.5);
     let inactive_params = (total_params - active_params).max(0.0);
@@ -548,6 +1432,8 @@ fn best_quant_for_runtime_budget(
     runtime: InferenceRuntime,
     budget: f64,
     estimation_ctx: u32,
+    requests: u32,
+    quants: &QuantTable,
 ) -> Option<(&'static str, f64)> {
     // Pre-quantized models (vLLM) don't support dynamic re-quantization
     if runtime == InferenceRuntime::Vllm {
@@ -559,10 +1445,16 @@ fn best_quant_for_runtime_budget(
         models::QUANT_HIERARCHY
     };
     model
-        .best_quant_for_budget_with(budget, estimation_ctx, hierarchy)
+        .best_quant_for_budget_with(budget, estimation_ctx, hierarchy, requests, quants)
         .or_else(|| {
             if runtime == InferenceRuntime::Mlx {
-                model.best_quant_for_budget(budget, estimation_ctx)
+                model.best_quant_for_budget_with(
+                    budget,
+                    estimation_ctx,
+                    models::QUANT_HIERARCHY,
+                    requests,
+                    quants,
+                )
             } else {
                 None
             }
@@ -579,6 +1471,99 @@ pub fn backend_compatible(model: &LlmModel, system: &SystemSpecs) -> bool {
     }
 }
 
+/// Memory to add to the fit's own pool (VRAM for GPU and MoE runs, RAM
+/// for CPU+GPU and CPU runs) to lift a Marginal or Too Tight fit to Good
+/// at its current quantization; with more room, quant selection would
+/// otherwise reach for a bigger quant. CPU-only runs top out at Marginal,
+/// so for them it's what it takes to run at all. `None` when the fit is
+/// already Good, or more memory wouldn't change its level.
+pub fn memory_deficit_gb(fit: &ModelFit) -> Option<f64> {
+    if matches!(fit.fit_level, FitLevel::Perfect | FitLevel::Good) {
+        return None;
+    }
+    let needed = match fit.run_mode {
+        RunMode::CpuOnly => fit.memory_required_gb,
+        _ => fit.memory_required_gb * GOOD_HEADROOM,
+    };
+    let deficit = needed - fit.memory_available_gb;
+    (deficit > 0.0).then_some(deficit)
+}
+
+/// System RAM to add so a dense model that fits neither a discrete GPU nor
+/// RAM could run Good by spilling into RAM (CPU+GPU), the cheaper upgrade
+/// next to a bigger card. `None` for anything else.
+pub fn offload_ram_deficit_gb(fit: &ModelFit, system: &SystemSpecs) -> Option<f64> {
+    let spills_to_ram = system.has_gpu
+        && !system.unified_memory
+        && system.total_gpu_vram_gb.is_some()
+        && !fit.model.is_moe
+        && !fit.notes.iter().any(|n| n.starts_with("Run mode forced"));
+    if fit.fit_level != FitLevel::TooTight || fit.run_mode != RunMode::Gpu || !spills_to_ram {
+        return None;
+    }
+    let deficit = fit.memory_required_gb * GOOD_HEADROOM - system.available_ram_gb;
+    (deficit > 0.0).then_some(deficit)
+}
+
+/// Analyze every model that can run on this backend. See [`analyze_many`].
+pub fn analyze_compatible(
+    models: &[LlmModel],
+    system: &SystemSpecs,
+    options: &FitOptions,
+) -> Vec<ModelFit> {
+    let compatible: Vec<&LlmModel> = models
+        .iter()
+        .filter(|m| backend_compatible(m, system))
+        .collect();
+    analyze_many(&compatible, system, options)
+}
+
+/// Analyze models in parallel on rayon's thread pool. Each fit depends only
+/// on its model and `system`; the results come back in input order.
+pub fn analyze_many(
+    models: &[&LlmModel],
+    system: &SystemSpecs,
+    options: &FitOptions,
+) -> Vec<ModelFit> {
+    models
+        .par_iter()
+        .map(|m| ModelFit::analyze_with_options(m, system, options))
+        .collect()
+}
+
+/// Whether `model` can run with `ctx` tokens of context: it has to support
+/// that much context, and its fit estimated at exactly `ctx` can't be too tight.
+pub fn fits_at_context(
+    model: &LlmModel,
+    system: &SystemSpecs,
+    ctx: u32,
+    options: &FitOptions,
+) -> bool {
+    let options = FitOptions {
+        context_limit: Some(ctx),
+        ..*options
+    };
+    model.context_length >= ctx
+        && ModelFit::analyze_with_options(model, system, &options).fit_level != FitLevel::TooTight
+}
+
+/// Keep only the first fit of each model family (see [`LlmModel::family`]),
+/// so a ranked list keeps its best variant. Returns the quantizations every
+/// variant of each kept family offers, best variant's first.
+pub fn collapse_families(fits: &mut Vec<ModelFit>) -> HashMap<String, Vec<String>> {
+    let mut quants: HashMap<String, Vec<String>> = HashMap::new();
+    fits.retain(|fit| {
+        let family = fit.model.family();
+        let seen = quants.contains_key(&family);
+        let list = quants.entry(family).or_default();
+        if !list.contains(&fit.model.quantization) {
+            list.push(fit.model.quantization.clone());
+        }
+        !seen
+    });
+    quants
+}
+
 pub fn rank_models_by_fit(models: Vec<ModelFit>) -> Vec<ModelFit> {
     rank_models_by_fit_opts(models, false)
 }
@@ -713,17 +1698,20 @@ fn estimate_tps(
     system: &SystemSpecs,
     run_mode: RunMode,
     runtime: InferenceRuntime,
+    quants: &QuantTable,
 ) -> f64 {
     use crate::hardware::gpu_memory_bandwidth_gbps;
 
-    // MoE models execute only active experts per token, so speed estimates should
-    // use active parameters when known; fit/memory paths still use full model size.
-    let params = model
-        .active_parameters
-        .filter(|_| model.is_moe)
-        .map(|p| (p as f64) / 1_000_000_000.0)
-        .unwrap_or_else(|| model.params_b())
-        .max(0.1);
+    // Run mode penalties. CPU-only is bound by system RAM bandwidth
+    // regardless of detected GPU.
+    let mode_factor = match run_mode {
+        RunMode::Gpu => 1.0,        // full speed
+        RunMode::MoeOffload => 0.8, // expert switching latency
+        RunMode::CpuOffload => 0.5, // significant penalty
+        RunMode::CpuOnly => return estimate_cpu_tps(model, quant, system, quants),
+    };
+
+    let params = speed_params_b(model);
 
     // ── Bandwidth-based estimation (preferred) ─────────────────────
     //
@@ -746,25 +1734,15 @@ fn estimate_tps(
     let gpu_name = system.gpu_name.as_deref().unwrap_or("");
     let bandwidth = gpu_memory_bandwidth_gbps(gpu_name);
 
-    if run_mode != RunMode::CpuOnly {
-        if let Some(bw) = bandwidth {
-            let bytes_per_param = models::quant_bytes_per_param(quant);
-            let model_gb = params * bytes_per_param;
-
-            // Efficiency factor — captures overhead not in the simple
-            // bandwidth / model-size formula.
-            let efficiency = 0.55;
-            let raw_tps = (bw / model_gb) * efficiency;
-
-            let mode_factor = match run_mode {
-                RunMode::Gpu => 1.0,
-                RunMode::MoeOffload => 0.8,
-                RunMode::CpuOffload => 0.5,
-                RunMode::CpuOnly => unreachable!(),
-            };
+    if let Some(bw) = bandwidth {
+        let model_gb = params * quants.bpp(quant);
 
-            return (raw_tps * mode_factor).max(0.1);
-        }
+        // Efficiency factor — captures overhead not in the simple
+        // bandwidth / model-size formula.
+        let efficiency = 0.55;
+        let raw_tps = (bw / model_gb) * efficiency;
+
+        return (raw_tps * mode_factor).max(0.1);
     }
 
     // ── Fallback: fixed-constant approach ──────────────────────────
@@ -793,28 +1771,66 @@ fn estimate_tps(
         base *= 1.1;
     }
 
-    // Run mode penalties
-    match run_mode {
-        RunMode::Gpu => {}                  // full speed
-        RunMode::MoeOffload => base *= 0.8, // expert switching latency
-        RunMode::CpuOffload => base *= 0.5, // significant penalty
-        RunMode::CpuOnly => base *= 0.3,    // worst case—override K to CPU
-    }
+    (base * mode_factor).max(0.1)
+}
 
-    // CPU-only should use CPU K regardless of detected GPU
-    if run_mode == RunMode::CpuOnly {
-        let cpu_k = if cfg!(target_arch = "aarch64") {
-            90.0
-        } else {
-            70.0
-        };
-        base = (cpu_k / params) * models::quant_speed_multiplier(quant);
-        if system.total_cpu_cores >= 8 {
-            base *= 1.1;
-        }
+/// Billions of parameters read per generated token. MoE models execute only
+/// their active experts, so speed uses active parameters when known;
+/// fit/memory paths still use the full model size.
+fn speed_params_b(model: &LlmModel) -> f64 {
+    model
+        .active_parameters
+        .filter(|_| model.is_moe)
+        .map(|p| (p as f64) / 1_000_000_000.0)
+        .unwrap_or_else(|| model.params_b())
+        .max(0.1)
+}
+
+/// Fraction of peak RAM bandwidth CPU inference reaches. CPUs get a smaller
+/// share than GPUs, and fewer cores can't keep every channel busy. AVX-512
+/// and AMX dequantize fast enough to get closer to the ceiling.
+fn cpu_efficiency(system: &SystemSpecs) -> f64 {
+    let base = if system.total_cpu_cores >= 8 {
+        0.5
+    } else {
+        0.4
+    };
+    if system.cpu_features.amx {
+        base * 1.15
+    } else if system.cpu_features.avx512 {
+        base * 1.1
+    } else {
+        base
     }
+}
+
+/// Roofline tok/s for running `model` at `quant` on the CPU: every token
+/// reads all (active) weights from RAM once, so throughput is RAM bandwidth
+/// over bytes read per token, scaled by [`cpu_efficiency`].
+pub fn estimate_cpu_tps(
+    model: &LlmModel,
+    quant: &str,
+    system: &SystemSpecs,
+    quants: &QuantTable,
+) -> f64 {
+    let model_gb = speed_params_b(model) * quants.bpp(quant);
+    (system.mem_bandwidth_gbps() / model_gb * cpu_efficiency(system)).max(0.1)
+}
 
-    base.max(0.1)
+/// The assumptions behind [`estimate_cpu_tps`], for a fit's notes.
+fn cpu_tps_note(
+    model: &LlmModel,
+    quant: &str,
+    system: &SystemSpecs,
+    quants: &QuantTable,
+) -> String {
+    format!(
+        "CPU speed: ~{:.1} tok/s ({:.0} GB/s RAM bandwidth / {:.1} GB read per token x {:.0}% efficiency)",
+        estimate_cpu_tps(model, quant, system, quants),
+        system.mem_bandwidth_gbps(),
+        speed_params_b(model) * quants.bpp(quant),
+        cpu_efficiency(system) * 100.0
+    )
 }
 
 // ────────────────────────────────────────────────────────────────────
@@ -1005,7 +2021,12 @@ mod tests {
             release_date: None,
             gguf_sources: vec![],
             capabilities: vec![],
+            tags: vec![],
             format: models::ModelFormat::default(),
+            num_layers: None,
+            kv_hidden_size: None,
+            hf_downloads: None,
+            gguf_file_sizes: Default::default(),
         }
     }
 
@@ -1031,6 +2052,21 @@ mod tests {
                 GpuBackend::CpuX86
             },
             gpus: vec![],
+            memory_bandwidth_gbps: None,
+            reserved_ram_gb: 0.0,
+            headroom_gb: 0.0,
+            gpu_wired_limit_gb: None,
+            swap_total_gb: 0.0,
+            swap_used_gb: 0.0,
+            process_mem_limit_gb: None,
+            driver_version: None,
+            cuda_version: None,
+            cpu_features: crate::hardware::CpuFeatures::default(),
+            has_npu: false,
+            npu_name: None,
+            loaded_from: None,
+            hypothetical: false,
+            free_disk_gb: None,
         }
     }
 
@@ -1135,6 +2171,8 @@ mod tests {
                 .iter()
                 .any(|n| n.contains("spilling to system RAM"))
         );
+        // One speed figure: the GPU estimate with the offload penalty
+        assert!(!fit.notes.iter().any(|n| n.starts_with("CPU speed")));
     }
 
     #[test]
@@ -1150,6 +2188,180 @@ mod tests {
         assert!(fit.notes.iter().any(|n| n.contains("Unified memory")));
     }
 
+    #[test]
+    fn test_apple_wired_limit_shrinks_gpu_pool() {
+        let model = test_model("10B", 6.0, Some(6.0));
+        let mut system = test_system(16.0, true, Some(16.0));
+        system.unified_memory = true;
+        system.backend = GpuBackend::Metal;
+        let uncapped = ModelFit::analyze(&model, &system);
+
+        let capped_system =
+            system.with_gpu_wired_limit(crate::hardware::default_apple_gpu_wired_limit_gb(16.0));
+        assert_eq!(capped_system.gpu_vram_gb.map(|v| v.round()), Some(11.0));
+        let capped = ModelFit::analyze(&model, &capped_system);
+        assert_eq!(capped.memory_available_gb.round(), 11.0);
+        assert_eq!(uncapped.fit_level, FitLevel::Perfect);
+        assert_ne!(capped.fit_level, FitLevel::Perfect);
+        assert!(
+            capped
+                .notes
+                .iter()
+                .any(|n| n.contains("iogpu.wired_limit_mb"))
+        );
+        assert!(!uncapped.notes.iter().any(|n| n.contains("iogpu")));
+    }
+
+    #[test]
+    fn test_disk_space_note_when_download_exceeds_free_disk() {
+        let model = test_model("7B", 4.0, Some(4.0));
+        let mut system = test_system(32.0, true, Some(24.0));
+        let unknown = ModelFit::analyze(&model, &system);
+        assert!(!unknown.notes.iter().any(|n| n.contains("free on disk")));
+
+        system.free_disk_gb = Some(2.0);
+        let short = ModelFit::analyze(&model, &system);
+        assert!(
+            short
+                .notes
+                .iter()
+                .any(|n| n.contains("only 2.0 GiB is free on disk"))
+        );
+        assert_eq!(short.fit_level, unknown.fit_level);
+
+        system.free_disk_gb = Some(500.0);
+        let plenty = ModelFit::analyze(&model, &system);
+        assert!(!plenty.notes.iter().any(|n| n.contains("free on disk")));
+    }
+
+    #[test]
+    fn test_cuda_version_note_for_old_driver() {
+        let mut model = test_model("7B", 4.0, Some(4.0));
+        model.quantization = "AWQ-4bit".to_string();
+        model.format = models::ModelFormat::Awq;
+        let mut system = test_system(32.0, true, Some(24.0));
+
+        // Unknown version: no note
+        let fit = ModelFit::analyze(&model, &system);
+        assert!(!fit.notes.iter().any(|n| n.contains("CUDA")));
+
+        system.cuda_version = Some("11.8".to_string());
+        let fit = ModelFit::analyze(&model, &system);
+        assert!(fit.notes.iter().any(|n| n.contains("need CUDA 12.1+")));
+
+        system.cuda_version = Some("12.4".to_string());
+        let fit = ModelFit::analyze(&model, &system);
+        assert!(!fit.notes.iter().any(|n| n.contains("CUDA")));
+    }
+
+    #[test]
+    fn test_analyze_many_preserves_input_order() {
+        let models: Vec<LlmModel> = (1..=200)
+            .map(|i| {
+                let mut m = test_model(&format!("{}B", i % 70 + 1), 4.0, Some(4.0));
+                m.name = format!("model-{i}");
+                m
+            })
+            .collect();
+        let refs: Vec<&LlmModel> = models.iter().collect();
+        let system = test_system(32.0, true, Some(12.0));
+
+        let fits = analyze_many(&refs, &system, &FitOptions::default());
+        let names: Vec<&str> = fits.iter().map(|f| f.model.name.as_str()).collect();
+        let expected: Vec<&str> = models.iter().map(|m| m.name.as_str()).collect();
+        assert_eq!(names, expected);
+        assert_eq!(
+            fits[150].memory_required_gb,
+            ModelFit::analyze(&models[150], &system).memory_required_gb
+        );
+    }
+
+    #[test]
+    fn test_fit_options_scale_kv_cache_per_request() {
+        let mut model = test_model("7B", 4.0, Some(4.0));
+        model.context_length = 8192;
+        let system = test_system(64.0, true, Some(48.0));
+
+        let one = ModelFit::analyze(&model, &system);
+        let options = FitOptions {
+            concurrency: 4,
+            ..FitOptions::default()
+        };
+        let four = ModelFit::analyze_with_options(&model, &system, &options);
+        let extra = model.kv_cache_gb(8192) * 3.0;
+        assert!((four.memory_required_gb - one.memory_required_gb - extra).abs() < 0.01);
+        assert_eq!(four.memory_breakdown().map(|b| b.requests), Some(4));
+        // Other analyses in the same process keep their own assumptions
+        assert_eq!(
+            ModelFit::analyze(&model, &system).memory_required_gb,
+            one.memory_required_gb
+        );
+    }
+
+    #[test]
+    fn test_memory_range_low_end_uses_short_context() {
+        let mut model = test_model("7B", 4.0, Some(4.0));
+        model.context_length = 32768;
+        let system = test_system(64.0, true, Some(48.0));
+
+        let fit = ModelFit::analyze(&model, &system);
+        assert!(fit.memory_required_low_gb < fit.memory_required_gb);
+        let expected_gap = model.kv_cache_gb(32768) - model.kv_cache_gb(TYPICAL_CONTEXT_TOKENS);
+        assert!((fit.memory_required_gb - fit.memory_required_low_gb - expected_gap).abs() < 1e-9);
+
+        // Context already short: no range
+        model.context_length = 1024;
+        let fit = ModelFit::analyze(&model, &system);
+        assert_eq!(fit.memory_required_low_gb, fit.memory_required_gb);
+    }
+
+    #[test]
+    fn test_kv_cache_grows_with_context() {
+        // Llama 3 8B: 32 layers, 8 KV heads x 128 = 1024-wide K and V
+        assert!((kv_cache_gb(131_072, 32, 1024, 16) - 17.18).abs() < 0.01);
+        assert!((kv_cache_gb(4096, 32, 1024, 8) - 0.268).abs() < 0.001);
+
+        let mut model = test_model("8B", 5.0, Some(5.0));
+        model.context_length = 131_072;
+        model.num_layers = Some(32);
+        model.kv_hidden_size = Some(1024);
+        let system = test_system(64.0, true, Some(48.0));
+        let full = ModelFit::analyze(&model, &system);
+        let short = ModelFit::analyze_with_context_limit(&model, &system, Some(4096));
+        assert!(full.memory_required_gb - short.memory_required_gb > 16.0);
+        assert!(full.utilization_pct > short.utilization_pct);
+    }
+
+    #[test]
+    fn test_forced_run_mode_overrides_path() {
+        let model = test_model("13B", 8.0, Some(8.0));
+        let system = test_system(32.0, true, Some(4.0));
+
+        let cpu = ModelFit::analyze_with_run_mode(&model, &system, None, Some(RunMode::CpuOnly));
+        assert_eq!(cpu.run_mode, RunMode::CpuOnly);
+        assert_eq!(cpu.memory_available_gb, system.available_ram_gb);
+
+        // Auto would offload to RAM; GPU-only scores against VRAM alone.
+        let gpu = ModelFit::analyze_with_run_mode(&model, &system, None, Some(RunMode::Gpu));
+        assert_eq!(gpu.run_mode, RunMode::Gpu);
+        assert_eq!(gpu.memory_available_gb, 4.0);
+
+        // Offload is honoured even for a model that fits in VRAM.
+        let small = test_model("3B", 2.0, Some(2.0));
+        let offload =
+            ModelFit::analyze_with_run_mode(&small, &system, None, Some(RunMode::CpuOffload));
+        assert_eq!(offload.run_mode, RunMode::CpuOffload);
+        assert_eq!(offload.memory_available_gb, system.available_ram_gb);
+        let auto = ModelFit::analyze_with_run_mode(&small, &system, None, None);
+        assert_eq!(auto.run_mode, RunMode::Gpu);
+
+        // With nothing to offload from it falls back to the CPU path.
+        let no_gpu = test_system(32.0, false, None);
+        let cpu_fallback =
+            ModelFit::analyze_with_run_mode(&small, &no_gpu, None, Some(RunMode::CpuOffload));
+        assert_eq!(cpu_fallback.run_mode, RunMode::CpuOnly);
+    }
+
     #[test]
     fn test_model_fit_too_tight() {
         let model = test_model("70B", 40.0, Some(40.0));
@@ -1181,7 +2393,12 @@ mod tests {
             release_date: None,
             gguf_sources: vec![],
             capabilities: vec![],
+            tags: vec![],
             format: models::ModelFormat::default(),
+            num_layers: None,
+            kv_hidden_size: None,
+            hf_downloads: None,
+            gguf_file_sizes: Default::default(),
         };
         let mut system = test_system(64.0, true, Some(8.0));
         system.backend = GpuBackend::Cuda;
@@ -1214,7 +2431,12 @@ mod tests {
             release_date: None,
             gguf_sources: vec![],
             capabilities: vec![],
+            tags: vec![],
             format: models::ModelFormat::default(),
+            num_layers: None,
+            kv_hidden_size: None,
+            hf_downloads: None,
+            gguf_file_sizes: Default::default(),
         };
         let system = test_system(12.0, true, Some(8.0));
 
@@ -1429,6 +2651,7 @@ mod tests {
             &system,
             RunMode::Gpu,
             InferenceRuntime::Mlx,
+            QuantTable::builtin(),
         );
         let tps_llamacpp = estimate_tps(
             &model,
@@ -1436,6 +2659,7 @@ mod tests {
             &system,
             RunMode::Gpu,
             InferenceRuntime::LlamaCpp,
+            QuantTable::builtin(),
         );
 
         // MLX should be faster on Metal
@@ -1466,6 +2690,136 @@ mod tests {
         assert_eq!(fit.runtime, InferenceRuntime::LlamaCpp);
     }
 
+    #[test]
+    fn test_explain_binding_breakdown_and_path() {
+        let mut model = test_model("7B", 4.0, Some(4.0));
+        model.context_length = 8192;
+
+        let roomy = test_system(64.0, true, Some(24.0));
+        let fit = ModelFit::analyze(&model, &roomy);
+        let why = fit.explain(&roomy, MemoryUnits::Binary);
+        assert_eq!(fit.fit_level, FitLevel::Perfect);
+        assert_eq!(why.binding, BindingConstraint::Compute);
+        let b = why.breakdown.expect("standard estimate");
+        assert_eq!(b.context_tokens, 8192);
+        assert!(
+            (b.weights_gb + b.kv_cache_gb + b.overhead_gb - why.memory_required_gb).abs() < 0.05
+        );
+        assert!(why.run_mode_path.last().unwrap().ends_with("→ GPU"));
+
+        assert_eq!(why.pool, BindingConstraint::Vram);
+        assert!(why.margin_gb > 0.0);
+        assert!(
+            why.margin_text(MemoryUnits::Binary)
+                .ends_with("GiB of VRAM to spare")
+        );
+
+        let cpu = test_system(8.0, false, None);
+        let why = ModelFit::analyze(&model, &cpu).explain(&cpu, MemoryUnits::Binary);
+        assert_eq!(why.binding, BindingConstraint::Ram);
+        assert!(why.run_mode_path[0].starts_with("No GPU detected"));
+
+        let huge = test_model("70B", 40.0, Some(40.0));
+        let fit = ModelFit::analyze(&huge, &cpu);
+        let why = fit.explain(&cpu, MemoryUnits::Binary);
+        assert_eq!(fit.fit_level, FitLevel::TooTight);
+        assert!((why.margin_gb - (fit.memory_available_gb - fit.memory_required_gb)).abs() < 1e-9);
+        assert!(why.margin_text(MemoryUnits::Binary).starts_with("over by "));
+        assert!(
+            why.margin_text(MemoryUnits::Decimal)
+                .ends_with(" GB of RAM")
+        );
+    }
+
+    #[test]
+    fn test_fit_that_needs_swap_is_too_tight() {
+        let model = test_model("70B", 40.0, Some(40.0));
+        let mut system = test_system(32.0, true, Some(32.0));
+        system.unified_memory = true;
+        system.swap_total_gb = 16.0;
+        let fit = ModelFit::analyze(&model, &system);
+        assert_ne!(fit.fit_level, FitLevel::TooTight);
+
+        // `--memory 96` on a 32 GB machine: the pool fits, physical RAM doesn't.
+        let system = system.with_gpu_memory_override(96.0);
+        let fit = ModelFit::analyze(&model, &system);
+        assert_eq!(fit.fit_level, FitLevel::TooTight);
+        assert!(
+            fit.notes
+                .iter()
+                .any(|n| n.ends_with("would rely on swap (very slow)"))
+        );
+        assert!((fit.utilization_pct - fit.memory_required_gb / 96.0 * 100.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_analyze_at_quant_honors_run_mode_and_target() {
+        let model = test_model("7B", 4.0, Some(4.0));
+        let system = test_system(64.0, true, Some(24.0));
+        let auto = ModelFit::analyze_at_quant(&model, &system, &FitOptions::default(), "Q4_K_M");
+        assert_eq!(auto.run_mode, RunMode::Gpu);
+
+        let cpu = FitOptions {
+            run_mode: Some(RunMode::CpuOnly),
+            ..FitOptions::default()
+        };
+        let fit = ModelFit::analyze_at_quant(&model, &system, &cpu, "Q4_K_M");
+        assert_eq!(fit.run_mode, RunMode::CpuOnly);
+        assert_eq!(fit.memory_available_gb, system.available_ram_gb);
+
+        let recommended = FitOptions {
+            target: FitTarget::Recommended,
+            ..FitOptions::default()
+        };
+        let fit = ModelFit::analyze_at_quant(&model, &system, &recommended, "Q4_K_M");
+        assert!(fit.memory_required_gb >= fit.model.recommended_ram_gb);
+        assert!(fit.memory_required_gb > auto.memory_required_gb);
+    }
+
+    #[test]
+    fn test_memory_deficit_flips_fit_to_good() {
+        let model = test_model("70B", 40.0, Some(40.0));
+        let system = test_system(16.0, true, Some(8.0));
+        let fit = ModelFit::analyze(&model, &system);
+        assert_eq!(fit.fit_level, FitLevel::TooTight);
+
+        let vram = memory_deficit_gb(&fit).expect("too tight");
+        let mut bigger_gpu = system.clone();
+        bigger_gpu.total_gpu_vram_gb = Some(8.0 + vram + 0.01);
+        let upgraded = ModelFit::analyze_at_quant(
+            &model,
+            &bigger_gpu,
+            &FitOptions::default(),
+            &fit.best_quant,
+        );
+        assert_eq!(upgraded.run_mode, RunMode::Gpu);
+        assert!(matches!(
+            upgraded.fit_level,
+            FitLevel::Good | FitLevel::Perfect
+        ));
+
+        let ram = offload_ram_deficit_gb(&fit, &system).expect("discrete GPU");
+        let mut more_ram = system.clone();
+        more_ram.available_ram_gb += ram + 0.01;
+        more_ram.total_ram_gb += ram + 0.01;
+        let upgraded =
+            ModelFit::analyze_at_quant(&model, &more_ram, &FitOptions::default(), &fit.best_quant);
+        assert_eq!(upgraded.run_mode, RunMode::CpuOffload);
+        assert_eq!(upgraded.fit_level, FitLevel::Good);
+
+        let small = test_model("7B", 4.0, Some(4.0));
+        let roomy = test_system(64.0, true, Some(24.0));
+        let fit = ModelFit::analyze(&small, &roomy);
+        assert_eq!(memory_deficit_gb(&fit), None);
+        assert_eq!(offload_ram_deficit_gb(&fit, &roomy), None);
+
+        // CPU-only can't reach Good; a Marginal CPU fit has nothing to add.
+        let cpu = test_system(64.0, false, None);
+        let fit = ModelFit::analyze(&small, &cpu);
+        assert_eq!(fit.fit_level, FitLevel::Marginal);
+        assert_eq!(memory_deficit_gb(&fit), None);
+    }
+
     #[test]
     fn test_analyze_with_context_limit_reduces_memory_estimate() {
         let mut model = test_model("7B", 4.0, Some(4.0));
@@ -1484,6 +2838,127 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_gpu_placement_single_vs_tensor_parallel() {
+        let mut system = test_system(64.0, true, Some(24.0));
+        assert_eq!(gpu_placement(&system, 10.0), None);
+
+        system.gpu_count = 4;
+        system.total_gpu_vram_gb = Some(96.0);
+        assert_eq!(
+            gpu_placement(&system, 20.0),
+            Some(GpuPlacement::SingleGpu { vram_gb: 24.0 })
+        );
+        assert_eq!(
+            gpu_placement(&system, 40.0),
+            Some(GpuPlacement::TensorParallel {
+                gpus: 2,
+                vram_gb: 24.0
+            })
+        );
+
+        let model = test_model("70B", 40.0, Some(40.0));
+        let fit = ModelFit::analyze(&model, &system);
+        assert_eq!(fit.run_mode, RunMode::Gpu);
+        assert!(matches!(
+            fit.gpu_placement(&system),
+            Some(GpuPlacement::TensorParallel { .. })
+        ));
+        assert!(fit.notes.iter().any(|n| n.contains("tensor-parallel")));
+    }
+
+    #[test]
+    fn test_tensor_parallel_split_per_gpu() {
+        // 40 GB of weights + 4 GB KV on 24 GB cards: 2-way needs 22.5 GB each
+        let split = tensor_parallel_split(40.0, 4.0, 24.0, 4).unwrap();
+        assert_eq!(split.gpus, 2);
+        assert!(split.fits);
+        assert!((split.per_gpu_required_gb - 22.5).abs() < 1e-9);
+        assert!(
+            split
+                .label(MemoryUnits::Binary)
+                .starts_with("Fits across 2 GPUs")
+        );
+
+        // 3 cards only allow 2-way, which is too small for 60 GB
+        let split = tensor_parallel_split(60.0, 0.0, 24.0, 3).unwrap();
+        assert_eq!(split.gpus, 2);
+        assert!(!split.fits);
+
+        assert_eq!(tensor_parallel_split(40.0, 4.0, 24.0, 1), None);
+    }
+
+    #[test]
+    fn test_fit_target_recommended_needs_headroom() {
+        // min 8 GB, recommended 16 GB
+        let model = test_model("7B", 8.0, None);
+        let target = |t, mode| target_requirement(t, &model, mode, 5.0);
+
+        assert_eq!(target(FitTarget::Minimum, RunMode::Gpu), 5.0);
+        assert_eq!(target(FitTarget::Recommended, RunMode::Gpu), 16.0);
+        assert_eq!(target(FitTarget::Recommended, RunMode::CpuOnly), 16.0);
+        assert_eq!(target(FitTarget::Recommended, RunMode::MoeOffload), 5.0);
+        assert_eq!(
+            score_fit(16.0, 12.0, 16.0, RunMode::CpuOnly),
+            FitLevel::TooTight
+        );
+    }
+
+    #[test]
+    fn test_collapse_families_keeps_best_variant() {
+        let system = test_system(64.0, false, None);
+        let fit_named = |name: &str, quant: &str| {
+            let mut model = test_model("8B", 8.0, None);
+            model.name = name.to_string();
+            model.quantization = quant.to_string();
+            ModelFit::analyze(&model, &system)
+        };
+        assert_eq!(
+            fit_named("Qwen/Qwen3-8B.w8a8", "Q8_0").model.family(),
+            "qwen3-8b"
+        );
+
+        let mut fits = vec![
+            fit_named("Qwen/Qwen3-8B-AWQ", "AWQ-4bit"),
+            fit_named("Qwen/Qwen3-14B", "Q4_K_M"),
+            fit_named("lmstudio-community/Qwen3-8B-MLX-8bit", "mlx-8bit"),
+            fit_named("Qwen/Qwen3-8B", "Q4_K_M"),
+        ];
+        let quants = collapse_families(&mut fits);
+        let names: Vec<&str> = fits.iter().map(|f| f.model.name.as_str()).collect();
+        assert_eq!(names, vec!["Qwen/Qwen3-8B-AWQ", "Qwen/Qwen3-14B"]);
+        assert_eq!(quants["qwen3-8b"], vec!["AWQ-4bit", "mlx-8bit", "Q4_K_M"]);
+        assert_eq!(quants["qwen3-14b"], vec!["Q4_K_M"]);
+    }
+
+    #[test]
+    fn test_fits_at_context() {
+        let mut model = test_model("7B", 4.0, Some(4.0));
+        model.context_length = 32768;
+        let system = test_system(32.0, true, Some(16.0));
+
+        assert!(fits_at_context(
+            &model,
+            &system,
+            8192,
+            &FitOptions::default()
+        ));
+        // Beyond what the model supports, however much memory there is
+        assert!(!fits_at_context(
+            &model,
+            &system,
+            65536,
+            &FitOptions::default()
+        ));
+        let tiny = test_system(2.0, false, None);
+        assert!(!fits_at_context(
+            &model,
+            &tiny,
+            8192,
+            &FitOptions::default()
+        ));
+    }
+
     #[test]
     fn test_estimate_tps_run_mode_penalties() {
         let model = test_model("7B", 4.0, Some(4.0));
@@ -1495,6 +2970,7 @@ mod tests {
             &system,
             RunMode::Gpu,
             InferenceRuntime::LlamaCpp,
+            QuantTable::builtin(),
         );
         let tps_moe = estimate_tps(
             &model,
@@ -1502,6 +2978,7 @@ mod tests {
             &system,
             RunMode::MoeOffload,
             InferenceRuntime::LlamaCpp,
+            QuantTable::builtin(),
         );
         let tps_offload = estimate_tps(
             &model,
@@ -1509,6 +2986,7 @@ mod tests {
             &system,
             RunMode::CpuOffload,
             InferenceRuntime::LlamaCpp,
+            QuantTable::builtin(),
         );
         let tps_cpu = estimate_tps(
             &model,
@@ -1516,6 +2994,7 @@ mod tests {
             &system,
             RunMode::CpuOnly,
             InferenceRuntime::LlamaCpp,
+            QuantTable::builtin(),
         );
 
         // GPU should be fastest
@@ -1543,6 +3022,7 @@ mod tests {
             &system,
             RunMode::Gpu,
             InferenceRuntime::LlamaCpp,
+            QuantTable::builtin(),
         );
         let tps_moe = estimate_tps(
             &moe_model,
@@ -1550,6 +3030,7 @@ mod tests {
             &system,
             RunMode::Gpu,
             InferenceRuntime::LlamaCpp,
+            QuantTable::builtin(),
         );
 
         assert!(tps_moe > tps_dense * 5.0);
@@ -1570,6 +3051,7 @@ mod tests {
             &system,
             RunMode::Gpu,
             InferenceRuntime::LlamaCpp,
+            QuantTable::builtin(),
         );
         let tps_moe = estimate_tps(
             &moe_without_active,
@@ -1577,6 +3059,7 @@ mod tests {
             &system,
             RunMode::Gpu,
             InferenceRuntime::LlamaCpp,
+            QuantTable::builtin(),
         );
 
         assert_eq!(tps_dense, tps_moe);
@@ -1656,6 +3139,21 @@ mod tests {
             unified_memory: false,
             backend: GpuBackend::Cuda,
             gpus: vec![],
+            memory_bandwidth_gbps: None,
+            reserved_ram_gb: 0.0,
+            headroom_gb: 0.0,
+            gpu_wired_limit_gb: None,
+            swap_total_gb: 0.0,
+            swap_used_gb: 0.0,
+            process_mem_limit_gb: None,
+            driver_version: None,
+            cuda_version: None,
+            cpu_features: crate::hardware::CpuFeatures::default(),
+            has_npu: false,
+            npu_name: None,
+            loaded_from: None,
+            hypothetical: false,
+            free_disk_gb: None,
         }
     }
 
@@ -1671,6 +3169,7 @@ mod tests {
             &sys_4090,
             RunMode::Gpu,
             InferenceRuntime::LlamaCpp,
+            QuantTable::builtin(),
         );
         let tps_3060 = estimate_tps(
             &model,
@@ -1678,6 +3177,7 @@ mod tests {
             &sys_3060,
             RunMode::Gpu,
             InferenceRuntime::LlamaCpp,
+            QuantTable::builtin(),
         );
 
         // RTX 4090 (1008 GB/s) should be ~2.8x faster than RTX 3060 (360 GB/s)
@@ -1700,6 +3200,7 @@ mod tests {
             &system,
             RunMode::Gpu,
             InferenceRuntime::LlamaCpp,
+            QuantTable::builtin(),
         );
 
         // Should be in the 30-50 tok/s range (measured: ~40)
@@ -1719,6 +3220,7 @@ mod tests {
             &system,
             RunMode::Gpu,
             InferenceRuntime::LlamaCpp,
+            QuantTable::builtin(),
         );
 
         // Should be in the 10-25 tok/s range (measured: ~16)
@@ -1738,6 +3240,7 @@ mod tests {
             &system,
             RunMode::Gpu,
             InferenceRuntime::LlamaCpp,
+            QuantTable::builtin(),
         );
 
         // Should fall back to K=220 path and produce a positive value
@@ -1757,6 +3260,7 @@ mod tests {
             &sys_4090,
             RunMode::CpuOnly,
             InferenceRuntime::LlamaCpp,
+            QuantTable::builtin(),
         );
         let tps_unknown = estimate_tps(
             &model,
@@ -1764,6 +3268,7 @@ mod tests {
             &sys_unknown,
             RunMode::CpuOnly,
             InferenceRuntime::LlamaCpp,
+            QuantTable::builtin(),
         );
 
         // CPU-only should produce the same result regardless of GPU
@@ -1773,6 +3278,44 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_cpu_tps_is_bandwidth_over_weight_bytes() {
+        let model = test_model("7B", 4.0, Some(4.0));
+        let mut system = test_system(64.0, false, None);
+        let q8 = estimate_cpu_tps(&model, "Q8_0", &system, QuantTable::builtin());
+        let q4 = estimate_cpu_tps(&model, "Q4_0", &system, QuantTable::builtin());
+        // 50 GB/s over 7.35 GB of Q8_0 weights (1.05 bytes each) at 50% efficiency
+        assert!((q8 - 3.40).abs() < 0.01, "q8={q8}");
+        assert!(q4 > q8 * 1.5);
+
+        system.memory_bandwidth_gbps = Some(100.0);
+        assert!(
+            (estimate_cpu_tps(&model, "Q8_0", &system, QuantTable::builtin()) - 2.0 * q8).abs()
+                < 0.01
+        );
+
+        let mut avx512 = test_system(64.0, false, None);
+        avx512.cpu_features.avx512 = true;
+        assert!(
+            (estimate_cpu_tps(&model, "Q8_0", &avx512, QuantTable::builtin()) - 1.1 * q8).abs()
+                < 0.01
+        );
+
+        let fit = ModelFit::analyze(&model, &system);
+        assert_eq!(fit.run_mode, RunMode::CpuOnly);
+        assert!(
+            (fit.estimated_tps
+                - estimate_cpu_tps(&model, &fit.best_quant, &system, QuantTable::builtin()))
+            .abs()
+                < 1e-9
+        );
+        assert!(
+            fit.notes
+                .iter()
+                .any(|n| n.starts_with("CPU speed: ~") && n.contains("100 GB/s RAM bandwidth"))
+        );
+    }
+
     #[test]
     fn test_prequantized_requires_cuda_or_rocm() {
         let mut model = test_model("7B", 4.0, Some(4.0));
@@ -0,0 +1,781 @@
+//! Exact model sizing from a GGUF file's header.
+//!
+//! A GGUF file lists every tensor with its shape and storage type before the
+//! weights themselves, so the weight memory can be summed exactly, and the
+//! architecture metadata gives the per-token KV-cache size. Only the header
+//! is read.
+
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{BufReader, Read};
+use std::path::{Path, PathBuf};
+
+use crate::fit::{DEFAULT_KV_BITS, TYPICAL_CONTEXT_TOKENS, kv_cache_gb};
+use crate::models::{Capability, DEFAULT_QUANT_BPP, LlmModel, ModelFormat, QuantTable, infer_tags};
+
+/// "GGUF" read as a little-endian u32.
+const GGUF_MAGIC: u32 = 0x4655_4747;
+
+/// Runtime overhead (CUDA/Metal context, buffers), matching
+/// `LlmModel::estimate_memory_gb`.
+const RUNTIME_OVERHEAD_GB: f64 = 0.5;
+
+/// Longest string the header may hold; anything larger means a corrupt file.
+const MAX_STRING_BYTES: u64 = 16 * 1024 * 1024;
+
+/// Why a GGUF file couldn't be read.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GgufError {
+    /// The file couldn't be opened or ended early.
+    Io(String),
+    /// The file doesn't start with the GGUF magic.
+    NotGguf,
+    /// GGUF versions before 2 used 32-bit lengths and aren't supported.
+    UnsupportedVersion(u32),
+    /// The header is inconsistent, e.g. an unknown value or tensor type.
+    Malformed(String),
+}
+
+impl std::fmt::Display for GgufError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GgufError::Io(message) => write!(f, "{}", message),
+            GgufError::NotGguf => write!(f, "not a GGUF file"),
+            GgufError::UnsupportedVersion(v) => write!(f, "unsupported GGUF version {}", v),
+            GgufError::Malformed(message) => write!(f, "malformed GGUF header: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for GgufError {}
+
+impl From<std::io::Error> for GgufError {
+    fn from(err: std::io::Error) -> Self {
+        GgufError::Io(err.to_string())
+    }
+}
+
+/// Scalar metadata values. Arrays (tokenizer vocabularies and the like) are
+/// skipped.
+#[derive(Debug, Clone, PartialEq)]
+enum MetaValue {
+    Uint(u64),
+    Int(i64),
+    Float(f64),
+    Bool(bool),
+    Str(String),
+}
+
+impl MetaValue {
+    fn as_u64(&self) -> Option<u64> {
+        match *self {
+            MetaValue::Uint(v) => Some(v),
+            MetaValue::Int(v) => u64::try_from(v).ok(),
+            _ => None,
+        }
+    }
+}
+
+/// What llmfit needs from a GGUF header.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GgufInfo {
+    /// `general.name`, if set.
+    pub name: Option<String>,
+    /// `general.architecture`, e.g. "llama".
+    pub architecture: String,
+    /// llmfit's name for `general.file_type`, e.g. "Q4_K_M".
+    pub quantization: String,
+    pub context_length: u32,
+    pub block_count: u64,
+    pub embedding_length: u64,
+    pub head_count: u64,
+    pub head_count_kv: u64,
+    /// Per-head key and value widths (default `embedding_length / head_count`).
+    pub key_length: u64,
+    pub value_length: u64,
+    /// Routed experts per MoE layer (0 for dense models).
+    pub expert_count: u64,
+    pub expert_used_count: u64,
+    /// Weight elements across all tensors.
+    pub parameter_count: u64,
+    /// Elements in per-expert tensors (`*_exps`), for the active count.
+    pub expert_parameter_count: u64,
+    /// Bytes the tensors occupy, i.e. the weight memory.
+    pub tensor_bytes: u64,
+    /// `split.count` for sharded files (1 otherwise).
+    pub split_count: u16,
+    /// `split.no`, 0 for the first shard.
+    pub split_no: u16,
+}
+
+impl GgufInfo {
+    /// Read the header of the GGUF file at `path`.
+    pub fn from_path(path: &Path) -> Result<Self, GgufError> {
+        let file =
+            File::open(path).map_err(|e| GgufError::Io(format!("{}: {}", path.display(), e)))?;
+        Self::from_reader(BufReader::new(file))
+    }
+
+    /// Read a GGUF header from `reader`, stopping before the tensor data.
+    pub fn from_reader(mut reader: impl Read) -> Result<Self, GgufError> {
+        let r = &mut reader;
+        if read_u32(r)? != GGUF_MAGIC {
+            return Err(GgufError::NotGguf);
+        }
+        let version = read_u32(r)?;
+        if !(2..=3).contains(&version) {
+            return Err(GgufError::UnsupportedVersion(version));
+        }
+        let tensor_count = read_u64(r)?;
+        let kv_count = read_u64(r)?;
+
+        let mut meta = HashMap::new();
+        for _ in 0..kv_count {
+            let key = read_string(r)?;
+            let value_type = read_u32(r)?;
+            if let Some(value) = read_value(r, value_type)? {
+                meta.insert(key, value);
+            }
+        }
+
+        let mut parameter_count = 0u64;
+        let mut expert_parameter_count = 0u64;
+        let mut tensor_bytes = 0u64;
+        for _ in 0..tensor_count {
+            let name = read_string(r)?;
+            let n_dims = read_u32(r)?;
+            if n_dims > 8 {
+                return Err(GgufError::Malformed(format!(
+                    "tensor {} has {} dimensions",
+                    name, n_dims
+                )));
+            }
+            let mut elements = 1u64;
+            for _ in 0..n_dims {
+                elements = elements
+                    .checked_mul(read_u64(r)?)
+                    .ok_or_else(|| overflow(&format!("tensor {} element count", name)))?;
+            }
+            let ggml_type = read_u32(r)?;
+            let _offset = read_u64(r)?;
+            let (block, block_bytes) = ggml_type_size(ggml_type).ok_or_else(|| {
+                GgufError::Malformed(format!("tensor {} has unknown type {}", name, ggml_type))
+            })?;
+            parameter_count = parameter_count
+                .checked_add(elements)
+                .ok_or_else(|| overflow("parameter count"))?;
+            if name.contains("_exps") {
+                expert_parameter_count = expert_parameter_count
+                    .checked_add(elements)
+                    .ok_or_else(|| overflow("expert parameter count"))?;
+            }
+            tensor_bytes = elements
+                .div_ceil(block)
+                .checked_mul(block_bytes)
+                .and_then(|bytes| tensor_bytes.checked_add(bytes))
+                .ok_or_else(|| overflow("tensor size"))?;
+        }
+
+        let text = |key: &str| match meta.get(key) {
+            Some(MetaValue::Str(s)) => Some(s.clone()),
+            _ => None,
+        };
+        let architecture = text("general.architecture").unwrap_or_else(|| "unknown".to_string());
+        let arch_u64 = |key: &str| {
+            meta.get(&format!("{}.{}", architecture, key))
+                .and_then(MetaValue::as_u64)
+        };
+        let embedding_length = arch_u64("embedding_length").unwrap_or(0);
+        let head_count = arch_u64("attention.head_count").unwrap_or(0);
+        let head_dim = embedding_length.checked_div(head_count).unwrap_or(0);
+        let quantization = meta
+            .get("general.file_type")
+            .and_then(MetaValue::as_u64)
+            .and_then(file_type_name)
+            .filter(|name| QuantTable::builtin().lookup(name).is_some())
+            .map(str::to_string)
+            .unwrap_or_else(|| nearest_quant(tensor_bytes, parameter_count).to_string());
+
+        let head_count_kv = arch_u64("attention.head_count_kv").unwrap_or(head_count);
+        let key_length = arch_u64("attention.key_length").unwrap_or(head_dim);
+        let value_length = arch_u64("attention.value_length").unwrap_or(head_dim);
+        key_length
+            .checked_add(value_length)
+            .and_then(|width| width.checked_mul(head_count_kv))
+            .ok_or_else(|| overflow("KV cache width"))?;
+        let expert_count = arch_u64("expert_count").unwrap_or(0);
+        let expert_used_count = arch_u64("expert_used_count").unwrap_or(0);
+        if expert_used_count > expert_count {
+            return Err(GgufError::Malformed(format!(
+                "{} of {} experts used per token",
+                expert_used_count, expert_count
+            )));
+        }
+
+        Ok(GgufInfo {
+            name: text("general.name").filter(|n| !n.trim().is_empty()),
+            quantization,
+            context_length: arch_u64("context_length")
+                .and_then(|c| u32::try_from(c).ok())
+                .unwrap_or(TYPICAL_CONTEXT_TOKENS),
+            block_count: arch_u64("block_count").unwrap_or(0),
+            embedding_length,
+            head_count,
+            head_count_kv,
+            key_length,
+            value_length,
+            expert_count,
+            expert_used_count,
+            parameter_count,
+            expert_parameter_count,
+            tensor_bytes,
+            split_count: meta
+                .get("split.count")
+                .and_then(MetaValue::as_u64)
+                .and_then(|c| u16::try_from(c).ok())
+                .unwrap_or(1)
+                .max(1),
+            split_no: meta
+                .get("split.no")
+                .and_then(MetaValue::as_u64)
+                .and_then(|c| u16::try_from(c).ok())
+                .unwrap_or(0),
+            architecture,
+        })
+    }
+
+    /// Weight memory in GB.
+    pub fn weights_gb(&self) -> f64 {
+        self.tensor_bytes as f64 / 1e9
+    }
+
+    fn num_layers(&self) -> Option<u32> {
+        u32::try_from(self.block_count).ok().filter(|&n| n > 0)
+    }
+
+    /// Width of the keys (and values) cached per layer and token, or `None`
+    /// when the header lacks the attention shape.
+    pub fn kv_hidden_size(&self) -> Option<u32> {
+        let width = self
+            .key_length
+            .checked_add(self.value_length)?
+            .checked_mul(self.head_count_kv)?
+            / 2;
+        u32::try_from(width).ok().filter(|&w| w > 0)
+    }
+
+    /// fp16 KV-cache memory in GB for `ctx` tokens, or `None` when the
+    /// header lacks the attention shape.
+    pub fn kv_cache_gb(&self, ctx: u32) -> Option<f64> {
+        Some(kv_cache_gb(
+            ctx,
+            self.num_layers()?,
+            self.kv_hidden_size()?,
+            DEFAULT_KV_BITS,
+        ))
+    }
+
+    /// Parameters touched per token (differs from the total only for MoE).
+    pub fn active_parameter_count(&self) -> u64 {
+        if self.expert_count == 0 {
+            return self.parameter_count;
+        }
+        let dense = self
+            .parameter_count
+            .saturating_sub(self.expert_parameter_count);
+        // u128 so the product can't overflow; the share is at most the
+        // expert total when no more experts are used than exist
+        let used = self.expert_parameter_count as u128 * self.expert_used_count as u128
+            / self.expert_count as u128;
+        dense.saturating_add(u64::try_from(used).unwrap_or(u64::MAX))
+    }
+
+    /// Build a catalog-style entry named `name`. The minimum is the weights
+    /// plus a typical context's KV cache; the recommendation covers the
+    /// model's full context.
+    pub fn to_model(&self, name: String) -> LlmModel {
+        let params = self.parameter_count;
+        let params_b = params as f64 / 1e9;
+        let is_moe = self.expert_count > 0;
+        let mut model = LlmModel {
+            name,
+            provider: "local".to_string(),
+            parameter_count: if params_b >= 1.0 {
+                format!("{:.1}B", params_b)
+            } else {
+                format!("{:.0}M", params_b * 1000.0)
+            },
+            parameters_raw: Some(params),
+            min_ram_gb: 0.0,
+            recommended_ram_gb: 0.0,
+            min_vram_gb: None,
+            quantization: self.quantization.clone(),
+            context_length: self.context_length,
+            use_case: "general".to_string(),
+            is_moe,
+            num_experts: is_moe.then_some(self.expert_count as u32),
+            active_experts: is_moe.then_some(self.expert_used_count as u32),
+            active_parameters: is_moe.then(|| self.active_parameter_count()),
+            release_date: None,
+            gguf_sources: vec![],
+            capabilities: vec![],
+            tags: vec![],
+            format: ModelFormat::Gguf,
+            num_layers: self.num_layers(),
+            kv_hidden_size: self.kv_hidden_size(),
+            hf_downloads: None,
+            gguf_file_sizes: Default::default(),
+        };
+        let min = self.weights_gb()
+            + model.kv_cache_gb(self.context_length.min(TYPICAL_CONTEXT_TOKENS))
+            + RUNTIME_OVERHEAD_GB;
+        let full = self.weights_gb() + model.kv_cache_gb(self.context_length) + RUNTIME_OVERHEAD_GB;
+        model.min_ram_gb = min;
+        model.recommended_ram_gb = full.max(min * 1.2);
+        model.min_vram_gb = Some(min);
+        model.capabilities = Capability::infer(&model);
+        model.tags = infer_tags(&model);
+        model
+    }
+}
+
+impl LlmModel {
+    /// Size a model from a local GGUF file. The name is `general.name`, or
+    /// the file name without the extension. For the first shard of a split
+    /// model, the other shards' tensors are included.
+    pub fn from_gguf(path: &Path) -> Result<LlmModel, GgufError> {
+        let mut info = GgufInfo::from_path(path)?;
+        if info.split_count > 1 {
+            for no in 1..info.split_count {
+                let shard = shard_path(path, no, info.split_count).ok_or_else(|| {
+                    GgufError::Malformed(format!(
+                        "{} is a split model but isn't named like one",
+                        path.display()
+                    ))
+                })?;
+                let part = GgufInfo::from_path(&shard)?;
+                let sum = |total: u64, part: u64, what: &str| {
+                    total.checked_add(part).ok_or_else(|| overflow(what))
+                };
+                info.parameter_count = sum(
+                    info.parameter_count,
+                    part.parameter_count,
+                    "parameter count",
+                )?;
+                info.expert_parameter_count = sum(
+                    info.expert_parameter_count,
+                    part.expert_parameter_count,
+                    "expert parameter count",
+                )?;
+                info.tensor_bytes = sum(info.tensor_bytes, part.tensor_bytes, "tensor size")?;
+            }
+        }
+        let name = info.name.clone().unwrap_or_else(|| file_stem(path));
+        Ok(info.to_model(name))
+    }
+}
+
+/// Every `*.gguf` under `dir` (recursively) as a model, plus the files that
+/// couldn't be read. Later shards of split models are folded into the first.
+/// A name already taken by an earlier file falls back to the file name.
+pub fn scan_dir(dir: &Path) -> (Vec<LlmModel>, Vec<(PathBuf, GgufError)>) {
+    let mut paths = Vec::new();
+    collect_gguf_paths(dir, &mut paths);
+    paths.sort();
+
+    let mut models: Vec<LlmModel> = Vec::new();
+    let mut errors = Vec::new();
+    for path in paths {
+        if split_position(&path).is_some_and(|(no, _)| no > 1) {
+            continue;
+        }
+        match LlmModel::from_gguf(&path) {
+            Ok(mut model) => {
+                if models.iter().any(|m| m.name == model.name) {
+                    model.name = file_stem(&path);
+                }
+                models.push(model);
+            }
+            Err(err) => errors.push((path, err)),
+        }
+    }
+    (models, errors)
+}
+
+/// Directory symlinks aren't followed, so a link loop can't recurse forever.
+/// Symlinked files are still picked up.
+fn collect_gguf_paths(dir: &Path, out: &mut Vec<PathBuf>) {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let Ok(file_type) = entry.file_type() else {
+            continue;
+        };
+        if file_type.is_dir() {
+            collect_gguf_paths(&path, out);
+        } else if !path.is_dir()
+            && path
+                .extension()
+                .is_some_and(|ext| ext.eq_ignore_ascii_case("gguf"))
+        {
+            out.push(path);
+        }
+    }
+}
+
+/// A header figure too large for 64 bits, which only a corrupt or crafted
+/// file produces.
+fn overflow(what: &str) -> GgufError {
+    GgufError::Malformed(format!("{} overflows", what))
+}
+
+fn file_stem(path: &Path) -> String {
+    path.file_stem()
+        .map(|s| s.to_string_lossy().into_owned())
+        .unwrap_or_default()
+}
+
+/// `(n, of)` from llama.cpp's shard naming, `<name>-00001-of-00003.gguf`.
+fn split_position(path: &Path) -> Option<(u16, u16)> {
+    let stem = file_stem(path);
+    let (head, of) = stem.rsplit_once("-of-")?;
+    let (_, no) = head.rsplit_once('-')?;
+    Some((no.parse().ok()?, of.parse().ok()?))
+}
+
+/// Path of shard `no` (0-based) next to the first shard at `first`.
+fn shard_path(first: &Path, no: u16, count: u16) -> Option<PathBuf> {
+    let stem = file_stem(first);
+    let prefix = stem.strip_suffix(&format!("-00001-of-{:05}", count))?;
+    let name = format!("{}-{:05}-of-{:05}.gguf", prefix, no + 1, count);
+    Some(first.with_file_name(name))
+}
+
+/// Block size in elements and bytes per block for a ggml tensor type.
+fn ggml_type_size(ggml_type: u32) -> Option<(u64, u64)> {
+    Some(match ggml_type {
+        0 => (1, 4),      // F32
+        1 => (1, 2),      // F16
+        2 => (32, 18),    // Q4_0
+        3 => (32, 20),    // Q4_1
+        6 => (32, 22),    // Q5_0
+        7 => (32, 24),    // Q5_1
+        8 => (32, 34),    // Q8_0
+        9 => (32, 36),    // Q8_1
+        10 => (256, 84),  // Q2_K
+        11 => (256, 110), // Q3_K
+        12 => (256, 144), // Q4_K
+        13 => (256, 176), // Q5_K
+        14 => (256, 210), // Q6_K
+        15 => (256, 292), // Q8_K
+        16 => (256, 66),  // IQ2_XXS
+        17 => (256, 74),  // IQ2_XS
+        18 => (256, 98),  // IQ3_XXS
+        19 => (256, 50),  // IQ1_S
+        20 => (32, 18),   // IQ4_NL
+        21 => (256, 110), // IQ3_S
+        22 => (256, 82),  // IQ2_S
+        23 => (256, 136), // IQ4_XS
+        24 => (1, 1),     // I8
+        25 => (1, 2),     // I16
+        26 => (1, 4),     // I32
+        27 => (1, 8),     // I64
+        28 => (1, 8),     // F64
+        29 => (256, 56),  // IQ1_M
+        30 => (1, 2),     // BF16
+        34 => (256, 54),  // TQ1_0
+        35 => (256, 66),  // TQ2_0
+        39 => (32, 17),   // MXFP4
+        _ => return None,
+    })
+}
+
+/// llama.cpp's name for a `general.file_type` value.
+fn file_type_name(file_type: u64) -> Option<&'static str> {
+    Some(match file_type {
+        0 => "F32",
+        1 => "F16",
+        2 => "Q4_0",
+        3 => "Q4_1",
+        7 => "Q8_0",
+        8 => "Q5_0",
+        9 => "Q5_1",
+        10 => "Q2_K",
+        11 => "Q3_K_S",
+        12 => "Q3_K_M",
+        13 => "Q3_K_L",
+        14 => "Q4_K_S",
+        15 => "Q4_K_M",
+        16 => "Q5_K_S",
+        17 => "Q5_K_M",
+        18 => "Q6_K",
+        19 => "IQ2_XXS",
+        20 => "IQ2_XS",
+        21 => "Q2_K_S",
+        22 => "IQ3_XS",
+        23 => "IQ3_XXS",
+        24 => "IQ1_S",
+        25 => "IQ4_NL",
+        26 => "IQ3_S",
+        27 => "IQ3_M",
+        28 => "IQ2_S",
+        29 => "IQ2_M",
+        30 => "IQ4_XS",
+        31 => "IQ1_M",
+        32 => "BF16",
+        _ => return None,
+    })
+}
+
+/// The GGUF quantization llmfit knows whose bytes per weight is closest to
+/// the file's, for file types it has no figure for.
+fn nearest_quant(tensor_bytes: u64, parameter_count: u64) -> &'static str {
+    let bpp = tensor_bytes as f64 / parameter_count.max(1) as f64;
+    DEFAULT_QUANT_BPP
+        .iter()
+        .filter(|(name, _)| {
+            !["mlx", "AWQ", "GPTQ", "FP8"]
+                .iter()
+                .any(|prefix| name.starts_with(prefix))
+        })
+        .min_by(|a, b| (a.1 - bpp).abs().total_cmp(&(b.1 - bpp).abs()))
+        .map(|&(name, _)| name)
+        .unwrap_or("F16")
+}
+
+fn read_bytes<const N: usize>(r: &mut impl Read) -> Result<[u8; N], GgufError> {
+    let mut buf = [0u8; N];
+    r.read_exact(&mut buf)?;
+    Ok(buf)
+}
+
+fn read_u32(r: &mut impl Read) -> Result<u32, GgufError> {
+    Ok(u32::from_le_bytes(read_bytes(r)?))
+}
+
+fn read_u64(r: &mut impl Read) -> Result<u64, GgufError> {
+    Ok(u64::from_le_bytes(read_bytes(r)?))
+}
+
+fn read_string(r: &mut impl Read) -> Result<String, GgufError> {
+    let len = read_u64(r)?;
+    if len > MAX_STRING_BYTES {
+        return Err(GgufError::Malformed(format!(
+            "string of {} bytes in header",
+            len
+        )));
+    }
+    let mut buf = vec![0u8; len as usize];
+    r.read_exact(&mut buf)?;
+    Ok(String::from_utf8_lossy(&buf).into_owned())
+}
+
+/// Read one metadata value of GGUF type `value_type`; arrays are consumed
+/// and return `None`.
+fn read_value(r: &mut impl Read, value_type: u32) -> Result<Option<MetaValue>, GgufError> {
+    let value = match value_type {
+        0 => MetaValue::Uint(u8::from_le_bytes(read_bytes(r)?).into()),
+        1 => MetaValue::Int(i8::from_le_bytes(read_bytes(r)?).into()),
+        2 => MetaValue::Uint(u16::from_le_bytes(read_bytes(r)?).into()),
+        3 => MetaValue::Int(i16::from_le_bytes(read_bytes(r)?).into()),
+        4 => MetaValue::Uint(read_u32(r)?.into()),
+        5 => MetaValue::Int(i32::from_le_bytes(read_bytes(r)?).into()),
+        6 => MetaValue::Float(f32::from_le_bytes(read_bytes(r)?).into()),
+        7 => MetaValue::Bool(read_bytes::<1>(r)?[0] != 0),
+        8 => MetaValue::Str(read_string(r)?),
+        9 => {
+            let item_type = read_u32(r)?;
+            let len = read_u64(r)?;
+            for _ in 0..len {
+                read_value(r, item_type)?;
+            }
+            return Ok(None);
+        }
+        10 => MetaValue::Uint(read_u64(r)?),
+        11 => MetaValue::Int(i64::from_le_bytes(read_bytes(r)?)),
+        12 => MetaValue::Float(f64::from_le_bytes(read_bytes(r)?)),
+        other => {
+            return Err(GgufError::Malformed(format!(
+                "unknown metadata type {}",
+                other
+            )));
+        }
+    };
+    Ok(Some(value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Minimal GGUF v3 writer for building test headers.
+    struct Writer(Vec<u8>);
+
+    impl Writer {
+        fn string(&mut self, s: &str) {
+            self.0.extend((s.len() as u64).to_le_bytes());
+            self.0.extend(s.as_bytes());
+        }
+        fn kv_u32(&mut self, key: &str, v: u32) {
+            self.string(key);
+            self.0.extend(4u32.to_le_bytes());
+            self.0.extend(v.to_le_bytes());
+        }
+        fn kv_str(&mut self, key: &str, v: &str) {
+            self.string(key);
+            self.0.extend(8u32.to_le_bytes());
+            self.string(v);
+        }
+        fn tensor(&mut self, name: &str, dims: &[u64], ggml_type: u32) {
+            self.string(name);
+            self.0.extend((dims.len() as u32).to_le_bytes());
+            for d in dims {
+                self.0.extend(d.to_le_bytes());
+            }
+            self.0.extend(ggml_type.to_le_bytes());
+            self.0.extend(0u64.to_le_bytes());
+        }
+    }
+
+    /// A two-layer llama-style header: Q4_K weights, F32 norm, GQA 32/8.
+    fn tiny_llama() -> Vec<u8> {
+        let mut w = Writer(Vec::new());
+        w.0.extend(GGUF_MAGIC.to_le_bytes());
+        w.0.extend(3u32.to_le_bytes());
+        w.0.extend(2u64.to_le_bytes()); // tensors
+        w.0.extend(9u64.to_le_bytes()); // metadata
+        w.kv_str("general.architecture", "llama");
+        w.kv_str("general.name", "Tiny Llama");
+        w.kv_u32("general.file_type", 15);
+        w.kv_u32("llama.context_length", 8192);
+        w.kv_u32("llama.block_count", 2);
+        w.kv_u32("llama.embedding_length", 4096);
+        w.kv_u32("llama.attention.head_count", 32);
+        w.kv_u32("llama.attention.head_count_kv", 8);
+        // A token array, which must be skipped
+        w.string("tokenizer.ggml.tokens");
+        w.0.extend(9u32.to_le_bytes());
+        w.0.extend(8u32.to_le_bytes());
+        w.0.extend(2u64.to_le_bytes());
+        w.string("<s>");
+        w.string("</s>");
+        w.tensor("blk.0.attn_q.weight", &[4096, 4096], 12);
+        w.tensor("output_norm.weight", &[4096], 0);
+        w.0
+    }
+
+    #[test]
+    fn test_reads_header_and_sizes_tensors() {
+        let info = GgufInfo::from_reader(tiny_llama().as_slice()).unwrap();
+        assert_eq!(info.name.as_deref(), Some("Tiny Llama"));
+        assert_eq!(info.quantization, "Q4_K_M");
+        assert_eq!(info.context_length, 8192);
+        assert_eq!((info.key_length, info.head_count_kv), (128, 8));
+        assert_eq!(info.parameter_count, 4096 * 4096 + 4096);
+        // 65536 Q4_K blocks of 144 bytes, plus 4096 f32s
+        assert_eq!(info.tensor_bytes, 65_536 * 144 + 4096 * 4);
+        // 2 layers x 8 heads x (128 + 128) x 2 bytes per token
+        let kv = info.kv_cache_gb(1000).unwrap();
+        assert!((kv - 8192.0 * 1000.0 / 1e9).abs() < 1e-9);
+
+        let model = info.to_model("Tiny Llama".to_string());
+        assert_eq!(model.provider, "local");
+        let expected_min = info.weights_gb() + info.kv_cache_gb(2048).unwrap() + 0.5;
+        assert!((model.min_ram_gb - expected_min).abs() < 1e-9);
+        assert_eq!(model.min_vram_gb, Some(model.min_ram_gb));
+    }
+
+    #[test]
+    fn test_rejects_non_gguf_and_old_versions() {
+        assert_eq!(
+            GgufInfo::from_reader(&b"PK\x03\x04rest"[..]),
+            Err(GgufError::NotGguf)
+        );
+        let mut v1 = GGUF_MAGIC.to_le_bytes().to_vec();
+        v1.extend(1u32.to_le_bytes());
+        assert_eq!(
+            GgufInfo::from_reader(v1.as_slice()),
+            Err(GgufError::UnsupportedVersion(1))
+        );
+        let mut truncated = tiny_llama();
+        truncated.truncate(truncated.len() - 10);
+        assert!(matches!(
+            GgufInfo::from_reader(truncated.as_slice()),
+            Err(GgufError::Io(_))
+        ));
+    }
+
+    #[test]
+    fn test_oversized_dimensions_are_malformed() {
+        let header = |tensors: &[(&str, &[u64], u32)], kv: &[(&str, u32)]| {
+            let mut w = Writer(Vec::new());
+            w.0.extend(GGUF_MAGIC.to_le_bytes());
+            w.0.extend(3u32.to_le_bytes());
+            w.0.extend((tensors.len() as u64).to_le_bytes());
+            w.0.extend((kv.len() as u64 + 1).to_le_bytes());
+            w.kv_str("general.architecture", "llama");
+            for &(key, v) in kv {
+                w.kv_u32(key, v);
+            }
+            for &(name, dims, ggml_type) in tensors {
+                w.tensor(name, dims, ggml_type);
+            }
+            w.0
+        };
+        let malformed = |bytes: Vec<u8>| {
+            matches!(
+                GgufInfo::from_reader(bytes.as_slice()),
+                Err(GgufError::Malformed(_))
+            )
+        };
+        // Element count past u64
+        assert!(malformed(header(&[("w", &[u64::MAX, 2], 0)], &[])));
+        // Elements fit, bytes don't: 2^62 f32s
+        assert!(malformed(header(&[("w", &[1 << 62], 0)], &[])));
+        // Parameter total past u64 across tensors
+        let big: &[u64] = &[1 << 63];
+        assert!(malformed(header(&[("a", big, 2), ("b", big, 2)], &[])));
+        // KV width past u64
+        assert!(malformed(header(
+            &[],
+            &[
+                ("llama.attention.head_count_kv", u32::MAX),
+                ("llama.attention.key_length", u32::MAX),
+                ("llama.attention.value_length", u32::MAX),
+            ]
+        )));
+        // More experts used than exist
+        assert!(malformed(header(
+            &[],
+            &[("llama.expert_count", 2), ("llama.expert_used_count", 8)]
+        )));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_scan_dir_survives_symlink_loop() {
+        let dir = std::env::temp_dir().join(format!("llmfit-gguf-loop-{}", std::process::id()));
+        let nested = dir.join("nested");
+        std::fs::create_dir_all(&nested).unwrap();
+        std::fs::write(nested.join("tiny.gguf"), tiny_llama()).unwrap();
+        std::os::unix::fs::symlink(&dir, nested.join("back")).unwrap();
+
+        let (models, errors) = scan_dir(&dir);
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(models.len(), 1);
+        assert!(errors.is_empty());
+    }
+
+    #[test]
+    fn test_split_shard_names() {
+        let first = Path::new("/m/Qwen-72B-Q4_K_M-00001-of-00003.gguf");
+        assert_eq!(split_position(first), Some((1, 3)));
+        assert_eq!(
+            shard_path(first, 2, 3).unwrap(),
+            Path::new("/m/Qwen-72B-Q4_K_M-00003-of-00003.gguf")
+        );
+        assert_eq!(split_position(Path::new("/m/llama-3-8b.gguf")), None);
+    }
+}
@@ -1,8 +1,10 @@
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, HashMap};
 use sysinfo::System;
 
+use crate::activity;
+
 /// The acceleration backend for inference speed estimation.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub enum GpuBackend {
     Cuda,
     Metal,
@@ -29,17 +31,26 @@ impl GpuBackend {
     }
 }
 
+/// Version of the profile format written by [`SystemSpecs::to_profile_json`].
+/// Bump it when a change to [`SystemSpecs`] can't be read with defaults.
+pub const SPECS_SCHEMA_VERSION: u32 = 1;
+
 /// Information about a single detected GPU.
-#[derive(Debug, Clone, serde::Serialize)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct GpuInfo {
     pub name: String,
+    /// VRAM of one card. When `count > 1` and the cards differ in size
+    /// (e.g. mixed AMD cards under rocm-smi), this is the largest card,
+    /// not the sum.
     pub vram_gb: Option<f64>,
     pub backend: GpuBackend,
     pub count: u32, // >1 for same-model multi-GPU (e.g. 2x RTX 4090)
     pub unified_memory: bool,
 }
 
-#[derive(Debug, Clone, serde::Serialize)]
+/// Detected hardware. Saved profiles ([`SystemSpecs::to_profile_json`])
+/// deserialize into it, so fields added later take `#[serde(default)]`.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct SystemSpecs {
     pub total_ram_gb: f64,
     pub available_ram_gb: f64,
@@ -57,26 +68,680 @@ pub struct SystemSpecs {
     pub backend: GpuBackend,
     /// All detected GPUs (may span different vendors/backends).
     pub gpus: Vec<GpuInfo>,
+    /// System RAM bandwidth in GB/s from DIMM info, the unified-memory chip
+    /// table or `--bench`; `None` when none of them had an answer. Estimates
+    /// use [`SystemSpecs::mem_bandwidth_gbps`], which falls back to a
+    /// per-class default.
+    pub memory_bandwidth_gbps: Option<f64>,
+    /// RAM the user carved out with `--reserve`, already subtracted from
+    /// `available_ram_gb`. Zero when nothing is reserved.
+    #[serde(default)]
+    pub reserved_ram_gb: f64,
+    /// OS/runtime headroom from `--headroom`, already subtracted from
+    /// `available_ram_gb` and the VRAM figures. Zero when none is applied.
+    #[serde(default)]
+    pub headroom_gb: f64,
+    /// How much of the unified pool macOS lets the GPU wire on Apple
+    /// Silicon, already applied to the VRAM figures. `None` elsewhere, or
+    /// when `--memory` set the pool explicitly.
+    pub gpu_wired_limit_gb: Option<f64>,
+    /// Swap space, total and in use. Never counted as available memory: a
+    /// model that only fits by swapping is scored Too Tight.
+    #[serde(default)]
+    pub swap_total_gb: f64,
+    #[serde(default)]
+    pub swap_used_gb: f64,
+    /// Per-process allocation cap from `RLIMIT_AS`/`RLIMIT_DATA` when it is
+    /// below available RAM, already applied to `available_ram_gb`.
+    pub process_mem_limit_gb: Option<f64>,
+    /// NVIDIA driver version from nvidia-smi, e.g. "550.54.14".
+    pub driver_version: Option<String>,
+    /// Highest CUDA version the installed driver supports, e.g. "12.4".
+    pub cuda_version: Option<String>,
+    /// SIMD extensions llama.cpp's CPU kernels use.
+    #[serde(default)]
+    pub cpu_features: CpuFeatures,
+    /// An on-die AI accelerator is present. Fit analysis doesn't use it
+    /// yet; it's surfaced so NPU-aware runtimes can be added later.
+    #[serde(default)]
+    pub has_npu: bool,
+    /// e.g. "Intel AI Boost", "AMD XDNA", "Apple Neural Engine".
+    pub npu_name: Option<String>,
+    /// Profile file these specs were loaded from (`--specs-from`) instead
+    /// of detected. Loaded specs have no live RAM figures to refresh.
+    #[serde(skip)]
+    pub loaded_from: Option<String>,
+    /// RAM, VRAM or core count was overridden to describe a machine the
+    /// user doesn't have (`--ram`, `--vram`, `--cores`).
+    #[serde(default)]
+    pub hypothetical: bool,
+    /// Free space (binary GB, like the RAM figures) on the disk models are
+    /// downloaded to, or `None` when it couldn't be determined.
+    #[serde(default)]
+    pub free_disk_gb: Option<f64>,
+}
+
+/// CPU SIMD extensions that matter for CPU inference speed. All false on
+/// architectures llmfit doesn't probe.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct CpuFeatures {
+    pub avx2: bool,
+    /// AVX-512 Foundation.
+    pub avx512: bool,
+    /// Intel Advanced Matrix Extensions (tile registers).
+    pub amx: bool,
+    pub neon: bool,
+    pub sve: bool,
+}
+
+impl CpuFeatures {
+    /// Probe the running CPU with `is_*_feature_detected!`, cross-checked
+    /// against the flags in /proc/cpuinfo on Linux. AMX has no stable
+    /// runtime probe, so it's only reported from /proc/cpuinfo.
+    pub fn detect() -> Self {
+        #[allow(unused_mut)]
+        let mut features = CpuFeatures::default();
+        #[cfg(target_arch = "x86_64")]
+        {
+            features.avx2 = std::arch::is_x86_feature_detected!("avx2");
+            features.avx512 = std::arch::is_x86_feature_detected!("avx512f");
+        }
+        #[cfg(target_arch = "aarch64")]
+        {
+            features.neon = std::arch::is_aarch64_feature_detected!("neon");
+            features.sve = std::arch::is_aarch64_feature_detected!("sve");
+        }
+        #[cfg(target_os = "linux")]
+        if let Ok(text) = std::fs::read_to_string("/proc/cpuinfo") {
+            let listed = parse_cpuinfo_features(&text);
+            features.amx = listed.amx;
+            if listed != features {
+                // The runtime probe also checks that the OS saves the wider
+                // registers, so it wins over the flag list
+                activity::detect(format!(
+                    "CPU features: /proc/cpuinfo lists {:?}, runtime probe found {:?}",
+                    listed.labels(),
+                    features.labels()
+                ));
+            }
+        }
+        features
+    }
+
+    /// Names of the detected extensions, e.g. `["AVX2", "AVX512"]`.
+    pub fn labels(&self) -> Vec<&'static str> {
+        [
+            (self.avx2, "AVX2"),
+            (self.avx512, "AVX512"),
+            (self.amx, "AMX"),
+            (self.neon, "NEON"),
+            (self.sve, "SVE"),
+        ]
+        .into_iter()
+        .filter_map(|(present, label)| present.then_some(label))
+        .collect()
+    }
+
+    /// The most capable extension, for a one-word badge.
+    pub fn badge(&self) -> Option<&'static str> {
+        self.labels().pop()
+    }
+}
+
+/// Storage class of the disk models are loaded from, used to estimate load time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+pub enum DiskTier {
+    Nvme,
+    Ssd,
+    Hdd,
+    Unknown,
+}
+
+impl DiskTier {
+    pub fn label(&self) -> &'static str {
+        match self {
+            DiskTier::Nvme => "NVMe",
+            DiskTier::Ssd => "SSD",
+            DiskTier::Hdd => "HDD",
+            DiskTier::Unknown => "unknown disk",
+        }
+    }
+
+    /// Typical sequential read throughput in GB/s. Unknown disks are assumed
+    /// to be SATA SSDs, which is the common case on machines running LLMs.
+    pub fn read_gbps(&self) -> f64 {
+        match self {
+            DiskTier::Nvme => 2.5,
+            DiskTier::Ssd => 0.5,
+            DiskTier::Hdd => 0.15,
+            DiskTier::Unknown => 0.5,
+        }
+    }
+
+    /// Spinning disks make load time dominate first-token latency.
+    pub fn is_slow(&self) -> bool {
+        matches!(self, DiskTier::Hdd)
+    }
+
+    /// Classify the disk holding `path`, using the mount point with the
+    /// longest matching prefix. NVMe is told apart from other SSDs by the
+    /// device name, which is all sysinfo exposes.
+    pub fn detect(path: &std::path::Path) -> Self {
+        let disks = sysinfo::Disks::new_with_refreshed_list();
+        let tier = disk_holding(&disks, path)
+            .map(|d| {
+                let name = d.name().to_string_lossy().to_lowercase();
+                match d.kind() {
+                    sysinfo::DiskKind::HDD => DiskTier::Hdd,
+                    _ if name.contains("nvme") => DiskTier::Nvme,
+                    sysinfo::DiskKind::SSD => DiskTier::Ssd,
+                    sysinfo::DiskKind::Unknown(_) => DiskTier::Unknown,
+                }
+            })
+            .unwrap_or(DiskTier::Unknown);
+        activity::detect(format!(
+            "Model disk: {} ({}, ~{:.2} GB/s read)",
+            path.display(),
+            tier.label(),
+            tier.read_gbps()
+        ));
+        tier
+    }
+
+    /// Seconds to read `size_gb` of weights from this disk.
+    pub fn load_seconds(&self, size_gb: f64) -> f64 {
+        size_gb / self.read_gbps()
+    }
+}
+
+/// The mounted disk `path` lives on: the mount point with the longest
+/// matching prefix.
+fn disk_holding<'a>(
+    disks: &'a sysinfo::Disks,
+    path: &std::path::Path,
+) -> Option<&'a sysinfo::Disk> {
+    disks
+        .list()
+        .iter()
+        .filter(|d| path.starts_with(d.mount_point()))
+        .max_by_key(|d| d.mount_point().as_os_str().len())
+}
+
+/// Free space (binary GB) on the disk holding `path`, or `None` if no
+/// mounted disk contains it.
+pub fn disk_free_gb(path: &std::path::Path) -> Option<f64> {
+    let disks = sysinfo::Disks::new_with_refreshed_list();
+    let free = disk_holding(&disks, path)?.available_space() as f64 / (1024.0 * 1024.0 * 1024.0);
+    activity::detect(format!(
+        "Model disk: {:.1} GiB free at {}",
+        free,
+        path.display()
+    ));
+    Some(free)
+}
+
+/// Raw memory figures from one `System::refresh_memory()`, cheap enough to
+/// take every few seconds. See [`SystemSpecs::apply_memory_reading`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MemoryReading {
+    pub available_ram_gb: f64,
+    pub swap_used_gb: f64,
+}
+
+impl MemoryReading {
+    pub fn take() -> Self {
+        let mut sys = System::new();
+        sys.refresh_memory();
+        MemoryReading {
+            available_ram_gb: SystemSpecs::current_available_ram_gb(&sys),
+            swap_used_gb: sys.used_swap() as f64 / (1024.0 * 1024.0 * 1024.0),
+        }
+    }
+}
+
+/// What a [`HardwareDetector`] gets to work with.
+pub struct DetectContext<'a> {
+    pub total_ram_gb: f64,
+    pub cpu_name: &'a str,
+    /// Accelerators already reported by higher-priority detectors, so a
+    /// detector that overlaps with another (WMI, Vulkan) can skip duplicates.
+    pub found: &'a [GpuInfo],
+}
+
+/// A source of GPU/accelerator information.
+///
+/// The built-in NVIDIA, AMD, Windows WMI, Intel, Apple, Ascend and Vulkan
+/// probes are implementations of this trait. Library users can teach llmfit
+/// about other accelerators by calling [`register_detector`] before
+/// [`SystemSpecs::detect`].
+pub trait HardwareDetector: Send + Sync {
+    /// Short name used in the activity log.
+    fn name(&self) -> &str;
+
+    /// Detectors run in ascending priority order. Built-in vendor tools use
+    /// 10-60 and the Vulkan fallback uses 90, so the default of 80 runs custom
+    /// detectors after the vendor tools but before the generic fallback.
+    fn priority(&self) -> u32 {
+        80
+    }
+
+    /// Accelerators this detector found; empty when there are none.
+    fn detect(&self, ctx: &DetectContext) -> Vec<GpuInfo>;
+}
+
+static CUSTOM_DETECTORS: std::sync::Mutex<Vec<std::sync::Arc<dyn HardwareDetector>>> =
+    std::sync::Mutex::new(Vec::new());
+
+/// Add a detector to every subsequent [`SystemSpecs::detect`] call.
+pub fn register_detector(detector: impl HardwareDetector + 'static) {
+    if let Ok(mut custom) = CUSTOM_DETECTORS.lock() {
+        custom.push(std::sync::Arc::new(detector));
+    }
+}
+
+/// Built-in and custom detectors, in the order they should run. Ties keep
+/// registration order, with built-ins first.
+fn registered_detectors() -> Vec<std::sync::Arc<dyn HardwareDetector>> {
+    let mut detectors: Vec<std::sync::Arc<dyn HardwareDetector>> = vec![
+        std::sync::Arc::new(NvidiaDetector),
+        std::sync::Arc::new(AmdDetector),
+        std::sync::Arc::new(WindowsWmiDetector),
+        std::sync::Arc::new(IntelDetector),
+        std::sync::Arc::new(AppleDetector),
+        std::sync::Arc::new(AscendDetector),
+        std::sync::Arc::new(VulkanDetector),
+    ];
+    if let Ok(custom) = CUSTOM_DETECTORS.lock() {
+        detectors.extend(custom.iter().cloned());
+    }
+    detectors.sort_by_key(|d| d.priority());
+    detectors
+}
+
+/// NVIDIA GPUs via nvidia-smi, with sysfs fallback for Linux/toolbox setups.
+struct NvidiaDetector;
+
+impl HardwareDetector for NvidiaDetector {
+    fn name(&self) -> &str {
+        "NVIDIA (nvidia-smi/sysfs)"
+    }
+
+    fn priority(&self) -> u32 {
+        10
+    }
+
+    fn detect(&self, _ctx: &DetectContext) -> Vec<GpuInfo> {
+        let nvidia = SystemSpecs::detect_nvidia_gpus();
+        if nvidia.is_empty() {
+            SystemSpecs::detect_nvidia_gpu_sysfs_info()
+                .into_iter()
+                .collect()
+        } else {
+            nvidia
+        }
+    }
+}
+
+/// AMD GPUs via rocm-smi or sysfs.
+struct AmdDetector;
+
+impl HardwareDetector for AmdDetector {
+    fn name(&self) -> &str {
+        "AMD (rocm-smi/sysfs)"
+    }
+
+    fn priority(&self) -> u32 {
+        20
+    }
+
+    fn detect(&self, _ctx: &DetectContext) -> Vec<GpuInfo> {
+        SystemSpecs::detect_amd_gpu_rocm_info()
+            .or_else(SystemSpecs::detect_amd_gpu_sysfs_info)
+            .into_iter()
+            .collect()
+    }
+}
+
+/// Windows WMI, which catches GPUs not found by vendor-specific tools.
+struct WindowsWmiDetector;
+
+impl HardwareDetector for WindowsWmiDetector {
+    fn name(&self) -> &str {
+        "Windows WMI"
+    }
+
+    fn priority(&self) -> u32 {
+        30
+    }
+
+    fn detect(&self, ctx: &DetectContext) -> Vec<GpuInfo> {
+        SystemSpecs::detect_gpu_windows_info()
+            .into_iter()
+            .filter(|wmi_gpu| {
+                // Skip if a vendor tool already found a GPU with the same name
+                let wmi_lower = wmi_gpu.name.to_lowercase();
+                !ctx.found.iter().any(|existing| {
+                    let existing_lower = existing.name.to_lowercase();
+                    existing_lower.contains(&wmi_lower) || wmi_lower.contains(&existing_lower)
+                })
+            })
+            .collect()
+    }
+}
+
+/// What the Intel probe found: a discrete Arc card with dedicated VRAM
+/// (`None` when the driver doesn't report it) or integrated Arc Graphics
+/// sharing system RAM, with the shared-memory limit if sysfs reports one.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum IntelGpu {
+    Discrete(Option<f64>),
+    Integrated(Option<f64>),
+}
+
+/// How much system RAM an integrated Intel GPU may use: the limit the
+/// driver reports, else half of RAM, the default shared-memory share on
+/// both Linux and Windows.
+fn intel_shared_memory_gb(reported_gb: Option<f64>, total_ram_gb: f64) -> f64 {
+    reported_gb
+        .filter(|&gb| gb > 0.0)
+        .map_or(total_ram_gb / 2.0, |gb| gb.min(total_ram_gb))
+}
+
+/// Classify an lspci line naming an Intel Arc device. Discrete cards carry a
+/// model number (`Arc A770`, `Arc B580`, `Arc Pro A60`) or the DG2 codename;
+/// integrated parts are listed as plain "Arc Graphics".
+fn classify_intel_arc_line(line: &str) -> Option<IntelGpu> {
+    let lower = line.to_lowercase();
+    if !lower.contains("intel") || !lower.contains("arc") {
+        return None;
+    }
+    let model_number = lower.split("arc").skip(1).any(|rest| {
+        let rest = rest.trim_start();
+        let rest = rest.strip_prefix("pro").unwrap_or(rest).trim_start();
+        let mut chars = rest.chars();
+        matches!(chars.next(), Some('a' | 'b')) && chars.next().is_some_and(|c| c.is_ascii_digit())
+    });
+    if model_number || lower.contains("dg2") || lower.contains("battlemage") {
+        Some(IntelGpu::Discrete(None))
+    } else {
+        Some(IntelGpu::Integrated(None))
+    }
+}
+
+/// Intel Arc via sysfs.
+struct IntelDetector;
+
+impl HardwareDetector for IntelDetector {
+    fn name(&self) -> &str {
+        "Intel sysfs"
+    }
+
+    fn priority(&self) -> u32 {
+        40
+    }
+
+    fn detect(&self, ctx: &DetectContext) -> Vec<GpuInfo> {
+        let already_found = ctx
+            .found
+            .iter()
+            .any(|g| g.name.to_lowercase().contains("intel"));
+        if already_found {
+            return Vec::new();
+        }
+        SystemSpecs::detect_intel_gpu()
+            .map(|gpu| match gpu {
+                IntelGpu::Discrete(vram_gb) => GpuInfo {
+                    name: "Intel Arc".to_string(),
+                    vram_gb,
+                    backend: GpuBackend::Sycl,
+                    count: 1,
+                    unified_memory: false,
+                },
+                // Shares system RAM like Apple Silicon, but only up to the
+                // driver's shared-memory limit rather than all of it.
+                IntelGpu::Integrated(shared_gb) => GpuInfo {
+                    name: "Intel Arc Graphics (integrated)".to_string(),
+                    vram_gb: Some(intel_shared_memory_gb(shared_gb, ctx.total_ram_gb)),
+                    backend: GpuBackend::Sycl,
+                    count: 1,
+                    unified_memory: true,
+                },
+            })
+            .into_iter()
+            .collect()
+    }
+}
+
+/// Apple Silicon (unified memory) via system_profiler.
+struct AppleDetector;
+
+impl HardwareDetector for AppleDetector {
+    fn name(&self) -> &str {
+        "Apple (system_profiler)"
+    }
+
+    fn priority(&self) -> u32 {
+        50
+    }
+
+    fn detect(&self, ctx: &DetectContext) -> Vec<GpuInfo> {
+        SystemSpecs::detect_apple_gpu(ctx.total_ram_gb)
+            .map(|(pool, chipset)| (apple_gpu_wired_limit_gb(pool), chipset))
+            .map(|(vram, chipset)| GpuInfo {
+                // The chip name ("Apple M2 Max") keys the bandwidth table
+                name: if ctx.cpu_name.to_lowercase().contains("apple") {
+                    ctx.cpu_name.to_string()
+                } else {
+                    chipset.unwrap_or_else(|| "Apple Silicon".to_string())
+                },
+                vram_gb: Some(vram),
+                backend: GpuBackend::Metal,
+                count: 1,
+                unified_memory: true,
+            })
+            .into_iter()
+            .collect()
+    }
+}
+
+/// Ascend NPUs via npu-smi.
+struct AscendDetector;
+
+impl HardwareDetector for AscendDetector {
+    fn name(&self) -> &str {
+        "Ascend (npu-smi)"
+    }
+
+    fn priority(&self) -> u32 {
+        60
+    }
+
+    fn detect(&self, _ctx: &DetectContext) -> Vec<GpuInfo> {
+        SystemSpecs::detect_ascend_npus()
+    }
+}
+
+/// Vulkan fallback (e.g. Android/Termux with Turnip).
+struct VulkanDetector;
+
+impl HardwareDetector for VulkanDetector {
+    fn name(&self) -> &str {
+        "Vulkan"
+    }
+
+    fn priority(&self) -> u32 {
+        90
+    }
+
+    fn detect(&self, ctx: &DetectContext) -> Vec<GpuInfo> {
+        SystemSpecs::detect_vulkan_gpu_info()
+            .into_iter()
+            .filter(|vulkan_gpu| {
+                !ctx.found
+                    .iter()
+                    .any(|existing| SystemSpecs::is_same_gpu_name(&existing.name, &vulkan_gpu.name))
+            })
+            .collect()
+    }
+}
+
+/// WMI reports `AdapterRAM` at or above this (just under 4 GiB) for any card
+/// with 4 GB or more, since the field is 32 bits wide.
+const WMI_ADAPTER_RAM_CAP_BYTES: u64 = 0xFFF0_0000;
+
+/// Largest per-GPU VRAM figure accepted from a detection tool. Anything
+/// above this (or negative) is malformed output, not a real card.
+const MAX_PLAUSIBLE_VRAM_GB: f64 = 1024.0;
+
+fn is_plausible_vram_gb(vram_gb: f64) -> bool {
+    vram_gb.is_finite() && (0.0..=MAX_PLAUSIBLE_VRAM_GB).contains(&vram_gb)
+}
+
+/// Memory held back from RAM and VRAM for the OS, drivers and inference
+/// runtime when `--headroom` isn't given.
+pub const DEFAULT_MEMORY_HEADROOM_GB: f64 = 1.5;
+
+/// Conservative system RAM bandwidth when it can't be detected:
+/// dual-channel DDR4-3200 (2 x 8 bytes x 3200 MT/s ≈ 51 GB/s).
+pub const DEFAULT_MEM_BANDWIDTH_GBPS: f64 = 50.0;
+
+/// Conservative RAM bandwidth for a CPU class when nothing was detected.
+///
+/// Apple Silicon gets the base M1's 68 GB/s, server CPUs the four channels
+/// even the smallest sockets have, and other ARM boards a single LPDDR4X
+/// channel pair. Everything else is a dual-channel desktop.
+pub fn default_mem_bandwidth_gbps(cpu_name: &str, backend: GpuBackend) -> f64 {
+    let lower = cpu_name.to_lowercase();
+    if lower.contains("apple") {
+        68.0
+    } else if ["epyc", "xeon", "threadripper"]
+        .iter()
+        .any(|server| lower.contains(server))
+    {
+        100.0
+    } else if backend == GpuBackend::CpuArm {
+        25.0
+    } else {
+        DEFAULT_MEM_BANDWIDTH_GBPS
+    }
+}
+
+/// A detection tool that is installed but couldn't be used. A tool that
+/// simply isn't installed is ordinary absence, not an error.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DetectError {
+    /// The tool couldn't be started (permissions, resource limits, ...).
+    CommandSpawn { command: String, message: String },
+    /// The tool ran but exited unsuccessfully.
+    CommandFailed { command: String, message: String },
+    /// The tool's output couldn't be read or understood.
+    ParseFailure { command: String, message: String },
+}
+
+impl DetectError {
+    /// The tool (or detector) that failed, e.g. "nvidia-smi".
+    pub fn command(&self) -> &str {
+        match self {
+            DetectError::CommandSpawn { command, .. }
+            | DetectError::CommandFailed { command, .. }
+            | DetectError::ParseFailure { command, .. } => command,
+        }
+    }
+
+    fn message(&self) -> String {
+        match self {
+            DetectError::CommandSpawn { message, .. } => format!("failed to run: {}", message),
+            DetectError::CommandFailed { message, .. }
+            | DetectError::ParseFailure { message, .. } => message.clone(),
+        }
+    }
+}
+
+impl std::fmt::Display for DetectError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.command(), self.message())
+    }
+}
+
+impl std::error::Error for DetectError {}
+
+thread_local! {
+    /// Failures seen by the detection running on this thread, while
+    /// [`collect_detect_errors`] is active.
+    static DETECT_ERRORS: std::cell::RefCell<Option<Vec<DetectError>>> =
+        const { std::cell::RefCell::new(None) };
+}
+
+/// Record a detection failure in the activity log as a warning, and for
+/// [`SystemSpecs::try_detect`].
+fn report(error: DetectError) {
+    activity::warn(error.command(), error.message());
+    DETECT_ERRORS.with(|errors| {
+        if let Some(errors) = errors.borrow_mut().as_mut() {
+            errors.push(error);
+        }
+    });
+}
+
+/// Run `f`, returning every failure it [`report`]ed.
+fn collect_detect_errors<T>(f: impl FnOnce() -> T) -> (T, Vec<DetectError>) {
+    let outer = DETECT_ERRORS.with(|errors| errors.borrow_mut().replace(Vec::new()));
+    let result = f();
+    let errors = DETECT_ERRORS.with(|errors| std::mem::replace(&mut *errors.borrow_mut(), outer));
+    (result, errors.unwrap_or_default())
 }
 
 impl SystemSpecs {
+    /// Detect hardware, falling back to "not present" for anything a tool
+    /// couldn't report. Failures are still logged as activity warnings; use
+    /// [`SystemSpecs::try_detect`] to act on them.
     pub fn detect() -> Self {
+        Self::detect_best_effort()
+    }
+
+    /// Detect hardware, or return the first tool that is installed but
+    /// failed, so "no GPU" can be told apart from "GPU detection failed".
+    pub fn try_detect() -> Result<Self, DetectError> {
+        let (specs, errors) = collect_detect_errors(Self::detect_best_effort);
+        match errors.into_iter().next() {
+            Some(error) => Err(error),
+            None => Ok(specs),
+        }
+    }
+
+    fn detect_best_effort() -> Self {
         let mut sys = System::new_all();
         sys.refresh_all();
 
         let total_ram_bytes = sys.total_memory();
         let available_ram_bytes = sys.available_memory();
         let total_ram_gb = total_ram_bytes as f64 / (1024.0 * 1024.0 * 1024.0);
-        let available_ram_gb = if available_ram_bytes == 0 && total_ram_bytes > 0 {
-            // sysinfo may fail to report available memory on some platforms
-            // (e.g. macOS Tahoe / newer macOS versions). Try fallbacks.
-            Self::available_ram_fallback(&sys, total_ram_bytes, total_ram_gb)
-        } else {
-            available_ram_bytes as f64 / (1024.0 * 1024.0 * 1024.0)
-        };
+        let available_ram_gb = Self::current_available_ram_gb(&sys);
+
+        let swap_total_gb = sys.total_swap() as f64 / (1024.0 * 1024.0 * 1024.0);
+        let swap_used_gb = sys.used_swap() as f64 / (1024.0 * 1024.0 * 1024.0);
 
         let total_cpu_cores = sys.cpus().len();
         let cpu_name = Self::detect_cpu_name(&sys);
+        activity::detect(format!(
+            "RAM: {:.1} GB total, {:.1} GB available{}",
+            total_ram_gb,
+            available_ram_gb,
+            if available_ram_bytes == 0 && total_ram_bytes > 0 {
+                " (fallback estimate)"
+            } else {
+                ""
+            }
+        ));
+        if swap_total_gb > 0.0 {
+            activity::detect(format!(
+                "Swap: {:.1} GB used of {:.1} GB (not counted as available)",
+                swap_used_gb, swap_total_gb
+            ));
+        }
+        activity::detect(format!("CPU: {} ({} cores)", cpu_name, total_cpu_cores));
+        let cpu_features = CpuFeatures::detect();
+        activity::detect(format!("CPU features: {:?}", cpu_features.labels()));
 
         let gpus = Self::detect_all_gpus(total_ram_gb, &cpu_name);
 
@@ -98,8 +763,49 @@ impl SystemSpecs {
                 GpuBackend::CpuX86
             };
         let backend = primary.map(|g| g.backend).unwrap_or(cpu_backend);
+        let memory_bandwidth_gbps = Self::detect_mem_bandwidth(unified_memory, gpu_name.as_deref());
+        match primary {
+            Some(g) => activity::detect(format!(
+                "Primary GPU: {} x{} ({}, {}{})",
+                g.name,
+                g.count,
+                g.backend.label(),
+                g.vram_gb
+                    .map(|v| format!("{:.1} GB", v))
+                    .unwrap_or_else(|| "VRAM unknown".to_string()),
+                if g.unified_memory { ", unified" } else { "" }
+            )),
+            None => activity::detect(format!("No GPU found, using {}", backend.label())),
+        }
+        match memory_bandwidth_gbps {
+            Some(gbps) => activity::detect(format!("Memory bandwidth: {:.0} GB/s", gbps)),
+            None => activity::detect(format!(
+                "Memory bandwidth: not detected, assuming {:.0} GB/s for this CPU class",
+                default_mem_bandwidth_gbps(&cpu_name, backend)
+            )),
+        }
 
-        SystemSpecs {
+        let (driver_version, cuda_version) = if gpus.iter().any(|g| g.backend == GpuBackend::Cuda) {
+            Self::detect_nvidia_driver_versions()
+        } else {
+            (None, None)
+        };
+        if driver_version.is_some() || cuda_version.is_some() {
+            activity::detect(format!(
+                "NVIDIA driver {}, CUDA {}",
+                driver_version.as_deref().unwrap_or("unknown"),
+                cuda_version.as_deref().unwrap_or("unknown")
+            ));
+        }
+
+        let gpu_wired_limit_gb =
+            gpu_vram_gb.filter(|_| backend == GpuBackend::Metal && unified_memory);
+        let npu_name = Self::detect_npu(&cpu_name);
+        if let Some(npu) = &npu_name {
+            activity::detect(format!("NPU: {}", npu));
+        }
+
+        let specs = SystemSpecs {
             total_ram_gb,
             available_ram_gb,
             total_cpu_cores,
@@ -112,45 +818,297 @@ impl SystemSpecs {
             unified_memory,
             backend,
             gpus,
+            memory_bandwidth_gbps,
+            reserved_ram_gb: 0.0,
+            headroom_gb: 0.0,
+            gpu_wired_limit_gb,
+            swap_total_gb,
+            swap_used_gb,
+            process_mem_limit_gb: None,
+            driver_version,
+            cuda_version,
+            cpu_features,
+            has_npu: npu_name.is_some(),
+            npu_name,
+            loaded_from: None,
+            hypothetical: false,
+            free_disk_gb: disk_free_gb(&crate::providers::llamacpp_models_dir()),
+        };
+        match Self::process_mem_limit_gb() {
+            Some(limit_gb) => specs.with_process_mem_limit(limit_gb),
+            None => specs,
         }
     }
 
-    /// Detect all GPUs across all vendors. Returns a Vec sorted by VRAM descending
-    /// (best GPU first). Unlike the old cascade, this does NOT short-circuit:
-    /// a system with both NVIDIA and AMD GPUs will report both.
-    fn detect_all_gpus(total_ram_gb: f64, cpu_name: &str) -> Vec<GpuInfo> {
-        let mut gpus = Vec::new();
+    /// The NPU next to the CPU, if any: Intel and AMD parts by the driver
+    /// bound under /sys/class/accel on Linux, the Neural Engine by chip on
+    /// macOS.
+    fn detect_npu(cpu_name: &str) -> Option<String> {
+        if cfg!(target_os = "macos") {
+            return apple_npu_name(cpu_name);
+        }
+        if !cfg!(target_os = "linux") {
+            return None;
+        }
+        let entries = std::fs::read_dir("/sys/class/accel").ok()?;
+        entries.flatten().find_map(|entry| {
+            let uevent =
+                std::fs::read_to_string(entry.path().join("device").join("uevent")).ok()?;
+            uevent
+                .lines()
+                .find_map(|line| line.strip_prefix("DRIVER="))
+                .and_then(npu_name_for_driver)
+                .map(str::to_string)
+        })
+    }
 
-        // NVIDIA GPUs via nvidia-smi, with sysfs fallback for Linux/toolbox setups
-        let nvidia = Self::detect_nvidia_gpus();
-        if nvidia.is_empty() {
-            if let Some(nvidia_sysfs) = Self::detect_nvidia_gpu_sysfs_info() {
-                gpus.push(nvidia_sysfs);
-            }
+    /// The tighter of the soft `RLIMIT_AS` and `RLIMIT_DATA` limits, or
+    /// `None` when both are unlimited. Locked-down HPC and managed hosts use
+    /// these to cap a single process well below physical RAM. Unix only;
+    /// elsewhere there is no such limit to read.
+    #[cfg(unix)]
+    fn process_mem_limit_gb() -> Option<f64> {
+        use rlimit::Resource;
+        let soft_limits = [Resource::AS, Resource::DATA]
+            .into_iter()
+            .filter_map(|resource| resource.get_soft().ok());
+        tightest_mem_limit_bytes(soft_limits, rlimit::INFINITY)
+            .map(|bytes| bytes as f64 / (1024.0 * 1024.0 * 1024.0))
+    }
+
+    #[cfg(not(unix))]
+    fn process_mem_limit_gb() -> Option<f64> {
+        None
+    }
+
+    /// The process-wide detection result, probed on first use. Hardware
+    /// doesn't change while llmfit runs, so later callers skip the GPU tool
+    /// spawns; call [`SystemSpecs::refresh`] on the copy for current free RAM.
+    pub fn detect_cached() -> Self {
+        static DETECTED: std::sync::OnceLock<SystemSpecs> = std::sync::OnceLock::new();
+        DETECTED.get_or_init(Self::detect).clone()
+    }
+
+    /// Serialize these specs as a shareable hardware profile:
+    /// `{"schema_version": N, "llmfit_version": "...", "specs": {...}}`.
+    pub fn to_profile_json(&self) -> String {
+        let profile = serde_json::json!({
+            "schema_version": SPECS_SCHEMA_VERSION,
+            "llmfit_version": env!("CARGO_PKG_VERSION"),
+            "specs": self,
+        });
+        serde_json::to_string_pretty(&profile).unwrap_or_default()
+    }
+
+    /// Read a profile written by [`SystemSpecs::to_profile_json`], checking
+    /// its schema version. Fields missing from older profiles take their
+    /// defaults.
+    pub fn from_profile_json(text: &str) -> Result<Self, String> {
+        let mut value: serde_json::Value =
+            serde_json::from_str(text).map_err(|e| format!("Invalid specs JSON: {}", e))?;
+        let version = value
+            .get("schema_version")
+            .and_then(|v| v.as_u64())
+            .ok_or("Specs profile is missing a numeric \"schema_version\" field")?;
+        if version > SPECS_SCHEMA_VERSION as u64 {
+            return Err(format!(
+                "Specs schema version {} is newer than this llmfit supports ({}). Update llmfit to read it.",
+                version, SPECS_SCHEMA_VERSION
+            ));
+        }
+        if version < 1 {
+            return Err(format!("Unknown specs schema version {}", version));
+        }
+        let specs = value
+            .get_mut("specs")
+            .map(serde_json::Value::take)
+            .ok_or("Specs profile is missing a \"specs\" object")?;
+        serde_json::from_value(specs).map_err(|e| format!("Invalid specs profile: {}", e))
+    }
+
+    /// Re-read the volatile figures (available RAM, swap use, free disk in
+    /// `models_dir`) without probing GPUs again. The process memory limit,
+    /// `--reserve` and `--headroom` stay applied. Specs loaded from a
+    /// profile are left as they are.
+    pub fn refresh(&mut self, models_dir: &std::path::Path) {
+        if self.loaded_from.is_some() {
+            return;
+        }
+        self.apply_memory_reading(MemoryReading::take());
+        self.free_disk_gb = disk_free_gb(models_dir);
+        activity::detect(format!(
+            "Refresh: {:.1} GB RAM available",
+            self.available_ram_gb
+        ));
+    }
+
+    /// Update available RAM and swap use from `reading`, keeping the process
+    /// memory limit, `--reserve` and `--headroom` applied.
+    pub fn apply_memory_reading(&mut self, reading: MemoryReading) {
+        let mut available_ram_gb = reading.available_ram_gb;
+        if let Some(limit_gb) = self.process_mem_limit_gb {
+            available_ram_gb = available_ram_gb.min(limit_gb);
+        }
+        self.available_ram_gb =
+            (available_ram_gb - self.reserved_ram_gb - self.headroom_gb).max(0.0);
+        self.swap_used_gb = reading.swap_used_gb;
+    }
+
+    /// Available RAM right now, from sysinfo with platform fallbacks and
+    /// capped at total RAM.
+    fn current_available_ram_gb(sys: &System) -> f64 {
+        let total_ram_bytes = sys.total_memory();
+        let available_ram_bytes = sys.available_memory();
+        let total_ram_gb = total_ram_bytes as f64 / (1024.0 * 1024.0 * 1024.0);
+        let mut available_ram_gb = if available_ram_bytes == 0 && total_ram_bytes > 0 {
+            // sysinfo may fail to report available memory on some platforms
+            // (e.g. macOS Tahoe / newer macOS versions). Try fallbacks.
+            Self::available_ram_fallback(sys, total_ram_bytes, total_ram_gb)
         } else {
-            gpus.extend(nvidia);
+            available_ram_bytes as f64 / (1024.0 * 1024.0 * 1024.0)
+        };
+
+        // macOS keeps reclaimable file cache and purgeable pages out of
+        // sysinfo's figure, which makes unified-memory fits too pessimistic.
+        // Count what the OS would free under pressure instead.
+        if cfg!(target_os = "macos")
+            && let Some(vm_avail) = Self::available_ram_from_vm_stat(total_ram_bytes)
+            && vm_avail > available_ram_gb
+        {
+            activity::detect(format!(
+                "vm_stat: {:.1} GB reclaimable-inclusive available (sysinfo said {:.1} GB)",
+                vm_avail, available_ram_gb
+            ));
+            available_ram_gb = vm_avail.min(total_ram_gb);
         }
+        Self::clamp_available_ram(available_ram_gb, total_ram_gb)
+    }
 
-        // AMD GPUs via rocm-smi or sysfs
-        if let Some(amd) = Self::detect_amd_gpu_rocm_info() {
-            gpus.push(amd);
-        } else if let Some(amd) = Self::detect_amd_gpu_sysfs_info() {
-            gpus.push(amd);
+    /// Driver version via `--query-gpu=driver_version`, and the CUDA version
+    /// from the banner of plain `nvidia-smi`. Either may be missing on old
+    /// drivers or restricted containers.
+    fn detect_nvidia_driver_versions() -> (Option<String>, Option<String>) {
+        let run = |args: &[&str]| {
+            std::process::Command::new("nvidia-smi")
+                .args(args)
+                .output()
+                .ok()
+                .filter(|o| o.status.success())
+                .and_then(|o| String::from_utf8(o.stdout).ok())
+        };
+        let driver = run(&["--query-gpu=driver_version", "--format=csv,noheader"])
+            .and_then(|text| text.lines().next().map(|l| l.trim().to_string()))
+            .filter(|v| !v.is_empty());
+        let cuda = run(&[]).and_then(|text| parse_nvidia_smi_cuda_version(&text));
+        (driver, cuda)
+    }
+
+    /// Detect system RAM bandwidth (GB/s) for CPU-side inference.
+    ///
+    /// Unified memory reuses the chip's known bandwidth. Otherwise DIMM speed
+    /// and population are read from `dmidecode` on Linux, which usually needs
+    /// root. `None` when neither is available.
+    fn detect_mem_bandwidth(unified_memory: bool, gpu_name: Option<&str>) -> Option<f64> {
+        if unified_memory && let Some(bw) = gpu_name.and_then(gpu_memory_bandwidth_gbps) {
+            activity::detect("Memory bandwidth: from unified-memory chip table");
+            return Some(bw);
         }
 
-        // Windows WMI (catches GPUs not found by vendor-specific tools)
-        for wmi_gpu in Self::detect_gpu_windows_info() {
-            // Skip if we already found a GPU with the same name from a vendor tool
-            let dominated = gpus.iter().any(|existing| {
-                let existing_lower = existing.name.to_lowercase();
-                let wmi_lower = wmi_gpu.name.to_lowercase();
-                existing_lower.contains(&wmi_lower) || wmi_lower.contains(&existing_lower)
+        #[cfg(target_os = "linux")]
+        {
+            if let Some(bw) = std::process::Command::new("dmidecode")
+                .args(["-t", "17"])
+                .output()
+                .ok()
+                .filter(|o| o.status.success())
+                .and_then(|o| String::from_utf8(o.stdout).ok())
+                .and_then(|text| parse_dmidecode_bandwidth(&text))
+            {
+                activity::detect("Memory bandwidth: from dmidecode DIMM info");
+                return Some(bw);
+            }
+        }
+
+        None
+    }
+
+    /// Some platforms (buggy drivers, virtualization) report more available
+    /// than total RAM, which breaks every utilization figure downstream.
+    fn clamp_available_ram(available_gb: f64, total_gb: f64) -> f64 {
+        if total_gb > 0.0 && available_gb > total_gb {
+            activity::warn(
+                "sysinfo",
+                format!(
+                    "available RAM {:.1} GB exceeds total {:.1} GB, clamped to total",
+                    available_gb, total_gb
+                ),
+            );
+            return total_gb;
+        }
+        available_gb
+    }
+
+    /// Detect all GPUs across all vendors. Returns a Vec sorted by VRAM descending
+    /// (best GPU first). Unlike the old cascade, this does NOT short-circuit:
+    /// a system with both NVIDIA and AMD GPUs will report both.
+    fn detect_all_gpus(total_ram_gb: f64, cpu_name: &str) -> Vec<GpuInfo> {
+        let mut gpus: Vec<GpuInfo> = Vec::new();
+
+        for detector in registered_detectors() {
+            let found = detector.detect(&DetectContext {
+                total_ram_gb,
+                cpu_name,
+                found: &gpus,
             });
-            if !dominated {
-                gpus.push(wmi_gpu);
+            if found.is_empty() {
+                activity::detect(format!("{}: nothing found", detector.name()));
+            }
+            for mut gpu in found {
+                if let Some(vram) = gpu.vram_gb
+                    && !is_plausible_vram_gb(vram)
+                {
+                    report(DetectError::ParseFailure {
+                        command: detector.name().to_string(),
+                        message: format!("{}: ignoring implausible VRAM {} GB", gpu.name, vram),
+                    });
+                    gpu.vram_gb = None;
+                }
+                activity::detect(format!(
+                    "{}: found {}{} ({})",
+                    detector.name(),
+                    gpu.name,
+                    if gpu.count > 1 {
+                        format!(" x{}", gpu.count)
+                    } else {
+                        String::new()
+                    },
+                    gpu.vram_gb
+                        .map(|v| format!("{:.1} GB", v))
+                        .unwrap_or_else(|| "VRAM unknown".to_string())
+                ));
+                gpus.push(gpu);
             }
         }
 
+        Self::apply_unified_memory_fixups(&mut gpus, total_ram_gb, cpu_name);
+
+        // Sort by VRAM descending so the best GPU is primary. A dedicated
+        // card beats an integrated GPU whose "VRAM" is the shared system RAM.
+        gpus.sort_by(|a, b| {
+            let va = a.vram_gb.unwrap_or(0.0);
+            let vb = b.vram_gb.unwrap_or(0.0);
+            a.unified_memory
+                .cmp(&b.unified_memory)
+                .then(vb.partial_cmp(&va).unwrap_or(std::cmp::Ordering::Equal))
+        });
+
+        gpus
+    }
+
+    /// Unified-memory SoCs report a small (or no) dedicated VRAM figure even
+    /// though the GPU can use all of system RAM. Runs after every detector so
+    /// it sees GPUs found by any of them.
+    fn apply_unified_memory_fixups(gpus: &mut Vec<GpuInfo>, total_ram_gb: f64, cpu_name: &str) {
         // AMD unified memory APUs (e.g. Ryzen AI MAX series).
         // These share the full system RAM between CPU and GPU, like Apple Silicon.
         // WMI AdapterRAM is a 32-bit field capped at ~4 GB, so we override with
@@ -160,6 +1118,10 @@ impl SystemSpecs {
                 let lower = g.name.to_lowercase();
                 lower.contains("amd") || lower.contains("radeon")
             });
+            activity::detect(format!(
+                "AMD unified-memory APU ({}): VRAM = system RAM",
+                cpu_name
+            ));
             if let Some(idx) = amd_idx {
                 gpus[idx].unified_memory = true;
                 gpus[idx].vram_gb = Some(total_ram_gb);
@@ -176,77 +1138,16 @@ impl SystemSpecs {
         }
 
         // NVIDIA Grace / DGX Spark unified memory SoCs (e.g. GB10, GB20).
-        // These share the full system RAM between CPU and GPU, like Apple Silicon.
         // nvidia-smi may report 0 VRAM or a small dedicated portion, so we
         // override with total system RAM and flag as unified memory.
-        let is_nvidia_unified = gpus.iter().any(|g| {
-            let lower = g.name.to_lowercase();
-            lower.contains("gb10") || lower.contains("gb20")
-        });
-        if is_nvidia_unified {
-            for gpu in &mut gpus {
-                let lower = gpu.name.to_lowercase();
-                if lower.contains("gb10") || lower.contains("gb20") {
-                    gpu.unified_memory = true;
-                    gpu.vram_gb = Some(total_ram_gb);
-                }
-            }
-        }
-
-        // Intel Arc via sysfs
-        if let Some(vram) = Self::detect_intel_gpu() {
-            let already_found = gpus.iter().any(|g| g.name.to_lowercase().contains("intel"));
-            if !already_found {
-                gpus.push(GpuInfo {
-                    name: "Intel Arc".to_string(),
-                    vram_gb: Some(vram),
-                    backend: GpuBackend::Sycl,
-                    count: 1,
-                    unified_memory: false,
-                });
+        for gpu in gpus.iter_mut() {
+            let lower = gpu.name.to_lowercase();
+            if lower.contains("gb10") || lower.contains("gb20") {
+                activity::detect(format!("{}: unified memory, VRAM = system RAM", gpu.name));
+                gpu.unified_memory = true;
+                gpu.vram_gb = Some(total_ram_gb);
             }
         }
-
-        // Apple Silicon (unified memory)
-        if let Some(vram) = Self::detect_apple_gpu(total_ram_gb) {
-            let name = if cpu_name.to_lowercase().contains("apple") {
-                cpu_name.to_string()
-            } else {
-                "Apple Silicon".to_string()
-            };
-            gpus.push(GpuInfo {
-                name,
-                vram_gb: Some(vram),
-                backend: GpuBackend::Metal,
-                count: 1,
-                unified_memory: true,
-            });
-        }
-
-        // Ascend NPUs via npu-smi
-        let ascend = Self::detect_ascend_npus();
-        if !ascend.is_empty() {
-            gpus.extend(ascend);
-        }
-
-        // Vulkan fallback (e.g. Android/Termux with Turnip)
-        for vulkan_gpu in Self::detect_vulkan_gpu_info() {
-            let dominated = gpus
-                .iter()
-                .any(|existing| Self::is_same_gpu_name(&existing.name, &vulkan_gpu.name));
-            if !dominated {
-                gpus.push(vulkan_gpu);
-            }
-        }
-
-        // Sort by VRAM descending so the best GPU is primary
-        gpus.sort_by(|a, b| {
-            let va = a.vram_gb.unwrap_or(0.0);
-            let vb = b.vram_gb.unwrap_or(0.0);
-            vb.partial_cmp(&va).unwrap_or(std::cmp::Ordering::Equal)
-        });
-
-        gpus
     }
 
     /// Detect NVIDIA GPUs via nvidia-smi. Returns one GpuInfo per unique model,
@@ -264,21 +1165,24 @@ impl SystemSpecs {
         }
 
         // Fallback: standard 2-column query for older nvidia-smi versions
-        let output = match std::process::Command::new("nvidia-smi")
-            .arg("--query-gpu=memory.total,name")
-            .arg("--format=csv,noheader,nounits")
-            .output()
-        {
-            Ok(o) if o.status.success() => o,
-            _ => return Vec::new(),
-        };
-
-        let text = match String::from_utf8(output.stdout) {
-            Ok(t) => t,
-            Err(_) => return Vec::new(),
+        let Some(text) = probe(
+            "nvidia-smi",
+            &[
+                "--query-gpu=memory.total,name",
+                "--format=csv,noheader,nounits",
+            ],
+        ) else {
+            return Vec::new();
         };
 
-        Self::parse_nvidia_smi_list(&text)
+        let gpus = Self::parse_nvidia_smi_list(&text);
+        if gpus.is_empty() && !text.trim().is_empty() {
+            report(DetectError::ParseFailure {
+                command: "nvidia-smi".to_string(),
+                message: "couldn't parse GPU list".to_string(),
+            });
+        }
+        gpus
     }
 
     /// Try nvidia-smi with `addressing_mode` column. Returns `None` if the
@@ -296,7 +1200,14 @@ impl SystemSpecs {
         }
 
         let text = String::from_utf8(output.stdout).ok()?;
-        Some(Self::parse_nvidia_smi_extended(&text))
+        let gpus = Self::parse_nvidia_smi_extended(&text);
+        if gpus.is_empty() && !text.trim().is_empty() {
+            report(DetectError::ParseFailure {
+                command: "nvidia-smi".to_string(),
+                message: "couldn't parse GPU list".to_string(),
+            });
+        }
+        Some(gpus)
     }
 
     /// Parse `nvidia-smi --query-gpu=addressing_mode,memory.total,name`.
@@ -511,43 +1422,16 @@ impl SystemSpecs {
     /// Detect AMD GPU via rocm-smi (available on Linux with ROCm installed).
     /// Parses per-card VRAM and GPU name from rocm-smi output.
     fn detect_amd_gpu_rocm_info() -> Option<GpuInfo> {
-        // Try rocm-smi --showmeminfo vram for VRAM
-        let vram_output = std::process::Command::new("rocm-smi")
-            .arg("--showmeminfo")
-            .arg("vram")
-            .output()
-            .ok()?;
-
-        if !vram_output.status.success() {
-            return None;
-        }
-
-        let vram_text = String::from_utf8(vram_output.stdout).ok()?;
-
-        // Parse VRAM total from rocm-smi output.
-        // Typical format includes a line like:
-        //   "GPU[0] : vram Total Memory (B): 8589934592"
-        // or in table format with "Total" and bytes.
-        let mut per_gpu_vram_bytes: Vec<u64> = Vec::new();
-        let mut gpu_count: u32 = 0;
-        for line in vram_text.lines() {
-            let lower = line.to_lowercase();
-            if lower.contains("total") && !lower.contains("used") {
-                // Extract the numeric value (bytes)
-                if let Some(val) = line
-                    .split_whitespace()
-                    .filter_map(|w| w.parse::<u64>().ok())
-                    .next_back()
-                    && val > 0
-                {
-                    per_gpu_vram_bytes.push(val);
-                    gpu_count += 1;
-                }
-            }
-        }
+        let vram_text = probe("rocm-smi", &["--showmeminfo", "vram", "--json"])?;
+        let per_gpu_vram_bytes = Self::parse_rocm_smi_vram(&vram_text);
+        let mut gpu_count = per_gpu_vram_bytes.len() as u32;
 
         if gpu_count == 0 {
             // rocm-smi succeeded but we couldn't parse VRAM; GPU exists though
+            report(DetectError::ParseFailure {
+                command: "rocm-smi".to_string(),
+                message: "couldn't parse VRAM total".to_string(),
+            });
             gpu_count = 1;
         }
 
@@ -597,6 +1481,49 @@ impl SystemSpecs {
         })
     }
 
+    /// Per-card VRAM totals in bytes from `rocm-smi --showmeminfo vram`.
+    /// Reads the `--json` form, e.g.
+    ///   {"card0": {"VRAM Total Memory (B)": "8589934592", ...}, "card1": ...}
+    /// and falls back to the plain text lines
+    ///   "GPU[0] : vram Total Memory (B): 8589934592"
+    /// when the output isn't JSON.
+    fn parse_rocm_smi_vram(text: &str) -> Vec<u64> {
+        let is_total = |key: &str| {
+            let lower = key.to_lowercase();
+            lower.contains("total") && !lower.contains("used")
+        };
+
+        if let Ok(serde_json::Value::Object(cards)) = serde_json::from_str(text.trim()) {
+            let mut card_names: Vec<&String> =
+                cards.keys().filter(|k| k.starts_with("card")).collect();
+            card_names.sort_by_key(|k| k[4..].parse::<u32>().unwrap_or(u32::MAX));
+            return card_names
+                .into_iter()
+                .filter_map(|name| {
+                    cards[name.as_str()]
+                        .as_object()?
+                        .iter()
+                        .find(|(key, _)| is_total(key))
+                        .and_then(|(_, value)| match value {
+                            serde_json::Value::String(s) => s.trim().parse().ok(),
+                            other => other.as_u64(),
+                        })
+                })
+                .filter(|&bytes| bytes > 0)
+                .collect();
+        }
+
+        text.lines()
+            .filter(|line| is_total(line))
+            .filter_map(|line| {
+                line.split_whitespace()
+                    .filter_map(|w| w.parse::<u64>().ok())
+                    .next_back()
+            })
+            .filter(|&bytes| bytes > 0)
+            .collect()
+    }
+
     /// Detect AMD GPU via sysfs on Linux (works without ROCm installed).
     /// AMD vendor ID is 0x1002.
     fn detect_amd_gpu_sysfs_info() -> Option<GpuInfo> {
@@ -733,12 +1660,7 @@ impl SystemSpecs {
 
     /// Read lspci output, with host fallback for containerized environments.
     fn lspci_output() -> Option<String> {
-        let local = std::process::Command::new("lspci")
-            .arg("-nnD")
-            .output()
-            .ok()
-            .filter(|o| o.status.success())
-            .and_then(|o| String::from_utf8(o.stdout).ok());
+        let local = probe("lspci", &["-nnD"]);
 
         if local.is_some() {
             return local;
@@ -795,6 +1717,8 @@ impl SystemSpecs {
             return Vec::new();
         }
 
+        let registry_vram = Self::detect_windows_registry_vram();
+
         // Use PowerShell to query WMI — more reliable than wmic (deprecated)
         if let Ok(output) = std::process::Command::new("powershell")
             .arg("-NoProfile")
@@ -803,18 +1727,45 @@ impl SystemSpecs {
             .output()
             && output.status.success()
                 && let Ok(text) = String::from_utf8(output.stdout) {
-                    let gpus = Self::parse_windows_gpu_list(&text);
+                    let gpus = Self::parse_windows_gpu_list(&text, &registry_vram);
                     if !gpus.is_empty() {
                         return gpus;
                     }
                 }
 
         // Fallback to wmic for older Windows
-        Self::detect_gpu_windows_wmic_list()
+        Self::detect_gpu_windows_wmic_list(&registry_vram)
+    }
+
+    /// Full 64-bit VRAM sizes that display drivers record under the display
+    /// adapter class key (`HardwareInformation.qwMemorySize`), keyed by
+    /// lowercased adapter name. Empty when unavailable.
+    fn detect_windows_registry_vram() -> HashMap<String, u64> {
+        std::process::Command::new("powershell")
+            .arg("-NoProfile")
+            .arg("-Command")
+            .arg("Get-ItemProperty 'HKLM:\\SYSTEM\\CurrentControlSet\\Control\\Class\\{4d36e968-e325-11ce-bfc1-08002be10318}\\0*' -ErrorAction SilentlyContinue | Where-Object { $_.'HardwareInformation.qwMemorySize' } | ForEach-Object { $_.DriverDesc + '|' + $_.'HardwareInformation.qwMemorySize' }")
+            .output()
+            .ok()
+            .filter(|o| o.status.success())
+            .and_then(|o| String::from_utf8(o.stdout).ok())
+            .map(|text| Self::parse_windows_registry_vram(&text))
+            .unwrap_or_default()
+    }
+
+    /// Parse `DriverDesc|qwMemorySize` lines into a name → bytes map.
+    fn parse_windows_registry_vram(text: &str) -> HashMap<String, u64> {
+        text.lines()
+            .filter_map(|line| {
+                let (name, bytes) = line.trim().split_once('|')?;
+                let bytes: u64 = bytes.trim().parse().ok()?;
+                (bytes > 0).then(|| (name.trim().to_lowercase(), bytes))
+            })
+            .collect()
     }
 
     /// Fallback Windows GPU detection via wmic (works on older systems).
-    fn detect_gpu_windows_wmic_list() -> Vec<GpuInfo> {
+    fn detect_gpu_windows_wmic_list(registry_vram: &HashMap<String, u64>) -> Vec<GpuInfo> {
         let output = match std::process::Command::new("wmic")
             .arg("path")
             .arg("win32_VideoController")
@@ -851,7 +1802,8 @@ impl SystemSpecs {
                     continue;
                 }
                 let backend = Self::infer_gpu_backend(&name);
-                let vram_gb = Self::resolve_wmi_vram(raw_vram, &name);
+                let vram_gb =
+                    Self::resolve_wmi_vram(raw_vram, &name, registry_vram.get(&lower).copied());
                 gpus.push(GpuInfo {
                     name,
                     vram_gb,
@@ -865,7 +1817,7 @@ impl SystemSpecs {
     }
 
     /// Parse all GPU entries from PowerShell output (Name|AdapterRAM per line).
-    fn parse_windows_gpu_list(text: &str) -> Vec<GpuInfo> {
+    fn parse_windows_gpu_list(text: &str, registry_vram: &HashMap<String, u64>) -> Vec<GpuInfo> {
         let mut gpus = Vec::new();
         for line in text.lines() {
             let line = line.trim();
@@ -889,7 +1841,8 @@ impl SystemSpecs {
             }
 
             let backend = Self::infer_gpu_backend(&name);
-            let vram_gb = Self::resolve_wmi_vram(raw_vram, &name);
+            let vram_gb =
+                Self::resolve_wmi_vram(raw_vram, &name, registry_vram.get(&lower).copied());
             gpus.push(GpuInfo {
                 name,
                 vram_gb,
@@ -901,17 +1854,25 @@ impl SystemSpecs {
         gpus
     }
 
-    /// WMI AdapterRAM is a 32-bit field, capped at ~4 GB.
-    /// If reported value is suspiciously low, estimate from GPU name.
-    fn resolve_wmi_vram(raw_bytes: u64, name: &str) -> Option<f64> {
-        let mut vram_gb = raw_bytes as f64 / (1024.0 * 1024.0 * 1024.0);
-        if vram_gb < 0.1 || (vram_gb <= 4.1 && estimate_vram_from_name(name) > 4.1) {
-            let estimated = estimate_vram_from_name(name);
-            if estimated > 0.0 {
-                vram_gb = estimated;
-            }
+    /// WMI AdapterRAM is a 32-bit field, capped at ~4 GB. Prefer the 64-bit
+    /// registry size when the driver recorded one; otherwise, if the reported
+    /// value is suspiciously low, estimate from GPU name. A capped value with
+    /// no estimate is reported as unknown rather than as 4 GB.
+    fn resolve_wmi_vram(raw_bytes: u64, name: &str, registry_bytes: Option<u64>) -> Option<f64> {
+        if let Some(bytes) = registry_bytes.filter(|&b| b > 0) {
+            return Some(bytes as f64 / (1024.0 * 1024.0 * 1024.0));
+        }
+        let vram_gb = raw_bytes as f64 / (1024.0 * 1024.0 * 1024.0);
+        let capped = raw_bytes >= WMI_ADAPTER_RAM_CAP_BYTES;
+        let estimated = estimate_vram_from_name(name);
+        if (vram_gb < 0.1 || capped || (vram_gb <= 4.1 && estimated > 4.1)) && estimated > 0.0 {
+            return Some(estimated);
+        }
+        if vram_gb > 0.0 && !capped {
+            Some(vram_gb)
+        } else {
+            None
         }
-        if vram_gb > 0.0 { Some(vram_gb) } else { None }
     }
 
     /// Infer the most likely inference backend from a GPU name string.
@@ -936,10 +1897,13 @@ impl SystemSpecs {
     }
 
     /// Detect Intel Arc / Intel integrated GPU via sysfs or lspci.
-    /// Intel Arc GPUs (A370M, A770, etc.) have dedicated VRAM exposed via
-    /// the DRM subsystem at /sys/class/drm/card*/device/. Even integrated
-    /// Intel GPUs that share system RAM are useful for inference via SYCL/oneAPI.
-    fn detect_intel_gpu() -> Option<f64> {
+    /// Discrete Arc cards (A770, B580, etc.) have dedicated VRAM exposed via
+    /// the DRM subsystem at /sys/class/drm/card*/device/. Integrated Arc
+    /// Graphics shares system RAM and is still useful via SYCL/oneAPI; its
+    /// shared-memory (GTT) limit is read from the same directory when the
+    /// driver exposes it.
+    fn detect_intel_gpu() -> Option<IntelGpu> {
+        let mut shared_gb = None;
         // Try sysfs first: works for Intel discrete (Arc) GPUs on Linux.
         // Walk /sys/class/drm/card*/device/ looking for Intel vendor ID (0x8086).
         if let Ok(entries) = std::fs::read_dir("/sys/class/drm") {
@@ -963,44 +1927,44 @@ impl SystemSpecs {
                     && vram_bytes > 0
                 {
                     let vram_gb = vram_bytes as f64 / (1024.0 * 1024.0 * 1024.0);
-                    return Some(vram_gb);
+                    return Some(IntelGpu::Discrete(Some(vram_gb)));
                 }
 
-                // For integrated Intel GPUs, check if it's an Arc-class device
-                // by looking for "Arc" in the device name via lspci
-                if let Some(text) = Self::lspci_output() {
-                    for line in text.lines() {
-                        let lower = line.to_lowercase();
-                        if lower.contains("intel") && lower.contains("arc") {
-                            // Intel Arc integrated (e.g. Arc Graphics in Meteor Lake)
-                            // These share system RAM; report None for VRAM and
-                            // let the caller know a GPU exists.
-                            return Some(0.0);
-                        }
-                    }
+                let gtt_path = card_path.join("device/mem_info_gtt_total");
+                if let Ok(gtt_str) = std::fs::read_to_string(&gtt_path)
+                    && let Ok(gtt_bytes) = gtt_str.trim().parse::<u64>()
+                    && gtt_bytes > 0
+                {
+                    shared_gb = Some(gtt_bytes as f64 / (1024.0 * 1024.0 * 1024.0));
                 }
             }
         }
 
-        // Fallback: check lspci directly for Intel Arc devices
-        // (covers cases where sysfs isn't available or card dirs don't exist)
-        if let Some(text) = Self::lspci_output() {
-            for line in text.lines() {
-                let lower = line.to_lowercase();
-                if lower.contains("intel") && lower.contains("arc") {
-                    return Some(0.0);
-                }
+        // No VRAM in sysfs (older i915 driver, or integrated graphics):
+        // classify the Arc device by its lspci name.
+        let gpu = Self::lspci_output()?
+            .lines()
+            .find_map(classify_intel_arc_line)?;
+        Some(match gpu {
+            IntelGpu::Integrated(_) => {
+                activity::detect(match shared_gb {
+                    Some(gb) => format!("Intel iGPU: {:.1} GB shared-memory limit (sysfs)", gb),
+                    None => "Intel iGPU: shared-memory limit not reported, assuming half of RAM"
+                        .to_string(),
+                });
+                IntelGpu::Integrated(shared_gb)
             }
-        }
-
-        None
+            discrete => discrete,
+        })
     }
 
     /// Detect Apple Silicon GPU via system_profiler.
+    /// The pool is capped afterwards by [`apple_gpu_wired_limit_gb`].
     /// Returns total system RAM as VRAM since memory is unified.
     /// The unified memory pool capacity is the total RAM -- it doesn't
     /// fluctuate with current usage the way available RAM does.
-    fn detect_apple_gpu(total_ram_gb: f64) -> Option<f64> {
+    /// Also returns the chipset name (e.g. "Apple M2 Max"), if listed.
+    fn detect_apple_gpu(total_ram_gb: f64) -> Option<(f64, Option<String>)> {
         // system_profiler only exists on macOS
         let output = std::process::Command::new("system_profiler")
             .arg("SPDisplaysDataType")
@@ -1023,7 +1987,7 @@ impl SystemSpecs {
         if is_apple_gpu {
             // Unified memory: GPU and CPU share the same RAM pool.
             // Report total RAM as the VRAM capacity.
-            Some(total_ram_gb)
+            Some((total_ram_gb, parse_apple_chipset(&text)))
         } else {
             None
         }
@@ -1228,7 +2192,7 @@ impl SystemSpecs {
         }
 
         // macOS fallback: parse vm_stat output
-        if let Some(avail) = Self::available_ram_from_vm_stat() {
+        if let Some(avail) = Self::available_ram_from_vm_stat(total_bytes) {
             return avail;
         }
 
@@ -1236,15 +2200,24 @@ impl SystemSpecs {
         total_gb * 0.8
     }
 
-    /// Parse macOS `vm_stat` to compute available memory.
-    /// Available ≈ (free + inactive + purgeable) * page_size
-    fn available_ram_from_vm_stat() -> Option<f64> {
+    /// Run macOS `vm_stat` and compute available memory from it.
+    fn available_ram_from_vm_stat(total_bytes: u64) -> Option<f64> {
         let output = std::process::Command::new("vm_stat").output().ok()?;
         if !output.status.success() {
             return None;
         }
         let text = String::from_utf8(output.stdout).ok()?;
+        Self::parse_vm_stat_available(&text, total_bytes)
+    }
 
+    /// Available memory (GB) from `vm_stat` output, the way Activity Monitor
+    /// sees it: everything except wired, compressed and non-purgeable app
+    /// (anonymous) memory. File-backed cache and purgeable pages count as
+    /// available because the OS drops them under pressure.
+    ///
+    /// Older `vm_stat` without the anonymous-page counter falls back to
+    /// free + inactive + speculative + purgeable.
+    fn parse_vm_stat_available(text: &str, total_bytes: u64) -> Option<f64> {
         // First line: "Mach Virtual Memory Statistics: (page size of NNNNN bytes)"
         let page_size: u64 = text
             .lines()
@@ -1259,21 +2232,30 @@ impl SystemSpecs {
             })
             .unwrap_or(16384); // Apple Silicon default is 16 KB pages
 
-        let mut free: u64 = 0;
-        let mut inactive: u64 = 0;
-        let mut purgeable: u64 = 0;
-
-        for line in text.lines() {
-            if let Some(val) = Self::parse_vm_stat_line(line, "Pages free") {
-                free = val;
-            } else if let Some(val) = Self::parse_vm_stat_line(line, "Pages inactive") {
-                inactive = val;
-            } else if let Some(val) = Self::parse_vm_stat_line(line, "Pages purgeable") {
-                purgeable = val;
+        let field = |key: &str| {
+            text.lines()
+                .find_map(|line| Self::parse_vm_stat_line(line, key))
+        };
+        let purgeable = field("Pages purgeable").unwrap_or(0);
+
+        let available_pages = match (
+            field("Anonymous pages"),
+            field("Pages wired down"),
+            field("Pages occupied by compressor"),
+        ) {
+            (Some(anonymous), Some(wired), compressed) if total_bytes > 0 => {
+                let used = wired + compressed.unwrap_or(0) + anonymous.saturating_sub(purgeable);
+                (total_bytes / page_size).saturating_sub(used)
             }
-        }
+            _ => {
+                field("Pages free").unwrap_or(0)
+                    + field("Pages inactive").unwrap_or(0)
+                    + field("Pages speculative").unwrap_or(0)
+                    + purgeable
+            }
+        };
 
-        let available_bytes = (free + inactive + purgeable) * page_size;
+        let available_bytes = available_pages * page_size;
         if available_bytes > 0 {
             Some(available_bytes as f64 / (1024.0 * 1024.0 * 1024.0))
         } else {
@@ -1376,6 +2358,7 @@ impl SystemSpecs {
     /// This is used by the `--memory` CLI flag when GPU autodetection fails.
     /// If no GPU was detected, this creates a synthetic GPU entry.
     pub fn with_gpu_memory_override(mut self, vram_gb: f64) -> Self {
+        activity::detect(format!("--memory override: GPU VRAM = {:.1} GB", vram_gb));
         if self.gpus.is_empty() {
             // No GPU was detected; create a synthetic one.
             let backend = if cfg!(target_arch = "aarch64")
@@ -1402,6 +2385,7 @@ impl SystemSpecs {
             // Override the primary (first) GPU's VRAM.
             self.gpus[0].vram_gb = Some(vram_gb);
             self.gpu_vram_gb = Some(vram_gb);
+            self.gpu_wired_limit_gb = None;
             // Update total VRAM: per-card VRAM * count.
             let count = self.gpus[0].count;
             self.total_gpu_vram_gb = Some(vram_gb * count as f64);
@@ -1410,12 +2394,259 @@ impl SystemSpecs {
         self
     }
 
-    pub fn display(&self) {
+    /// Let the GPU use `limit_gb` of a Metal unified pool, for users who
+    /// raised macOS's wired limit (`sudo sysctl iogpu.wired_limit_mb=...`)
+    /// after llmfit detected the default. No effect on other systems.
+    pub fn with_gpu_wired_limit(mut self, limit_gb: f64) -> Self {
+        if self.backend != GpuBackend::Metal || !self.unified_memory {
+            return self;
+        }
+        let limit_gb = limit_gb.min(self.total_ram_gb);
+        activity::detect(format!("--gpu-wired-limit: GPU may use {:.1} GB", limit_gb));
+        self.gpu_vram_gb = Some(limit_gb);
+        self.total_gpu_vram_gb = Some(limit_gb);
+        for gpu in self.gpus.iter_mut().filter(|g| g.unified_memory) {
+            gpu.vram_gb = Some(limit_gb);
+        }
+        self.gpu_wired_limit_gb = Some(limit_gb);
+        self
+    }
+
+    /// Pretend the machine has `ram_gb` of RAM. Memory in use now stays in
+    /// use, so available RAM moves by the same amount, and a unified GPU
+    /// pool follows the new total.
+    pub fn with_ram_override(mut self, ram_gb: f64) -> Self {
+        activity::detect(format!("--ram override: {:.1} GB RAM", ram_gb));
+        let in_use = (self.total_ram_gb - self.available_ram_gb).max(0.0);
+        self.total_ram_gb = ram_gb;
+        self.available_ram_gb = (ram_gb - in_use).max(0.0);
+        if self.unified_memory && self.has_gpu {
+            let pool = if self.backend == GpuBackend::Metal {
+                let limit = default_apple_gpu_wired_limit_gb(ram_gb);
+                self.gpu_wired_limit_gb = Some(limit);
+                limit
+            } else {
+                ram_gb
+            };
+            self.gpu_vram_gb = Some(pool);
+            self.total_gpu_vram_gb = Some(pool);
+            for gpu in self.gpus.iter_mut().filter(|g| g.unified_memory) {
+                gpu.vram_gb = Some(pool);
+            }
+        }
+        self.hypothetical = true;
+        self
+    }
+
+    /// Pretend the machine has `cores` CPU cores.
+    pub fn with_cpu_cores_override(mut self, cores: usize) -> Self {
+        activity::detect(format!("--cores override: {} cores", cores));
+        self.total_cpu_cores = cores;
+        self.hypothetical = true;
+        self
+    }
+
+    /// Use a measured RAM bandwidth (see [`measure_mem_bandwidth_gbps`])
+    /// instead of the detected or default figure.
+    pub fn with_mem_bandwidth(mut self, gbps: f64) -> Self {
+        activity::detect(format!("Memory bandwidth: {:.0} GB/s (measured)", gbps));
+        self.memory_bandwidth_gbps = Some(gbps);
+        self
+    }
+
+    /// RAM bandwidth for CPU-side estimates: the detected figure, or the
+    /// CPU class's conservative default.
+    pub fn mem_bandwidth_gbps(&self) -> f64 {
+        self.memory_bandwidth_gbps
+            .unwrap_or_else(|| default_mem_bandwidth_gbps(&self.cpu_name, self.backend))
+    }
+
+    /// Measure free disk space in `models_dir` instead of the default
+    /// download directory detection checked. Loaded profiles keep theirs.
+    pub fn with_models_dir(mut self, models_dir: &std::path::Path) -> Self {
+        if self.loaded_from.is_none() {
+            self.free_disk_gb = disk_free_gb(models_dir);
+        }
+        self
+    }
+
+    /// Treat `reserve_gb` of RAM as permanently unavailable (e.g. a browser
+    /// and editor that stay open). Unlike the per-model overhead margin this
+    /// is a flat carve-out. On unified-memory systems the GPU pool is the
+    /// same RAM, so it shrinks too.
+    pub fn with_ram_reserve(mut self, reserve_gb: f64) -> Self {
+        if reserve_gb <= 0.0 {
+            return self;
+        }
+        activity::detect(format!("--reserve: {:.1} GB of RAM held back", reserve_gb));
+        self.available_ram_gb = (self.available_ram_gb - reserve_gb).max(0.0);
+        if self.unified_memory {
+            self.gpu_vram_gb = self.gpu_vram_gb.map(|v| (v - reserve_gb).max(0.0));
+            self.total_gpu_vram_gb = self.total_gpu_vram_gb.map(|v| (v - reserve_gb).max(0.0));
+            for gpu in self.gpus.iter_mut().filter(|g| g.unified_memory) {
+                gpu.vram_gb = gpu.vram_gb.map(|v| (v - reserve_gb).max(0.0));
+            }
+        }
+        self.reserved_ram_gb += reserve_gb;
+        self
+    }
+
+    /// Hold back `headroom_gb` for the OS, drivers and the inference runtime
+    /// itself, which a model can never use. Unlike `--reserve` this applies
+    /// to VRAM as well: each discrete card loses it to the display server and
+    /// CUDA/Metal context, and a unified pool loses it once.
+    pub fn with_memory_headroom(mut self, headroom_gb: f64) -> Self {
+        if headroom_gb <= 0.0 {
+            return self;
+        }
+        activity::detect(format!(
+            "--headroom: {:.1} GB held back from RAM and VRAM",
+            headroom_gb
+        ));
+        self.available_ram_gb = (self.available_ram_gb - headroom_gb).max(0.0);
+        if self.has_gpu {
+            // Discrete cards each lose the headroom; a unified pool once.
+            let cards = if self.unified_memory {
+                1.0
+            } else {
+                self.gpu_count.max(1) as f64
+            };
+            self.gpu_vram_gb = self.gpu_vram_gb.map(|v| (v - headroom_gb).max(0.0));
+            self.total_gpu_vram_gb = self
+                .total_gpu_vram_gb
+                .map(|v| (v - headroom_gb * cards).max(0.0));
+            for gpu in &mut self.gpus {
+                gpu.vram_gb = gpu.vram_gb.map(|v| (v - headroom_gb).max(0.0));
+            }
+        }
+        self.headroom_gb += headroom_gb;
+        self
+    }
+
+    /// VRAM of every detected card, one entry per physical GPU (a `x2`
+    /// group contributes two). Cards with unknown VRAM are left out.
+    pub fn per_gpu_vram_gb(&self) -> Vec<f64> {
+        self.gpus
+            .iter()
+            .filter_map(|gpu| gpu.vram_gb.map(|vram| (vram, gpu.count)))
+            .flat_map(|(vram, count)| std::iter::repeat_n(vram, count as usize))
+            .collect()
+    }
+
+    /// The pooled primary GPU group, e.g. "2x NVIDIA RTX 4090 (48 GB total)".
+    /// `None` with a single card, unified memory or unknown VRAM.
+    pub fn gpu_group_label(&self, units: MemoryUnits) -> Option<String> {
+        let primary = self.gpus.first()?;
+        if primary.count < 2 || self.unified_memory {
+            return None;
+        }
+        let total = self.total_gpu_vram_gb.filter(|&v| v > 0.0)?;
+        Some(format!(
+            "{}x {} ({} total)",
+            primary.count,
+            primary.name,
+            fmt_mem(total, 0, units)
+        ))
+    }
+
+    /// Clamp the memory budget to a per-process allocation limit. A model
+    /// can't use RAM the process isn't allowed to map, however much is free.
+    /// No-op when the limit is above available RAM.
+    pub fn with_process_mem_limit(mut self, limit_gb: f64) -> Self {
+        if limit_gb >= self.available_ram_gb {
+            return self;
+        }
+        activity::detect(format!(
+            "rlimit: process may allocate at most {:.1} GB, below {:.1} GB available RAM",
+            limit_gb, self.available_ram_gb
+        ));
+        self.available_ram_gb = limit_gb;
+        if self.unified_memory {
+            self.gpu_vram_gb = self.gpu_vram_gb.map(|v| v.min(limit_gb));
+            self.total_gpu_vram_gb = self.total_gpu_vram_gb.map(|v| v.min(limit_gb));
+            for gpu in self.gpus.iter_mut().filter(|g| g.unified_memory) {
+                gpu.vram_gb = gpu.vram_gb.map(|v| v.min(limit_gb));
+            }
+        }
+        self.process_mem_limit_gb = Some(limit_gb);
+        self
+    }
+
+    /// Print the specs for `llmfit system`; free disk space is labeled
+    /// with `models_dir`, where it was measured.
+    pub fn display(&self, units: MemoryUnits, models_dir: &std::path::Path) {
         println!("\n=== System Specifications ===");
+        if let Some(path) = &self.loaded_from {
+            println!("Profile: {} (loaded, not detected)", path);
+        }
+        if self.hypothetical {
+            println!("Hypothetical machine: --ram/--vram/--cores overrides applied");
+        }
         println!("CPU: {} ({} cores)", self.cpu_name, self.total_cpu_cores);
-        println!("Total RAM: {:.2} GB", self.total_ram_gb);
-        println!("Available RAM: {:.2} GB", self.available_ram_gb);
+        let features = self.cpu_features.labels();
+        if !features.is_empty() {
+            println!("CPU features: {}", features.join(" "));
+        }
+        println!("Total RAM: {}", fmt_mem(self.total_ram_gb, 2, units));
+        if self.reserved_ram_gb > 0.0 || self.headroom_gb > 0.0 {
+            println!(
+                "Available RAM: {} ({} reserved, {} headroom)",
+                fmt_mem(self.available_ram_gb, 2, units),
+                fmt_mem(self.reserved_ram_gb, 1, units),
+                fmt_mem(self.headroom_gb, 1, units)
+            );
+        } else {
+            println!(
+                "Available RAM: {}",
+                fmt_mem(self.available_ram_gb, 2, units)
+            );
+        }
+        if self.swap_total_gb > 0.0 {
+            println!(
+                "Swap: {} used / {} total (not counted as available)",
+                fmt_mem(self.swap_used_gb, 1, units),
+                fmt_mem(self.swap_total_gb, 1, units)
+            );
+        }
+        if let Some(limit) = self.process_mem_limit_gb {
+            println!(
+                "Process memory limit: {} (ulimit; caps available RAM)",
+                fmt_mem(limit, 2, units)
+            );
+        }
+        if let Some(limit) = self.gpu_wired_limit_gb {
+            println!(
+                "GPU wired limit: {} of unified memory (iogpu.wired_limit_mb)",
+                fmt_mem(limit, 1, units)
+            );
+        }
+        if let Some(free) = self.free_disk_gb {
+            println!(
+                "Disk: {} free in {}",
+                fmt_mem(free, 1, units),
+                models_dir.display()
+            );
+        }
+        println!(
+            "Memory bandwidth: ~{:.0} GB/s{}",
+            self.mem_bandwidth_gbps(),
+            if self.memory_bandwidth_gbps.is_none() {
+                " (not detected; default for this CPU class)"
+            } else {
+                ""
+            }
+        );
         println!("Backend: {}", self.backend.label());
+        if let Some(npu) = &self.npu_name {
+            println!("NPU: {} (not used for fit yet)", npu);
+        }
+        if let Some(driver) = &self.driver_version {
+            println!(
+                "NVIDIA driver: {} (CUDA {})",
+                driver,
+                self.cuda_version.as_deref().unwrap_or("unknown")
+            );
+        }
 
         if self.gpus.is_empty() {
             println!("GPU: Not detected");
@@ -1428,10 +2659,10 @@ impl SystemSpecs {
                 };
                 if gpu.unified_memory {
                     println!(
-                        "{}{} (unified memory, {:.2} GB shared, {})",
+                        "{}{} (unified memory, {} shared, {})",
                         prefix,
                         gpu.name,
-                        gpu.vram_gb.unwrap_or(0.0),
+                        fmt_mem(gpu.vram_gb.unwrap_or(0.0), 2, units),
                         gpu.backend.label(),
                     );
                 } else {
@@ -1440,20 +2671,20 @@ impl SystemSpecs {
                             if gpu.count > 1 {
                                 let total_vram = vram * gpu.count as f64;
                                 println!(
-                                    "{}{} x{} ({:.2} GB VRAM each = {:.0} GB total, {})",
+                                    "{}{} x{} ({} VRAM each = {} total, {})",
                                     prefix,
                                     gpu.name,
                                     gpu.count,
-                                    vram,
-                                    total_vram,
+                                    fmt_mem(vram, 2, units),
+                                    fmt_mem(total_vram, 0, units),
                                     gpu.backend.label()
                                 );
                             } else {
                                 println!(
-                                    "{}{} ({:.2} GB VRAM, {})",
+                                    "{}{} ({} VRAM, {})",
                                     prefix,
                                     gpu.name,
-                                    vram,
+                                    fmt_mem(vram, 2, units),
                                     gpu.backend.label()
                                 );
                             }
@@ -1478,6 +2709,92 @@ impl SystemSpecs {
     }
 }
 
+/// How memory sizes are labelled in human-readable output.
+///
+/// RAM and VRAM are read in binary units (1024³ bytes) and all fit math runs
+/// on those figures. `Decimal` only changes display: values are converted to
+/// 1000³-byte gigabytes so they line up with vendor spec sheets.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MemoryUnits {
+    #[default]
+    Binary,
+    Decimal,
+}
+
+impl MemoryUnits {
+    pub fn label(&self) -> &'static str {
+        match self {
+            MemoryUnits::Binary => "GiB",
+            MemoryUnits::Decimal => "GB",
+        }
+    }
+
+    /// Convert a value in binary gigabytes to this unit.
+    pub fn convert(&self, gib: f64) -> f64 {
+        match self {
+            MemoryUnits::Binary => gib,
+            MemoryUnits::Decimal => gib * 1.073_741_824,
+        }
+    }
+
+    /// `gib` converted and labelled, e.g. "15.6 GiB" or "16.8 GB".
+    pub fn format(&self, gib: f64, precision: usize) -> String {
+        format!("{:.*} {}", precision, self.convert(gib), self.label())
+    }
+}
+
+/// Format a binary-gigabyte memory size in `units`.
+pub fn fmt_mem(gib: f64, precision: usize, units: MemoryUnits) -> String {
+    units.format(gib, precision)
+}
+
+/// The smallest of `limits` in bytes, skipping any equal to `unlimited`
+/// (`RLIM_INFINITY`).
+#[cfg_attr(not(unix), allow(dead_code))]
+fn tightest_mem_limit_bytes(limits: impl IntoIterator<Item = u64>, unlimited: u64) -> Option<u64> {
+    limits.into_iter().filter(|&bytes| bytes != unlimited).min()
+}
+
+/// Product name for an NPU kernel driver: `intel_vpu` drives Intel's
+/// Meteor Lake and later NPUs, `amdxdna` the Ryzen AI ones.
+fn npu_name_for_driver(driver: &str) -> Option<&'static str> {
+    match driver.trim() {
+        "intel_vpu" => Some("Intel AI Boost"),
+        "amdxdna" => Some("AMD XDNA"),
+        _ => None,
+    }
+}
+
+/// Every Apple Silicon Mac has a Neural Engine; the Ultra chips fuse two
+/// dies and so have twice the cores.
+fn apple_npu_name(cpu_name: &str) -> Option<String> {
+    if !cpu_name.starts_with("Apple M") {
+        return None;
+    }
+    let cores = if cpu_name.contains("Ultra") { 32 } else { 16 };
+    Some(format!("Apple Neural Engine ({}-core)", cores))
+}
+
+/// Pull "12.4" out of the nvidia-smi banner line
+/// `| NVIDIA-SMI 550.54.14   Driver Version: 550.54.14   CUDA Version: 12.4 |`.
+fn parse_nvidia_smi_cuda_version(text: &str) -> Option<String> {
+    let rest = text.split("CUDA Version:").nth(1)?;
+    let version: String = rest
+        .trim_start()
+        .chars()
+        .take_while(|c| c.is_ascii_digit() || *c == '.')
+        .collect();
+    (!version.is_empty()).then_some(version)
+}
+
+/// Parse "12.4" / "11.8.0" into (major, minor).
+pub fn parse_version_major_minor(version: &str) -> Option<(u32, u32)> {
+    let mut parts = version.trim().split('.');
+    let major = parts.next()?.parse().ok()?;
+    let minor = parts.next().and_then(|m| m.parse().ok()).unwrap_or(0);
+    Some((major, minor))
+}
+
 /// Parse a human-readable memory size string into gigabytes.
 /// Accepts formats: "32G", "32g", "32GB", "32gb", "32000M", "32000m", "32000MB", etc.
 /// Returns `None` if the input is malformed.
@@ -1549,6 +2866,48 @@ fn is_amd_unified_memory_apu(cpu_name: &str) -> bool {
     false
 }
 
+/// Run a detection tool and return its stdout. A tool that isn't installed
+/// is ordinary absence and only logged; one that is installed but fails or
+/// prints non-UTF-8 is a warning, since the hardware it probes may be there.
+fn probe(program: &str, args: &[&str]) -> Option<String> {
+    let output = match std::process::Command::new(program).args(args).output() {
+        Ok(output) => output,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+            activity::detect(format!("{}: not installed", program));
+            return None;
+        }
+        Err(e) => {
+            report(DetectError::CommandSpawn {
+                command: program.to_string(),
+                message: e.to_string(),
+            });
+            return None;
+        }
+    };
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        let reason = stderr.lines().map(str::trim).find(|l| !l.is_empty());
+        report(DetectError::CommandFailed {
+            command: program.to_string(),
+            message: match reason {
+                Some(reason) => format!("{} ({})", output.status, reason),
+                None => output.status.to_string(),
+            },
+        });
+        return None;
+    }
+    match String::from_utf8(output.stdout) {
+        Ok(text) => Some(text),
+        Err(_) => {
+            report(DetectError::ParseFailure {
+                command: program.to_string(),
+                message: "output is not valid UTF-8".to_string(),
+            });
+            None
+        }
+    }
+}
+
 /// Read total system RAM from /proc/meminfo (Linux only).
 /// Used as the unified memory pool on NVIDIA Tegra / Grace Blackwell platforms
 /// where nvidia-smi cannot report dedicated VRAM.
@@ -1563,6 +2922,157 @@ fn read_proc_meminfo_total_gb() -> Option<f64> {
     None
 }
 
+/// How much of a `total_ram_gb` unified pool Metal lets the GPU wire by
+/// default: about two thirds up to 36 GB, three quarters above.
+pub fn default_apple_gpu_wired_limit_gb(total_ram_gb: f64) -> f64 {
+    if total_ram_gb <= 36.0 {
+        total_ram_gb * 2.0 / 3.0
+    } else {
+        total_ram_gb * 0.75
+    }
+}
+
+/// The GPU's share of the unified pool: `iogpu.wired_limit_mb` when the
+/// user has raised it with sysctl, the macOS default otherwise.
+fn apple_gpu_wired_limit_gb(total_ram_gb: f64) -> f64 {
+    let raised_mb = std::process::Command::new("sysctl")
+        .args(["-n", "iogpu.wired_limit_mb"])
+        .output()
+        .ok()
+        .filter(|out| out.status.success())
+        .and_then(|out| String::from_utf8(out.stdout).ok())
+        .and_then(|text| text.trim().parse::<f64>().ok())
+        .filter(|&mb| mb > 0.0);
+    match raised_mb {
+        Some(mb) => {
+            activity::detect(format!("iogpu.wired_limit_mb = {:.0}", mb));
+            (mb / 1024.0).min(total_ram_gb)
+        }
+        None => default_apple_gpu_wired_limit_gb(total_ram_gb),
+    }
+}
+
+/// The "Chipset Model: Apple M2 Max" value from `system_profiler
+/// SPDisplaysDataType` output.
+fn parse_apple_chipset(text: &str) -> Option<String> {
+    text.lines()
+        .filter_map(|line| line.trim().strip_prefix("Chipset Model:"))
+        .map(str::trim)
+        .find(|model| model.to_lowercase().starts_with("apple"))
+        .map(str::to_string)
+}
+
+/// SIMD extensions named in the first CPU's `flags` (x86) or `Features`
+/// (ARM) line of /proc/cpuinfo.
+fn parse_cpuinfo_features(text: &str) -> CpuFeatures {
+    let Some(flags) = text.lines().find_map(|line| {
+        let (key, value) = line.split_once(':')?;
+        matches!(key.trim(), "flags" | "Features").then_some(value)
+    }) else {
+        return CpuFeatures::default();
+    };
+    let has = |flag: &str| flags.split_whitespace().any(|f| f == flag);
+    CpuFeatures {
+        avx2: has("avx2"),
+        avx512: has("avx512f"),
+        amx: has("amx_tile"),
+        // 64-bit ARM lists Advanced SIMD as "asimd"
+        neon: has("asimd") || has("neon"),
+        sve: has("sve"),
+    }
+}
+
+/// Measure sustained RAM bandwidth in GB/s with a short copy loop.
+///
+/// One thread per core (up to 8) repeatedly copies its own 16 MB buffer,
+/// large enough together to spill out of the last-level cache, until
+/// `budget` runs out. Both the read and the write side count as traffic.
+pub fn measure_mem_bandwidth_gbps(budget: std::time::Duration) -> f64 {
+    let threads = std::thread::available_parallelism()
+        .map(|n| n.get())
+        .unwrap_or(1)
+        .min(8);
+    measure_copy_bandwidth(16 << 20, threads, budget)
+}
+
+fn measure_copy_bandwidth(buffer_bytes: usize, threads: usize, budget: std::time::Duration) -> f64 {
+    use std::time::Instant;
+
+    let barrier = std::sync::Barrier::new(threads + 1);
+    let deadline = std::sync::OnceLock::new();
+    let (passes, elapsed) = std::thread::scope(|scope| {
+        let handles: Vec<_> = (0..threads)
+            .map(|_| {
+                scope.spawn(|| {
+                    let src = vec![1u8; buffer_bytes];
+                    let mut dst = vec![0u8; buffer_bytes];
+                    // Fault in both buffers before the clock starts.
+                    dst.copy_from_slice(&src);
+                    barrier.wait();
+                    let deadline: Instant = *deadline.wait();
+                    let mut passes = 0u64;
+                    while Instant::now() < deadline {
+                        dst.copy_from_slice(std::hint::black_box(&src));
+                        std::hint::black_box(&mut dst);
+                        passes += 1;
+                    }
+                    passes
+                })
+            })
+            .collect();
+        barrier.wait();
+        let start = Instant::now();
+        let _ = deadline.set(start + budget);
+        let passes: u64 = handles.into_iter().map(|h| h.join().unwrap_or(0)).sum();
+        (passes, start.elapsed())
+    });
+
+    let bytes = passes as f64 * buffer_bytes as f64 * 2.0;
+    bytes / elapsed.as_secs_f64().max(1e-9) / 1e9
+}
+
+/// Estimate system RAM bandwidth in GB/s from `dmidecode -t 17` output.
+///
+/// Uses the slowest populated DIMM's configured speed (MT/s x 8 bytes per
+/// channel). Channel count is guessed conservatively from DIMM population:
+/// up to two DIMMs are assumed to be on separate channels, larger
+/// populations are assumed to be two DIMMs per channel.
+fn parse_dmidecode_bandwidth(text: &str) -> Option<f64> {
+    let mut speeds: Vec<f64> = Vec::new();
+    for device in text.split("Memory Device").skip(1) {
+        let mut configured = None;
+        let mut rated = None;
+        for line in device.lines() {
+            let line = line.trim();
+            let Some((key, value)) = line.split_once(':') else {
+                continue;
+            };
+            let mts = value
+                .split_whitespace()
+                .next()
+                .and_then(|v| v.parse::<f64>().ok())
+                .filter(|v| *v > 0.0);
+            match key.trim() {
+                "Configured Memory Speed" | "Configured Clock Speed" => configured = mts,
+                "Speed" => rated = mts,
+                _ => {}
+            }
+        }
+        if let Some(speed) = configured.or(rated) {
+            speeds.push(speed);
+        }
+    }
+
+    let populated = speeds.len();
+    let slowest = speeds.into_iter().reduce(f64::min)?;
+    let channels = if populated <= 2 {
+        populated
+    } else {
+        (populated / 2).max(2)
+    };
+    Some(slowest * 8.0 * channels as f64 / 1000.0)
+}
+
 /// Estimate GPU memory bandwidth in GB/s from the GPU model name.
 ///
 /// Token generation in LLM inference is memory-bandwidth-bound (each token
@@ -2102,7 +3612,7 @@ fn estimate_vram_from_name(name: &str) -> f64 {
 
 #[cfg(test)]
 mod tests {
-    use super::SystemSpecs;
+    use super::{SPECS_SCHEMA_VERSION, SystemSpecs};
 
     #[test]
     fn test_parse_nvidia_smi_does_not_sum_multi_gpu_vram() {
@@ -2118,6 +3628,55 @@ mod tests {
         assert!(vram > 23.0 && vram < 25.0, "unexpected VRAM value: {vram}");
     }
 
+    #[test]
+    fn test_windows_vram_prefers_registry_over_capped_wmi() {
+        let registry = SystemSpecs::parse_windows_registry_vram(
+            "NVIDIA GeForce RTX 4090|25757220864\r\nIntel(R) UHD Graphics|\r\n",
+        );
+        assert_eq!(registry.len(), 1);
+
+        let gpus = SystemSpecs::parse_windows_gpu_list(
+            "NVIDIA GeForce RTX 4090|4293918720\r\nMicrosoft Basic Display Adapter|0\r\n",
+            &registry,
+        );
+        assert_eq!(gpus.len(), 1);
+        let vram = gpus[0].vram_gb.unwrap();
+        assert!((vram - 23.99).abs() < 0.01, "got {vram}");
+
+        // Capped with nothing better to go on: unknown, not 4 GB
+        assert_eq!(
+            SystemSpecs::resolve_wmi_vram(4293918720, "Mystery Adapter", None),
+            None
+        );
+        // Below the cap the WMI figure is trusted
+        let vram = SystemSpecs::resolve_wmi_vram(2147483648, "Mystery Adapter", None);
+        assert_eq!(vram, Some(2.0));
+    }
+
+    #[test]
+    fn test_parse_rocm_smi_vram_json_and_text() {
+        let json = r#"{"card1": {"VRAM Total Memory (B)": "25753026560", "VRAM Total Used Memory (B)": "12345"},
+                      "card0": {"VRAM Total Memory (B)": "17163091968", "VRAM Total Used Memory (B)": "6789"}}"#;
+        assert_eq!(
+            SystemSpecs::parse_rocm_smi_vram(json),
+            vec![17163091968, 25753026560]
+        );
+
+        let text = "\
+============================ ROCm System Management Interface ============================
+GPU[0]		: VRAM Total Memory (B): 17163091968
+GPU[0]		: VRAM Total Used Memory (B): 6789
+GPU[1]		: VRAM Total Memory (B): 25753026560
+GPU[1]		: VRAM Total Used Memory (B): 12345
+";
+        assert_eq!(
+            SystemSpecs::parse_rocm_smi_vram(text),
+            vec![17163091968, 25753026560]
+        );
+
+        assert!(SystemSpecs::parse_rocm_smi_vram("{}").is_empty());
+    }
+
     #[test]
     fn test_parse_nvidia_smi_keeps_distinct_models() {
         let text = "24564, NVIDIA GeForce RTX 4090\n16376, NVIDIA GeForce RTX 4080\n";
@@ -2350,6 +3909,46 @@ GPU id = 1 (NVIDIA GeForce RTX 4090)
         assert_eq!(super::GpuBackend::Ascend.label(), "NPU (Ascend)");
     }
 
+    #[test]
+    #[cfg(unix)]
+    fn probe_returns_stdout_only_on_success() {
+        assert_eq!(super::probe("llmfit-no-such-tool", &[]), None);
+        assert_eq!(super::probe("sh", &["-c", "exit 3"]), None);
+        assert_eq!(
+            super::probe("sh", &["-c", "echo ok"]).as_deref(),
+            Some("ok\n")
+        );
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn probe_failures_are_collected_but_absence_is_not() {
+        let (_, errors) = super::collect_detect_errors(|| {
+            super::probe("llmfit-no-such-tool", &[]);
+            super::probe("sh", &["-c", "echo boom >&2; exit 3"]);
+        });
+        assert_eq!(errors.len(), 1);
+        assert!(matches!(
+            &errors[0],
+            super::DetectError::CommandFailed { command, message }
+                if command == "sh" && message.contains("boom")
+        ));
+        assert!(errors[0].to_string().starts_with("sh: "));
+
+        // Outside a collection scope nothing is kept
+        super::probe("sh", &["-c", "exit 3"]);
+        let (_, errors) = super::collect_detect_errors(|| ());
+        assert!(errors.is_empty());
+    }
+
+    #[test]
+    fn memory_units_convert_binary_to_decimal() {
+        use super::MemoryUnits;
+        assert_eq!(MemoryUnits::Binary.format(16.0, 1), "16.0 GiB");
+        assert_eq!(MemoryUnits::Decimal.format(16.0, 1), "17.2 GB");
+        assert_eq!(MemoryUnits::default(), MemoryUnits::Binary);
+    }
+
     // ── parse_memory_size ────────────────────────────────────────────
 
     #[test]
@@ -2404,6 +4003,193 @@ GPU id = 1 (NVIDIA GeForce RTX 4090)
         assert_eq!(super::parse_memory_size("16.5G"), Some(16.5));
     }
 
+    // ── parse_dmidecode_bandwidth ────────────────────────────────────
+
+    #[test]
+    fn test_parse_dmidecode_bandwidth_dual_channel_ddr5() {
+        let text = "\
+Handle 0x0040, DMI type 17, 92 bytes
+Memory Device
+\tSize: 16 GB
+\tType: DDR5
+\tSpeed: 5600 MT/s
+\tConfigured Memory Speed: 4800 MT/s
+
+Handle 0x0041, DMI type 17, 92 bytes
+Memory Device
+\tSize: No Module Installed
+\tSpeed: Unknown
+\tConfigured Memory Speed: Unknown
+
+Handle 0x0042, DMI type 17, 92 bytes
+Memory Device
+\tSize: 16 GB
+\tType: DDR5
+\tSpeed: 5600 MT/s
+\tConfigured Memory Speed: 4800 MT/s
+";
+        // 4800 MT/s x 8 bytes x 2 channels
+        assert_eq!(super::parse_dmidecode_bandwidth(text), Some(76.8));
+    }
+
+    #[test]
+    fn test_parse_dmidecode_bandwidth_four_dimms_assumes_two_channels() {
+        let dimm = "Memory Device\n\tSpeed: 3200 MT/s\n\n";
+        let text = dimm.repeat(4);
+        assert_eq!(super::parse_dmidecode_bandwidth(&text), Some(51.2));
+    }
+
+    #[test]
+    fn test_parse_dmidecode_bandwidth_no_modules() {
+        assert_eq!(super::parse_dmidecode_bandwidth(""), None);
+        assert_eq!(
+            super::parse_dmidecode_bandwidth("Memory Device\n\tSpeed: Unknown\n"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_apple_chipset_keys_bandwidth_table() {
+        let text = "Graphics/Displays:\n\n    Apple M2 Max:\n\n      Chipset Model: Apple M2 Max\n      Type: GPU\n";
+        let chipset = super::parse_apple_chipset(text).unwrap();
+        assert_eq!(chipset, "Apple M2 Max");
+        assert_eq!(super::gpu_memory_bandwidth_gbps(&chipset), Some(400.0));
+        assert_eq!(
+            super::parse_apple_chipset("      Chipset Model: AMD Radeon Pro 5500M\n"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_parse_cpuinfo_features() {
+        let x86 =
+            "processor\t: 0\nflags\t\t: fpu sse2 avx avx2 avx512f amx_tile\n\nprocessor\t: 1\n";
+        let features = super::parse_cpuinfo_features(x86);
+        assert!(features.avx2 && features.avx512 && features.amx);
+        assert_eq!(features.badge(), Some("AMX"));
+
+        let arm = "processor\t: 0\nFeatures\t: fp asimd evtstrm aes sve\n";
+        assert_eq!(super::parse_cpuinfo_features(arm).labels(), ["NEON", "SVE"]);
+
+        let bare = super::parse_cpuinfo_features("processor\t: 0\n");
+        assert_eq!(bare, super::CpuFeatures::default());
+        assert_eq!(bare.badge(), None);
+    }
+
+    #[test]
+    fn test_undetected_bandwidth_falls_back_per_cpu_class() {
+        use super::{GpuBackend, default_mem_bandwidth_gbps};
+        assert_eq!(
+            default_mem_bandwidth_gbps("AMD Ryzen 7 7700X", GpuBackend::Cuda),
+            super::DEFAULT_MEM_BANDWIDTH_GBPS
+        );
+        assert_eq!(
+            default_mem_bandwidth_gbps("AMD EPYC 7763 64-Core", GpuBackend::CpuX86),
+            100.0
+        );
+        assert_eq!(
+            default_mem_bandwidth_gbps("Apple M1", GpuBackend::Metal),
+            68.0
+        );
+        assert_eq!(
+            default_mem_bandwidth_gbps("Cortex-A76", GpuBackend::CpuArm),
+            25.0
+        );
+    }
+
+    #[test]
+    fn test_disk_tier_load_seconds() {
+        use super::DiskTier;
+        assert_eq!(DiskTier::Ssd.load_seconds(10.0), 20.0);
+        assert!(DiskTier::Hdd.load_seconds(40.0) > DiskTier::Nvme.load_seconds(40.0) * 10.0);
+        assert!(DiskTier::Hdd.is_slow());
+        assert!(!DiskTier::Unknown.is_slow());
+    }
+
+    #[test]
+    fn test_parse_nvidia_smi_cuda_version() {
+        let banner = "+-----------------------------------------------------------------------------+\n\
+| NVIDIA-SMI 550.54.14    Driver Version: 550.54.14    CUDA Version: 12.4     |\n";
+        assert_eq!(
+            super::parse_nvidia_smi_cuda_version(banner).as_deref(),
+            Some("12.4")
+        );
+        assert_eq!(super::parse_nvidia_smi_cuda_version("no banner"), None);
+        assert_eq!(super::parse_version_major_minor("11.8.0"), Some((11, 8)));
+        assert_eq!(super::parse_version_major_minor("12"), Some((12, 0)));
+    }
+
+    #[test]
+    fn test_npu_names() {
+        assert_eq!(
+            super::npu_name_for_driver("intel_vpu"),
+            Some("Intel AI Boost")
+        );
+        assert_eq!(super::npu_name_for_driver("amdxdna\n"), Some("AMD XDNA"));
+        assert_eq!(super::npu_name_for_driver("habanalabs"), None);
+        assert_eq!(
+            super::apple_npu_name("Apple M3 Pro").as_deref(),
+            Some("Apple Neural Engine (16-core)")
+        );
+        assert_eq!(
+            super::apple_npu_name("Apple M2 Ultra").as_deref(),
+            Some("Apple Neural Engine (32-core)")
+        );
+        assert_eq!(super::apple_npu_name("Intel(R) Core(TM) i7"), None);
+    }
+
+    #[test]
+    fn test_parse_vm_stat_available_counts_reclaimable_cache() {
+        let text = "Mach Virtual Memory Statistics: (page size of 16384 bytes)\n\
+Pages free:                                5000.\n\
+Pages active:                            300000.\n\
+Pages inactive:                          290000.\n\
+Pages speculative:                         2000.\n\
+Pages wired down:                        150000.\n\
+Pages purgeable:                          10000.\n\
+File-backed pages:                       400000.\n\
+Anonymous pages:                         200000.\n\
+Pages occupied by compressor:             50000.\n";
+        // 32 GiB = 2,097,152 pages of 16 KiB.
+        let total = 32u64 * 1024 * 1024 * 1024;
+        let avail = SystemSpecs::parse_vm_stat_available(text, total).unwrap();
+        // used = wired 150000 + compressed 50000 + (anonymous 200000 - purgeable 10000)
+        let expected = (2_097_152u64 - 390_000) as f64 * 16384.0 / (1024.0 * 1024.0 * 1024.0);
+        assert!((avail - expected).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_parse_vm_stat_available_old_format() {
+        let text = "Mach Virtual Memory Statistics: (page size of 4096 bytes)\n\
+Pages free:                              262144.\n\
+Pages inactive:                          262144.\n";
+        let avail = SystemSpecs::parse_vm_stat_available(text, 0).unwrap();
+        assert!((avail - 2.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_custom_detector_runs_before_vulkan_fallback() {
+        struct Fpga;
+        impl super::HardwareDetector for Fpga {
+            fn name(&self) -> &str {
+                "test-fpga"
+            }
+            fn detect(&self, _ctx: &super::DetectContext) -> Vec<super::GpuInfo> {
+                Vec::new()
+            }
+        }
+        super::register_detector(Fpga);
+
+        let names: Vec<String> = super::registered_detectors()
+            .iter()
+            .map(|d| d.name().to_string())
+            .collect();
+        let fpga = names.iter().position(|n| n == "test-fpga").unwrap();
+        let nvidia = names.iter().position(|n| n.starts_with("NVIDIA")).unwrap();
+        let vulkan = names.iter().position(|n| n == "Vulkan").unwrap();
+        assert!(nvidia < fpga && fpga < vulkan);
+    }
+
     // ── with_gpu_memory_override ─────────────────────────────────────
 
     fn make_specs_no_gpu() -> SystemSpecs {
@@ -2420,6 +4206,21 @@ GPU id = 1 (NVIDIA GeForce RTX 4090)
             unified_memory: false,
             backend: super::GpuBackend::CpuX86,
             gpus: vec![],
+            memory_bandwidth_gbps: None,
+            reserved_ram_gb: 0.0,
+            headroom_gb: 0.0,
+            gpu_wired_limit_gb: None,
+            swap_total_gb: 0.0,
+            swap_used_gb: 0.0,
+            process_mem_limit_gb: None,
+            driver_version: None,
+            cuda_version: None,
+            cpu_features: super::CpuFeatures::default(),
+            has_npu: false,
+            npu_name: None,
+            loaded_from: None,
+            hypothetical: false,
+            free_disk_gb: None,
         }
     }
 
@@ -2443,9 +4244,78 @@ GPU id = 1 (NVIDIA GeForce RTX 4090)
                 count: 1,
                 unified_memory: false,
             }],
+            memory_bandwidth_gbps: None,
+            reserved_ram_gb: 0.0,
+            headroom_gb: 0.0,
+            gpu_wired_limit_gb: None,
+            swap_total_gb: 0.0,
+            swap_used_gb: 0.0,
+            process_mem_limit_gb: None,
+            driver_version: None,
+            cuda_version: None,
+            cpu_features: super::CpuFeatures::default(),
+            has_npu: false,
+            npu_name: None,
+            loaded_from: None,
+            hypothetical: false,
+            free_disk_gb: None,
         }
     }
 
+    #[test]
+    fn test_specs_profile_round_trip_and_version_check() {
+        let specs = make_specs_with_gpu();
+        let json = specs.to_profile_json();
+        let loaded = SystemSpecs::from_profile_json(&json).unwrap();
+        assert_eq!(loaded.gpu_name.as_deref(), Some("NVIDIA RTX 3070"));
+        assert_eq!(loaded.gpus[0].backend, super::GpuBackend::Cuda);
+        assert_eq!(loaded.available_ram_gb, specs.available_ram_gb);
+        assert_eq!(
+            serde_json::to_value(&loaded).unwrap(),
+            serde_json::to_value(&specs).unwrap()
+        );
+
+        // Fields added after a profile was written take their defaults
+        let mut old: serde_json::Value = serde_json::from_str(&json).unwrap();
+        old["specs"].as_object_mut().unwrap().remove("has_npu");
+        old["specs"]
+            .as_object_mut()
+            .unwrap()
+            .remove("swap_total_gb");
+        let loaded = SystemSpecs::from_profile_json(&old.to_string()).unwrap();
+        assert!(!loaded.has_npu);
+        assert_eq!(loaded.swap_total_gb, 0.0);
+
+        old["schema_version"] = serde_json::json!(SPECS_SCHEMA_VERSION + 1);
+        assert!(
+            SystemSpecs::from_profile_json(&old.to_string())
+                .unwrap_err()
+                .contains("newer")
+        );
+        assert!(SystemSpecs::from_profile_json("{\"specs\": {}}").is_err());
+    }
+
+    #[test]
+    fn test_ram_and_cores_override_describe_hypothetical_machine() {
+        let specs = make_specs_with_gpu();
+        assert!(!specs.hypothetical);
+        let specs = specs.with_ram_override(64.0).with_cpu_cores_override(16);
+        assert!(specs.hypothetical);
+        assert_eq!(specs.total_ram_gb, 64.0);
+        // 8 GB was in use on the 32 GB machine and still is
+        assert_eq!(specs.available_ram_gb, 56.0);
+        assert_eq!(specs.total_cpu_cores, 16);
+        assert_eq!(specs.gpu_vram_gb, Some(8.0), "discrete VRAM is untouched");
+
+        let mut mac = make_specs_no_gpu().with_gpu_memory_override(16.0);
+        mac.unified_memory = true;
+        mac.backend = super::GpuBackend::Metal;
+        mac.gpus[0].unified_memory = true;
+        let mac = mac.with_ram_override(36.0);
+        assert_eq!(mac.gpu_vram_gb, Some(24.0));
+        assert_eq!(mac.gpu_wired_limit_gb, Some(24.0));
+    }
+
     #[test]
     fn test_gpu_override_creates_synthetic_gpu_when_none() {
         let specs = make_specs_no_gpu().with_gpu_memory_override(24.0);
@@ -2466,6 +4336,122 @@ GPU id = 1 (NVIDIA GeForce RTX 4090)
         assert_eq!(specs.gpus[0].name, "NVIDIA RTX 3070");
     }
 
+    #[test]
+    fn test_ram_reserve_subtracts_from_available_only() {
+        let specs = make_specs_with_gpu().with_ram_reserve(4.0);
+        assert_eq!(specs.available_ram_gb, 20.0);
+        assert_eq!(specs.total_ram_gb, 32.0);
+        assert_eq!(specs.reserved_ram_gb, 4.0);
+        // Discrete VRAM is a separate pool.
+        assert_eq!(specs.gpu_vram_gb, Some(8.0));
+
+        let specs = make_specs_no_gpu().with_ram_reserve(100.0);
+        assert_eq!(specs.available_ram_gb, 0.0);
+    }
+
+    #[test]
+    fn test_memory_headroom_subtracts_from_ram_and_vram() {
+        let specs = make_specs_with_gpu().with_memory_headroom(1.5);
+        assert_eq!(specs.available_ram_gb, 22.5);
+        assert_eq!(specs.headroom_gb, 1.5);
+        assert_eq!(specs.reserved_ram_gb, 0.0);
+        assert_eq!(specs.gpu_vram_gb, Some(6.5));
+        assert_eq!(specs.total_gpu_vram_gb, Some(6.5));
+        assert_eq!(specs.gpus[0].vram_gb, Some(6.5));
+
+        let specs = make_specs_no_gpu().with_memory_headroom(100.0);
+        assert_eq!(specs.available_ram_gb, 0.0);
+        assert_eq!(specs.gpu_vram_gb, None);
+
+        let mut specs = make_specs_no_gpu().with_memory_headroom(1.0e6);
+        specs.refresh(&crate::providers::llamacpp_models_dir());
+        assert_eq!(specs.available_ram_gb, 0.0);
+    }
+
+    #[test]
+    fn test_refresh_keeps_limit_and_reserve_applied() {
+        let mut specs = make_specs_no_gpu().with_process_mem_limit(0.5);
+        specs.refresh(&crate::providers::llamacpp_models_dir());
+        assert!(specs.available_ram_gb <= 0.5);
+
+        let mut specs = make_specs_no_gpu().with_ram_reserve(1.0e6);
+        specs.refresh(&crate::providers::llamacpp_models_dir());
+        assert_eq!(specs.available_ram_gb, 0.0);
+        assert_eq!(specs.total_ram_gb, 32.0);
+    }
+
+    #[test]
+    fn test_available_ram_clamped_to_total() {
+        assert_eq!(SystemSpecs::clamp_available_ram(40.0, 32.0), 32.0);
+        assert_eq!(SystemSpecs::clamp_available_ram(24.0, 32.0), 24.0);
+        // Unknown total leaves the figure alone.
+        assert_eq!(SystemSpecs::clamp_available_ram(8.0, 0.0), 8.0);
+    }
+
+    #[test]
+    fn test_implausible_vram_rejected() {
+        assert!(super::is_plausible_vram_gb(24.0));
+        assert!(super::is_plausible_vram_gb(0.0));
+        assert!(!super::is_plausible_vram_gb(-4.0));
+        assert!(!super::is_plausible_vram_gb(4096.0));
+        assert!(!super::is_plausible_vram_gb(f64::NAN));
+    }
+
+    #[test]
+    fn test_tightest_mem_limit_bytes_skips_unlimited() {
+        let unlimited = u64::MAX;
+        assert_eq!(
+            super::tightest_mem_limit_bytes([unlimited, 8_589_934_592], unlimited),
+            Some(8_589_934_592)
+        );
+        assert_eq!(
+            super::tightest_mem_limit_bytes([17_179_869_184, 8_589_934_592], unlimited),
+            Some(8_589_934_592)
+        );
+        assert_eq!(
+            super::tightest_mem_limit_bytes([unlimited, unlimited], unlimited),
+            None
+        );
+    }
+
+    #[test]
+    fn test_process_mem_limit_clamps_available_ram() {
+        let specs = make_specs_with_gpu().with_process_mem_limit(6.0);
+        assert_eq!(specs.available_ram_gb, 6.0);
+        assert_eq!(specs.process_mem_limit_gb, Some(6.0));
+        assert_eq!(specs.total_ram_gb, 32.0);
+        assert_eq!(specs.gpu_vram_gb, Some(8.0));
+
+        // A limit above what's available changes nothing.
+        let specs = make_specs_no_gpu().with_process_mem_limit(64.0);
+        assert_eq!(specs.available_ram_gb, 24.0);
+        assert_eq!(specs.process_mem_limit_gb, None);
+    }
+
+    #[test]
+    fn test_per_gpu_vram_and_group_label() {
+        let single = make_specs_with_gpu();
+        assert_eq!(single.per_gpu_vram_gb(), vec![8.0]);
+        assert_eq!(single.gpu_group_label(super::MemoryUnits::Binary), None);
+
+        let mut multi = make_specs_with_gpu();
+        multi.gpus[0].count = 2;
+        multi.gpu_count = 2;
+        multi.total_gpu_vram_gb = Some(16.0);
+        multi.gpus.push(super::GpuInfo {
+            name: "NVIDIA T400".to_string(),
+            vram_gb: Some(2.0),
+            backend: super::GpuBackend::Cuda,
+            count: 1,
+            unified_memory: false,
+        });
+        assert_eq!(multi.per_gpu_vram_gb(), vec![8.0, 8.0, 2.0]);
+        assert_eq!(
+            multi.gpu_group_label(super::MemoryUnits::Binary).as_deref(),
+            Some("2x NVIDIA RTX 3070 (16 GiB total)")
+        );
+    }
+
     #[test]
     fn test_gpu_override_multi_gpu_scales_total() {
         let mut specs = make_specs_with_gpu();
@@ -2475,6 +4461,52 @@ GPU id = 1 (NVIDIA GeForce RTX 4090)
         assert_eq!(specs.total_gpu_vram_gb, Some(48.0));
     }
 
+    #[test]
+    fn test_measure_copy_bandwidth_is_positive() {
+        let gbps = super::measure_copy_bandwidth(1 << 20, 2, std::time::Duration::from_millis(20));
+        assert!(gbps > 0.0 && gbps.is_finite(), "{gbps}");
+    }
+
+    // ── Intel Arc classification ─────────────────────────────────────
+
+    #[test]
+    fn test_classify_intel_arc_line() {
+        use super::{IntelGpu, classify_intel_arc_line};
+        assert_eq!(
+            classify_intel_arc_line(
+                "0000:03:00.0 VGA compatible controller [0300]: Intel Corporation DG2 [Arc A770] [8086:56a0] (rev 08)"
+            ),
+            Some(IntelGpu::Discrete(None))
+        );
+        assert_eq!(
+            classify_intel_arc_line(
+                "0000:03:00.0 VGA compatible controller [0300]: Intel Corporation Battlemage G21 [Arc B580] [8086:e20b]"
+            ),
+            Some(IntelGpu::Discrete(None))
+        );
+        assert_eq!(
+            classify_intel_arc_line(
+                "0000:00:02.0 VGA compatible controller [0300]: Intel Corporation Meteor Lake-P [Intel Arc Graphics] [8086:7d55] (rev 08)"
+            ),
+            Some(IntelGpu::Integrated(None))
+        );
+        assert_eq!(
+            classify_intel_arc_line(
+                "0000:00:02.0 VGA compatible controller [0300]: Intel Corporation UHD Graphics 770 [8086:4680]"
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn test_intel_shared_memory_defaults_to_half_of_ram() {
+        use super::intel_shared_memory_gb;
+        assert_eq!(intel_shared_memory_gb(None, 32.0), 16.0);
+        assert_eq!(intel_shared_memory_gb(Some(0.0), 32.0), 16.0);
+        assert_eq!(intel_shared_memory_gb(Some(24.0), 32.0), 24.0);
+        assert_eq!(intel_shared_memory_gb(Some(64.0), 32.0), 32.0);
+    }
+
     // ── is_amd_unified_memory_apu ────────────────────────────────────
 
     #[test]
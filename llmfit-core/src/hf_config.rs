@@ -0,0 +1,541 @@
+//! Exact model sizing from a Hugging Face `config.json`.
+//!
+//! The catalog only knows approximate parameter counts and a generic KV-cache
+//! heuristic. For a checkpoint on disk we can do better: the architecture
+//! fields give the exact parameter count and per-token KV-cache size.
+
+use std::path::Path;
+
+use serde_json::Value;
+
+use crate::fit::{DEFAULT_KV_BITS, kv_cache_gb};
+use crate::models::{LlmModel, ModelFormat, QuantTable};
+
+/// Runtime overhead (CUDA/Metal context, buffers), matching
+/// `LlmModel::estimate_memory_gb`.
+const RUNTIME_OVERHEAD_GB: f64 = 0.5;
+
+/// Architectures whose MLP is a plain up/down projection rather than a
+/// gated (SwiGLU/GeGLU) block with three matrices.
+const NON_GATED_MODEL_TYPES: &[&str] = &[
+    "gpt2",
+    "gpt_neox",
+    "gptj",
+    "gpt_bigcode",
+    "opt",
+    "bloom",
+    "falcon",
+    "phi",
+    "starcoder2",
+    "mpt",
+];
+
+/// Adam keeps two fp32 moments per parameter.
+const ADAM_STATE_BYTES: f64 = 2.0 * 4.0;
+
+/// Activation bytes saved per token per layer, in units of `hidden_size`,
+/// for a 16-bit transformer layer with fused attention (Korthikanti et al.,
+/// "Reducing Activation Recomputation in Large Transformer Models").
+const ACTIVATION_BYTES_PER_HIDDEN: f64 = 34.0;
+
+/// Batch shape and recomputation choice for a full fine-tuning run.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TrainingSetup {
+    pub batch_size: u32,
+    pub seq_len: u32,
+    /// Keep only each layer's input and recompute the rest during the
+    /// backward pass: far less activation memory for ~1/3 more compute.
+    pub gradient_checkpointing: bool,
+}
+
+/// Full fine-tuning memory with Adam, split by term, in GB.
+#[derive(Debug, Clone, Copy, PartialEq, serde::Serialize)]
+pub struct TrainingMemory {
+    pub weights_gb: f64,
+    pub gradients_gb: f64,
+    pub optimizer_gb: f64,
+    pub activations_gb: f64,
+    pub overhead_gb: f64,
+}
+
+impl TrainingMemory {
+    pub fn total_gb(&self) -> f64 {
+        self.weights_gb
+            + self.gradients_gb
+            + self.optimizer_gb
+            + self.activations_gb
+            + self.overhead_gb
+    }
+}
+
+/// Architecture fields read from a `config.json`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HfConfig {
+    pub name: Option<String>,
+    pub model_type: String,
+    pub hidden_size: u64,
+    pub num_hidden_layers: u64,
+    pub num_attention_heads: u64,
+    pub num_key_value_heads: u64,
+    pub head_dim: u64,
+    pub intermediate_size: u64,
+    pub vocab_size: u64,
+    pub max_position_embeddings: u32,
+    pub tie_word_embeddings: bool,
+    /// Routed experts per MoE layer (0 for dense models).
+    pub num_experts: u64,
+    pub num_experts_per_tok: u64,
+    pub num_shared_experts: u64,
+    /// Per-expert MLP width, when it differs from `intermediate_size`.
+    pub moe_intermediate_size: u64,
+    /// Quantization implied by `torch_dtype` / `quantization_config`.
+    pub dtype: Option<String>,
+}
+
+/// First integer found under any of `keys`.
+fn get_u64(v: &Value, keys: &[&str]) -> Option<u64> {
+    keys.iter().find_map(|k| v.get(*k).and_then(Value::as_u64))
+}
+
+impl HfConfig {
+    /// Parse a `config.json` document. Multimodal configs that nest the
+    /// language model under `text_config` / `llm_config` are unwrapped.
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        let root: Value =
+            serde_json::from_str(json).map_err(|e| format!("Invalid config.json: {}", e))?;
+        let text = ["text_config", "llm_config", "language_config"]
+            .iter()
+            .find_map(|k| root.get(*k).filter(|v| v.is_object()))
+            .filter(|_| get_u64(&root, &["hidden_size", "n_embd", "d_model"]).is_none())
+            .unwrap_or(&root);
+
+        let required = |keys: &[&str]| {
+            get_u64(text, keys).ok_or_else(|| format!("config.json is missing '{}'", keys[0]))
+        };
+        let hidden_size = required(&["hidden_size", "n_embd", "d_model"])?;
+        let num_hidden_layers =
+            required(&["num_hidden_layers", "n_layer", "n_layers", "num_layers"])?;
+        let num_attention_heads = required(&["num_attention_heads", "n_head", "n_heads"])?;
+        let vocab_size = get_u64(text, &["vocab_size", "padded_vocab_size"])
+            .or_else(|| get_u64(&root, &["vocab_size"]))
+            .ok_or("config.json is missing 'vocab_size'")?;
+        if num_attention_heads == 0 {
+            return Err("config.json has num_attention_heads = 0".to_string());
+        }
+
+        let model_type = text
+            .get("model_type")
+            .or_else(|| root.get("model_type"))
+            .and_then(Value::as_str)
+            .unwrap_or("unknown")
+            .to_string();
+        let num_key_value_heads = get_u64(
+            text,
+            &[
+                "num_key_value_heads",
+                "num_kv_heads",
+                "n_head_kv",
+                "multi_query_group_num",
+            ],
+        )
+        .or_else(|| {
+            text.get("multi_query")
+                .and_then(Value::as_bool)
+                .filter(|&mq| mq)
+                .map(|_| 1)
+        })
+        .unwrap_or(num_attention_heads);
+        let head_dim = get_u64(text, &["head_dim", "kv_channels"])
+            .unwrap_or(hidden_size / num_attention_heads);
+        let intermediate_size = get_u64(
+            text,
+            &["intermediate_size", "ffn_hidden_size", "n_inner", "ffn_dim"],
+        )
+        .unwrap_or(4 * hidden_size);
+        let max_position_embeddings = get_u64(
+            text,
+            &[
+                "max_position_embeddings",
+                "n_positions",
+                "max_sequence_length",
+                "seq_length",
+                "max_seq_len",
+            ],
+        )
+        .unwrap_or(4096)
+        .min(u32::MAX as u64) as u32;
+        let tie_word_embeddings = text
+            .get("tie_word_embeddings")
+            .or_else(|| root.get("tie_word_embeddings"))
+            .and_then(Value::as_bool)
+            .unwrap_or(true);
+
+        let num_experts = get_u64(
+            text,
+            &[
+                "num_local_experts",
+                "num_experts",
+                "n_routed_experts",
+                "moe_num_experts",
+            ],
+        )
+        .unwrap_or(0);
+        let num_experts_per_tok = get_u64(
+            text,
+            &[
+                "num_experts_per_tok",
+                "num_experts_per_token",
+                "moe_top_k",
+                "top_k",
+            ],
+        )
+        .unwrap_or(if num_experts > 0 { 2 } else { 0 });
+        let num_shared_experts =
+            get_u64(text, &["n_shared_experts", "num_shared_experts"]).unwrap_or(0);
+        let moe_intermediate_size =
+            get_u64(text, &["moe_intermediate_size", "expert_intermediate_size"])
+                .unwrap_or(intermediate_size);
+
+        let name = root
+            .get("_name_or_path")
+            .and_then(Value::as_str)
+            .filter(|s| !s.is_empty())
+            .map(str::to_string);
+
+        Ok(HfConfig {
+            name,
+            model_type,
+            hidden_size,
+            num_hidden_layers,
+            num_attention_heads,
+            num_key_value_heads,
+            head_dim,
+            intermediate_size,
+            vocab_size,
+            max_position_embeddings,
+            tie_word_embeddings,
+            num_experts,
+            num_experts_per_tok,
+            num_shared_experts,
+            moe_intermediate_size,
+            dtype: detect_dtype(&root).or_else(|| detect_dtype(text)),
+        })
+    }
+
+    /// Read a `config.json` file, or `<dir>/config.json` for a checkout.
+    /// Falls back to the directory name when the config has no `_name_or_path`.
+    pub fn from_path(path: &Path) -> Result<Self, String> {
+        let file = if path.is_dir() {
+            path.join("config.json")
+        } else {
+            path.to_path_buf()
+        };
+        let json = std::fs::read_to_string(&file)
+            .map_err(|e| format!("Cannot read {}: {}", file.display(), e))?;
+        let mut config = Self::from_json(&json)?;
+        if config.name.is_none() {
+            config.name = file
+                .parent()
+                .and_then(Path::file_name)
+                .and_then(|n| n.to_str())
+                .map(str::to_string);
+        }
+        Ok(config)
+    }
+
+    fn is_gated(&self) -> bool {
+        !NON_GATED_MODEL_TYPES.contains(&self.model_type.as_str())
+    }
+
+    fn mlp_params(&self, width: u64) -> u64 {
+        let matrices = if self.is_gated() { 3 } else { 2 };
+        matrices * self.hidden_size * width
+    }
+
+    fn attention_params(&self) -> u64 {
+        let q_out = self.num_attention_heads * self.head_dim;
+        let kv_out = self.num_key_value_heads * self.head_dim;
+        // q, k, v projections in; o projection back to hidden
+        self.hidden_size * (q_out + 2 * kv_out) + q_out * self.hidden_size
+    }
+
+    fn embedding_params(&self) -> u64 {
+        let embed = self.vocab_size * self.hidden_size;
+        if self.tie_word_embeddings {
+            embed
+        } else {
+            2 * embed
+        }
+    }
+
+    /// Per-layer MLP parameters, split into (total, active per token).
+    fn layer_mlp_params(&self) -> (u64, u64) {
+        if self.num_experts == 0 {
+            let p = self.mlp_params(self.intermediate_size);
+            return (p, p);
+        }
+        let expert = self.mlp_params(self.moe_intermediate_size);
+        let shared = self.num_shared_experts * expert;
+        let router = self.hidden_size * self.num_experts;
+        (
+            self.num_experts * expert + shared + router,
+            self.num_experts_per_tok * expert + shared + router,
+        )
+    }
+
+    /// Total parameter count (weights only; norms and biases included approximately).
+    pub fn parameter_count(&self) -> u64 {
+        let (mlp, _) = self.layer_mlp_params();
+        let per_layer = self.attention_params() + mlp + 2 * self.hidden_size;
+        self.num_hidden_layers * per_layer + self.embedding_params() + self.hidden_size
+    }
+
+    /// Parameters touched per token (differs from the total only for MoE).
+    pub fn active_parameter_count(&self) -> u64 {
+        let (_, mlp) = self.layer_mlp_params();
+        let per_layer = self.attention_params() + mlp + 2 * self.hidden_size;
+        self.num_hidden_layers * per_layer + self.embedding_params() + self.hidden_size
+    }
+
+    /// Weight memory in GB at the given quantization.
+    pub fn weights_gb(&self, quant: &str, quants: &QuantTable) -> f64 {
+        self.parameter_count() as f64 / 1e9 * quants.bpp(quant)
+    }
+
+    /// Width of the keys (and values) cached per layer and token.
+    pub fn kv_hidden_size(&self) -> u64 {
+        self.num_key_value_heads * self.head_dim
+    }
+
+    /// fp16 KV-cache memory in GB for `ctx` tokens.
+    pub fn kv_cache_gb(&self, ctx: u32) -> f64 {
+        kv_cache_gb(
+            ctx,
+            self.num_hidden_layers as u32,
+            self.kv_hidden_size() as u32,
+            DEFAULT_KV_BITS,
+        )
+    }
+
+    /// Weights + KV cache + runtime overhead, in GB.
+    pub fn total_memory_gb(&self, quant: &str, ctx: u32, quants: &QuantTable) -> f64 {
+        self.weights_gb(quant, quants) + self.kv_cache_gb(ctx) + RUNTIME_OVERHEAD_GB
+    }
+
+    /// Memory to fully fine-tune this model with Adam. Weights and gradients
+    /// are held at `quant` but never below 16 bits, since quantized weights
+    /// can't be trained directly. Activations grow with batch × sequence ×
+    /// hidden × layers, plus fp32 logits over the vocabulary for the loss.
+    pub fn training_memory(
+        &self,
+        quant: &str,
+        setup: TrainingSetup,
+        quants: &QuantTable,
+    ) -> TrainingMemory {
+        let params = self.parameter_count() as f64;
+        let weight_bytes = quants.bpp(quant).max(2.0);
+        let tokens = setup.batch_size as f64 * setup.seq_len as f64;
+        let hidden = self.hidden_size as f64;
+
+        let full_layer = tokens * hidden * ACTIVATION_BYTES_PER_HIDDEN;
+        let layers = if setup.gradient_checkpointing {
+            // Every layer's 16-bit input, plus one layer rebuilt at a time
+            self.num_hidden_layers as f64 * tokens * hidden * 2.0 + full_layer
+        } else {
+            self.num_hidden_layers as f64 * full_layer
+        };
+        let logits = tokens * self.vocab_size as f64 * 4.0;
+
+        TrainingMemory {
+            weights_gb: params * weight_bytes / 1e9,
+            gradients_gb: params * weight_bytes / 1e9,
+            optimizer_gb: params * ADAM_STATE_BYTES / 1e9,
+            activations_gb: (layers + logits) / 1e9,
+            overhead_gb: RUNTIME_OVERHEAD_GB,
+        }
+    }
+
+    /// Build a catalog-style entry sized exactly for `quant` at `ctx` tokens.
+    pub fn to_model(&self, quant: &str, ctx: u32, quants: &QuantTable) -> LlmModel {
+        let params = self.parameter_count();
+        let total = self.total_memory_gb(quant, ctx, quants);
+        let is_moe = self.num_experts > 0;
+        let format = match quant {
+            q if q.starts_with("AWQ") => ModelFormat::Awq,
+            q if q.starts_with("GPTQ") => ModelFormat::Gptq,
+            q if q.starts_with("mlx") => ModelFormat::Mlx,
+            "F32" | "F16" | "BF16" => ModelFormat::Safetensors,
+            _ => ModelFormat::Gguf,
+        };
+        let params_b = params as f64 / 1e9;
+        LlmModel {
+            name: self.name.clone().unwrap_or_else(|| self.model_type.clone()),
+            provider: "local".to_string(),
+            parameter_count: if params_b >= 1.0 {
+                format!("{:.1}B", params_b)
+            } else {
+                format!("{:.0}M", params_b * 1000.0)
+            },
+            parameters_raw: Some(params),
+            min_ram_gb: total,
+            recommended_ram_gb: total * 1.2,
+            min_vram_gb: Some(total),
+            quantization: quant.to_string(),
+            context_length: ctx,
+            use_case: "general".to_string(),
+            is_moe,
+            num_experts: is_moe.then_some(self.num_experts as u32),
+            active_experts: is_moe.then_some(self.num_experts_per_tok as u32),
+            active_parameters: is_moe.then(|| self.active_parameter_count()),
+            release_date: None,
+            gguf_sources: vec![],
+            capabilities: vec![],
+            tags: vec![],
+            format,
+            num_layers: u32::try_from(self.num_hidden_layers).ok(),
+            kv_hidden_size: u32::try_from(self.kv_hidden_size()).ok(),
+            hf_downloads: None,
+            gguf_file_sizes: Default::default(),
+        }
+    }
+}
+
+/// Map `torch_dtype` (or `dtype`) and `quantization_config` onto llmfit's
+/// quantization names.
+fn detect_dtype(v: &Value) -> Option<String> {
+    if let Some(qc) = v.get("quantization_config") {
+        let method = qc.get("quant_method").and_then(Value::as_str).unwrap_or("");
+        let bits = qc.get("bits").and_then(Value::as_u64).unwrap_or(4);
+        match (method, bits) {
+            ("awq", 8) => return Some("AWQ-8bit".to_string()),
+            ("awq", _) => return Some("AWQ-4bit".to_string()),
+            ("gptq", 8) => return Some("GPTQ-Int8".to_string()),
+            ("gptq", _) => return Some("GPTQ-Int4".to_string()),
+            _ => {}
+        }
+    }
+    let dtype = v
+        .get("torch_dtype")
+        .or_else(|| v.get("dtype"))
+        .and_then(Value::as_str)?;
+    match dtype {
+        "float32" => Some("F32".to_string()),
+        "float16" => Some("F16".to_string()),
+        "bfloat16" => Some("BF16".to_string()),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LLAMA3_8B: &str = r#"{
+        "_name_or_path": "meta-llama/Meta-Llama-3-8B",
+        "model_type": "llama",
+        "hidden_size": 4096,
+        "intermediate_size": 14336,
+        "num_attention_heads": 32,
+        "num_hidden_layers": 32,
+        "num_key_value_heads": 8,
+        "vocab_size": 128256,
+        "max_position_embeddings": 8192,
+        "tie_word_embeddings": false,
+        "torch_dtype": "bfloat16"
+    }"#;
+
+    #[test]
+    fn test_llama3_8b_parameter_count_and_kv() {
+        let cfg = HfConfig::from_json(LLAMA3_8B).unwrap();
+        let params_b = cfg.parameter_count() as f64 / 1e9;
+        assert!((params_b - 8.03).abs() < 0.02, "got {params_b}");
+        assert_eq!(cfg.dtype.as_deref(), Some("BF16"));
+        assert_eq!(cfg.head_dim, 128);
+        // 2 (k,v) * 32 layers * 8 heads * 128 dims * 8192 tokens * 2 bytes
+        assert!((cfg.kv_cache_gb(8192) - 1.074).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_gpt2_style_keys_and_nested_text_config() {
+        let gpt2 = r#"{"model_type":"gpt2","n_embd":768,"n_layer":12,"n_head":12,
+            "n_positions":1024,"vocab_size":50257}"#;
+        let cfg = HfConfig::from_json(gpt2).unwrap();
+        let params_m = cfg.parameter_count() as f64 / 1e6;
+        // GPT-2 small is ~124M (our count omits biases and position embeddings)
+        assert!((118.0..126.0).contains(&params_m), "got {params_m}");
+        assert_eq!(cfg.num_key_value_heads, 12);
+        assert_eq!(cfg.max_position_embeddings, 1024);
+
+        let nested = r#"{"model_type":"llava","text_config":{"model_type":"llama",
+            "hidden_size":4096,"num_hidden_layers":32,"num_attention_heads":32,
+            "vocab_size":32064}}"#;
+        assert_eq!(HfConfig::from_json(nested).unwrap().hidden_size, 4096);
+    }
+
+    #[test]
+    fn test_moe_active_parameters() {
+        let mixtral = r#"{"model_type":"mixtral","hidden_size":4096,"intermediate_size":14336,
+            "num_attention_heads":32,"num_hidden_layers":32,"num_key_value_heads":8,
+            "vocab_size":32000,"num_local_experts":8,"num_experts_per_tok":2,
+            "tie_word_embeddings":false}"#;
+        let cfg = HfConfig::from_json(mixtral).unwrap();
+        let total = cfg.parameter_count() as f64 / 1e9;
+        let active = cfg.active_parameter_count() as f64 / 1e9;
+        assert!((total - 46.7).abs() < 0.3, "got {total}");
+        assert!((active - 12.9).abs() < 0.3, "got {active}");
+        let model = cfg.to_model("Q4_K_M", 4096, QuantTable::builtin());
+        assert!(model.is_moe);
+        assert_eq!(model.active_experts, Some(2));
+    }
+
+    #[test]
+    fn test_training_memory_terms() {
+        let cfg = HfConfig::from_json(LLAMA3_8B).unwrap();
+        let setup = TrainingSetup {
+            batch_size: 1,
+            seq_len: 2048,
+            gradient_checkpointing: false,
+        };
+        let full = cfg.training_memory("BF16", setup, QuantTable::builtin());
+        // 8.03B params: 2 + 2 + 8 bytes each
+        assert!((full.weights_gb - 16.06).abs() < 0.1);
+        assert_eq!(full.gradients_gb, full.weights_gb);
+        assert!((full.optimizer_gb - 64.24).abs() < 0.3);
+        // 32 layers * 2048 tokens * 4096 * 34 B + fp32 logits
+        assert!((full.activations_gb - 10.18).abs() < 0.05);
+
+        // Quantized weights still train at 16 bits
+        assert_eq!(
+            cfg.training_memory("Q4_K_M", setup, QuantTable::builtin())
+                .weights_gb,
+            full.weights_gb
+        );
+
+        // Activations scale with batch, and checkpointing cuts them sharply
+        let doubled = cfg.training_memory(
+            "BF16",
+            TrainingSetup {
+                batch_size: 2,
+                ..setup
+            },
+            QuantTable::builtin(),
+        );
+        assert!((doubled.activations_gb - 2.0 * full.activations_gb).abs() < 1e-9);
+        let ckpt = cfg.training_memory(
+            "BF16",
+            TrainingSetup {
+                gradient_checkpointing: true,
+                ..setup
+            },
+            QuantTable::builtin(),
+        );
+        assert!(ckpt.activations_gb < full.activations_gb / 4.0);
+        assert!(ckpt.total_gb() < full.total_gb());
+    }
+
+    #[test]
+    fn test_missing_fields_error() {
+        let err = HfConfig::from_json(r#"{"model_type":"llama"}"#).unwrap_err();
+        assert!(err.contains("hidden_size"));
+    }
+}
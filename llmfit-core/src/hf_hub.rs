@@ -0,0 +1,387 @@
+//! Fill gaps in catalog entries from the Hugging Face Hub API.
+//!
+//! Only runs when asked (`--enrich`); llmfit never touches the network for
+//! this otherwise. Responses are cached in ~/.cache/llmfit/hf_cache/ for a
+//! week, so repeated runs cost nothing, and a batch stops as soon as the Hub
+//! answers 429. Set `HF_TOKEN` for the higher authenticated rate limit.
+
+use crate::models::{GgufSource, LlmModel};
+use rayon::ThreadPoolBuilder;
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+const API_BASE: &str = "https://huggingface.co/api/models";
+const FETCH_TIMEOUT: Duration = Duration::from_secs(10);
+/// Cached responses older than this are fetched again.
+const CACHE_TTL: Duration = Duration::from_secs(7 * 24 * 3600);
+/// Requests in flight at once during [`enrich_many`]; kept low to stay well
+/// inside the Hub's anonymous rate limit.
+const MAX_CONCURRENT: usize = 4;
+
+/// Why a Hub lookup failed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HfError {
+    /// The name isn't an `owner/repo` id, or the Hub has no such repo.
+    NotFound { repo: String },
+    /// HTTP 429: too many requests. Later lookups in a batch are skipped.
+    RateLimited,
+    /// The Hub couldn't be reached or answered with an error.
+    Network { repo: String, message: String },
+    /// The response wasn't the JSON this module expects.
+    Invalid { repo: String, message: String },
+}
+
+impl std::fmt::Display for HfError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            HfError::NotFound { repo } => write!(f, "{} not found on Hugging Face", repo),
+            HfError::RateLimited => write!(f, "Hugging Face rate limit reached"),
+            HfError::Network { repo, message } => {
+                write!(f, "couldn't query Hugging Face for {}: {}", repo, message)
+            }
+            HfError::Invalid { repo, message } => {
+                write!(
+                    f,
+                    "unexpected Hugging Face response for {}: {}",
+                    repo, message
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for HfError {}
+
+/// What the Hub knows about a model repo.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HfModelInfo {
+    pub repo: String,
+    /// From the safetensors or GGUF metadata the Hub indexes.
+    pub parameters: Option<u64>,
+    pub context_length: Option<u32>,
+    pub downloads: u64,
+    /// Creation date, `YYYY-MM-DD`.
+    pub created: Option<String>,
+    /// GGUF files in the repo as (quant, size in bytes), e.g. ("Q4_K_M", ...).
+    pub gguf_files: Vec<(String, u64)>,
+}
+
+impl HfModelInfo {
+    /// Parse the body of `GET /api/models/{repo}?blobs=true`.
+    pub fn from_api_json(repo: &str, json: &serde_json::Value) -> HfModelInfo {
+        let parameters = json
+            .pointer("/safetensors/total")
+            .or_else(|| json.pointer("/gguf/total"))
+            .and_then(|v| v.as_u64())
+            .filter(|&p| p > 0);
+        let context_length = json
+            .pointer("/gguf/context_length")
+            .and_then(|v| v.as_u64())
+            .and_then(|c| u32::try_from(c).ok())
+            .filter(|&c| c > 0);
+        let created = json
+            .get("createdAt")
+            .and_then(|v| v.as_str())
+            .and_then(|s| s.get(..10))
+            .map(str::to_string);
+        let gguf_files = json
+            .get("siblings")
+            .and_then(|v| v.as_array())
+            .map(|siblings| {
+                siblings
+                    .iter()
+                    .filter_map(|s| {
+                        let file = s.get("rfilename")?.as_str()?;
+                        if !file.to_lowercase().ends_with(".gguf") {
+                            return None;
+                        }
+                        let size = s.get("size").and_then(|v| v.as_u64()).unwrap_or(0);
+                        Some((quant_in_filename(file)?, size))
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+        HfModelInfo {
+            repo: repo.to_string(),
+            parameters,
+            context_length,
+            downloads: json.get("downloads").and_then(|v| v.as_u64()).unwrap_or(0),
+            created,
+            gguf_files,
+        }
+    }
+
+    /// Fill the fields `model` is missing. Anything the catalog already
+    /// states is left alone.
+    pub fn apply_to(&self, model: &mut LlmModel) {
+        if model.parameters_raw.is_none()
+            && let Some(params) = self.parameters
+        {
+            model.parameters_raw = Some(params);
+            if model.parameter_count.trim().is_empty() || model.parameter_count == "?" {
+                let params_b = params as f64 / 1e9;
+                model.parameter_count = if params_b >= 1.0 {
+                    format!("{:.1}B", params_b)
+                } else {
+                    format!("{:.0}M", params_b * 1000.0)
+                };
+            }
+        }
+        if model.context_length == 0
+            && let Some(ctx) = self.context_length
+        {
+            model.context_length = ctx;
+        }
+        if model.release_date.is_none() {
+            model.release_date = self.created.clone();
+        }
+        if model.hf_downloads.is_none() && self.downloads > 0 {
+            model.hf_downloads = Some(self.downloads);
+        }
+        for (quant, size) in &self.gguf_files {
+            if *size > 0 {
+                model.gguf_file_sizes.entry(quant.clone()).or_insert(*size);
+            }
+        }
+        if !self.gguf_files.is_empty() && !model.gguf_sources.iter().any(|s| s.repo == self.repo) {
+            model.gguf_sources.push(GgufSource {
+                repo: self.repo.clone(),
+                provider: self.repo.split('/').next().unwrap_or_default().to_string(),
+            });
+        }
+    }
+}
+
+/// GGUF quant named in a file name, e.g. "Q4_K_M" from
+/// "llama-3.1-8b-instruct-q4_k_m.gguf".
+fn quant_in_filename(file: &str) -> Option<String> {
+    const QUANTS: &[&str] = &[
+        "Q4_K_M", "Q4_K_S", "Q5_K_M", "Q5_K_S", "Q3_K_M", "Q3_K_S", "Q3_K_L", "Q6_K", "Q8_0",
+        "Q4_0", "Q5_0", "Q2_K", "IQ4_XS", "IQ3_M", "IQ2_M", "BF16", "F16", "F32",
+    ];
+    let upper = file.to_uppercase();
+    QUANTS
+        .iter()
+        .find(|q| upper.contains(*q))
+        .map(|q| q.to_string())
+}
+
+/// Whether `model` has gaps the Hub could fill and a name that is a Hub repo.
+pub fn needs_enrichment(model: &LlmModel) -> bool {
+    let is_repo = model.provider != "local" && model.name.split('/').count() == 2;
+    is_repo
+        && (model.parameters_raw.is_none()
+            || model.release_date.is_none()
+            || model.context_length == 0)
+}
+
+/// Look `model` up on the Hub by its repo id and fill in missing fields.
+pub fn enrich_from_hf(model: &mut LlmModel) -> Result<(), HfError> {
+    let info = fetch_model_info(&model.name)?;
+    info.apply_to(model);
+    Ok(())
+}
+
+/// Repo metadata from the cache, or from the Hub when the cached copy is
+/// missing or older than a week.
+pub fn fetch_model_info(repo: &str) -> Result<HfModelInfo, HfError> {
+    if repo.split('/').count() != 2 {
+        return Err(HfError::NotFound {
+            repo: repo.to_string(),
+        });
+    }
+    let cache_path = cache_path(repo);
+    if let Some(info) = cache_path.as_ref().and_then(|p| read_cache(p)) {
+        return Ok(info);
+    }
+
+    let url = format!("{}/{}?blobs=true", API_BASE, repo);
+    let mut request = ureq::get(&url)
+        .config()
+        .timeout_global(Some(FETCH_TIMEOUT))
+        .build();
+    if let Ok(token) = std::env::var("HF_TOKEN") {
+        request = request.header("Authorization", &format!("Bearer {}", token));
+    }
+    let response = match request.call() {
+        Ok(response) => response,
+        Err(ureq::Error::StatusCode(429)) => return Err(HfError::RateLimited),
+        Err(ureq::Error::StatusCode(401 | 404)) => {
+            return Err(HfError::NotFound {
+                repo: repo.to_string(),
+            });
+        }
+        Err(err) => {
+            return Err(HfError::Network {
+                repo: repo.to_string(),
+                message: err.to_string(),
+            });
+        }
+    };
+    let json: serde_json::Value =
+        response
+            .into_body()
+            .read_json()
+            .map_err(|err| HfError::Invalid {
+                repo: repo.to_string(),
+                message: err.to_string(),
+            })?;
+    let info = HfModelInfo::from_api_json(repo, &json);
+    if let Some(path) = cache_path {
+        write_cache(&path, &info);
+    }
+    Ok(info)
+}
+
+/// Outcome of [`enrich_many`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct EnrichSummary {
+    /// Models that needed enrichment.
+    pub attempted: usize,
+    pub enriched: usize,
+    pub failed: usize,
+    /// The Hub returned 429 and the remaining lookups were skipped.
+    pub rate_limited: bool,
+}
+
+/// Enrich every model in `models` that [`needs_enrichment`], a few lookups
+/// at a time.
+pub fn enrich_many(models: &mut [LlmModel]) -> EnrichSummary {
+    let mut todo: Vec<&mut LlmModel> = models.iter_mut().filter(|m| needs_enrichment(m)).collect();
+    let attempted = todo.len();
+    if attempted == 0 {
+        return EnrichSummary::default();
+    }
+
+    let stop = AtomicBool::new(false);
+    let enrich = |model: &mut &mut LlmModel| -> Option<bool> {
+        if stop.load(Ordering::Relaxed) {
+            return None;
+        }
+        match enrich_from_hf(model) {
+            Ok(()) => Some(true),
+            Err(HfError::RateLimited) => {
+                stop.store(true, Ordering::Relaxed);
+                Some(false)
+            }
+            Err(err) => {
+                crate::activity::warn("hf", err.to_string());
+                Some(false)
+            }
+        }
+    };
+    // A dedicated pool, so the Hub never sees more than MAX_CONCURRENT
+    // requests however many cores the machine has.
+    let results: Vec<Option<bool>> =
+        match ThreadPoolBuilder::new().num_threads(MAX_CONCURRENT).build() {
+            Ok(pool) => pool.install(|| todo.par_iter_mut().map(enrich).collect()),
+            Err(_) => todo.iter_mut().map(enrich).collect(),
+        };
+
+    EnrichSummary {
+        attempted,
+        enriched: results.iter().filter(|r| **r == Some(true)).count(),
+        failed: results.iter().filter(|r| **r == Some(false)).count(),
+        rate_limited: stop.load(Ordering::Relaxed),
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct CacheEntry {
+    /// Seconds since the Unix epoch.
+    fetched_at: u64,
+    info: HfModelInfo,
+}
+
+/// ~/.cache/llmfit/hf_cache/{owner}__{repo}.json
+fn cache_path(repo: &str) -> Option<PathBuf> {
+    Some(
+        crate::paths::cache_dir()?
+            .join("hf_cache")
+            .join(format!("{}.json", repo.replace('/', "__"))),
+    )
+}
+
+fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+fn read_cache(path: &std::path::Path) -> Option<HfModelInfo> {
+    let text = std::fs::read_to_string(path).ok()?;
+    let entry: CacheEntry = serde_json::from_str(&text).ok()?;
+    (now_secs().saturating_sub(entry.fetched_at) < CACHE_TTL.as_secs()).then_some(entry.info)
+}
+
+fn write_cache(path: &std::path::Path, info: &HfModelInfo) {
+    if let Some(parent) = path.parent() {
+        let _ = std::fs::create_dir_all(parent);
+    }
+    let entry = CacheEntry {
+        fetched_at: now_secs(),
+        info: info.clone(),
+    };
+    if let Ok(text) = serde_json::to_string(&entry) {
+        let _ = std::fs::write(path, text);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_info_from_api_json_fills_only_missing_fields() {
+        let json = serde_json::json!({
+            "id": "acme/Widget-7B-GGUF",
+            "downloads": 1234,
+            "createdAt": "2024-07-18T08:56:00.000Z",
+            "gguf": { "total": 7_240_000_000u64, "context_length": 32768 },
+            "siblings": [
+                { "rfilename": "README.md", "size": 10 },
+                { "rfilename": "widget-7b.Q4_K_M.gguf", "size": 4_370_000_000u64 },
+                { "rfilename": "widget-7b-q8_0.gguf", "size": 7_700_000_000u64 }
+            ]
+        });
+        let info = HfModelInfo::from_api_json("acme/Widget-7B-GGUF", &json);
+        assert_eq!(info.parameters, Some(7_240_000_000));
+        assert_eq!(info.context_length, Some(32768));
+        assert_eq!(info.downloads, 1234);
+        assert_eq!(info.created.as_deref(), Some("2024-07-18"));
+        assert_eq!(
+            info.gguf_files,
+            vec![
+                ("Q4_K_M".to_string(), 4_370_000_000),
+                ("Q8_0".to_string(), 7_700_000_000)
+            ]
+        );
+
+        let mut model = crate::models::ModelDatabase::new().get_all_models()[0].clone();
+        model.name = "acme/Widget-7B-GGUF".to_string();
+        model.parameters_raw = None;
+        model.release_date = None;
+        model.context_length = 4096;
+        model.gguf_sources.clear();
+        model.hf_downloads = None;
+        assert!(needs_enrichment(&model));
+        info.apply_to(&mut model);
+        assert_eq!(model.parameters_raw, Some(7_240_000_000));
+        assert_eq!(model.release_date.as_deref(), Some("2024-07-18"));
+        assert_eq!(model.context_length, 4096, "catalog value is kept");
+        assert_eq!(model.gguf_sources[0].provider, "acme");
+        assert_eq!(model.hf_downloads, Some(1234));
+        assert_eq!(model.gguf_file_sizes.get("Q8_0"), Some(&7_700_000_000));
+        let quants = crate::models::QuantTable::builtin();
+        assert!((model.weights_file_gb("Q4_K_M", quants) - 4.37).abs() < 1e-9);
+        assert!(!needs_enrichment(&model));
+
+        model.provider = "local".to_string();
+        model.release_date = None;
+        assert!(!needs_enrichment(&model));
+    }
+}
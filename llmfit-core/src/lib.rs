@@ -1,15 +1,37 @@
+pub mod activity;
+pub mod catalog;
 pub mod fit;
+pub mod gguf;
 pub mod hardware;
+pub mod hf_config;
+pub mod hf_hub;
 pub mod models;
+pub mod paths;
 pub mod plan;
 pub mod providers;
 
-pub use fit::{FitLevel, InferenceRuntime, ModelFit, RunMode, ScoreComponents, SortColumn};
-pub use hardware::{GpuBackend, SystemSpecs};
-pub use models::{Capability, LlmModel, ModelDatabase, ModelFormat, UseCase};
+pub use catalog::{CatalogError, CatalogSource, ModelCatalog};
+pub use fit::{
+    BindingConstraint, FitExplanation, FitLevel, FitOptions, FitTarget, GpuPlacement,
+    InferenceRuntime, MemoryBreakdown, ModelFit, RunMode, ScoreComponents, SortColumn,
+    TensorParallelSplit,
+};
+pub use gguf::{GgufError, GgufInfo};
+pub use hardware::{
+    DetectContext, DetectError, GpuBackend, HardwareDetector, MemoryReading, SystemSpecs,
+    register_detector,
+};
+pub use hf_config::{HfConfig, TrainingMemory, TrainingSetup};
+pub use hf_hub::{HfError, enrich_from_hf};
+pub use models::{
+    Capability, CatalogSources, LlmModel, ModelDatabase, ModelFormat, QuantTable, UseCase,
+    parse_param_count,
+};
+pub use paths::config_dir;
 pub use plan::{
     HardwareEstimate, PathEstimate, PlanCurrentStatus, PlanEstimate, PlanRequest, PlanRunPath,
-    UpgradeDelta, estimate_model_plan, normalize_quant, resolve_model_selector,
+    UpgradeAdvice, UpgradeDelta, UpgradeOutcome, UpgradeScenario, advise_upgrades,
+    default_upgrade_scenarios, estimate_model_plan, normalize_quant, resolve_model_selector,
 };
 pub use providers::{
     LlamaCppProvider, LmStudioProvider, MlxProvider, ModelProvider, OllamaProvider,
@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 
 use serde::{Deserialize, Serialize};
 
@@ -6,27 +6,236 @@ use serde::{Deserialize, Serialize};
 /// Used for dynamic quantization selection: try the best that fits.
 pub const QUANT_HIERARCHY: &[&str] = &["Q8_0", "Q6_K", "Q5_K_M", "Q4_K_M", "Q3_K_M", "Q2_K"];
 
+/// GGUF quantizations a model can be viewed at in place of the one the
+/// catalog lists, most compressed first.
+pub const REQUANT_CYCLE: &[&str] = &["Q4_K_M", "Q5_K_M", "Q6_K", "Q8_0", "F16"];
+
 /// MLX-native quantization hierarchy (best quality to most compressed).
 pub const MLX_QUANT_HIERARCHY: &[&str] = &["mlx-8bit", "mlx-4bit"];
 
-/// Bytes per parameter for each quantization level.
-pub fn quant_bpp(quant: &str) -> f64 {
-    match quant {
-        "F32" => 4.0,
-        "F16" | "BF16" => 2.0,
-        "Q8_0" => 1.05,
-        "Q6_K" => 0.80,
-        "Q5_K_M" => 0.68,
-        "Q4_K_M" | "Q4_0" => 0.58,
-        "Q3_K_M" => 0.48,
-        "Q2_K" => 0.37,
-        "mlx-4bit" => 0.55,
-        "mlx-8bit" => 1.0,
-        "AWQ-4bit" => 0.5,
-        "AWQ-8bit" => 1.0,
-        "GPTQ-Int4" => 0.5,
-        "GPTQ-Int8" => 1.0,
-        _ => 0.58,
+/// Built-in bytes per weight for each quantization level, including block
+/// scales. User entries in a [`QuantTable`] take precedence.
+pub const DEFAULT_QUANT_BPP: &[(&str, f64)] = &[
+    ("F32", 4.0),
+    ("F16", 2.0),
+    ("BF16", 2.0),
+    ("FP8", 1.0),
+    ("Q8_0", 1.05),
+    ("Q6_K", 0.80),
+    ("Q5_K_M", 0.68),
+    ("Q5_K_S", 0.66),
+    ("Q5_0", 0.66),
+    ("Q4_K_M", 0.58),
+    ("Q4_K_S", 0.56),
+    ("Q4_0", 0.58),
+    ("Q4_0_4_8", 0.58),
+    ("IQ4_XS", 0.53),
+    ("Q3_K_L", 0.51),
+    ("Q3_K_M", 0.48),
+    ("Q3_K_S", 0.44),
+    ("IQ3_XXS", 0.39),
+    ("Q2_K", 0.37),
+    ("IQ2_XXS", 0.29),
+    ("mlx-4bit", 0.55),
+    ("mlx-6bit", 0.80),
+    ("mlx-8bit", 1.0),
+    ("AWQ-4bit", 0.5),
+    ("AWQ-8bit", 1.0),
+    ("GPTQ-Int4", 0.5),
+    ("GPTQ-Int8", 1.0),
+];
+
+/// Bytes per weight assumed for an unrecognized quantization. Deliberately
+/// 8-bit so memory is over- rather than under-estimated.
+pub const UNKNOWN_QUANT_BPP: f64 = 1.05;
+
+/// Name suffixes that mark a quantized or repackaged copy of a model rather
+/// than a different model. Matched case-insensitively by [`LlmModel::family`].
+const VARIANT_TAGS: &[&str] = &[
+    "gguf", "awq", "gptq", "mlx", "exl2", "bnb", "fp8", "fp4", "nvfp4", "bf16", "fp16", "int4",
+    "int8", "3bit", "4bit", "5bit", "6bit", "8bit", "w4a16", "w8a8", "w8a16",
+];
+
+/// Bytes per weight for each quantization: user entries layered on
+/// [`DEFAULT_QUANT_BPP`]. The default table has no user entries.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct QuantTable {
+    entries: Vec<(String, f64)>,
+}
+
+static BUILTIN_QUANTS: QuantTable = QuantTable {
+    entries: Vec::new(),
+};
+
+impl QuantTable {
+    /// The built-in table alone, for callers without user entries.
+    pub fn builtin() -> &'static QuantTable {
+        &BUILTIN_QUANTS
+    }
+
+    /// Parse a bytes-per-weight table with one `NAME = BYTES` entry per line.
+    /// Blank lines and `#` comments are ignored.
+    pub fn parse(text: &str) -> Result<Self, String> {
+        parse_quant_bpp_table(text).map(|entries| QuantTable { entries })
+    }
+
+    /// Bytes per weight for `quant`, if the user or built-in table lists it.
+    pub fn lookup(&self, quant: &str) -> Option<f64> {
+        self.entries
+            .iter()
+            .map(|(name, bytes)| (name.as_str(), *bytes))
+            .chain(DEFAULT_QUANT_BPP.iter().copied())
+            .find(|(name, _)| name.eq_ignore_ascii_case(quant))
+            .map(|(_, bytes)| bytes)
+    }
+
+    /// Canonical spelling of a quantization listed in the user or built-in table.
+    pub fn known_name(&self, quant: &str) -> Option<String> {
+        self.entries
+            .iter()
+            .map(|(name, _)| name.as_str())
+            .chain(DEFAULT_QUANT_BPP.iter().map(|(name, _)| *name))
+            .find(|name| name.eq_ignore_ascii_case(quant))
+            .map(str::to_string)
+    }
+
+    /// Bytes per parameter for `quant`. Unknown names use
+    /// [`UNKNOWN_QUANT_BPP`]; see [`QuantTable::unknown_quants`].
+    pub fn bpp(&self, quant: &str) -> f64 {
+        self.lookup(quant).unwrap_or(UNKNOWN_QUANT_BPP)
+    }
+
+    /// The quantizations of `models` this table doesn't list, sorted and
+    /// without duplicates.
+    pub fn unknown_quants(&self, models: &[LlmModel]) -> Vec<String> {
+        models
+            .iter()
+            .map(|m| m.quantization.as_str())
+            .filter(|quant| self.lookup(quant).is_none())
+            .map(str::to_string)
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect()
+    }
+}
+
+fn parse_quant_bpp_table(text: &str) -> Result<Vec<(String, f64)>, String> {
+    let mut entries = Vec::new();
+    for (i, raw) in text.lines().enumerate() {
+        let line = raw.split('#').next().unwrap_or("").trim();
+        if line.is_empty() {
+            continue;
+        }
+        let (name, value) = line
+            .split_once('=')
+            .ok_or_else(|| format!("line {}: expected NAME = BYTES", i + 1))?;
+        let name = name.trim();
+        let bytes: f64 = value
+            .trim()
+            .parse()
+            .map_err(|_| format!("line {}: invalid bytes value '{}'", i + 1, value.trim()))?;
+        if name.is_empty() || !(bytes > 0.0 && bytes <= 8.0) {
+            return Err(format!(
+                "line {}: bytes per weight must be in (0, 8] for a named quant",
+                i + 1
+            ));
+        }
+        entries.push((name.to_string(), bytes));
+    }
+    Ok(entries)
+}
+
+/// One entry of a catalog overrides file, layered on the base catalog by
+/// [`ModelDatabase::apply_overrides`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct CatalogOverride {
+    pub name: String,
+    /// Drop the model from the catalog.
+    pub hidden: bool,
+    /// Fields replacing the base entry's, or a complete entry for a new model.
+    pub fields: serde_json::Map<String, serde_json::Value>,
+}
+
+/// What [`ModelDatabase::from_sources`] builds a catalog from: a base
+/// catalog, local models added to it, and overrides layered on top.
+#[derive(Debug, Clone, Default)]
+pub struct CatalogSources {
+    /// Replaces the embedded catalog, e.g. a [`crate::catalog::ModelCatalog`].
+    pub base: Option<Vec<LlmModel>>,
+    /// Models added to the base, e.g. from [`crate::gguf::scan_dir`].
+    pub local: Vec<LlmModel>,
+    pub overrides: Vec<CatalogOverride>,
+}
+
+impl CatalogSources {
+    /// The base catalog before local models and overrides: [`Self::base`]
+    /// or the embedded one.
+    pub fn base_models(&self) -> Vec<LlmModel> {
+        self.base
+            .clone()
+            .unwrap_or_else(|| ModelDatabase::new().models)
+    }
+}
+
+/// Parse a catalog overrides file: a JSON array of entries, or an object
+/// with a `models` array. Every entry needs a `name`; `"hidden": true` drops
+/// the model. Lines starting with `//` or `#` are comments.
+pub fn parse_catalog_overrides(text: &str) -> Result<Vec<CatalogOverride>, String> {
+    let json: String = text
+        .lines()
+        .filter(|line| {
+            let line = line.trim_start();
+            !line.starts_with("//") && !line.starts_with('#')
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    let value: serde_json::Value =
+        serde_json::from_str(&json).map_err(|e| format!("invalid JSON: {}", e))?;
+    let entries = match value {
+        serde_json::Value::Array(entries) => entries,
+        serde_json::Value::Object(mut obj) => match obj.remove("models") {
+            Some(serde_json::Value::Array(entries)) => entries,
+            _ => return Err("expected a \"models\" array".to_string()),
+        },
+        _ => return Err("expected a JSON array or object".to_string()),
+    };
+
+    entries
+        .into_iter()
+        .enumerate()
+        .map(|(i, entry)| {
+            let serde_json::Value::Object(mut fields) = entry else {
+                return Err(format!("entry {}: expected an object", i + 1));
+            };
+            let name = match fields.remove("name") {
+                Some(serde_json::Value::String(name)) if !name.trim().is_empty() => name,
+                _ => return Err(format!("entry {}: missing \"name\"", i + 1)),
+            };
+            let hidden = match fields.remove("hidden") {
+                None => false,
+                Some(serde_json::Value::Bool(hidden)) => hidden,
+                Some(_) => return Err(format!("{}: \"hidden\" must be true or false", name)),
+            };
+            Ok(CatalogOverride {
+                name,
+                hidden,
+                fields,
+            })
+        })
+        .collect()
+}
+
+/// `model` as if published at `quant`: the catalog's memory figures are
+/// scaled by the bytes-per-weight ratio between its own quantization and
+/// `quant`, which becomes the model's quantization.
+pub fn requantize(model: &LlmModel, quant: &str, quants: &QuantTable) -> LlmModel {
+    let ratio = quants.bpp(quant) / quants.bpp(&model.quantization);
+    LlmModel {
+        min_ram_gb: model.min_ram_gb * ratio,
+        recommended_ram_gb: model.recommended_ram_gb * ratio,
+        min_vram_gb: model.min_vram_gb.map(|v| v * ratio),
+        quantization: quant.to_string(),
+        ..model.clone()
     }
 }
 
@@ -48,25 +257,6 @@ pub fn quant_speed_multiplier(quant: &str) -> f64 {
     }
 }
 
-/// Bytes per parameter for a given quantization format.
-/// Used by the bandwidth-based tok/s estimator to compute model size in GB.
-pub fn quant_bytes_per_param(quant: &str) -> f64 {
-    match quant {
-        "F16" | "BF16" => 2.0,
-        "Q8_0" => 1.0,
-        "Q6_K" => 0.75,
-        "Q5_K_M" => 0.625,
-        "Q4_K_M" | "Q4_0" => 0.5,
-        "Q3_K_M" => 0.375,
-        "Q2_K" => 0.25,
-        "mlx-4bit" => 0.5,
-        "mlx-8bit" => 1.0,
-        "AWQ-4bit" | "GPTQ-Int4" => 0.5,
-        "AWQ-8bit" | "GPTQ-Int8" => 1.0,
-        _ => 0.5, // default to ~4-bit
-    }
-}
-
 /// Quality penalty for quantization (lower quant = lower quality).
 pub fn quant_quality_penalty(quant: &str) -> f64 {
     match quant {
@@ -170,6 +360,50 @@ impl ModelFormat {
     }
 }
 
+/// Context length from which a model is tagged "long-context".
+pub const LONG_CONTEXT_TOKENS: u32 = 100_000;
+
+/// Model families trained for broad multilingual use.
+const MULTILINGUAL_FAMILIES: &[&str] = &["qwen", "aya", "bloom", "glm", "gemma-3", "nemo"];
+
+/// Catalog tags plus tags inferred from capabilities and metadata, lowercased,
+/// sorted and deduplicated.
+pub fn infer_tags(model: &LlmModel) -> Vec<String> {
+    let mut tags: Vec<String> = model.tags.iter().map(|t| t.trim().to_lowercase()).collect();
+    let name = model.name.to_lowercase();
+    let use_case = model.use_case.to_lowercase();
+
+    if model.capabilities.contains(&Capability::Vision) {
+        tags.push("vision".to_string());
+    }
+    if model.capabilities.contains(&Capability::ToolUse) {
+        tags.push("tool-calling".to_string());
+    }
+    if use_case.contains("reason") || name.contains("-r1") || name.contains("qwq") {
+        tags.push("reasoning".to_string());
+    }
+    if use_case.contains("cod") || name.contains("coder") || name.contains("starcoder") {
+        tags.push("coding".to_string());
+    }
+    if use_case.contains("embed") || name.contains("embed") {
+        tags.push("embedding".to_string());
+    }
+    if model.context_length >= LONG_CONTEXT_TOKENS {
+        tags.push("long-context".to_string());
+    }
+    if MULTILINGUAL_FAMILIES.iter().any(|f| name.contains(f)) {
+        tags.push("multilingual".to_string());
+    }
+    if model.is_moe {
+        tags.push("moe".to_string());
+    }
+
+    tags.retain(|t| !t.is_empty());
+    tags.sort();
+    tags.dedup();
+    tags
+}
+
 /// Use-case category for scoring weights.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
 pub enum UseCase {
@@ -246,9 +480,28 @@ pub struct LlmModel {
     /// Model capabilities (vision, tool use, etc.)
     #[serde(default)]
     pub capabilities: Vec<Capability>,
+    /// Free-form lowercase capability tags (e.g. "tool-calling", "long-context").
+    /// Catalog tags are extended with ones inferred by [`infer_tags`] on load.
+    #[serde(default)]
+    pub tags: Vec<String>,
     /// Model weight format (gguf, awq, gptq, mlx, safetensors)
     #[serde(default)]
     pub format: ModelFormat,
+    /// Transformer layers, for exact KV-cache sizing.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub num_layers: Option<u32>,
+    /// Width of the keys (and of the values) cached per layer and token:
+    /// `num_key_value_heads × head_dim`, which is `hidden_size` unless the
+    /// model uses grouped-query attention.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub kv_hidden_size: Option<u32>,
+    /// Hugging Face download count, from `--enrich`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hf_downloads: Option<u64>,
+    /// Size in bytes of each published GGUF file, by quant (e.g. "Q4_K_M"),
+    /// from `--enrich`. Used in place of the size estimate when present.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub gguf_file_sizes: BTreeMap<String, u64>,
 }
 
 /// A known GGUF download source for a model on HuggingFace.
@@ -275,57 +528,131 @@ impl LlmModel {
         self.format.is_prequantized()
     }
 
-    /// Bytes-per-parameter for the model's quantization level.
-    fn quant_bpp(&self) -> f64 {
-        quant_bpp(&self.quantization)
-    }
-
     /// Parameter count in billions, extracted from parameters_raw or parameter_count.
     pub fn params_b(&self) -> f64 {
         if let Some(raw) = self.parameters_raw {
             raw as f64 / 1_000_000_000.0
         } else {
-            // Parse from string like "7B", "1.1B", "137M"
-            let s = self.parameter_count.trim().to_uppercase();
-            if let Some(num_str) = s.strip_suffix('B') {
-                num_str.parse::<f64>().unwrap_or(7.0)
-            } else if let Some(num_str) = s.strip_suffix('M') {
-                num_str.parse::<f64>().unwrap_or(0.0) / 1000.0
-            } else {
-                7.0
-            }
+            parse_param_count(&self.parameter_count).unwrap_or(7.0)
+        }
+    }
+
+    /// Parameters (billions) active per token for MoE models, which is
+    /// what bandwidth-bound speed depends on; `None` for dense models.
+    pub fn active_params_b(&self) -> Option<f64> {
+        if !self.is_moe {
+            return None;
         }
+        self.active_parameters
+            .map(|active| active as f64 / 1_000_000_000.0)
+    }
+
+    /// Fill in MoE fields the catalog left out from an "8x7B"-style
+    /// parameter count: eight 7B experts, 56B loaded in total. Without a
+    /// routing figure two experts are assumed active per token, the usual
+    /// top-2 routing, so active parameters are two experts' worth. Fields
+    /// the catalog sets are kept.
+    pub fn infer_moe_from_parameter_count(&mut self) {
+        let Some((experts, expert_b)) = expert_shorthand(&self.parameter_count) else {
+            return;
+        };
+        if experts < 2 {
+            return;
+        }
+        self.is_moe = true;
+        let experts = *self.num_experts.get_or_insert(experts);
+        let active = *self.active_experts.get_or_insert(2.min(experts));
+        self.parameters_raw
+            .get_or_insert((experts as f64 * expert_b * 1_000_000_000.0).round() as u64);
+        self.active_parameters
+            .get_or_insert((active as f64 * expert_b * 1_000_000_000.0).round() as u64);
+    }
+
+    /// "46.7B total, 12.9B active" for MoE models, else the catalog's
+    /// parameter count as is.
+    pub fn params_label(&self) -> String {
+        match self.active_params_b() {
+            Some(active) => format!("{:.1}B total, {:.1}B active", self.params_b(), active),
+            None => self.parameter_count.clone(),
+        }
+    }
+
+    /// Approximate size (GB) of the weights file at a given quantization,
+    /// i.e. what has to be read from disk to load the model. The published
+    /// file size is used when `--enrich` found one for this quant.
+    pub fn weights_file_gb(&self, quant: &str, quants: &QuantTable) -> f64 {
+        match self.gguf_file_sizes.get(quant) {
+            Some(&bytes) if bytes > 0 => bytes as f64 / 1e9,
+            _ => self.params_b() * quants.bpp(quant),
+        }
+    }
+
+    /// [`LlmModel::weights_file_gb`] in binary GB (1024³ bytes), the unit
+    /// free disk space is measured in. The former counts 10⁹-byte GB.
+    pub fn weights_file_gib(&self, quant: &str, quants: &QuantTable) -> f64 {
+        self.weights_file_gb(quant, quants) * 1e9 / (1024.0 * 1024.0 * 1024.0)
     }
 
     /// Estimate memory required (GB) at a given quantization and context length.
     /// Formula: model_weights + KV_cache + runtime_overhead
-    pub fn estimate_memory_gb(&self, quant: &str, ctx: u32) -> f64 {
-        let bpp = quant_bpp(quant);
+    pub fn estimate_memory_gb(&self, quant: &str, ctx: u32, quants: &QuantTable) -> f64 {
+        self.estimate_memory_gb_for(quant, ctx, 1, quants)
+    }
+
+    /// Like [`LlmModel::estimate_memory_gb`], with a KV cache for each of
+    /// `requests` served at once. The weights are counted once.
+    pub fn estimate_memory_gb_for(
+        &self,
+        quant: &str,
+        ctx: u32,
+        requests: u32,
+        quants: &QuantTable,
+    ) -> f64 {
+        let bpp = quants.bpp(quant);
         let params = self.params_b();
         let model_mem = params * bpp;
-        // KV cache: ~0.000008 GB per billion params per context token
-        let kv_cache = 0.000008 * params * ctx as f64;
+        let kv_cache = self.kv_cache_gb(ctx) * requests.max(1) as f64;
         // Runtime overhead (CUDA/Metal context, buffers)
         let overhead = 0.5;
         model_mem + kv_cache + overhead
     }
 
+    /// fp16 KV cache for one request of `ctx` tokens: exact when the layer
+    /// count and KV width are known, otherwise ~0.000008 GB per billion
+    /// params per token.
+    pub fn kv_cache_gb(&self, ctx: u32) -> f64 {
+        match (self.num_layers, self.kv_hidden_size) {
+            (Some(layers), Some(width)) => {
+                crate::fit::kv_cache_gb(ctx, layers, width, crate::fit::DEFAULT_KV_BITS)
+            }
+            _ => 0.000008 * self.params_b() * ctx as f64,
+        }
+    }
+
     /// Select the best quantization level that fits within a memory budget.
     /// Returns the quant name and estimated memory in GB, or None if nothing fits.
-    pub fn best_quant_for_budget(&self, budget_gb: f64, ctx: u32) -> Option<(&'static str, f64)> {
-        self.best_quant_for_budget_with(budget_gb, ctx, QUANT_HIERARCHY)
+    pub fn best_quant_for_budget(
+        &self,
+        budget_gb: f64,
+        ctx: u32,
+        quants: &QuantTable,
+    ) -> Option<(&'static str, f64)> {
+        self.best_quant_for_budget_with(budget_gb, ctx, QUANT_HIERARCHY, 1, quants)
     }
 
-    /// Select the best quantization from a custom hierarchy that fits within a memory budget.
+    /// Select the best quantization from a custom hierarchy that fits within
+    /// a memory budget, with a KV cache for each of `requests`.
     pub fn best_quant_for_budget_with(
         &self,
         budget_gb: f64,
         ctx: u32,
         hierarchy: &[&'static str],
+        requests: u32,
+        quants: &QuantTable,
     ) -> Option<(&'static str, f64)> {
         // Try best quality first
         for &q in hierarchy {
-            let mem = self.estimate_memory_gb(q, ctx);
+            let mem = self.estimate_memory_gb_for(q, ctx, requests, quants);
             if mem <= budget_gb {
                 return Some((q, mem));
             }
@@ -334,7 +661,7 @@ impl LlmModel {
         let half_ctx = ctx / 2;
         if half_ctx >= 1024 {
             for &q in hierarchy {
-                let mem = self.estimate_memory_gb(q, half_ctx);
+                let mem = self.estimate_memory_gb_for(q, half_ctx, requests, quants);
                 if mem <= budget_gb {
                     return Some((q, mem));
                 }
@@ -345,16 +672,36 @@ impl LlmModel {
 
     /// For MoE models, compute estimated VRAM for active experts only.
     /// Returns None for dense models.
-    pub fn moe_active_vram_gb(&self) -> Option<f64> {
+    pub fn moe_active_vram_gb(&self, quants: &QuantTable) -> Option<f64> {
         if !self.is_moe {
             return None;
         }
         let active_params = self.active_parameters? as f64;
-        let bpp = self.quant_bpp();
+        let bpp = quants.bpp(&self.quantization);
         let size_gb = (active_params * bpp) / (1024.0 * 1024.0 * 1024.0);
         Some((size_gb * 1.1).max(0.5))
     }
 
+    /// Family key shared by repackaged quant/format variants of one model:
+    /// the repo basename, lowercased, without trailing tags like `-GGUF`,
+    /// `-AWQ`, `-MLX-8bit` or `.w8a8`. So `lmstudio-community/Qwen3-8B-MLX-4bit`
+    /// and `Qwen/Qwen3-8B-AWQ` are both `qwen3-8b`.
+    pub fn family(&self) -> String {
+        let mut family = self
+            .name
+            .rsplit('/')
+            .next()
+            .unwrap_or(&self.name)
+            .to_lowercase();
+        while let Some(cut) = family.rfind(['-', '_', '.']) {
+            if !VARIANT_TAGS.contains(&&family[cut + 1..]) {
+                break;
+            }
+            family.truncate(cut);
+        }
+        family
+    }
+
     /// Returns true if this model is MLX-specific (Apple Silicon only).
     /// MLX models are identified by having "-MLX" in their name.
     pub fn is_mlx_only(&self) -> bool {
@@ -363,7 +710,7 @@ impl LlmModel {
 
     /// For MoE models, compute RAM needed for offloaded (inactive) experts.
     /// Returns None for dense models.
-    pub fn moe_offloaded_ram_gb(&self) -> Option<f64> {
+    pub fn moe_offloaded_ram_gb(&self, quants: &QuantTable) -> Option<f64> {
         if !self.is_moe {
             return None;
         }
@@ -373,7 +720,7 @@ impl LlmModel {
         if inactive <= 0.0 {
             return Some(0.0);
         }
-        let bpp = self.quant_bpp();
+        let bpp = quants.bpp(&self.quantization);
         Some((inactive * bpp) / (1024.0 * 1024.0 * 1024.0))
     }
 }
@@ -408,27 +755,50 @@ struct HfModelEntry {
     #[serde(default)]
     capabilities: Vec<Capability>,
     #[serde(default)]
+    tags: Vec<String>,
+    #[serde(default)]
     format: ModelFormat,
     #[serde(default)]
+    num_layers: Option<u32>,
+    #[serde(default)]
+    kv_hidden_size: Option<u32>,
+    #[serde(default)]
     hf_downloads: u64,
     #[serde(default)]
     hf_likes: u64,
 }
 
-fn parse_parameter_count_hint(parameter_count: &str) -> Option<u64> {
+/// Parse a parameter count like "7B", "1.1B", "137M", "1T" or the MoE
+/// shorthand "8x7B" (eight 7B experts, 56B in total) into billions.
+pub fn parse_param_count(parameter_count: &str) -> Option<f64> {
     let normalized = parameter_count.trim().replace(',', "").to_uppercase();
-
-    if let Some(raw) = normalized.strip_suffix('B') {
-        raw.parse::<f64>()
-            .ok()
-            .map(|value| (value * 1_000_000_000.0).round() as u64)
-    } else if let Some(raw) = normalized.strip_suffix('M') {
-        raw.parse::<f64>()
-            .ok()
-            .map(|value| (value * 1_000_000.0).round() as u64)
+    let (experts, size) = match normalized.split_once('X') {
+        Some((experts, size)) => (experts.trim().parse::<f64>().ok()?, size.trim()),
+        None => (1.0, normalized.as_str()),
+    };
+    let (number, scale) = if let Some(raw) = size.strip_suffix('T') {
+        (raw, 1_000.0)
+    } else if let Some(raw) = size.strip_suffix('B') {
+        (raw, 1.0)
+    } else if let Some(raw) = size.strip_suffix('M') {
+        (raw, 0.001)
     } else {
-        None
-    }
+        return None;
+    };
+    let value = number.trim().parse::<f64>().ok()?;
+    (value.is_finite() && value >= 0.0).then_some(experts * value * scale)
+}
+
+/// Split the MoE shorthand "8x7B" into the expert count and the size of
+/// one expert in billions. `None` for a plain count like "7B".
+fn expert_shorthand(parameter_count: &str) -> Option<(u32, f64)> {
+    let normalized = parameter_count.trim().to_uppercase();
+    let (experts, size) = normalized.split_once('X')?;
+    Some((experts.trim().parse().ok()?, parse_param_count(size)?))
+}
+
+fn parse_parameter_count_hint(parameter_count: &str) -> Option<u64> {
+    parse_param_count(parameter_count).map(|billions| (billions * 1_000_000_000.0).round() as u64)
 }
 
 fn effective_parameters_raw(entry: &HfModelEntry) -> Option<u64> {
@@ -505,6 +875,12 @@ fn merge_exact_name_entries(
         }
     }
 
+    for tag in secondary.tags {
+        if !primary.tags.contains(&tag) {
+            primary.tags.push(tag);
+        }
+    }
+
     for source in secondary.gguf_sources {
         let exists = primary
             .gguf_sources
@@ -535,8 +911,81 @@ fn dedupe_hf_entries(entries: Vec<HfModelEntry>) -> Vec<HfModelEntry> {
     deduped_entries
 }
 
+fn model_from_entry(e: HfModelEntry) -> LlmModel {
+    let mut model = LlmModel {
+        name: e.name,
+        provider: e.provider,
+        parameter_count: e.parameter_count,
+        parameters_raw: e.parameters_raw,
+        min_ram_gb: e.min_ram_gb,
+        recommended_ram_gb: e.recommended_ram_gb,
+        min_vram_gb: e.min_vram_gb,
+        quantization: e.quantization,
+        context_length: e.context_length,
+        use_case: e.use_case,
+        is_moe: e.is_moe,
+        num_experts: e.num_experts,
+        active_experts: e.active_experts,
+        active_parameters: e.active_parameters,
+        release_date: e.release_date,
+        gguf_sources: e.gguf_sources,
+        capabilities: e.capabilities,
+        tags: e.tags,
+        format: e.format,
+        num_layers: e.num_layers,
+        kv_hidden_size: e.kv_hidden_size,
+        hf_downloads: (e.hf_downloads > 0).then_some(e.hf_downloads),
+        gguf_file_sizes: Default::default(),
+    };
+    model.infer_moe_from_parameter_count();
+    model.capabilities = Capability::infer(&model);
+    model.tags = infer_tags(&model);
+    model
+}
+
 const HF_MODELS_JSON: &str = include_str!("../data/hf_models.json");
 
+/// Oldest catalog schema version this build can read.
+pub const MIN_CATALOG_SCHEMA_VERSION: u32 = 1;
+/// Newest catalog schema version this build can read.
+pub const MAX_CATALOG_SCHEMA_VERSION: u32 = 1;
+
+/// Parse catalog JSON into raw entries, checking its schema version.
+///
+/// Accepts either `{"schema_version": N, "models": [...]}` or a bare array of
+/// models, which predates versioning and is treated as version 1.
+fn parse_catalog(json: &str) -> Result<Vec<HfModelEntry>, String> {
+    let value: serde_json::Value =
+        serde_json::from_str(json).map_err(|e| format!("Invalid catalog JSON: {}", e))?;
+
+    let models = match value {
+        serde_json::Value::Array(_) => value,
+        serde_json::Value::Object(mut obj) => {
+            let version = obj
+                .get("schema_version")
+                .and_then(|v| v.as_u64())
+                .ok_or("Catalog is missing a numeric \"schema_version\" field")?;
+            if version > MAX_CATALOG_SCHEMA_VERSION as u64 {
+                return Err(format!(
+                    "Catalog schema version {} is newer than this llmfit supports ({}..={}). Update llmfit to read it.",
+                    version, MIN_CATALOG_SCHEMA_VERSION, MAX_CATALOG_SCHEMA_VERSION
+                ));
+            }
+            if version < MIN_CATALOG_SCHEMA_VERSION as u64 {
+                return Err(format!(
+                    "Catalog schema version {} is older than this llmfit supports ({}..={}). Regenerate the catalog.",
+                    version, MIN_CATALOG_SCHEMA_VERSION, MAX_CATALOG_SCHEMA_VERSION
+                ));
+            }
+            obj.remove("models")
+                .ok_or("Catalog is missing a \"models\" array")?
+        }
+        _ => return Err("Catalog must be a JSON object or array".to_string()),
+    };
+
+    serde_json::from_value(models).map_err(|e| format!("Invalid catalog entry: {}", e))
+}
+
 pub struct ModelDatabase {
     models: Vec<LlmModel>,
 }
@@ -548,47 +997,91 @@ impl Default for ModelDatabase {
 }
 
 impl ModelDatabase {
+    /// The embedded catalog.
     pub fn new() -> Self {
-        let entries: Vec<HfModelEntry> =
-            serde_json::from_str(HF_MODELS_JSON).expect("Failed to parse embedded hf_models.json");
+        Self::from_json(HF_MODELS_JSON).expect("Failed to parse embedded hf_models.json")
+    }
+
+    /// The base catalog of `sources` plus its local models, with its
+    /// overrides layered on top. Also returns one message per override that
+    /// couldn't be applied (see [`ModelDatabase::apply_overrides`]).
+    pub fn from_sources(sources: &CatalogSources) -> (Self, Vec<String>) {
+        let mut db = ModelDatabase {
+            models: sources.base_models(),
+        };
+        db.models.extend(sources.local.iter().cloned());
+        let errors = db.apply_overrides(&sources.overrides);
+        (db, errors)
+    }
 
+    /// Build a database from catalog JSON, rejecting unsupported schema versions.
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        let entries = parse_catalog(json)?;
         let deduped_entries = dedupe_hf_entries(entries);
 
-        let models = deduped_entries
-            .into_iter()
-            .map(|e| {
-                let mut model = LlmModel {
-                    name: e.name,
-                    provider: e.provider,
-                    parameter_count: e.parameter_count,
-                    parameters_raw: e.parameters_raw,
-                    min_ram_gb: e.min_ram_gb,
-                    recommended_ram_gb: e.recommended_ram_gb,
-                    min_vram_gb: e.min_vram_gb,
-                    quantization: e.quantization,
-                    context_length: e.context_length,
-                    use_case: e.use_case,
-                    is_moe: e.is_moe,
-                    num_experts: e.num_experts,
-                    active_experts: e.active_experts,
-                    active_parameters: e.active_parameters,
-                    release_date: e.release_date,
-                    gguf_sources: e.gguf_sources,
-                    capabilities: e.capabilities,
-                    format: e.format,
-                };
-                model.capabilities = Capability::infer(&model);
-                model
-            })
-            .collect();
+        let models = deduped_entries.into_iter().map(model_from_entry).collect();
+
+        Ok(ModelDatabase { models })
+    }
 
-        ModelDatabase { models }
+    /// Layer overrides on this catalog in order. Entries match models by
+    /// name (case-insensitive): a match has the listed fields replaced and
+    /// keeps the rest, a hidden entry removes it, and an unmatched entry is
+    /// added as a new model, so it must be complete. Capabilities and tags
+    /// are re-inferred afterwards. Returns one message per entry that
+    /// couldn't be applied; the others still are.
+    pub fn apply_overrides(&mut self, overrides: &[CatalogOverride]) -> Vec<String> {
+        let mut errors = Vec::new();
+        for ov in overrides {
+            let idx = self
+                .models
+                .iter()
+                .position(|m| m.name.eq_ignore_ascii_case(&ov.name));
+            match (idx, ov.hidden) {
+                (Some(idx), true) => {
+                    self.models.remove(idx);
+                }
+                (None, true) => {
+                    errors.push(format!("{}: can't hide, not in the catalog", ov.name));
+                }
+                (Some(idx), false) => {
+                    let base = &self.models[idx];
+                    let mut value = match serde_json::to_value(base) {
+                        Ok(serde_json::Value::Object(value)) => value,
+                        _ => continue,
+                    };
+                    value.extend(ov.fields.clone());
+                    match serde_json::from_value::<LlmModel>(value.into()) {
+                        Ok(mut model) => {
+                            model.infer_moe_from_parameter_count();
+                            model.capabilities = Capability::infer(&model);
+                            model.tags = infer_tags(&model);
+                            self.models[idx] = model;
+                        }
+                        Err(e) => errors.push(format!("{}: {}", ov.name, e)),
+                    }
+                }
+                (None, false) => {
+                    let mut value = ov.fields.clone();
+                    value.insert("name".to_string(), ov.name.clone().into());
+                    match serde_json::from_value::<HfModelEntry>(value.into()) {
+                        Ok(entry) => self.models.push(model_from_entry(entry)),
+                        Err(e) => errors.push(format!("{}: new model: {}", ov.name, e)),
+                    }
+                }
+            }
+        }
+        errors
     }
 
     pub fn get_all_models(&self) -> &Vec<LlmModel> {
         &self.models
     }
 
+    pub fn into_models(self) -> Vec<LlmModel> {
+        self.models
+    }
+
     pub fn find_model(&self, query: &str) -> Vec<&LlmModel> {
         let query_lower = query.to_lowercase();
         self.models
@@ -644,8 +1137,8 @@ mod tests {
 
     #[test]
     fn test_mlx_quant_bpp_values() {
-        assert_eq!(quant_bpp("mlx-4bit"), 0.55);
-        assert_eq!(quant_bpp("mlx-8bit"), 1.0);
+        assert_eq!(QuantTable::builtin().bpp("mlx-4bit"), 0.55);
+        assert_eq!(QuantTable::builtin().bpp("mlx-8bit"), 1.0);
         assert_eq!(quant_speed_multiplier("mlx-4bit"), 1.15);
         assert_eq!(quant_speed_multiplier("mlx-8bit"), 0.85);
         assert_eq!(quant_quality_penalty("mlx-4bit"), -4.0);
@@ -672,31 +1165,107 @@ mod tests {
             release_date: None,
             gguf_sources: vec![],
             capabilities: vec![],
+            tags: vec![],
             format: ModelFormat::default(),
+            num_layers: None,
+            kv_hidden_size: None,
+            hf_downloads: None,
+            gguf_file_sizes: Default::default(),
         };
 
         // Large budget should return mlx-8bit (best in MLX hierarchy)
-        let result = model.best_quant_for_budget_with(10.0, 4096, MLX_QUANT_HIERARCHY);
+        let result = model.best_quant_for_budget_with(
+            10.0,
+            4096,
+            MLX_QUANT_HIERARCHY,
+            1,
+            QuantTable::builtin(),
+        );
         assert!(result.is_some());
         let (quant, _) = result.unwrap();
         assert_eq!(quant, "mlx-8bit");
 
         // Tighter budget should fall to mlx-4bit
-        let result = model.best_quant_for_budget_with(5.0, 4096, MLX_QUANT_HIERARCHY);
+        let result = model.best_quant_for_budget_with(
+            5.0,
+            4096,
+            MLX_QUANT_HIERARCHY,
+            1,
+            QuantTable::builtin(),
+        );
         assert!(result.is_some());
         let (quant, _) = result.unwrap();
         assert_eq!(quant, "mlx-4bit");
     }
 
+    #[test]
+    fn test_infer_tags_merges_catalog_and_derived() {
+        let model = LlmModel {
+            name: "Qwen/Qwen2.5-Coder-7B-Instruct".to_string(),
+            provider: "Qwen".to_string(),
+            parameter_count: "7B".to_string(),
+            parameters_raw: Some(7_000_000_000),
+            min_ram_gb: 4.0,
+            recommended_ram_gb: 8.0,
+            min_vram_gb: Some(4.0),
+            quantization: "Q4_K_M".to_string(),
+            context_length: 131_072,
+            use_case: "Code generation".to_string(),
+            is_moe: false,
+            num_experts: None,
+            active_experts: None,
+            active_parameters: None,
+            release_date: None,
+            gguf_sources: vec![],
+            capabilities: vec![Capability::ToolUse],
+            tags: vec!["Agentic".to_string(), "coding".to_string()],
+            format: ModelFormat::default(),
+            num_layers: None,
+            kv_hidden_size: None,
+            hf_downloads: None,
+            gguf_file_sizes: Default::default(),
+        };
+        assert_eq!(
+            infer_tags(&model),
+            vec![
+                "agentic",
+                "coding",
+                "long-context",
+                "multilingual",
+                "tool-calling"
+            ]
+        );
+    }
+
     #[test]
     fn test_quant_bpp() {
-        assert_eq!(quant_bpp("F32"), 4.0);
-        assert_eq!(quant_bpp("F16"), 2.0);
-        assert_eq!(quant_bpp("Q8_0"), 1.05);
-        assert_eq!(quant_bpp("Q4_K_M"), 0.58);
-        assert_eq!(quant_bpp("Q2_K"), 0.37);
-        // Unknown quant defaults to Q4_K_M
-        assert_eq!(quant_bpp("UNKNOWN"), 0.58);
+        assert_eq!(QuantTable::builtin().bpp("F32"), 4.0);
+        assert_eq!(QuantTable::builtin().bpp("F16"), 2.0);
+        assert_eq!(QuantTable::builtin().bpp("Q8_0"), 1.05);
+        assert_eq!(QuantTable::builtin().bpp("Q4_K_M"), 0.58);
+        assert_eq!(QuantTable::builtin().bpp("Q2_K"), 0.37);
+        // Unknown quant falls back conservatively and is reported
+        assert_eq!(QuantTable::builtin().bpp("UNKNOWN"), UNKNOWN_QUANT_BPP);
+        let mut model = ModelDatabase::new().into_models().remove(0);
+        model.quantization = "UNKNOWN".to_string();
+        assert_eq!(
+            QuantTable::builtin().unknown_quants(&[model]),
+            vec!["UNKNOWN".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_quant_bpp_overrides_and_table_parsing() {
+        let table = "# custom quants\nTEST_Q1_7 = 0.22  # 1.75 bits\n\nQ4_K_M = 0.6\n";
+        let quants = QuantTable::parse(table).unwrap();
+        assert_eq!(quants.bpp("test_q1_7"), 0.22);
+        assert_eq!(quants.known_name("test_q1_7").as_deref(), Some("TEST_Q1_7"));
+        // User entries win over the built-ins, which stay untouched
+        assert_eq!(quants.bpp("Q4_K_M"), 0.6);
+        assert_eq!(QuantTable::builtin().bpp("Q4_K_M"), 0.58);
+        assert_eq!(QuantTable::builtin().lookup("test_q1_7"), None);
+        assert!(QuantTable::parse("Q4 0.5").is_err());
+        assert!(QuantTable::parse("Q4 = -1").is_err());
     }
 
     #[test]
@@ -743,7 +1312,12 @@ mod tests {
             release_date: None,
             gguf_sources: vec![],
             capabilities: vec![],
+            tags: vec![],
             format: ModelFormat::default(),
+            num_layers: None,
+            kv_hidden_size: None,
+            hf_downloads: None,
+            gguf_file_sizes: Default::default(),
         };
         assert_eq!(model.params_b(), 7.0);
     }
@@ -768,7 +1342,12 @@ mod tests {
             release_date: None,
             gguf_sources: vec![],
             capabilities: vec![],
+            tags: vec![],
             format: ModelFormat::default(),
+            num_layers: None,
+            kv_hidden_size: None,
+            hf_downloads: None,
+            gguf_file_sizes: Default::default(),
         };
         assert_eq!(model.params_b(), 13.0);
     }
@@ -793,11 +1372,30 @@ mod tests {
             release_date: None,
             gguf_sources: vec![],
             capabilities: vec![],
+            tags: vec![],
             format: ModelFormat::default(),
+            num_layers: None,
+            kv_hidden_size: None,
+            hf_downloads: None,
+            gguf_file_sizes: Default::default(),
         };
         assert_eq!(model.params_b(), 0.5);
     }
 
+    #[test]
+    fn test_parse_param_count() {
+        assert_eq!(parse_param_count("7B"), Some(7.0));
+        assert_eq!(parse_param_count(" 1.1b "), Some(1.1));
+        assert_eq!(parse_param_count("137M"), Some(0.137));
+        assert_eq!(parse_param_count("1,000M"), Some(1.0));
+        assert_eq!(parse_param_count("8x7B"), Some(56.0));
+        assert_eq!(parse_param_count("8X22B"), Some(176.0));
+        assert_eq!(parse_param_count("1T"), Some(1000.0));
+        assert_eq!(parse_param_count("7"), None);
+        assert_eq!(parse_param_count("large"), None);
+        assert_eq!(parse_param_count("x7B"), None);
+    }
+
     #[test]
     fn test_estimate_memory_gb() {
         let model = LlmModel {
@@ -818,19 +1416,64 @@ mod tests {
             release_date: None,
             gguf_sources: vec![],
             capabilities: vec![],
+            tags: vec![],
             format: ModelFormat::default(),
+            num_layers: None,
+            kv_hidden_size: None,
+            hf_downloads: None,
+            gguf_file_sizes: Default::default(),
         };
 
-        let mem = model.estimate_memory_gb("Q4_K_M", 4096);
+        let mem = model.estimate_memory_gb("Q4_K_M", 4096, QuantTable::builtin());
         // 7B params * 0.58 bytes = 4.06 GB + KV cache + overhead
         assert!(mem > 4.0);
         assert!(mem < 6.0);
 
         // Q8_0 should require more memory
-        let mem_q8 = model.estimate_memory_gb("Q8_0", 4096);
+        let mem_q8 = model.estimate_memory_gb("Q8_0", 4096, QuantTable::builtin());
         assert!(mem_q8 > mem);
     }
 
+    #[test]
+    fn test_requantize_scales_memory_by_bpp_ratio() {
+        let model = LlmModel {
+            name: "Test Model".to_string(),
+            provider: "Test".to_string(),
+            parameter_count: "7B".to_string(),
+            parameters_raw: Some(7_000_000_000),
+            min_ram_gb: 5.8,
+            recommended_ram_gb: 8.7,
+            min_vram_gb: Some(4.64),
+            quantization: "Q4_K_M".to_string(),
+            context_length: 4096,
+            use_case: "General".to_string(),
+            is_moe: false,
+            num_experts: None,
+            active_experts: None,
+            active_parameters: None,
+            release_date: None,
+            gguf_sources: vec![],
+            capabilities: vec![],
+            tags: vec![],
+            format: ModelFormat::default(),
+            num_layers: None,
+            kv_hidden_size: None,
+            hf_downloads: None,
+            gguf_file_sizes: Default::default(),
+        };
+
+        let f16 = requantize(&model, "F16", QuantTable::builtin());
+        assert_eq!(f16.quantization, "F16");
+        assert!((f16.min_ram_gb - 20.0).abs() < 1e-9);
+        assert!((f16.recommended_ram_gb - 30.0).abs() < 1e-9);
+        assert!((f16.min_vram_gb.unwrap() - 16.0).abs() < 1e-9);
+        assert_eq!(f16.name, model.name);
+
+        // Round-trips back to the catalog figures
+        let back = requantize(&f16, "Q4_K_M", QuantTable::builtin());
+        assert!((back.min_ram_gb - model.min_ram_gb).abs() < 1e-9);
+    }
+
     #[test]
     fn test_best_quant_for_budget() {
         let model = LlmModel {
@@ -851,21 +1494,26 @@ mod tests {
             release_date: None,
             gguf_sources: vec![],
             capabilities: vec![],
+            tags: vec![],
             format: ModelFormat::default(),
+            num_layers: None,
+            kv_hidden_size: None,
+            hf_downloads: None,
+            gguf_file_sizes: Default::default(),
         };
 
         // Large budget should return best quant
-        let result = model.best_quant_for_budget(10.0, 4096);
+        let result = model.best_quant_for_budget(10.0, 4096, QuantTable::builtin());
         assert!(result.is_some());
         let (quant, _) = result.unwrap();
         assert_eq!(quant, "Q8_0");
 
         // Medium budget should find acceptable quant
-        let result = model.best_quant_for_budget(5.0, 4096);
+        let result = model.best_quant_for_budget(5.0, 4096, QuantTable::builtin());
         assert!(result.is_some());
 
         // Tiny budget should return None
-        let result = model.best_quant_for_budget(1.0, 4096);
+        let result = model.best_quant_for_budget(1.0, 4096, QuantTable::builtin());
         assert!(result.is_none());
     }
 
@@ -890,9 +1538,18 @@ mod tests {
             release_date: None,
             gguf_sources: vec![],
             capabilities: vec![],
+            tags: vec![],
             format: ModelFormat::default(),
+            num_layers: None,
+            kv_hidden_size: None,
+            hf_downloads: None,
+            gguf_file_sizes: Default::default(),
         };
-        assert!(dense_model.moe_active_vram_gb().is_none());
+        assert!(
+            dense_model
+                .moe_active_vram_gb(QuantTable::builtin())
+                .is_none()
+        );
 
         // MoE model should calculate active VRAM
         let moe_model = LlmModel {
@@ -913,9 +1570,14 @@ mod tests {
             release_date: None,
             gguf_sources: vec![],
             capabilities: vec![],
+            tags: vec![],
             format: ModelFormat::default(),
+            num_layers: None,
+            kv_hidden_size: None,
+            hf_downloads: None,
+            gguf_file_sizes: Default::default(),
         };
-        let vram = moe_model.moe_active_vram_gb();
+        let vram = moe_model.moe_active_vram_gb(QuantTable::builtin());
         assert!(vram.is_some());
         let vram_val = vram.unwrap();
         // Should be significantly less than full model
@@ -944,9 +1606,18 @@ mod tests {
             release_date: None,
             gguf_sources: vec![],
             capabilities: vec![],
+            tags: vec![],
             format: ModelFormat::default(),
+            num_layers: None,
+            kv_hidden_size: None,
+            hf_downloads: None,
+            gguf_file_sizes: Default::default(),
         };
-        assert!(dense_model.moe_offloaded_ram_gb().is_none());
+        assert!(
+            dense_model
+                .moe_offloaded_ram_gb(QuantTable::builtin())
+                .is_none()
+        );
 
         // MoE model should calculate offloaded RAM
         let moe_model = LlmModel {
@@ -967,9 +1638,14 @@ mod tests {
             release_date: None,
             gguf_sources: vec![],
             capabilities: vec![],
+            tags: vec![],
             format: ModelFormat::default(),
+            num_layers: None,
+            kv_hidden_size: None,
+            hf_downloads: None,
+            gguf_file_sizes: Default::default(),
         };
-        let offloaded = moe_model.moe_offloaded_ram_gb();
+        let offloaded = moe_model.moe_offloaded_ram_gb(QuantTable::builtin());
         assert!(offloaded.is_some());
         let offloaded_val = offloaded.unwrap();
         // Should be substantial
@@ -1000,7 +1676,12 @@ mod tests {
             release_date: None,
             gguf_sources: vec![],
             capabilities: vec![],
+            tags: vec![],
             format: ModelFormat::default(),
+            num_layers: None,
+            kv_hidden_size: None,
+            hf_downloads: None,
+            gguf_file_sizes: Default::default(),
         };
         assert_eq!(UseCase::from_model(&model), UseCase::Coding);
     }
@@ -1025,7 +1706,12 @@ mod tests {
             release_date: None,
             gguf_sources: vec![],
             capabilities: vec![],
+            tags: vec![],
             format: ModelFormat::default(),
+            num_layers: None,
+            kv_hidden_size: None,
+            hf_downloads: None,
+            gguf_file_sizes: Default::default(),
         };
         assert_eq!(UseCase::from_model(&model), UseCase::Embedding);
     }
@@ -1050,7 +1736,12 @@ mod tests {
             release_date: None,
             gguf_sources: vec![],
             capabilities: vec![],
+            tags: vec![],
             format: ModelFormat::default(),
+            num_layers: None,
+            kv_hidden_size: None,
+            hf_downloads: None,
+            gguf_file_sizes: Default::default(),
         };
         assert_eq!(UseCase::from_model(&model), UseCase::Reasoning);
     }
@@ -1091,7 +1782,10 @@ mod tests {
                     provider: "example".to_string(),
                 }],
                 capabilities: vec![Capability::Vision],
+                tags: vec![],
                 format: ModelFormat::Safetensors,
+                num_layers: None,
+                kv_hidden_size: None,
                 hf_downloads: 10_000,
                 hf_likes: 500,
             },
@@ -1116,7 +1810,10 @@ mod tests {
                     provider: "unsloth".to_string(),
                 }],
                 capabilities: vec![Capability::ToolUse],
+                tags: vec![],
                 format: ModelFormat::Gguf,
+                num_layers: None,
+                kv_hidden_size: None,
                 hf_downloads: 100,
                 hf_likes: 10,
             },
@@ -1245,7 +1942,12 @@ mod tests {
             release_date: None,
             gguf_sources: vec![],
             capabilities: vec![],
+            tags: vec![],
             format: ModelFormat::default(),
+            num_layers: None,
+            kv_hidden_size: None,
+            hf_downloads: None,
+            gguf_file_sizes: Default::default(),
         };
         let caps = Capability::infer(&model);
         assert!(caps.contains(&Capability::Vision));
@@ -1273,7 +1975,12 @@ mod tests {
             release_date: None,
             gguf_sources: vec![],
             capabilities: vec![],
+            tags: vec![],
             format: ModelFormat::default(),
+            num_layers: None,
+            kv_hidden_size: None,
+            hf_downloads: None,
+            gguf_file_sizes: Default::default(),
         };
         let caps = Capability::infer(&model);
         assert!(caps.contains(&Capability::ToolUse));
@@ -1300,7 +2007,12 @@ mod tests {
             release_date: None,
             gguf_sources: vec![],
             capabilities: vec![],
+            tags: vec![],
             format: ModelFormat::default(),
+            num_layers: None,
+            kv_hidden_size: None,
+            hf_downloads: None,
+            gguf_file_sizes: Default::default(),
         };
         let caps = Capability::infer(&model);
         assert!(caps.is_empty());
@@ -1326,7 +2038,12 @@ mod tests {
             release_date: None,
             gguf_sources: vec![],
             capabilities: vec![Capability::Vision],
+            tags: vec![],
             format: ModelFormat::default(),
+            num_layers: None,
+            kv_hidden_size: None,
+            hf_downloads: None,
+            gguf_file_sizes: Default::default(),
         };
         let caps = Capability::infer(&model);
         // Should keep the explicit Vision and not duplicate it
@@ -1336,15 +2053,15 @@ mod tests {
     #[test]
     fn test_awq_gptq_quant_values() {
         // AWQ
-        assert_eq!(quant_bpp("AWQ-4bit"), 0.5);
-        assert_eq!(quant_bpp("AWQ-8bit"), 1.0);
+        assert_eq!(QuantTable::builtin().bpp("AWQ-4bit"), 0.5);
+        assert_eq!(QuantTable::builtin().bpp("AWQ-8bit"), 1.0);
         assert_eq!(quant_speed_multiplier("AWQ-4bit"), 1.2);
         assert_eq!(quant_speed_multiplier("AWQ-8bit"), 0.85);
         assert_eq!(quant_quality_penalty("AWQ-4bit"), -3.0);
         assert_eq!(quant_quality_penalty("AWQ-8bit"), 0.0);
         // GPTQ
-        assert_eq!(quant_bpp("GPTQ-Int4"), 0.5);
-        assert_eq!(quant_bpp("GPTQ-Int8"), 1.0);
+        assert_eq!(QuantTable::builtin().bpp("GPTQ-Int4"), 0.5);
+        assert_eq!(QuantTable::builtin().bpp("GPTQ-Int8"), 1.0);
         assert_eq!(quant_speed_multiplier("GPTQ-Int4"), 1.2);
         assert_eq!(quant_speed_multiplier("GPTQ-Int8"), 0.85);
         assert_eq!(quant_quality_penalty("GPTQ-Int4"), -3.0);
@@ -1438,6 +2155,140 @@ mod tests {
         }
     }
 
+    // ────────────────────────────────────────────────────────────────────
+    // Catalog schema version tests
+    // ────────────────────────────────────────────────────────────────────
+
+    const CATALOG_ENTRY: &str = r#"{
+        "name": "org/test-7b",
+        "provider": "org",
+        "parameter_count": "7B",
+        "min_ram_gb": 4.0,
+        "recommended_ram_gb": 8.0,
+        "min_vram_gb": 4.0,
+        "quantization": "Q4_K_M",
+        "context_length": 8192,
+        "use_case": "General"
+    }"#;
+
+    #[test]
+    fn test_moe_fields_inferred_from_expert_shorthand() {
+        let entry = CATALOG_ENTRY
+            .replace("org/test-7b", "org/test-8x7b")
+            .replace(r#""7B""#, r#""8x7B""#);
+        let db = ModelDatabase::from_json(&format!("[{}]", entry)).unwrap();
+        let model = &db.get_all_models()[0];
+        assert!(model.is_moe);
+        assert_eq!(model.num_experts, Some(8));
+        assert_eq!(model.active_experts, Some(2));
+        assert_eq!(model.params_b(), 56.0);
+        assert_eq!(model.active_params_b(), Some(14.0));
+
+        // Catalog figures win over the shorthand estimate
+        let entry = entry.replace(
+            r#""use_case": "General""#,
+            r#""use_case": "General", "active_parameters": 12900000000"#,
+        );
+        let db = ModelDatabase::from_json(&format!("[{}]", entry)).unwrap();
+        assert_eq!(db.get_all_models()[0].active_params_b(), Some(12.9));
+
+        let db = ModelDatabase::from_json(&format!("[{}]", CATALOG_ENTRY)).unwrap();
+        assert!(!db.get_all_models()[0].is_moe);
+    }
+
+    #[test]
+    fn test_catalog_overrides_patch_add_and_hide() {
+        let base = CATALOG_ENTRY.replace("org/test-7b", "org/base-3b");
+        let mut db = ModelDatabase::from_json(&format!("[{}, {}]", CATALOG_ENTRY, base)).unwrap();
+        let overrides = parse_catalog_overrides(&format!(
+            r#"// team overrides
+            {{"models": [
+                {{"name": "ORG/test-7b", "min_ram_gb": 5.5}},
+                {{"name": "org/base-3b", "hidden": true}},
+                {},
+                # typo'd name
+                {{"name": "org/missing", "hidden": true}}
+            ]}}"#,
+            CATALOG_ENTRY.replace("org/test-7b", "team/new-1b")
+        ))
+        .unwrap();
+
+        let errors = db.apply_overrides(&overrides);
+        assert_eq!(errors.len(), 1, "{errors:?}");
+        assert!(errors[0].starts_with("org/missing"));
+
+        let names: Vec<&str> = db
+            .get_all_models()
+            .iter()
+            .map(|m| m.name.as_str())
+            .collect();
+        assert_eq!(names, vec!["org/test-7b", "team/new-1b"]);
+        let patched = &db.get_all_models()[0];
+        assert_eq!(patched.min_ram_gb, 5.5);
+        assert_eq!(patched.quantization, "Q4_K_M");
+    }
+
+    #[test]
+    fn test_catalog_overrides_report_bad_entries() {
+        let mut db = ModelDatabase::from_json(&format!("[{}]", CATALOG_ENTRY)).unwrap();
+        let overrides = parse_catalog_overrides(
+            r#"[{"name": "org/test-7b", "min_ram_gb": "lots"}, {"name": "team/partial", "min_ram_gb": 2.0}]"#,
+        )
+        .unwrap();
+        let errors = db.apply_overrides(&overrides);
+        assert_eq!(errors.len(), 2, "{errors:?}");
+        assert_eq!(db.get_all_models()[0].min_ram_gb, 4.0);
+        assert_eq!(db.get_all_models().len(), 1);
+
+        assert!(parse_catalog_overrides(r#"[{"min_ram_gb": 1.0}]"#).is_err());
+    }
+
+    #[test]
+    fn test_catalog_legacy_array_is_accepted() {
+        let db = ModelDatabase::from_json(&format!("[{}]", CATALOG_ENTRY)).unwrap();
+        assert_eq!(db.get_all_models().len(), 1);
+    }
+
+    #[test]
+    fn test_catalog_versioned_object_is_accepted() {
+        let json = format!(r#"{{"schema_version": 1, "models": [{}]}}"#, CATALOG_ENTRY);
+        let db = ModelDatabase::from_json(&json).unwrap();
+        assert_eq!(db.get_all_models()[0].name, "org/test-7b");
+    }
+
+    #[test]
+    fn test_catalog_newer_schema_is_rejected_with_range() {
+        let json = format!(
+            r#"{{"schema_version": {}, "models": []}}"#,
+            MAX_CATALOG_SCHEMA_VERSION + 1
+        );
+        let err = ModelDatabase::from_json(&json).err().unwrap();
+        assert!(err.contains("newer"), "{err}");
+        assert!(
+            err.contains(&format!(
+                "{}..={}",
+                MIN_CATALOG_SCHEMA_VERSION, MAX_CATALOG_SCHEMA_VERSION
+            )),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn test_catalog_missing_schema_version_is_rejected() {
+        let err = ModelDatabase::from_json(r#"{"models": []}"#).err().unwrap();
+        assert!(err.contains("schema_version"), "{err}");
+    }
+
+    #[test]
+    fn test_embedded_catalog_is_versioned() {
+        let value: serde_json::Value = serde_json::from_str(HF_MODELS_JSON).unwrap();
+        let version = value["schema_version"].as_u64().expect("schema_version");
+        assert!(
+            (MIN_CATALOG_SCHEMA_VERSION as u64..=MAX_CATALOG_SCHEMA_VERSION as u64)
+                .contains(&version)
+        );
+    }
+
     #[test]
     fn test_catalog_has_significant_gguf_coverage() {
         let db = ModelDatabase::new();
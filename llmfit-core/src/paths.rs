@@ -0,0 +1,21 @@
+//! Where llmfit keeps its files on disk.
+
+use std::path::PathBuf;
+
+/// `~/.config/llmfit`: settings, caches and saved TUI state all live here.
+/// `None` when neither `HOME` nor `USERPROFILE` is set.
+pub fn config_dir() -> Option<PathBuf> {
+    let home = std::env::var("HOME")
+        .or_else(|_| std::env::var("USERPROFILE"))
+        .ok()?;
+    Some(PathBuf::from(home).join(".config").join("llmfit"))
+}
+
+/// `~/.cache/llmfit`: data that can be fetched again, such as Hub responses.
+/// `None` when neither `HOME` nor `USERPROFILE` is set.
+pub fn cache_dir() -> Option<PathBuf> {
+    let home = std::env::var("HOME")
+        .or_else(|_| std::env::var("USERPROFILE"))
+        .ok()?;
+    Some(PathBuf::from(home).join(".cache").join("llmfit"))
+}
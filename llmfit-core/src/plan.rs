@@ -1,6 +1,6 @@
-use crate::fit::{FitLevel, RunMode};
-use crate::hardware::{GpuBackend, SystemSpecs};
-use crate::models::{LlmModel, quant_speed_multiplier};
+use crate::fit::{FitLevel, FitOptions, ModelFit, RunMode};
+use crate::hardware::{GpuBackend, GpuInfo, SystemSpecs};
+use crate::models::{LlmModel, QuantTable, quant_speed_multiplier};
 
 const SUPPORTED_QUANTS: &[&str] = &[
     "F32",
@@ -104,7 +104,7 @@ pub struct PlanEstimate {
     pub upgrade_deltas: Vec<UpgradeDelta>,
 }
 
-pub fn normalize_quant(quant: &str) -> Option<String> {
+pub fn normalize_quant(quant: &str, quants: &QuantTable) -> Option<String> {
     let trimmed = quant.trim();
     if trimmed.is_empty() {
         return None;
@@ -136,7 +136,7 @@ pub fn normalize_quant(quant: &str) -> Option<String> {
     if SUPPORTED_QUANTS.contains(&upper.as_str()) {
         Some(upper)
     } else {
-        None
+        quants.known_name(trimmed)
     }
 }
 
@@ -146,8 +146,9 @@ fn estimate_tps(
     backend: GpuBackend,
     path: PlanRunPath,
     cpu_cores: usize,
+    quants: &QuantTable,
 ) -> f64 {
-    estimate_tps_with_gpu(model, quant, backend, path, cpu_cores, None)
+    estimate_tps_with_gpu(model, quant, backend, path, cpu_cores, None, quants)
 }
 
 /// Bandwidth-aware tok/s estimation (mirrors fit.rs logic).
@@ -160,9 +161,9 @@ fn estimate_tps_with_gpu(
     path: PlanRunPath,
     cpu_cores: usize,
     gpu_name: Option<&str>,
+    quants: &QuantTable,
 ) -> f64 {
     use crate::hardware::gpu_memory_bandwidth_gbps;
-    use crate::models::quant_bytes_per_param;
 
     let params = model.params_b().max(0.1);
 
@@ -170,7 +171,7 @@ fn estimate_tps_with_gpu(
     if path != PlanRunPath::CpuOnly {
         if let Some(name) = gpu_name {
             if let Some(bw) = gpu_memory_bandwidth_gbps(name) {
-                let model_gb = params * quant_bytes_per_param(quant);
+                let model_gb = params * quants.bpp(quant);
                 let efficiency = 0.55;
                 let raw_tps = (bw / model_gb) * efficiency;
 
@@ -259,13 +260,14 @@ fn minimum_cores_for_target(
     backend: GpuBackend,
     path: PlanRunPath,
     target_tps: Option<f64>,
+    quants: &QuantTable,
 ) -> Option<usize> {
     let Some(target) = target_tps else {
         return Some(4);
     };
 
     for cores in 1..=64 {
-        let tps = estimate_tps(model, quant, backend, path, cores);
+        let tps = estimate_tps(model, quant, backend, path, cores, quants);
         if tps >= target {
             return Some(cores);
         }
@@ -288,8 +290,9 @@ fn evaluate_current(
     context: u32,
     target_tps: Option<f64>,
     system: &SystemSpecs,
+    quants: &QuantTable,
 ) -> PlanCurrentStatus {
-    let model_mem = model.estimate_memory_gb(quant, context);
+    let model_mem = model.estimate_memory_gb(quant, context, quants);
     let gpu_vram = system
         .total_gpu_vram_gb
         .or(system.gpu_vram_gb)
@@ -312,6 +315,7 @@ fn evaluate_current(
             PlanRunPath::Gpu,
             system.total_cpu_cores,
             gpu_name,
+            quants,
         );
         if target_tps.is_none_or(|t| gpu_tps >= t) {
             candidates.push((gpu_fit, PlanRunPath::Gpu, gpu_tps));
@@ -331,6 +335,7 @@ fn evaluate_current(
                 PlanRunPath::CpuOffload,
                 system.total_cpu_cores,
                 gpu_name,
+                quants,
             );
             if target_tps.is_none_or(|t| offload_tps >= t) {
                 candidates.push((offload_fit, PlanRunPath::CpuOffload, offload_tps));
@@ -350,6 +355,7 @@ fn evaluate_current(
         system.backend,
         PlanRunPath::CpuOnly,
         system.total_cpu_cores,
+        quants,
     );
     if target_tps.is_none_or(|t| cpu_tps >= t) {
         candidates.push((cpu_fit, PlanRunPath::CpuOnly, cpu_tps));
@@ -394,12 +400,14 @@ fn build_path_estimate(
     target_tps: Option<f64>,
     path: PlanRunPath,
     system: &SystemSpecs,
+    quants: &QuantTable,
 ) -> PathEstimate {
-    let model_mem = model.estimate_memory_gb(quant, context);
+    let model_mem = model.estimate_memory_gb(quant, context, quants);
     let backend = default_gpu_backend(system);
     let mut notes = vec![];
 
-    let min_cores = match minimum_cores_for_target(model, quant, backend, path, target_tps) {
+    let min_cores = match minimum_cores_for_target(model, quant, backend, path, target_tps, quants)
+    {
         Some(c) => c,
         None => {
             return PathEstimate {
@@ -426,7 +434,8 @@ fn build_path_estimate(
             let rec_vram = model.recommended_ram_gb.max(model_mem * 1.2);
             let min_ram = (model_mem * 0.2).max(8.0);
             let rec_ram = (min_ram * 1.25).max(12.0);
-            let tps = estimate_tps_with_gpu(model, quant, backend, path, min_cores, gpu_name);
+            let tps =
+                estimate_tps_with_gpu(model, quant, backend, path, min_cores, gpu_name, quants);
 
             let fit = fit_level_for(path, min_vram, min_vram, model.recommended_ram_gb);
             notes.push(
@@ -469,7 +478,8 @@ fn build_path_estimate(
             let min_ram = model_mem;
             let rec_ram = model_mem * 1.2;
             let fit = fit_level_for(path, min_ram, min_ram, model.recommended_ram_gb);
-            let tps = estimate_tps_with_gpu(model, quant, backend, path, min_cores, gpu_name);
+            let tps =
+                estimate_tps_with_gpu(model, quant, backend, path, min_cores, gpu_name, quants);
             notes.push("RAM is the primary memory pool for CPU offload".to_string());
 
             PathEstimate {
@@ -494,7 +504,7 @@ fn build_path_estimate(
             let min_ram = model_mem;
             let rec_ram = model_mem * 1.2;
             let fit = fit_level_for(path, min_ram, min_ram, model.recommended_ram_gb);
-            let tps = estimate_tps(model, quant, GpuBackend::CpuX86, path, min_cores);
+            let tps = estimate_tps(model, quant, GpuBackend::CpuX86, path, min_cores, quants);
             notes.push(
                 "CPU-only fit is always capped at Marginal in current heuristics".to_string(),
             );
@@ -524,6 +534,7 @@ pub fn estimate_model_plan(
     model: &LlmModel,
     request: &PlanRequest,
     system: &SystemSpecs,
+    quants: &QuantTable,
 ) -> Result<PlanEstimate, String> {
     if request.context == 0 {
         return Err("--context must be greater than 0".to_string());
@@ -535,7 +546,7 @@ pub fn estimate_model_plan(
     }
 
     let quant = if let Some(ref q) = request.quant {
-        normalize_quant(q).ok_or_else(|| format!("Unsupported quantization '{}'.", q))?
+        normalize_quant(q, quants).ok_or_else(|| format!("Unsupported quantization '{}'.", q))?
     } else {
         model.quantization.clone()
     };
@@ -549,6 +560,7 @@ pub fn estimate_model_plan(
             request.target_tps,
             PlanRunPath::Gpu,
             system,
+            quants,
         ),
         build_path_estimate(
             model,
@@ -557,6 +569,7 @@ pub fn estimate_model_plan(
             request.target_tps,
             PlanRunPath::CpuOffload,
             system,
+            quants,
         ),
         build_path_estimate(
             model,
@@ -565,10 +578,11 @@ pub fn estimate_model_plan(
             request.target_tps,
             PlanRunPath::CpuOnly,
             system,
+            quants,
         ),
     ];
 
-    let current = evaluate_current(model, &quant, context, request.target_tps, system);
+    let current = evaluate_current(model, &quant, context, request.target_tps, system, quants);
 
     let preferred = run_paths
         .iter()
@@ -668,6 +682,166 @@ pub fn estimate_model_plan(
     })
 }
 
+/// A hypothetical hardware change for the what-if upgrade advisor.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct UpgradeScenario {
+    pub label: String,
+    pub add_vram_gb: f64,
+    pub add_ram_gb: f64,
+}
+
+impl UpgradeScenario {
+    fn new(label: &str, add_vram_gb: f64, add_ram_gb: f64) -> Self {
+        UpgradeScenario {
+            label: label.to_string(),
+            add_vram_gb,
+            add_ram_gb,
+        }
+    }
+
+    /// The specs this machine would have after the upgrade.
+    ///
+    /// Extra VRAM goes to the primary GPU (a discrete card is assumed when
+    /// none was detected). On unified-memory systems VRAM and RAM are the
+    /// same pool, so either kind of upgrade grows both.
+    pub fn apply(&self, system: &SystemSpecs) -> SystemSpecs {
+        let mut specs = system.clone();
+        if specs.unified_memory {
+            let add = self.add_vram_gb + self.add_ram_gb;
+            specs.total_ram_gb += add;
+            specs.available_ram_gb += add;
+            specs.gpu_vram_gb = specs.gpu_vram_gb.map(|v| v + add);
+            specs.total_gpu_vram_gb = specs.total_gpu_vram_gb.map(|v| v + add);
+            for gpu in specs.gpus.iter_mut().filter(|g| g.unified_memory) {
+                gpu.vram_gb = gpu.vram_gb.map(|v| v + add);
+            }
+            return specs;
+        }
+
+        specs.total_ram_gb += self.add_ram_gb;
+        specs.available_ram_gb += self.add_ram_gb;
+        if self.add_vram_gb > 0.0 {
+            if specs.has_gpu {
+                let per_card = specs.gpu_vram_gb.unwrap_or(0.0) + self.add_vram_gb;
+                let count = specs.gpu_count.max(1);
+                if let Some(gpu) = specs.gpus.first_mut() {
+                    gpu.vram_gb = Some(per_card);
+                }
+                specs.gpu_vram_gb = Some(per_card);
+                specs.total_gpu_vram_gb = Some(per_card * count as f64);
+            } else {
+                specs.gpus.push(GpuInfo {
+                    name: "Hypothetical GPU".to_string(),
+                    vram_gb: Some(self.add_vram_gb),
+                    backend: GpuBackend::Cuda,
+                    count: 1,
+                    unified_memory: false,
+                });
+                specs.has_gpu = true;
+                specs.gpu_name = Some("Hypothetical GPU".to_string());
+                specs.gpu_count = 1;
+                specs.backend = GpuBackend::Cuda;
+                specs.gpu_vram_gb = Some(self.add_vram_gb);
+                specs.total_gpu_vram_gb = Some(self.add_vram_gb);
+            }
+        }
+        specs
+    }
+}
+
+/// The scenarios the advisor tries by default: +16 GB VRAM, +24 GB VRAM
+/// and +32 GB RAM.
+pub fn default_upgrade_scenarios() -> Vec<UpgradeScenario> {
+    vec![
+        UpgradeScenario::new("+16 GB VRAM", 16.0, 0.0),
+        UpgradeScenario::new("+24 GB VRAM", 24.0, 0.0),
+        UpgradeScenario::new("+32 GB RAM", 0.0, 32.0),
+    ]
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct UpgradeOutcome {
+    pub scenario: UpgradeScenario,
+    pub perfect: usize,
+    pub runnable: usize,
+    pub perfect_gained: usize,
+    pub runnable_gained: usize,
+    /// Models that don't run today but would after the upgrade, best first.
+    pub unlocks: Vec<String>,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct UpgradeAdvice {
+    pub perfect: usize,
+    pub runnable: usize,
+    pub outcomes: Vec<UpgradeOutcome>,
+}
+
+/// Re-run fits against each scenario and report how many models move to
+/// Perfect or become runnable, with up to `max_unlocks` notable unlocks.
+pub fn advise_upgrades(
+    models: &[LlmModel],
+    system: &SystemSpecs,
+    options: &FitOptions,
+    scenarios: &[UpgradeScenario],
+    max_unlocks: usize,
+) -> UpgradeAdvice {
+    let baseline = crate::fit::analyze_compatible(models, system, options);
+    let baseline_levels: std::collections::HashMap<&str, FitLevel> = baseline
+        .iter()
+        .map(|f| (f.model.name.as_str(), f.fit_level))
+        .collect();
+    let runs_today = |name: &str| {
+        baseline_levels
+            .get(name)
+            .is_some_and(|level| *level != FitLevel::TooTight)
+    };
+    let (perfect, runnable) = count_fits(&baseline);
+
+    let outcomes = scenarios
+        .iter()
+        .map(|scenario| {
+            let upgraded = scenario.apply(system);
+            let fits = crate::fit::rank_models_by_fit(crate::fit::analyze_compatible(
+                models, &upgraded, options,
+            ));
+            let (up_perfect, up_runnable) = count_fits(&fits);
+            let unlocks = fits
+                .iter()
+                .filter(|f| f.fit_level != FitLevel::TooTight && !runs_today(&f.model.name))
+                .take(max_unlocks)
+                .map(|f| f.model.name.clone())
+                .collect();
+            UpgradeOutcome {
+                scenario: scenario.clone(),
+                perfect: up_perfect,
+                runnable: up_runnable,
+                perfect_gained: up_perfect.saturating_sub(perfect),
+                runnable_gained: up_runnable.saturating_sub(runnable),
+                unlocks,
+            }
+        })
+        .collect();
+
+    UpgradeAdvice {
+        perfect,
+        runnable,
+        outcomes,
+    }
+}
+
+fn count_fits(fits: &[ModelFit]) -> (usize, usize) {
+    let perfect = fits
+        .iter()
+        .filter(|f| f.fit_level == FitLevel::Perfect)
+        .count();
+    let runnable = fits
+        .iter()
+        .filter(|f| f.fit_level != FitLevel::TooTight)
+        .count();
+    (perfect, runnable)
+}
+
 pub fn resolve_model_selector<'a>(
     models: &'a [LlmModel],
     selector: &str,
@@ -731,7 +905,12 @@ mod tests {
             release_date: None,
             gguf_sources: vec![],
             capabilities: vec![],
+            tags: vec![],
             format: crate::models::ModelFormat::default(),
+            num_layers: None,
+            kv_hidden_size: None,
+            hf_downloads: None,
+            gguf_file_sizes: Default::default(),
         }
     }
 
@@ -749,14 +928,91 @@ mod tests {
             unified_memory: false,
             backend: GpuBackend::Cuda,
             gpus: vec![],
+            memory_bandwidth_gbps: None,
+            reserved_ram_gb: 0.0,
+            headroom_gb: 0.0,
+            gpu_wired_limit_gb: None,
+            swap_total_gb: 0.0,
+            swap_used_gb: 0.0,
+            process_mem_limit_gb: None,
+            driver_version: None,
+            cuda_version: None,
+            cpu_features: crate::hardware::CpuFeatures::default(),
+            has_npu: false,
+            npu_name: None,
+            loaded_from: None,
+            hypothetical: false,
+            free_disk_gb: None,
         }
     }
 
+    #[test]
+    fn test_advise_upgrades_reports_unlocks() {
+        let small = test_model();
+        let big = LlmModel {
+            name: "Big-Test-70B".to_string(),
+            parameter_count: "70B".to_string(),
+            parameters_raw: Some(70_000_000_000),
+            min_ram_gb: 40.0,
+            recommended_ram_gb: 48.0,
+            min_vram_gb: Some(40.0),
+            ..test_model()
+        };
+        let scenarios = default_upgrade_scenarios();
+        let advice = advise_upgrades(
+            &[small, big],
+            &test_specs(),
+            &FitOptions::with_context_limit(Some(4096)),
+            &scenarios,
+            5,
+        );
+        assert_eq!(advice.outcomes.len(), 3);
+
+        assert_eq!(advice.runnable, 1);
+        for outcome in &advice.outcomes {
+            assert_eq!(outcome.runnable_gained, 1);
+            assert_eq!(outcome.unlocks, vec!["Big-Test-70B".to_string()]);
+        }
+    }
+
+    #[test]
+    fn test_upgrade_scenario_apply() {
+        let specs = test_specs();
+        let vram = UpgradeScenario::new("+16 GB VRAM", 16.0, 0.0).apply(&specs);
+        assert_eq!(vram.gpu_vram_gb, Some(28.0));
+        assert_eq!(vram.total_ram_gb, specs.total_ram_gb);
+
+        let ram = UpgradeScenario::new("+32 GB RAM", 0.0, 32.0).apply(&specs);
+        assert_eq!(ram.available_ram_gb, 56.0);
+        assert_eq!(ram.gpu_vram_gb, Some(12.0));
+
+        // Unified memory grows RAM and the GPU pool together
+        let mut mac = specs.clone();
+        mac.unified_memory = true;
+        let mac = UpgradeScenario::new("+32 GB RAM", 0.0, 32.0).apply(&mac);
+        assert_eq!(mac.gpu_vram_gb, Some(44.0));
+
+        // CPU-only machine gets a hypothetical card
+        let mut cpu = specs;
+        cpu.has_gpu = false;
+        cpu.gpu_vram_gb = None;
+        cpu.total_gpu_vram_gb = None;
+        let cpu = UpgradeScenario::new("+16 GB VRAM", 16.0, 0.0).apply(&cpu);
+        assert!(cpu.has_gpu);
+        assert_eq!(cpu.total_gpu_vram_gb, Some(16.0));
+    }
+
     #[test]
     fn test_normalize_quant() {
-        assert_eq!(normalize_quant("q4_k_m"), Some("Q4_K_M".to_string()));
-        assert_eq!(normalize_quant("mlx-4bit"), Some("mlx-4bit".to_string()));
-        assert_eq!(normalize_quant("bad"), None);
+        assert_eq!(
+            normalize_quant("q4_k_m", QuantTable::builtin()),
+            Some("Q4_K_M".to_string())
+        );
+        assert_eq!(
+            normalize_quant("mlx-4bit", QuantTable::builtin()),
+            Some("mlx-4bit".to_string())
+        );
+        assert_eq!(normalize_quant("bad", QuantTable::builtin()), None);
     }
 
     #[test]
@@ -766,7 +1022,7 @@ mod tests {
                 continue; // handled by case-insensitive paths
             }
             assert_eq!(
-                normalize_quant(&q.to_lowercase()),
+                normalize_quant(&q.to_lowercase(), QuantTable::builtin()),
                 Some(q.to_string()),
                 "lowercase '{}' should normalize",
                 q
@@ -776,9 +1032,12 @@ mod tests {
 
     #[test]
     fn test_normalize_quant_whitespace_handling() {
-        assert_eq!(normalize_quant("  q4_k_m  "), Some("Q4_K_M".to_string()));
-        assert_eq!(normalize_quant(""), None);
-        assert_eq!(normalize_quant("   "), None);
+        assert_eq!(
+            normalize_quant("  q4_k_m  ", QuantTable::builtin()),
+            Some("Q4_K_M".to_string())
+        );
+        assert_eq!(normalize_quant("", QuantTable::builtin()), None);
+        assert_eq!(normalize_quant("   ", QuantTable::builtin()), None);
     }
 
     #[test]
@@ -788,8 +1047,8 @@ mod tests {
             quant: Some("Q4_K_M".to_string()),
             target_tps: Some(8.0),
         };
-        let plan =
-            estimate_model_plan(&test_model(), &req, &test_specs()).expect("plan should build");
+        let plan = estimate_model_plan(&test_model(), &req, &test_specs(), QuantTable::builtin())
+            .expect("plan should build");
         assert_eq!(plan.quantization, "Q4_K_M");
         assert!(!plan.run_paths.is_empty());
         assert!(plan.minimum.ram_gb > 0.0);
@@ -802,7 +1061,7 @@ mod tests {
             quant: None,
             target_tps: None,
         };
-        let result = estimate_model_plan(&test_model(), &req, &test_specs());
+        let result = estimate_model_plan(&test_model(), &req, &test_specs(), QuantTable::builtin());
         assert!(result.is_err());
         assert!(
             result
@@ -818,7 +1077,7 @@ mod tests {
             quant: None,
             target_tps: Some(-5.0),
         };
-        let result = estimate_model_plan(&test_model(), &req, &test_specs());
+        let result = estimate_model_plan(&test_model(), &req, &test_specs(), QuantTable::builtin());
         assert!(result.is_err());
         assert!(
             result
@@ -834,7 +1093,7 @@ mod tests {
             quant: Some("INVALID_QUANT".to_string()),
             target_tps: None,
         };
-        let result = estimate_model_plan(&test_model(), &req, &test_specs());
+        let result = estimate_model_plan(&test_model(), &req, &test_specs(), QuantTable::builtin());
         assert!(result.is_err());
         assert!(result.unwrap_err().contains("Unsupported quantization"));
     }
@@ -846,7 +1105,8 @@ mod tests {
             quant: None,
             target_tps: None,
         };
-        let plan = estimate_model_plan(&test_model(), &req, &test_specs()).unwrap();
+        let plan =
+            estimate_model_plan(&test_model(), &req, &test_specs(), QuantTable::builtin()).unwrap();
         assert_eq!(plan.quantization, "Q4_K_M"); // model default
     }
 
@@ -857,7 +1117,8 @@ mod tests {
             quant: None,
             target_tps: None,
         };
-        let plan = estimate_model_plan(&test_model(), &req, &test_specs()).unwrap();
+        let plan =
+            estimate_model_plan(&test_model(), &req, &test_specs(), QuantTable::builtin()).unwrap();
         assert_eq!(plan.run_paths.len(), 3);
         assert_eq!(plan.run_paths[0].path, PlanRunPath::Gpu);
         assert_eq!(plan.run_paths[1].path, PlanRunPath::CpuOffload);
@@ -871,7 +1132,8 @@ mod tests {
             quant: Some("Q4_K_M".to_string()),
             target_tps: None,
         };
-        let plan = estimate_model_plan(&test_model(), &req, &test_specs()).unwrap();
+        let plan =
+            estimate_model_plan(&test_model(), &req, &test_specs(), QuantTable::builtin()).unwrap();
         let gpu_path = &plan.run_paths[0];
         assert!(gpu_path.feasible);
         assert!(gpu_path.minimum.is_some());
@@ -940,13 +1202,21 @@ mod tests {
     #[test]
     fn test_estimate_tps_gpu_faster_than_cpu() {
         let model = test_model();
-        let gpu_tps = estimate_tps(&model, "Q4_K_M", GpuBackend::Cuda, PlanRunPath::Gpu, 8);
+        let gpu_tps = estimate_tps(
+            &model,
+            "Q4_K_M",
+            GpuBackend::Cuda,
+            PlanRunPath::Gpu,
+            8,
+            QuantTable::builtin(),
+        );
         let cpu_tps = estimate_tps(
             &model,
             "Q4_K_M",
             GpuBackend::CpuX86,
             PlanRunPath::CpuOnly,
             8,
+            QuantTable::builtin(),
         );
         assert!(gpu_tps > cpu_tps);
     }
@@ -954,13 +1224,21 @@ mod tests {
     #[test]
     fn test_estimate_tps_cpu_offload_slower_than_gpu() {
         let model = test_model();
-        let gpu_tps = estimate_tps(&model, "Q4_K_M", GpuBackend::Cuda, PlanRunPath::Gpu, 8);
+        let gpu_tps = estimate_tps(
+            &model,
+            "Q4_K_M",
+            GpuBackend::Cuda,
+            PlanRunPath::Gpu,
+            8,
+            QuantTable::builtin(),
+        );
         let offload_tps = estimate_tps(
             &model,
             "Q4_K_M",
             GpuBackend::Cuda,
             PlanRunPath::CpuOffload,
             8,
+            QuantTable::builtin(),
         );
         assert!(gpu_tps > offload_tps);
     }
@@ -968,8 +1246,22 @@ mod tests {
     #[test]
     fn test_estimate_tps_more_cores_helps() {
         let model = test_model();
-        let tps_4 = estimate_tps(&model, "Q4_K_M", GpuBackend::Cuda, PlanRunPath::Gpu, 4);
-        let tps_16 = estimate_tps(&model, "Q4_K_M", GpuBackend::Cuda, PlanRunPath::Gpu, 16);
+        let tps_4 = estimate_tps(
+            &model,
+            "Q4_K_M",
+            GpuBackend::Cuda,
+            PlanRunPath::Gpu,
+            4,
+            QuantTable::builtin(),
+        );
+        let tps_16 = estimate_tps(
+            &model,
+            "Q4_K_M",
+            GpuBackend::Cuda,
+            PlanRunPath::Gpu,
+            16,
+            QuantTable::builtin(),
+        );
         assert!(tps_16 >= tps_4);
     }
 
@@ -983,6 +1275,7 @@ mod tests {
             PlanRunPath::Gpu,
             8,
             Some("NVIDIA RTX 4090"),
+            QuantTable::builtin(),
         );
         let fallback_tps = estimate_tps_with_gpu(
             &model,
@@ -991,6 +1284,7 @@ mod tests {
             PlanRunPath::Gpu,
             8,
             None,
+            QuantTable::builtin(),
         );
         // Known GPU should give a different (bandwidth-based) estimate
         assert!((bw_tps - fallback_tps).abs() > 0.01);
@@ -1001,8 +1295,14 @@ mod tests {
     #[test]
     fn test_minimum_cores_no_target_returns_default() {
         let model = test_model();
-        let cores =
-            minimum_cores_for_target(&model, "Q4_K_M", GpuBackend::Cuda, PlanRunPath::Gpu, None);
+        let cores = minimum_cores_for_target(
+            &model,
+            "Q4_K_M",
+            GpuBackend::Cuda,
+            PlanRunPath::Gpu,
+            None,
+            QuantTable::builtin(),
+        );
         assert_eq!(cores, Some(4));
     }
 
@@ -1015,6 +1315,7 @@ mod tests {
             GpuBackend::Cuda,
             PlanRunPath::Gpu,
             Some(5.0),
+            QuantTable::builtin(),
         );
         assert!(cores.is_some());
         assert!(cores.unwrap() >= 1);
@@ -1029,6 +1330,7 @@ mod tests {
             GpuBackend::CpuX86,
             PlanRunPath::CpuOnly,
             Some(999999.0),
+            QuantTable::builtin(),
         );
         assert!(cores.is_none());
     }
@@ -1054,7 +1356,7 @@ mod tests {
     fn test_evaluate_current_with_gpu() {
         let model = test_model();
         let specs = test_specs();
-        let status = evaluate_current(&model, "Q4_K_M", 4096, None, &specs);
+        let status = evaluate_current(&model, "Q4_K_M", 4096, None, &specs, QuantTable::builtin());
         assert!(status.estimated_tps > 0.0);
         // With 12GB VRAM and 7B model, GPU should be preferred
         assert_eq!(status.run_mode, RunMode::Gpu);
@@ -1067,7 +1369,7 @@ mod tests {
         specs.has_gpu = false;
         specs.gpu_vram_gb = None;
         specs.total_gpu_vram_gb = None;
-        let status = evaluate_current(&model, "Q4_K_M", 4096, None, &specs);
+        let status = evaluate_current(&model, "Q4_K_M", 4096, None, &specs, QuantTable::builtin());
         assert_eq!(status.run_mode, RunMode::CpuOnly);
         assert!(status.estimated_tps > 0.0);
     }
@@ -1080,7 +1382,14 @@ mod tests {
         specs.gpu_vram_gb = None;
         specs.total_gpu_vram_gb = None;
         specs.available_ram_gb = 0.5; // too small for the model
-        let status = evaluate_current(&model, "Q4_K_M", 4096, Some(999999.0), &specs);
+        let status = evaluate_current(
+            &model,
+            "Q4_K_M",
+            4096,
+            Some(999999.0),
+            &specs,
+            QuantTable::builtin(),
+        );
         assert_eq!(status.fit_level, FitLevel::TooTight);
     }
 
@@ -1090,7 +1399,15 @@ mod tests {
     fn test_build_path_estimate_gpu() {
         let model = test_model();
         let specs = test_specs();
-        let estimate = build_path_estimate(&model, "Q4_K_M", 4096, None, PlanRunPath::Gpu, &specs);
+        let estimate = build_path_estimate(
+            &model,
+            "Q4_K_M",
+            4096,
+            None,
+            PlanRunPath::Gpu,
+            &specs,
+            QuantTable::builtin(),
+        );
         assert!(estimate.feasible);
         let min = estimate.minimum.unwrap();
         assert!(min.vram_gb.unwrap() > 0.0);
@@ -1109,6 +1426,7 @@ mod tests {
             None,
             PlanRunPath::CpuOffload,
             &specs,
+            QuantTable::builtin(),
         );
         assert!(!estimate.feasible);
         assert!(estimate.notes.iter().any(|n| n.contains("unified-memory")));
@@ -1118,8 +1436,15 @@ mod tests {
     fn test_build_path_estimate_cpu_only_no_vram() {
         let model = test_model();
         let specs = test_specs();
-        let estimate =
-            build_path_estimate(&model, "Q4_K_M", 4096, None, PlanRunPath::CpuOnly, &specs);
+        let estimate = build_path_estimate(
+            &model,
+            "Q4_K_M",
+            4096,
+            None,
+            PlanRunPath::CpuOnly,
+            &specs,
+            QuantTable::builtin(),
+        );
         assert!(estimate.feasible);
         assert!(estimate.minimum.as_ref().unwrap().vram_gb.is_none());
     }
@@ -1181,16 +1506,31 @@ mod tests {
             quant: Some("Q4_K_M".to_string()),
             target_tps: None,
         };
-        let plan = estimate_model_plan(&model, &req, &specs).unwrap();
+        let plan = estimate_model_plan(&model, &req, &specs, QuantTable::builtin()).unwrap();
         assert!(!plan.upgrade_deltas.is_empty());
     }
 
     #[test]
     fn test_normalize_awq_gptq_quants() {
-        assert_eq!(normalize_quant("awq-4bit"), Some("AWQ-4bit".to_string()));
-        assert_eq!(normalize_quant("AWQ-4BIT"), Some("AWQ-4bit".to_string()));
-        assert_eq!(normalize_quant("awq-8bit"), Some("AWQ-8bit".to_string()));
-        assert_eq!(normalize_quant("gptq-int4"), Some("GPTQ-Int4".to_string()));
-        assert_eq!(normalize_quant("GPTQ-INT8"), Some("GPTQ-Int8".to_string()));
+        assert_eq!(
+            normalize_quant("awq-4bit", QuantTable::builtin()),
+            Some("AWQ-4bit".to_string())
+        );
+        assert_eq!(
+            normalize_quant("AWQ-4BIT", QuantTable::builtin()),
+            Some("AWQ-4bit".to_string())
+        );
+        assert_eq!(
+            normalize_quant("awq-8bit", QuantTable::builtin()),
+            Some("AWQ-8bit".to_string())
+        );
+        assert_eq!(
+            normalize_quant("gptq-int4", QuantTable::builtin()),
+            Some("GPTQ-Int4".to_string())
+        );
+        assert_eq!(
+            normalize_quant("GPTQ-INT8", QuantTable::builtin()),
+            Some("GPTQ-Int8".to_string())
+        );
     }
 }
@@ -3,7 +3,7 @@
 //! Each provider can list locally installed models and pull new ones.
 //! The trait is designed to be extended for vLLM, etc.
 
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::path::PathBuf;
 
 // ---------------------------------------------------------------------------
@@ -153,6 +153,52 @@ impl OllamaProvider {
         (set, count)
     }
 
+    /// Size in bytes of each pulled model, keyed by lowercase tag.
+    pub fn installed_sizes(&self) -> HashMap<String, u64> {
+        let Ok(resp) = ureq::get(&self.api_url("tags"))
+            .config()
+            .timeout_global(Some(std::time::Duration::from_millis(800)))
+            .build()
+            .call()
+        else {
+            return HashMap::new();
+        };
+        let Ok(tags): Result<TagsResponse, _> = resp.into_body().read_json() else {
+            return HashMap::new();
+        };
+        tags.models
+            .into_iter()
+            .map(|m| (m.name.to_lowercase(), m.size))
+            .collect()
+    }
+
+    /// Models the daemon has loaded in memory right now (`/api/ps`), as
+    /// lowercase tags plus their family names, like the installed set.
+    /// Empty when Ollama isn't reachable.
+    pub fn running_models(&self) -> HashSet<String> {
+        let mut set = HashSet::new();
+        let Ok(resp) = ureq::get(&self.api_url("ps"))
+            .config()
+            .timeout_global(Some(std::time::Duration::from_millis(800)))
+            .build()
+            .call()
+        else {
+            return set;
+        };
+        // Same `{"models": [{"name": ...}]}` shape as /api/tags.
+        let Ok(ps): Result<TagsResponse, _> = resp.into_body().read_json() else {
+            return set;
+        };
+        for m in ps.models {
+            let lower = m.name.to_lowercase();
+            set.insert(lower.clone());
+            if let Some(family) = lower.split(':').next() {
+                set.insert(family.to_string());
+            }
+        }
+        set
+    }
+
     /// Best-effort check that a tag exists in Ollama's remote registry.
     /// Uses the local Ollama daemon's `/api/show` resolution path.
     pub fn has_remote_tag(&self, model_tag: &str) -> bool {
@@ -177,6 +223,9 @@ struct TagsResponse {
 struct OllamaModel {
     /// e.g. "llama3.1:8b-instruct-q4_K_M"
     name: String,
+    /// Size on disk in bytes.
+    #[serde(default)]
+    size: u64,
 }
 
 #[derive(serde::Deserialize)]
@@ -532,7 +581,17 @@ pub struct LlamaCppProvider {
 
 impl Default for LlamaCppProvider {
     fn default() -> Self {
-        let models_dir = llamacpp_models_dir();
+        Self::with_models_dir(llamacpp_models_dir())
+    }
+}
+
+impl LlamaCppProvider {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Download to and scan `models_dir` instead of the default directory.
+    pub fn with_models_dir(models_dir: PathBuf) -> Self {
         let llama_cli = find_binary("llama-cli");
         let llama_server = find_binary("llama-server");
         Self {
@@ -541,12 +600,6 @@ impl Default for LlamaCppProvider {
             llama_server,
         }
     }
-}
-
-impl LlamaCppProvider {
-    pub fn new() -> Self {
-        Self::default()
-    }
 
     /// Like `installed_models`, but also returns the true GGUF file count.
     /// The HashSet may have fewer entries than 2*count due to deduplication
@@ -567,6 +620,18 @@ impl LlamaCppProvider {
         (set, count)
     }
 
+    /// Size in bytes of each cached GGUF file, keyed by lowercase file stem.
+    pub fn installed_sizes(&self) -> HashMap<String, u64> {
+        self.list_gguf_files()
+            .into_iter()
+            .filter_map(|path| {
+                let stem = path.file_stem()?.to_str()?.to_lowercase();
+                let size = std::fs::metadata(&path).ok()?.len();
+                Some((stem, size))
+            })
+            .collect()
+    }
+
     /// Return the directory where GGUF models are cached.
     pub fn models_dir(&self) -> &std::path::Path {
         &self.models_dir
@@ -901,8 +966,10 @@ fn parse_repo_gguf_entries(entries: Vec<serde_json::Value>) -> Vec<(String, u64)
         .collect()
 }
 
-/// Default directory for llama.cpp GGUF model cache.
-fn llamacpp_models_dir() -> PathBuf {
+/// Default directory llama.cpp GGUF models are downloaded to and scanned
+/// from: `LLMFIT_MODELS_DIR`, then `~/.cache/llmfit/models`. Callers with
+/// their own directory pass it to [`LlamaCppProvider::with_models_dir`].
+pub fn llamacpp_models_dir() -> PathBuf {
     if let Ok(dir) = std::env::var("LLMFIT_MODELS_DIR") {
         PathBuf::from(dir)
     } else if let Ok(home) = std::env::var("HOME") {
@@ -1832,16 +1899,40 @@ pub fn is_model_installed_llamacpp(hf_name: &str, installed: &HashSet<String>) -
         return true;
     }
 
-    // Check with common suffixes stripped
+    installed
+        .iter()
+        .any(|name| llamacpp_stem_matches(&repo, name))
+}
+
+/// Fuzzy match between an HF repo name (lowercase, without org) and a GGUF
+/// file stem, ignoring common instruct/chat suffixes.
+fn llamacpp_stem_matches(repo: &str, stem: &str) -> bool {
     let stripped = repo
         .replace("-instruct", "")
         .replace("-chat", "")
         .replace("-hf", "")
         .replace("-it", "");
+    stem.contains(repo) || stem.contains(&stripped) || repo.contains(stem)
+}
 
-    installed.iter().any(|name| {
-        name.contains(&repo) || name.contains(&stripped) || repo.contains(name.as_str())
-    })
+/// Total bytes of cached GGUF files for an HF model (all quantizations),
+/// or `None` if nothing matching is on disk.
+pub fn llamacpp_disk_size(hf_name: &str, sizes: &HashMap<String, u64>) -> Option<u64> {
+    let repo = hf_name
+        .split('/')
+        .next_back()
+        .unwrap_or(hf_name)
+        .to_lowercase();
+    let matching: Vec<u64> = sizes
+        .iter()
+        .filter(|(stem, _)| llamacpp_stem_matches(&repo, stem))
+        .map(|(_, &size)| size)
+        .collect();
+    if matching.is_empty() {
+        None
+    } else {
+        Some(matching.iter().sum())
+    }
 }
 
 /// Given an HF model name, return the best GGUF repo to pull from.
@@ -2246,6 +2337,21 @@ pub fn is_model_installed(hf_name: &str, installed: &HashSet<String>) -> bool {
     })
 }
 
+/// Bytes on disk for an HF model pulled via Ollama. Variant tags share
+/// layers, so the largest matching tag is reported rather than the sum.
+pub fn ollama_disk_size(hf_name: &str, sizes: &HashMap<String, u64>) -> Option<u64> {
+    let candidates = hf_name_to_ollama_candidates(hf_name);
+    sizes
+        .iter()
+        .filter(|(name, _)| {
+            candidates
+                .iter()
+                .any(|candidate| ollama_installed_matches_candidate(name, candidate))
+        })
+        .map(|(_, &size)| size)
+        .max()
+}
+
 /// Given an HF model name, return the Ollama tag to use for pulling.
 /// Returns `None` if the model has no known Ollama mapping.
 pub fn ollama_pull_tag(hf_name: &str) -> Option<String> {
@@ -2700,6 +2806,37 @@ mod tests {
         ));
     }
 
+    // ── disk sizes ───────────────────────────────────────────────────
+
+    #[test]
+    fn test_llamacpp_disk_size_sums_quantizations() {
+        let sizes = HashMap::from([
+            ("llama-3.1-8b-instruct-q4_k_m".to_string(), 4_000),
+            ("llama-3.1-8b-instruct-q8_0".to_string(), 8_000),
+            ("qwen2.5-7b-instruct-q4_k_m".to_string(), 5_000),
+        ]);
+        assert_eq!(
+            llamacpp_disk_size("meta-llama/Llama-3.1-8B-Instruct", &sizes),
+            Some(12_000)
+        );
+        assert_eq!(llamacpp_disk_size("google/gemma-2-9b-it", &sizes), None);
+    }
+
+    #[test]
+    fn test_ollama_disk_size_uses_largest_variant() {
+        let tag = ollama_pull_tag("meta-llama/Llama-3.1-8B-Instruct")
+            .expect("llama 3.1 8B has an ollama tag");
+        let sizes = HashMap::from([
+            (tag.clone(), 4_900),
+            (format!("{tag}-instruct-q8_0"), 8_500),
+            ("unrelated:1b".to_string(), 1_000),
+        ]);
+        assert_eq!(
+            ollama_disk_size("meta-llama/Llama-3.1-8B-Instruct", &sizes),
+            Some(8_500)
+        );
+    }
+
     // ── gguf_pull_tag ────────────────────────────────────────────────
 
     #[test]
@@ -0,0 +1,44 @@
+use llmfit_core::hardware::SystemSpecs;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+
+/// A `--bench` result, tied to the machine it was measured on.
+#[derive(Debug, Serialize, Deserialize)]
+struct CachedBench {
+    cpu_name: String,
+    total_ram_gb: f64,
+    mem_bandwidth_gbps: f64,
+}
+
+/// Path to the cached benchmark: ~/.config/llmfit/bench.json
+fn config_path() -> Option<PathBuf> {
+    Some(llmfit_core::config_dir()?.join("bench.json"))
+}
+
+/// The cached bandwidth, if it was measured on this CPU with this much RAM.
+/// A CPU or RAM change invalidates it.
+pub fn load(specs: &SystemSpecs) -> Option<f64> {
+    let text = fs::read_to_string(config_path()?).ok()?;
+    let cached: CachedBench = serde_json::from_str(&text).ok()?;
+    (cached.cpu_name == specs.cpu_name
+        && (cached.total_ram_gb - specs.total_ram_gb).abs() < 1.0
+        && cached.mem_bandwidth_gbps > 0.0)
+        .then_some(cached.mem_bandwidth_gbps)
+}
+
+pub fn save(specs: &SystemSpecs, gbps: f64) {
+    if let Some(path) = config_path() {
+        if let Some(parent) = path.parent() {
+            let _ = fs::create_dir_all(parent);
+        }
+        let cached = CachedBench {
+            cpu_name: specs.cpu_name.clone(),
+            total_ram_gb: specs.total_ram_gb,
+            mem_bandwidth_gbps: gbps,
+        };
+        if let Ok(json) = serde_json::to_string_pretty(&cached) {
+            let _ = fs::write(&path, json + "\n");
+        }
+    }
+}
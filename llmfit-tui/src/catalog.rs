@@ -0,0 +1,140 @@
+use llmfit_core::catalog::{CatalogSource, ModelCatalog};
+use llmfit_core::models::{CatalogSources, ModelDatabase};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+/// Where this run's catalog comes from; set once by [`set_sources`].
+static SOURCES: OnceLock<CatalogSources> = OnceLock::new();
+
+/// Fix the catalog sources for the rest of the run.
+pub fn set_sources(sources: CatalogSources) {
+    let _ = SOURCES.set(sources);
+}
+
+/// The sources from [`set_sources`], or just the embedded catalog.
+pub fn sources() -> CatalogSources {
+    SOURCES.get().cloned().unwrap_or_default()
+}
+
+/// The catalog built from [`sources`].
+pub fn database() -> ModelDatabase {
+    match SOURCES.get() {
+        Some(sources) => ModelDatabase::from_sources(sources).0,
+        None => ModelDatabase::new(),
+    }
+}
+
+/// Path to the catalog overrides file: `LLMFIT_CATALOG_OVERRIDES`, or
+/// ~/.config/llmfit/catalog.json
+fn config_path() -> Option<PathBuf> {
+    if let Ok(path) = std::env::var("LLMFIT_CATALOG_OVERRIDES") {
+        return Some(PathBuf::from(path));
+    }
+    Some(llmfit_core::config_dir()?.join("catalog.json"))
+}
+
+/// Load catalog overrides to layer on the catalog. A malformed file is
+/// reported and ignored.
+pub fn load(sources: &mut CatalogSources) {
+    let Some(path) = config_path() else {
+        return;
+    };
+    let Ok(text) = fs::read_to_string(&path) else {
+        return;
+    };
+    match llmfit_core::models::parse_catalog_overrides(&text) {
+        Ok(overrides) => sources.overrides = overrides,
+        Err(err) => eprintln!("Warning: ignoring {}: {}", path.display(), err),
+    }
+}
+
+/// Print a warning for every override entry that couldn't be applied.
+pub fn warn_errors() {
+    let Some(sources) = SOURCES.get() else {
+        return;
+    };
+    for err in ModelDatabase::from_sources(sources).1 {
+        log::warn!("catalog override skipped: {}", err);
+        eprintln!("Warning: catalog override skipped: {}", err);
+    }
+}
+
+/// `--catalog-url` outside the TUI: fetch the catalog (or its cached copy
+/// when offline) and use it instead of the embedded one. On failure the
+/// embedded catalog is kept.
+pub fn load_remote(url: &str, sources: &mut CatalogSources) {
+    match ModelCatalog::fetch_remote(url) {
+        Ok(catalog) => {
+            if catalog.source == CatalogSource::Cache {
+                log::warn!("{} unreachable, using cached catalog", url);
+                eprintln!("Warning: {} unreachable, using cached catalog", url);
+            }
+            sources.base = Some(catalog.models);
+        }
+        Err(err) => {
+            log::warn!("{}", err);
+            eprintln!("Warning: {}; using the built-in catalog", err);
+        }
+    }
+}
+
+/// `--catalog-url` in the TUI: start from the cached copy, if any, without
+/// touching the network. The TUI fetches a fresh copy in the background.
+pub fn load_cached_remote(url: &str, sources: &mut CatalogSources) -> Option<ModelCatalog> {
+    let catalog = ModelCatalog::load_cached(url)?;
+    sources.base = Some(catalog.models.clone());
+    Some(catalog)
+}
+
+/// `--scan-dir`: add every GGUF file under `dir` to the catalog as a
+/// "local" model. Files that can't be read are reported and skipped.
+pub fn scan_local(dir: &Path, sources: &mut CatalogSources) {
+    let (models, errors) = llmfit_core::gguf::scan_dir(dir);
+    for (path, err) in errors {
+        log::warn!("skipping {}: {}", path.display(), err);
+        eprintln!("Warning: skipping {}: {}", path.display(), err);
+    }
+    sources.local = models;
+}
+
+/// `--enrich`: fill gaps in the base catalog from the Hugging Face Hub,
+/// with a spinner on stderr while the lookups run.
+pub fn enrich(quiet: bool, sources: &mut CatalogSources) {
+    use std::io::{IsTerminal, Write};
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    let mut models = sources.base_models();
+    let done = AtomicBool::new(false);
+    let show_spinner = !quiet && std::io::stderr().is_terminal();
+    let summary = std::thread::scope(|scope| {
+        if show_spinner {
+            scope.spawn(|| {
+                let frames = ['|', '/', '-', '\\'];
+                let mut i = 0;
+                while !done.load(Ordering::Relaxed) {
+                    eprint!("\r{} Looking up models on Hugging Face...", frames[i % 4]);
+                    let _ = std::io::stderr().flush();
+                    i += 1;
+                    std::thread::sleep(std::time::Duration::from_millis(100));
+                }
+                eprint!("\r{:50}\r", "");
+            });
+        }
+        let summary = llmfit_core::hf_hub::enrich_many(&mut models);
+        done.store(true, Ordering::Relaxed);
+        summary
+    });
+    sources.base = Some(models);
+
+    if summary.rate_limited {
+        log::warn!("Hugging Face rate limit reached, enrichment stopped early");
+        eprintln!("Warning: Hugging Face rate limit reached; set HF_TOKEN or retry later");
+    }
+    if !quiet && summary.attempted > 0 {
+        eprintln!(
+            "Enriched {} of {} models from Hugging Face ({} failed)",
+            summary.enriched, summary.attempted, summary.failed
+        );
+    }
+}
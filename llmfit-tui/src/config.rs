@@ -0,0 +1,87 @@
+//! UI preferences remembered between runs: ~/.config/llmfit/config.toml
+//!
+//! Read at startup and written when the TUI quits, so the provider filter,
+//! fit filter, sort order, favorites, `--headroom` and `--ascii` carry over
+//! to the next launch. `--no-config` skips both. Filters are stored by label, like
+//! saved views, so a config keeps working when the catalog changes.
+
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Config {
+    /// Selected providers; `None` means all of them.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub providers: Option<Vec<String>>,
+    pub fit: String,
+    pub sort: String,
+    pub sort_ascending: bool,
+    /// Used when `--headroom` isn't given.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub headroom_gb: Option<f64>,
+    /// Used when `--ascii` isn't given.
+    pub ascii: bool,
+    /// Pinned model names, sorted.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub favorites: Vec<String>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            providers: None,
+            fit: "All".to_string(),
+            sort: "Score".to_string(),
+            sort_ascending: false,
+            headroom_gb: None,
+            ascii: false,
+            favorites: Vec::new(),
+        }
+    }
+}
+
+impl Config {
+    pub fn from_toml(text: &str) -> Result<Config, String> {
+        toml::from_str(text).map_err(|e| e.message().to_string())
+    }
+
+    pub fn to_toml(&self) -> String {
+        toml::to_string(self).unwrap_or_default()
+    }
+}
+
+/// Path to the config file: ~/.config/llmfit/config.toml
+fn config_path() -> Option<PathBuf> {
+    Some(llmfit_core::config_dir()?.join("config.toml"))
+}
+
+/// Load the saved preferences. A missing file gives the defaults; a
+/// malformed one is reported and ignored.
+pub fn load() -> Config {
+    let Some(path) = config_path() else {
+        return Config::default();
+    };
+    let Ok(text) = fs::read_to_string(&path) else {
+        return Config::default();
+    };
+    match Config::from_toml(&text) {
+        Ok(config) => config,
+        Err(err) => {
+            log::warn!("ignoring {}: {}", path.display(), err);
+            eprintln!("Warning: ignoring {}: {}", path.display(), err);
+            Config::default()
+        }
+    }
+}
+
+/// Save preferences to disk.
+pub fn save(config: &Config) {
+    if let Some(path) = config_path() {
+        if let Some(parent) = path.parent() {
+            let _ = fs::create_dir_all(parent);
+        }
+        let _ = fs::write(&path, config.to_toml());
+    }
+}
@@ -1,8 +1,10 @@
 use colored::*;
-use llmfit_core::fit::{FitLevel, ModelFit};
-use llmfit_core::hardware::SystemSpecs;
+use llmfit_core::fit::{FitLevel, ModelFit, RunMode, memory_deficit_gb, offload_ram_deficit_gb};
+use llmfit_core::hardware::{MemoryUnits, SystemSpecs, fmt_mem};
+use llmfit_core::hf_config::TrainingMemory;
 use llmfit_core::models::LlmModel;
-use llmfit_core::plan::PlanEstimate;
+use llmfit_core::plan::{PlanEstimate, UpgradeAdvice};
+use std::collections::HashMap;
 use tabled::{Table, Tabled, settings::Style};
 
 #[derive(Tabled)]
@@ -77,7 +79,7 @@ pub fn display_model_fits(fits: &[ModelFit]) {
                 status: status_text,
                 name: fit.model.name.clone(),
                 provider: fit.model.provider.clone(),
-                size: fit.model.parameter_count.clone(),
+                size: format!("{} ({:.1}G)", fit.model.parameter_count, fit.weights_gb),
                 score: format!("{:.0}", fit.score),
                 tps: format!("{:.1}", fit.estimated_tps),
                 quant: fit.best_quant.clone(),
@@ -96,11 +98,17 @@ pub fn display_model_fits(fits: &[ModelFit]) {
     );
 }
 
-pub fn display_model_detail(fit: &ModelFit) {
+pub fn display_model_detail(fit: &ModelFit, units: MemoryUnits) {
     println!("\n{}", format!("=== {} ===", fit.model.name).bold().cyan());
     println!();
     println!("{}: {}", "Provider".bold(), fit.model.provider);
-    println!("{}: {}", "Parameters".bold(), fit.model.parameter_count);
+    println!("{}: {}", "Parameters".bold(), fit.model.params_label());
+    println!(
+        "{}: {} at {}",
+        "Weights".bold(),
+        fmt_mem(fit.weights_gb, 1, units),
+        fit.best_quant
+    );
     println!("{}: {}", "Quantization".bold(), fit.model.quantization);
     println!("{}: {}", "Best Quant".bold(), fit.best_quant);
     println!(
@@ -135,10 +143,16 @@ pub fn display_model_detail(fit: &ModelFit) {
 
     println!("{}", "Resource Requirements:".bold().underline());
     if let Some(vram) = fit.model.min_vram_gb {
-        println!("  Min VRAM: {:.1} GB", vram);
+        println!("  Min VRAM: {}", fmt_mem(vram, 1, units));
     }
-    println!("  Min RAM: {:.1} GB (CPU inference)", fit.model.min_ram_gb);
-    println!("  Recommended RAM: {:.1} GB", fit.model.recommended_ram_gb);
+    println!(
+        "  Min RAM: {} (CPU inference)",
+        fmt_mem(fit.model.min_ram_gb, 1, units)
+    );
+    println!(
+        "  Recommended RAM: {}",
+        fmt_mem(fit.model.recommended_ram_gb, 1, units)
+    );
 
     // MoE Architecture info
     if fit.model.is_moe {
@@ -152,15 +166,18 @@ pub fn display_model_detail(fit: &ModelFit) {
                 active_experts, num_experts
             );
         }
-        if let Some(active_vram) = fit.model.moe_active_vram_gb() {
+        if let Some(active_vram) = fit.model.moe_active_vram_gb(crate::quants::table()) {
             println!(
-                "  Active VRAM: {:.1} GB (vs {:.1} GB full model)",
-                active_vram,
-                fit.model.min_vram_gb.unwrap_or(0.0)
+                "  Active VRAM: {} (vs {} full model)",
+                fmt_mem(active_vram, 1, units),
+                fmt_mem(fit.model.min_vram_gb.unwrap_or(0.0), 1, units)
             );
         }
         if let Some(offloaded) = fit.moe_offloaded_gb {
-            println!("  Offloaded: {:.1} GB inactive experts in RAM", offloaded);
+            println!(
+                "  Offloaded: {} inactive experts in RAM",
+                fmt_mem(offloaded, 1, units)
+            );
         }
     }
     println!();
@@ -181,8 +198,10 @@ pub fn display_model_detail(fit: &ModelFit) {
     );
     println!("  Run Mode: {}", fit.run_mode_text());
     println!(
-        "  Memory Utilization: {:.1}% ({:.1} / {:.1} GB)",
-        fit.utilization_pct, fit.memory_required_gb, fit.memory_available_gb
+        "  Memory Utilization: {:.1}% ({} / {})",
+        fit.utilization_pct,
+        fmt_mem(fit.memory_required_gb, 1, units),
+        fmt_mem(fit.memory_available_gb, 1, units)
     );
     println!();
 
@@ -416,7 +435,7 @@ pub fn display_json_system(specs: &SystemSpecs) {
 
 /// Serialize system specs + model fits to JSON and print to stdout.
 pub fn display_json_fits(specs: &SystemSpecs, fits: &[ModelFit]) {
-    let models: Vec<serde_json::Value> = fits.iter().map(fit_to_json).collect();
+    let models: Vec<serde_json::Value> = fits.iter().map(|f| fit_to_json(f, specs)).collect();
     let output = serde_json::json!({
         "system": system_json(specs),
         "models": models,
@@ -427,6 +446,63 @@ pub fn display_json_fits(specs: &SystemSpecs, fits: &[ModelFit]) {
     );
 }
 
+/// `display_json_fits` after [`collapse_families`]: each model also carries
+/// its `family` and the `family_quants` its variants are available in.
+///
+/// [`collapse_families`]: llmfit_core::fit::collapse_families
+pub fn display_json_family_fits(
+    specs: &SystemSpecs,
+    fits: &[ModelFit],
+    family_quants: &HashMap<String, Vec<String>>,
+) {
+    let models: Vec<serde_json::Value> = fits
+        .iter()
+        .map(|f| {
+            let family = f.model.family();
+            let mut value = fit_to_json(f, specs);
+            value["family_quants"] = serde_json::json!(family_quants.get(&family));
+            value["family"] = serde_json::json!(family);
+            value
+        })
+        .collect();
+    let output = serde_json::json!({
+        "system": system_json(specs),
+        "models": models,
+    });
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&output).expect("JSON serialization failed")
+    );
+}
+
+/// `display_json_fits` for a training estimate, with the memory breakdown
+/// under `training`.
+pub fn display_json_training_fit(specs: &SystemSpecs, fit: &ModelFit, training: &TrainingMemory) {
+    let mut training_json = serde_json::to_value(training).expect("JSON serialization failed");
+    training_json["total_gb"] = serde_json::json!(training.total_gb());
+    let output = serde_json::json!({
+        "system": system_json(specs),
+        "training": training_json,
+        "models": [fit_to_json(fit, specs)],
+    });
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&output).expect("JSON serialization failed")
+    );
+}
+
+/// Write one fit as a single-line JSON object (the `--json` `models[]`
+/// schema) and flush, so a reader sees it immediately.
+pub fn write_jsonl_fit(
+    out: &mut impl std::io::Write,
+    fit: &ModelFit,
+    specs: &SystemSpecs,
+) -> std::io::Result<()> {
+    serde_json::to_writer(&mut *out, &fit_to_json(fit, specs))?;
+    out.write_all(b"\n")?;
+    out.flush()
+}
+
 /// Serialize diff output via serde derives (new diff-only path).
 pub fn display_json_diff_fits(specs: &SystemSpecs, fits: &[ModelFit]) {
     #[derive(serde::Serialize)]
@@ -444,6 +520,182 @@ pub fn display_json_diff_fits(specs: &SystemSpecs, fits: &[ModelFit]) {
     );
 }
 
+/// Render detected specs and fit counts as Prometheus text-format gauges.
+pub fn prometheus_metrics(specs: &SystemSpecs, fits: &[ModelFit]) -> String {
+    let mut out = String::new();
+    let mut gauge = |name: &str, help: &str, samples: &[(String, f64)]| {
+        out.push_str(&format!("# HELP {} {}\n", name, help));
+        out.push_str(&format!("# TYPE {} gauge\n", name));
+        for (labels, value) in samples {
+            out.push_str(&format!("{}{} {}\n", name, labels, value));
+        }
+    };
+
+    gauge(
+        "llmfit_info",
+        "Detected hardware; always 1.",
+        &[(
+            format!(
+                "{{cpu=\"{}\",gpu=\"{}\",backend=\"{}\"}}",
+                prom_escape(&specs.cpu_name),
+                prom_escape(specs.gpu_name.as_deref().unwrap_or("none")),
+                prom_escape(specs.backend.label()),
+            ),
+            1.0,
+        )],
+    );
+    gauge(
+        "llmfit_total_ram_gb",
+        "Total system RAM in GB.",
+        &[(String::new(), round2(specs.total_ram_gb))],
+    );
+    gauge(
+        "llmfit_available_ram_gb",
+        "Available system RAM in GB.",
+        &[(String::new(), round2(specs.available_ram_gb))],
+    );
+    gauge(
+        "llmfit_cpu_cores",
+        "Logical CPU cores.",
+        &[(String::new(), specs.total_cpu_cores as f64)],
+    );
+    gauge(
+        "llmfit_gpu_count",
+        "Number of detected GPUs.",
+        &[(String::new(), specs.gpu_count as f64)],
+    );
+    gauge(
+        "llmfit_gpu_vram_gb",
+        "VRAM of the primary GPU in GB (0 when unknown or absent).",
+        &[(String::new(), round2(specs.gpu_vram_gb.unwrap_or(0.0)))],
+    );
+    gauge(
+        "llmfit_gpu_vram_total_gb",
+        "VRAM summed across all GPUs in GB (0 when unknown or absent).",
+        &[(
+            String::new(),
+            round2(specs.total_gpu_vram_gb.unwrap_or(0.0)),
+        )],
+    );
+    gauge(
+        "llmfit_models_total",
+        "Catalog models compatible with the detected backend.",
+        &[(String::new(), fits.len() as f64)],
+    );
+
+    let by_fit: Vec<(String, f64)> = [
+        (FitLevel::Perfect, "perfect"),
+        (FitLevel::Good, "good"),
+        (FitLevel::Marginal, "marginal"),
+        (FitLevel::TooTight, "too_tight"),
+    ]
+    .iter()
+    .map(|(level, label)| {
+        let n = fits.iter().filter(|f| f.fit_level == *level).count();
+        (format!("{{fit=\"{}\"}}", label), n as f64)
+    })
+    .collect();
+    gauge(
+        "llmfit_models_by_fit",
+        "Catalog models per fit level.",
+        &by_fit,
+    );
+
+    let by_mode: Vec<(String, f64)> = [
+        (RunMode::Gpu, "gpu"),
+        (RunMode::MoeOffload, "moe_offload"),
+        (RunMode::CpuOffload, "cpu_offload"),
+        (RunMode::CpuOnly, "cpu_only"),
+    ]
+    .iter()
+    .map(|(mode, label)| {
+        let n = fits
+            .iter()
+            .filter(|f| f.run_mode == *mode && f.fit_level != FitLevel::TooTight)
+            .count();
+        (format!("{{mode=\"{}\"}}", label), n as f64)
+    })
+    .collect();
+    gauge(
+        "llmfit_models_fitting",
+        "Runnable catalog models (fit better than too tight) per run mode.",
+        &by_mode,
+    );
+
+    out
+}
+
+/// Render fits as a GitHub-flavored markdown table, in the given order.
+pub fn markdown_fits_table(fits: &[&ModelFit]) -> String {
+    let mut out = String::from(
+        "| Model | Provider | Params | Score | tok/s | Quant | Mode | Mem % | Fit |\n\
+         |---|---|---|---:|---:|---|---|---:|---|\n",
+    );
+    for fit in fits {
+        out.push_str(&format!(
+            "| {} | {} | {} | {:.0} | {:.1} | {} | {} | {:.0}% | {} |\n",
+            md_escape(&fit.model.name),
+            md_escape(&fit.model.provider),
+            md_escape(&fit.model.parameter_count),
+            fit.score,
+            fit.estimated_tps,
+            md_escape(&fit.best_quant),
+            fit.run_mode_text(),
+            fit.utilization_pct,
+            fit.fit_text()
+        ));
+    }
+    out
+}
+
+/// Render fits as CSV with the TUI table's columns, in the given order.
+pub fn csv_fits_table(fits: &[&ModelFit]) -> String {
+    let mut out = String::from(
+        "Installed,Model,Provider,Params,Score,tok/s,Quant,Mode,Mem %,Ctx,Date,Fit,Use Case\n",
+    );
+    for fit in fits {
+        let fields = [
+            if fit.installed { "yes" } else { "no" }.to_string(),
+            csv_escape(&fit.model.name),
+            csv_escape(&fit.model.provider),
+            csv_escape(&fit.model.parameter_count),
+            format!("{:.0}", fit.score),
+            format!("{:.1}", fit.estimated_tps),
+            csv_escape(&fit.best_quant),
+            fit.run_mode_text().to_string(),
+            format!("{:.0}", fit.utilization_pct),
+            fit.model.context_length.to_string(),
+            csv_escape(fit.model.release_date.as_deref().unwrap_or("")),
+            fit.fit_text().to_string(),
+            csv_escape(&fit.model.use_case),
+        ];
+        out.push_str(&fields.join(","));
+        out.push('\n');
+    }
+    out
+}
+
+/// Quote a CSV field if it contains a comma, quote or line break.
+fn csv_escape(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+/// Escape a markdown table cell.
+fn md_escape(s: &str) -> String {
+    s.replace('|', "\\|")
+}
+
+/// Escape a Prometheus label value.
+fn prom_escape(s: &str) -> String {
+    s.replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
 fn system_json(specs: &SystemSpecs) -> serde_json::Value {
     let gpus_json: Vec<serde_json::Value> = specs
         .gpus
@@ -462,24 +714,42 @@ fn system_json(specs: &SystemSpecs) -> serde_json::Value {
     serde_json::json!({
         "total_ram_gb": round2(specs.total_ram_gb),
         "available_ram_gb": round2(specs.available_ram_gb),
+        "reserved_ram_gb": round2(specs.reserved_ram_gb),
+        "headroom_gb": round2(specs.headroom_gb),
+        "swap_total_gb": round2(specs.swap_total_gb),
+        "swap_used_gb": round2(specs.swap_used_gb),
+        "process_mem_limit_gb": specs.process_mem_limit_gb.map(round2),
+        "gpu_wired_limit_gb": specs.gpu_wired_limit_gb.map(round2),
+        "hypothetical": specs.hypothetical,
+        "free_disk_gb": specs.free_disk_gb.map(round2),
+        "driver_version": specs.driver_version,
+        "cuda_version": specs.cuda_version,
+        "mem_bandwidth_gbps": round1(specs.mem_bandwidth_gbps()),
+        "mem_bandwidth_detected": specs.memory_bandwidth_gbps.is_some(),
         "cpu_cores": specs.total_cpu_cores,
         "cpu_name": specs.cpu_name,
+        "cpu_features": specs.cpu_features.labels(),
+        "has_npu": specs.has_npu,
+        "npu_name": specs.npu_name,
         "has_gpu": specs.has_gpu,
         "gpu_vram_gb": specs.gpu_vram_gb.map(round2),
         "gpu_name": specs.gpu_name,
         "gpu_count": specs.gpu_count,
+        "per_gpu_vram_gb": specs.per_gpu_vram_gb().into_iter().map(round2).collect::<Vec<_>>(),
         "unified_memory": specs.unified_memory,
         "backend": specs.backend.label(),
         "gpus": gpus_json,
     })
 }
 
-fn fit_to_json(fit: &ModelFit) -> serde_json::Value {
+fn fit_to_json(fit: &ModelFit, specs: &SystemSpecs) -> serde_json::Value {
     serde_json::json!({
         "name": fit.model.name,
         "provider": fit.model.provider,
         "parameter_count": fit.model.parameter_count,
         "params_b": round2(fit.model.params_b()),
+        "active_params_b": fit.model.active_params_b().map(round2),
+        "weight_size_gb": round2(fit.weights_gb),
         "context_length": fit.model.context_length,
         "use_case": fit.model.use_case,
         "category": fit.use_case.label(),
@@ -499,11 +769,16 @@ fn fit_to_json(fit: &ModelFit) -> serde_json::Value {
         "runtime_label": fit.runtime.label(),
         "best_quant": fit.best_quant,
         "memory_required_gb": round2(fit.memory_required_gb),
+        "memory_required_low_gb": round2(fit.memory_required_low_gb),
         "memory_available_gb": round2(fit.memory_available_gb),
         "moe_offloaded_gb": fit.moe_offloaded_gb.map(round2),
         "total_memory_gb": round2(fit.memory_required_gb + fit.moe_offloaded_gb.unwrap_or(0.0)),
         "utilization_pct": round1(fit.utilization_pct),
         "notes": fit.notes,
+        // JSON sizes are always binary GB, so the explanation text is too
+        "explanation": fit.explain(specs, MemoryUnits::Binary),
+        "memory_deficit_gb": memory_deficit_gb(fit).map(round2),
+        "offload_ram_deficit_gb": offload_ram_deficit_gb(fit, specs).map(round2),
         "gguf_sources": fit.model.gguf_sources,
     })
 }
@@ -516,7 +791,7 @@ fn round2(v: f64) -> f64 {
     (v * 100.0).round() / 100.0
 }
 
-pub fn display_model_plan(plan: &PlanEstimate) {
+pub fn display_model_plan(plan: &PlanEstimate, units: MemoryUnits) {
     println!("\n{}", "=== Hardware Planning Estimate ===".bold().cyan());
     println!("{} {}", "Model:".bold(), plan.model_name);
     println!("{} {}", "Provider:".bold(), plan.provider);
@@ -533,10 +808,10 @@ pub fn display_model_plan(plan: &PlanEstimate) {
         "  VRAM: {}",
         plan.minimum
             .vram_gb
-            .map(|v| format!("{v:.1} GB"))
+            .map(|v| fmt_mem(v, 1, units))
             .unwrap_or_else(|| "Not required".to_string())
     );
-    println!("  RAM: {:.1} GB", plan.minimum.ram_gb);
+    println!("  RAM: {}", fmt_mem(plan.minimum.ram_gb, 1, units));
     println!("  CPU Cores: {}", plan.minimum.cpu_cores);
     println!();
 
@@ -545,10 +820,10 @@ pub fn display_model_plan(plan: &PlanEstimate) {
         "  VRAM: {}",
         plan.recommended
             .vram_gb
-            .map(|v| format!("{v:.1} GB"))
+            .map(|v| fmt_mem(v, 1, units))
             .unwrap_or_else(|| "Not required".to_string())
     );
-    println!("  RAM: {:.1} GB", plan.recommended.ram_gb);
+    println!("  RAM: {}", fmt_mem(plan.recommended.ram_gb, 1, units));
     println!("  CPU Cores: {}", plan.recommended.cpu_cores);
     println!();
 
@@ -561,11 +836,11 @@ pub fn display_model_plan(plan: &PlanEstimate) {
         );
         if let Some(min) = &path.minimum {
             println!(
-                "    min: VRAM={} RAM={:.1} GB cores={}",
+                "    min: VRAM={} RAM={} cores={}",
                 min.vram_gb
-                    .map(|v| format!("{v:.1} GB"))
+                    .map(|v| fmt_mem(v, 1, units))
                     .unwrap_or_else(|| "n/a".to_string()),
-                min.ram_gb,
+                fmt_mem(min.ram_gb, 1, units),
                 min.cpu_cores
             );
         }
@@ -592,3 +867,140 @@ pub fn display_json_plan(plan: &PlanEstimate) {
         serde_json::to_string_pretty(plan).expect("JSON serialization failed")
     );
 }
+
+pub fn display_upgrade_advice(specs: &SystemSpecs, advice: &UpgradeAdvice, units: MemoryUnits) {
+    println!("\n{}", "=== What-if Upgrade Advisor ===".bold().cyan());
+    let vram = specs
+        .total_gpu_vram_gb
+        .map(|v| format!("{} VRAM", fmt_mem(v, 0, units)))
+        .unwrap_or_else(|| "no GPU".to_string());
+    println!(
+        "{} {} RAM, {} — {} perfect, {} runnable",
+        "Today:".bold(),
+        fmt_mem(specs.total_ram_gb, 0, units),
+        vram,
+        advice.perfect,
+        advice.runnable
+    );
+    println!();
+
+    for outcome in &advice.outcomes {
+        println!(
+            "{}  {} perfect ({}), {} runnable ({})",
+            format!("{:<12}", outcome.scenario.label).bold(),
+            outcome.perfect,
+            gain(outcome.perfect_gained),
+            outcome.runnable,
+            gain(outcome.runnable_gained)
+        );
+        if outcome.unlocks.is_empty() {
+            println!("  {}", "No new models become runnable.".dimmed());
+        } else {
+            println!("  unlocks: {}", outcome.unlocks.join(", "));
+        }
+    }
+    println!();
+}
+
+fn gain(n: usize) -> ColoredString {
+    if n > 0 {
+        format!("+{n}").green()
+    } else {
+        "+0".dimmed()
+    }
+}
+
+pub fn display_json_upgrade_advice(specs: &SystemSpecs, advice: &UpgradeAdvice) {
+    let output = serde_json::json!({
+        "system": system_json(specs),
+        "advice": advice,
+    });
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&output).expect("JSON serialization failed")
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use llmfit_core::hardware::GpuBackend;
+    use llmfit_core::models::ModelDatabase;
+
+    fn cpu_specs(cpu_name: &str) -> SystemSpecs {
+        SystemSpecs {
+            total_ram_gb: 16.0,
+            available_ram_gb: 14.0,
+            total_cpu_cores: 8,
+            cpu_name: cpu_name.to_string(),
+            has_gpu: false,
+            gpu_vram_gb: None,
+            total_gpu_vram_gb: None,
+            gpu_name: None,
+            gpu_count: 0,
+            unified_memory: false,
+            backend: GpuBackend::CpuX86,
+            gpus: vec![],
+            memory_bandwidth_gbps: None,
+            reserved_ram_gb: 0.0,
+            headroom_gb: 0.0,
+            gpu_wired_limit_gb: None,
+            swap_total_gb: 0.0,
+            swap_used_gb: 0.0,
+            process_mem_limit_gb: None,
+            driver_version: None,
+            cuda_version: None,
+            cpu_features: Default::default(),
+            has_npu: false,
+            npu_name: None,
+            loaded_from: None,
+            hypothetical: false,
+            free_disk_gb: None,
+        }
+    }
+
+    #[test]
+    fn test_prometheus_metrics_declares_each_gauge_and_escapes_labels() {
+        let specs = cpu_specs("Acme \"Turbo\" C:\\x86\nrev2");
+        let fits: Vec<ModelFit> = ModelDatabase::new().get_all_models()[..3]
+            .iter()
+            .map(|model| ModelFit::analyze(model, &specs))
+            .collect();
+        let text = prometheus_metrics(&specs, &fits);
+
+        for name in [
+            "llmfit_info",
+            "llmfit_total_ram_gb",
+            "llmfit_available_ram_gb",
+            "llmfit_cpu_cores",
+            "llmfit_gpu_count",
+            "llmfit_gpu_vram_gb",
+            "llmfit_gpu_vram_total_gb",
+            "llmfit_models_total",
+            "llmfit_models_by_fit",
+            "llmfit_models_fitting",
+        ] {
+            let help = format!("# HELP {} ", name);
+            assert!(text.lines().any(|l| l.starts_with(&help)), "{name}");
+            assert!(text.contains(&format!("# TYPE {} gauge\n", name)), "{name}");
+        }
+        // Every sample belongs to a gauge declared above it
+        for line in text.lines().filter(|l| !l.starts_with('#')) {
+            let name = line.split(['{', ' ']).next().unwrap();
+            let declared = text.find(&format!("# TYPE {} gauge", name)).unwrap();
+            assert!(declared < text.find(line).unwrap(), "{line}");
+        }
+
+        assert!(
+            text.contains(r#"llmfit_info{cpu="Acme \"Turbo\" C:\\x86\nrev2",gpu="none",backend="#)
+        );
+        assert!(text.contains("llmfit_cpu_cores 8\n"));
+        assert!(text.contains("llmfit_models_total 3\n"));
+        let by_fit: f64 = text
+            .lines()
+            .filter(|l| l.starts_with("llmfit_models_by_fit{fit="))
+            .map(|l| l.rsplit(' ').next().unwrap().parse::<f64>().unwrap())
+            .sum();
+        assert_eq!(by_fit, 3.0);
+    }
+}
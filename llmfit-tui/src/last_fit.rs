@@ -0,0 +1,58 @@
+use llmfit_core::fit::{FitLevel, ModelFit};
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+
+/// Path to the previous run's fit levels: ~/.config/llmfit/last_fit.json
+fn config_path() -> Option<PathBuf> {
+    Some(llmfit_core::config_dir()?.join("last_fit.json"))
+}
+
+fn level_key(level: FitLevel) -> &'static str {
+    match level {
+        FitLevel::Perfect => "perfect",
+        FitLevel::Good => "good",
+        FitLevel::Marginal => "marginal",
+        FitLevel::TooTight => "too_tight",
+    }
+}
+
+fn parse_level(key: &str) -> Option<FitLevel> {
+    match key {
+        "perfect" => Some(FitLevel::Perfect),
+        "good" => Some(FitLevel::Good),
+        "marginal" => Some(FitLevel::Marginal),
+        "too_tight" => Some(FitLevel::TooTight),
+        _ => None,
+    }
+}
+
+/// Load the previous run's fit level per model name, returning an empty map
+/// on the first run or if the file can't be parsed.
+pub fn load() -> HashMap<String, FitLevel> {
+    config_path()
+        .and_then(|path| fs::read_to_string(path).ok())
+        .and_then(|s| serde_json::from_str::<HashMap<String, String>>(&s).ok())
+        .map(|map| {
+            map.into_iter()
+                .filter_map(|(name, key)| parse_level(&key).map(|level| (name, level)))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Record this run's fit levels for the next run to compare against.
+pub fn save(fits: &[ModelFit]) {
+    if let Some(path) = config_path() {
+        if let Some(parent) = path.parent() {
+            let _ = fs::create_dir_all(parent);
+        }
+        let levels: std::collections::BTreeMap<&str, &str> = fits
+            .iter()
+            .map(|f| (f.model.name.as_str(), level_key(f.fit_level)))
+            .collect();
+        if let Ok(json) = serde_json::to_string_pretty(&levels) {
+            let _ = fs::write(&path, json + "\n");
+        }
+    }
+}
@@ -0,0 +1,139 @@
+//! `--log-file`: a persistent, timestamped record of detection steps, fit
+//! decisions, warnings and errors.
+//!
+//! Everything llmfit records in its activity log is forwarded to the `log`
+//! facade; this module installs a logger that appends those records to a file,
+//! one line each. Levels follow `RUST_LOG` (`warn`, `info`, `debug`, ... or
+//! `llmfit=<level>`), defaulting to `debug` so fit decisions are included.
+
+use log::{LevelFilter, Log, Metadata, Record};
+use std::fs::{File, OpenOptions};
+use std::io::{self, Write};
+use std::path::Path;
+use std::sync::Mutex;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const DEFAULT_LEVEL: LevelFilter = LevelFilter::Debug;
+
+struct FileLogger {
+    file: Mutex<File>,
+}
+
+impl Log for FileLogger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= log::max_level()
+    }
+
+    fn log(&self, record: &Record) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+        let line = format!(
+            "{} {:<5} {}: {}\n",
+            timestamp(SystemTime::now()),
+            record.level(),
+            record.target(),
+            record.args()
+        );
+        if let Ok(mut file) = self.file.lock() {
+            let _ = file.write_all(line.as_bytes());
+        }
+    }
+
+    fn flush(&self) {
+        if let Ok(mut file) = self.file.lock() {
+            let _ = file.flush();
+        }
+    }
+}
+
+/// Append log records to `path`, creating it if needed. The level comes from
+/// `RUST_LOG`.
+pub fn init(path: &Path) -> io::Result<()> {
+    let file = OpenOptions::new().create(true).append(true).open(path)?;
+    let level = level_from_env(std::env::var("RUST_LOG").ok().as_deref());
+    log::set_boxed_logger(Box::new(FileLogger {
+        file: Mutex::new(file),
+    }))
+    .map_err(io::Error::other)?;
+    log::set_max_level(level);
+    Ok(())
+}
+
+/// Resolve a `RUST_LOG`-style value to the level for llmfit's records.
+///
+/// Comma-separated directives are read left to right: a bare level sets the
+/// default and `llmfit=<level>` (or `llmfit::<kind>=<level>`) sets ours.
+/// Directives for other crates and unparsable levels are ignored.
+fn level_from_env(value: Option<&str>) -> LevelFilter {
+    let mut bare = None;
+    let mut ours = None;
+    for directive in value.unwrap_or("").split(',').map(str::trim) {
+        match directive.split_once('=') {
+            Some((target, level)) if target == "llmfit" || target.starts_with("llmfit::") => {
+                if let Ok(level) = level.trim().parse() {
+                    ours = Some(level);
+                }
+            }
+            Some(_) => {}
+            None => {
+                if let Ok(level) = directive.parse() {
+                    bare = Some(level);
+                }
+            }
+        }
+    }
+    ours.or(bare).unwrap_or(DEFAULT_LEVEL)
+}
+
+/// RFC 3339 UTC timestamp with milliseconds, e.g. `2025-01-31T09:05:00.123Z`.
+fn timestamp(time: SystemTime) -> String {
+    let since_epoch = time.duration_since(UNIX_EPOCH).unwrap_or_default();
+    let secs = since_epoch.as_secs();
+    let (days, rem) = (secs / 86_400, secs % 86_400);
+
+    // Civil date from days since 1970-01-01 (Howard Hinnant's algorithm)
+    let z = days as i64 + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}.{:03}Z",
+        year,
+        month,
+        day,
+        rem / 3_600,
+        rem % 3_600 / 60,
+        rem % 60,
+        since_epoch.subsec_millis()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn test_level_from_env() {
+        assert_eq!(level_from_env(None), LevelFilter::Debug);
+        assert_eq!(level_from_env(Some("warn")), LevelFilter::Warn);
+        assert_eq!(level_from_env(Some("llmfit=info,debug")), LevelFilter::Info);
+        assert_eq!(level_from_env(Some("hyper=trace")), LevelFilter::Debug);
+        assert_eq!(level_from_env(Some("llmfit::fit=OFF")), LevelFilter::Off);
+        assert_eq!(level_from_env(Some("loud")), LevelFilter::Debug);
+    }
+
+    #[test]
+    fn test_timestamp_is_utc_rfc3339() {
+        assert_eq!(timestamp(UNIX_EPOCH), "1970-01-01T00:00:00.000Z");
+        let leap_day = UNIX_EPOCH + Duration::from_millis(1_709_210_096_789);
+        assert_eq!(timestamp(leap_day), "2024-02-29T12:34:56.789Z");
+    }
+}
@@ -1,14 +1,22 @@
+mod bench;
+mod catalog;
+mod clipboard;
+mod config;
 mod display;
+mod last_fit;
+mod logging;
+mod quants;
 mod serve_api;
 mod theme;
 mod tui_app;
 mod tui_events;
 mod tui_ui;
+mod views;
 
 use clap::{Parser, Subcommand};
-use llmfit_core::fit::{ModelFit, SortColumn, backend_compatible};
-use llmfit_core::hardware::SystemSpecs;
-use llmfit_core::models::ModelDatabase;
+use llmfit_core::fit::{FitOptions, FitTarget, ModelFit, SortColumn, backend_compatible};
+use llmfit_core::hardware::{MemoryUnits, SystemSpecs, fmt_mem};
+use llmfit_core::models::CatalogSources;
 use llmfit_core::plan::{PlanRequest, estimate_model_plan, resolve_model_selector};
 
 #[derive(clap::ValueEnum, Clone, Copy, Debug)]
@@ -56,6 +64,62 @@ enum FitArg {
     Marginal,
     Tight,
     Runnable,
+    /// Runnable entirely in VRAM (no CPU offload)
+    #[value(alias = "gpu-only")]
+    Gpu,
+}
+
+/// How memory sizes are labelled in human-readable output.
+#[derive(clap::ValueEnum, Clone, Copy, Debug)]
+enum UnitsArg {
+    /// GiB (1024³ bytes), as the OS reports RAM (default)
+    Binary,
+    /// GB (1000³ bytes), as on vendor spec sheets
+    Decimal,
+}
+
+impl From<UnitsArg> for MemoryUnits {
+    fn from(value: UnitsArg) -> Self {
+        match value {
+            UnitsArg::Binary => MemoryUnits::Binary,
+            UnitsArg::Decimal => MemoryUnits::Decimal,
+        }
+    }
+}
+
+/// Memory figure fits are scored against.
+#[derive(clap::ValueEnum, Clone, Copy, Debug)]
+enum FitTargetArg {
+    /// Estimated requirement at the chosen quant (default)
+    #[value(alias = "minimum")]
+    Min,
+    /// The catalog's recommended RAM, for headroom
+    #[value(alias = "rec")]
+    Recommended,
+}
+
+impl From<FitTargetArg> for FitTarget {
+    fn from(value: FitTargetArg) -> Self {
+        match value {
+            FitTargetArg::Min => FitTarget::Minimum,
+            FitTargetArg::Recommended => FitTarget::Recommended,
+        }
+    }
+}
+
+/// Minimum outcome `llmfit query` treats as success.
+#[derive(clap::ValueEnum, Clone, Copy, Debug)]
+enum QueryRequire {
+    /// Succeed whenever the model is found
+    Found,
+    /// Model must be runnable (not too tight)
+    Runnable,
+    /// Model must run entirely in VRAM
+    Gpu,
+    /// Fit level must be Good or Perfect
+    Good,
+    /// Fit level must be Perfect
+    Perfect,
 }
 
 #[derive(Parser)]
@@ -71,7 +135,8 @@ GGUF weights, and launch inference — all from a single binary.
 
 GLOBAL FLAGS:
   --json           Output structured JSON on every subcommand (for tool/agent
-                   integration). Always exits 0 on success, 1 on error.
+                   integration). Exits 0 on success, 1 on error; fit
+                   output exits 2 when no listed model is runnable.
   --memory <SIZE>  Override GPU VRAM (e.g. \"32G\", \"32000M\", \"1.5T\").
   --max-context N  Cap context length for memory estimation (tokens).
                    Falls back to OLLAMA_CONTEXT_LENGTH env var if unset.
@@ -92,8 +157,8 @@ struct Cli {
     #[arg(short, long)]
     perfect: bool,
 
-    /// Limit number of results
-    #[arg(short = 'n', long)]
+    /// Limit number of results (after filtering and sorting)
+    #[arg(short = 'n', long, visible_alias = "top")]
     limit: Option<usize>,
 
     /// Sort column for CLI fit output
@@ -108,11 +173,156 @@ struct Cli {
     #[arg(long, global = true)]
     json: bool,
 
+    /// Print Prometheus text-format gauges (for a node_exporter textfile collector)
+    #[arg(long, conflicts_with = "json")]
+    metrics: bool,
+
+    /// Stream one JSON object per model per line as fits are computed (unsorted)
+    #[arg(long, conflicts_with_all = ["json", "metrics", "dedupe_family"])]
+    jsonl: bool,
+
+    /// In fit and recommend output, keep only the best-ranked variant of each
+    /// model family; JSON lists the family's quants in `family_quants`
+    #[arg(long, global = true)]
+    dedupe_family: bool,
+
+    /// Dump the detection and fit-decision log to stderr on exit
+    #[arg(short, long, global = true)]
+    verbose: bool,
+
+    /// Don't print detection warnings to stderr
+    #[arg(short, long, global = true)]
+    quiet: bool,
+
+    /// Append timestamped detection steps, fit decisions, warnings and
+    /// errors to this file. `RUST_LOG` sets the level (default: debug).
+    #[arg(long, value_name = "PATH", global = true)]
+    log_file: Option<std::path::PathBuf>,
+
+    /// Directory GGUF models are downloaded to and whose free space is
+    /// checked against model sizes (default: $LLMFIT_MODELS_DIR or
+    /// ~/.cache/llmfit/models).
+    #[arg(long, value_name = "DIR", global = true)]
+    models_dir: Option<std::path::PathBuf>,
+
     /// Override GPU VRAM size (e.g. "32G", "32000M", "1.5T").
     /// Useful when GPU memory autodetection fails.
     #[arg(long, value_name = "SIZE")]
     memory: Option<String>,
 
+    /// Evaluate a hypothetical machine with this much RAM (e.g. "64G").
+    /// RAM in use now is assumed to stay in use.
+    #[arg(long, value_name = "SIZE", global = true)]
+    ram: Option<String>,
+
+    /// Evaluate a hypothetical machine with a GPU of this much VRAM (e.g.
+    /// "24G"). Like --memory, but the system bar marks it hypothetical.
+    #[arg(long, value_name = "SIZE", global = true)]
+    vram: Option<String>,
+
+    /// Evaluate a hypothetical machine with this many CPU cores.
+    #[arg(long, value_name = "N", global = true)]
+    cores: Option<usize>,
+
+    /// On Apple Silicon, how much unified memory the GPU may use (e.g.
+    /// "28G"), for when you've raised macOS's limit with
+    /// `sudo sysctl iogpu.wired_limit_mb` (default: read from sysctl).
+    #[arg(long, value_name = "SIZE", global = true)]
+    gpu_wired_limit: Option<String>,
+
+    /// Hold back this much RAM (GB) from every fit, e.g. for a browser and
+    /// editor that always stay open.
+    #[arg(long, value_name = "GB", global = true)]
+    reserve: Option<f64>,
+
+    /// Memory (GB) held back from both RAM and each GPU's VRAM for the OS,
+    /// drivers and inference runtime before fitting (default: 1.5).
+    #[arg(long, value_name = "GB", global = true)]
+    headroom: Option<f64>,
+
+    /// Don't read or write ~/.config/llmfit/config.toml, for reproducible
+    /// runs.
+    #[arg(long, global = true)]
+    no_config: bool,
+
+    /// Measure RAM bandwidth (under a second) to calibrate CPU tok/s
+    /// estimates, and cache the result for later runs.
+    #[arg(long, global = true)]
+    bench: bool,
+
+    /// Units for memory sizes in human-readable output. JSON output is
+    /// always in binary GB.
+    #[arg(long, value_enum, default_value_t = UnitsArg::Binary, global = true)]
+    units: UnitsArg,
+
+    /// Score fits against the estimated minimum memory or the catalog's
+    /// recommended RAM (toggle with B in the TUI).
+    #[arg(long, value_enum, default_value_t = FitTargetArg::Min, global = true)]
+    fit_target: FitTargetArg,
+
+    /// Size the KV cache for this many requests served at once (adjust
+    /// with +/- in the TUI). Weights are counted once.
+    #[arg(long, value_name = "N", default_value_t = 1, global = true)]
+    concurrency: u32,
+
+    /// Use the model catalog at this URL instead of the built-in one. The
+    /// last download is cached and used when offline.
+    #[arg(long, value_name = "URL", global = true)]
+    catalog_url: Option<String>,
+
+    /// Add every *.gguf file under this directory to the catalog, sized from
+    /// the file's own header (provider "local").
+    #[arg(long, value_name = "DIR", global = true)]
+    scan_dir: Option<std::path::PathBuf>,
+
+    /// Fill missing parameter counts, context lengths and release dates
+    /// from the Hugging Face Hub. Lookups are cached for a week.
+    #[arg(long, global = true)]
+    enrich: bool,
+
+    /// Save this machine's detected hardware as a JSON profile ("-" for
+    /// stdout) and exit. Load it elsewhere with --specs-from.
+    #[arg(long, value_name = "FILE", global = true)]
+    dump_specs: Option<std::path::PathBuf>,
+
+    /// Evaluate fits against a hardware profile saved with --dump-specs
+    /// instead of detecting this machine.
+    #[arg(long, value_name = "FILE", global = true)]
+    specs_from: Option<std::path::PathBuf>,
+
+    /// Draw fit indicators in the TUI with ASCII (+ o ~ x) instead of
+    /// Unicode glyphs, for terminals without good Unicode support.
+    #[arg(long)]
+    ascii: bool,
+
+    /// TUI color theme for this run, e.g. "light" or "dark" (default: the
+    /// last one picked with t, or light when COLORFGBG reports a light
+    /// background).
+    #[arg(long, value_name = "NAME")]
+    theme: Option<String>,
+
+    /// Re-read available RAM in the TUI every SECS seconds (default 5) and
+    /// refit as it changes, e.g. after closing a browser. GPUs aren't
+    /// probed again. Give the interval as --watch=SECS.
+    #[arg(
+        long,
+        value_name = "SECS",
+        num_args = 0..=1,
+        require_equals = true,
+        default_missing_value = "5"
+    )]
+    watch: Option<u64>,
+
+    /// Don't capture the mouse in the TUI, leaving the terminal's own text
+    /// selection working (some setups over SSH need this).
+    #[arg(long)]
+    no_mouse: bool,
+
+    /// File the TUI's `e` key writes the filtered model list to as CSV
+    /// (default: llmfit-export.csv in the working directory).
+    #[arg(long, value_name = "PATH")]
+    export_path: Option<std::path::PathBuf>,
+
     /// Cap context length used for memory estimation (tokens).
     /// Falls back to OLLAMA_CONTEXT_LENGTH if not set.
     #[arg(long, value_name = "TOKENS", value_parser = clap::value_parser!(u32).range(1..))]
@@ -186,28 +396,38 @@ SIDE EFFECTS:
 EXIT CODES:
   0  Success
   1  Hardware detection or internal error
+  2  With --json: no listed model is runnable (all Too Tight, or none listed)
 
 AGENT USAGE:
   llmfit fit --json
   llmfit fit --json --perfect -n 5
   llmfit fit --json --sort tps
+  llmfit fit --jsonl | jq -c 'select(.fit_level == \"Perfect\")'
 
   JSON output fields: { system: {...}, models: [{ name, provider,
   parameter_count, fit_level, run_mode, score, score_components,
   estimated_tps, memory_required_gb, memory_available_gb,
-  utilization_pct, best_quant, use_case, runtime }] }")]
+  utilization_pct, best_quant, use_case, runtime, explanation: { binding,
+  breakdown, run_mode_path, ... } }] }
+
+  --jsonl prints one models[] object per line, flushed as each fit is
+  computed, in catalog order (--sort is ignored; -n stops early).")]
     Fit {
         /// Show only models that perfectly match recommended specs
         #[arg(short, long)]
         perfect: bool,
 
-        /// Limit number of results
-        #[arg(short = 'n', long)]
+        /// Limit number of results (after filtering and sorting)
+        #[arg(short = 'n', long, visible_alias = "top")]
         limit: Option<usize>,
 
         /// Sort column for fit output
         #[arg(long, value_enum, default_value_t = SortArg::Score)]
         sort: SortArg,
+
+        /// Stream one JSON object per model per line, unsorted
+        #[arg(long)]
+        jsonl: bool,
     },
 
     /// Search for specific models
@@ -262,6 +482,37 @@ AGENT USAGE:
         model: String,
     },
 
+    /// Check whether one model fits this machine (quiet, script-friendly)
+    #[command(long_about = "\
+Check whether one model fits this machine (quiet, script-friendly).
+
+Resolves a single model by exact name, Ollama tag (e.g. llama3:8b), or fuzzy
+partial name, and prints a one-line fit verdict. Unlike 'info', ambiguous
+partial matches resolve to the closest (shortest) name instead of failing.
+
+PRECONDITIONS:
+  None. Hardware detection runs automatically.
+
+SIDE EFFECTS:
+  None — read-only.
+
+EXIT CODES:
+  0  Model found and meets --require
+  1  No model found
+  2  Model found but does not meet --require
+
+AGENT USAGE:
+  llmfit query \"llama3:8b\" && docker pull ...
+  llmfit query \"qwen2.5-7b\" --require gpu --json")]
+    Query {
+        /// Model name, Ollama tag, or partial name
+        model: String,
+
+        /// What counts as success for the exit code
+        #[arg(long, value_enum, default_value_t = QueryRequire::Runnable)]
+        require: QueryRequire,
+    },
+
     /// Compare two models side-by-side, or auto-compare top N filtered models
     #[command(long_about = "\
 Compare two models side-by-side, or auto-compare top N filtered models.
@@ -351,6 +602,38 @@ AGENT USAGE:
         target_tps: Option<f64>,
     },
 
+    /// Show how many more models would fit after a hardware upgrade
+    #[command(long_about = "\
+Show how many more models would fit after a hardware upgrade.
+
+Re-runs the fit analysis against hypothetical upgraded specs (+16 GB VRAM,
++24 GB VRAM, +32 GB RAM) and reports how many models move to Perfect or
+become runnable, with a few notable unlocks for each. On unified-memory
+systems (Apple Silicon) every upgrade grows the shared pool. Without a GPU,
+the VRAM scenarios assume a discrete CUDA card of that size.
+
+PRECONDITIONS:
+  Requires hardware detection. Use --memory to override GPU VRAM if needed.
+
+SIDE EFFECTS:
+  None — read-only.
+
+EXIT CODES:
+  0  Success
+
+AGENT USAGE:
+  llmfit upgrade --json
+  llmfit upgrade -n 10
+
+  JSON output fields: { system: {...}, advice: { perfect, runnable,
+  outcomes: [{ scenario { label, add_vram_gb, add_ram_gb }, perfect,
+  runnable, perfect_gained, runnable_gained, unlocks }] } }")]
+    Upgrade {
+        /// Number of unlocked models to list per scenario
+        #[arg(short = 'n', long, visible_alias = "top", default_value_t = 5)]
+        limit: usize,
+    },
+
     /// Recommend top models for your hardware (JSON-friendly)
     #[command(long_about = "\
 Recommend top models for your hardware (JSON-friendly).
@@ -381,7 +664,7 @@ AGENT USAGE:
   capabilities }] }")]
     Recommend {
         /// Limit number of recommendations
-        #[arg(short = 'n', long, default_value = "5")]
+        #[arg(short = 'n', long, visible_alias = "top", default_value = "5")]
         limit: usize,
 
         /// Filter by use case: general, coding, reasoning, chat, multimodal, embedding
@@ -478,6 +761,59 @@ AGENT USAGE:
         limit: usize,
     },
 
+    /// Size a local Hugging Face checkpoint exactly from its config.json
+    #[command(long_about = "\
+Size a local Hugging Face checkpoint exactly from its config.json.
+
+Reads hidden_size, num_hidden_layers, attention heads, vocab_size and
+max_position_embeddings (plus common per-architecture key variants) to compute
+the exact parameter count, weight memory at the checkpoint's dtype, and the
+KV cache for the chosen context, then fits that against this machine.
+
+PRECONDITIONS:
+  PATH is a model directory containing config.json, or the file itself.
+
+SIDE EFFECTS:
+  None — read-only.
+
+EXIT CODES:
+  0  Success
+  1  config.json missing, unreadable, or lacking required fields
+
+With --train, sizes full fine-tuning with Adam instead: weights, gradients,
+optimizer state and activations for --batch sequences of --context tokens,
+optionally with gradient checkpointing.
+
+AGENT USAGE:
+  llmfit hf ./Meta-Llama-3-8B --json
+  llmfit hf ./Meta-Llama-3-8B/config.json --dtype Q4_K_M --context 8192
+  llmfit hf ./Meta-Llama-3-8B --train --batch 4 --context 2048 --checkpointing")]
+    Hf {
+        /// Model directory or path to config.json
+        path: std::path::PathBuf,
+
+        /// Weight dtype/quantization (default: torch_dtype from config, else F16)
+        #[arg(long)]
+        dtype: Option<String>,
+
+        /// Context length for KV cache, or sequence length with --train
+        /// (default: max_position_embeddings)
+        #[arg(long, value_name = "TOKENS", value_parser = clap::value_parser!(u32).range(1..))]
+        context: Option<u32>,
+
+        /// Estimate memory for full fine-tuning with Adam instead of inference
+        #[arg(long)]
+        train: bool,
+
+        /// Sequences per training step
+        #[arg(long, default_value = "1", requires = "train", value_parser = clap::value_parser!(u32).range(1..))]
+        batch: u32,
+
+        /// Recompute activations in the backward pass to save memory
+        #[arg(long, requires = "train")]
+        checkpointing: bool,
+    },
+
     /// Run a downloaded GGUF model with llama-cli or llama-server
     #[command(long_about = "\
 Run a downloaded GGUF model with llama-cli or llama-server.
@@ -557,22 +893,128 @@ AGENT USAGE:
     },
 }
 
-/// Detect system specs with optional GPU memory override.
-fn detect_specs(memory_override: &Option<String>) -> SystemSpecs {
-    let specs = SystemSpecs::detect();
-    if let Some(mem_str) = memory_override {
-        match llmfit_core::hardware::parse_memory_size(mem_str) {
-            Some(gb) => specs.with_gpu_memory_override(gb),
-            None => {
-                eprintln!(
+/// `--ram`, `--vram` and `--cores`: the machine to evaluate instead of
+/// this one.
+#[derive(Default)]
+struct Hypothetical {
+    ram_gb: Option<f64>,
+    vram_gb: Option<f64>,
+    cores: Option<usize>,
+}
+
+/// The machine to evaluate, resolved once in `main` from the hardware flags
+/// and passed to every command that needs specs.
+struct SpecsSource {
+    /// Hardware profile from `--specs-from`, used in place of detection.
+    loaded: Option<SystemSpecs>,
+    hypothetical: Hypothetical,
+    /// GPU share of a Metal unified pool from `--gpu-wired-limit`.
+    gpu_wired_limit_gb: Option<f64>,
+    /// RAM held back by `--reserve`.
+    ram_reserve_gb: Option<f64>,
+    /// `--memory`: GPU memory to assume, as given on the command line.
+    memory_override: Option<String>,
+    /// OS/runtime headroom from `--headroom` or the config file.
+    headroom_gb: f64,
+    /// Whether `--bench` was passed.
+    bench: bool,
+    /// RAM bandwidth measured this run or cached from an earlier `--bench`.
+    /// Resolved once, so `serve` doesn't re-measure per request.
+    measured_bandwidth: std::sync::OnceLock<Option<f64>>,
+    /// Where GGUF files are downloaded (`--models-dir`); free disk space is
+    /// measured there.
+    models_dir: std::path::PathBuf,
+}
+
+impl SpecsSource {
+    /// This machine's specs before user adjustments: the `--specs-from`
+    /// profile, or detection.
+    fn base(&self) -> SystemSpecs {
+        self.loaded
+            .clone()
+            .unwrap_or_else(SystemSpecs::detect_cached)
+            .with_models_dir(&self.models_dir)
+    }
+
+    /// Apply the `--ram`/`--vram`/`--cores` overrides, if any.
+    fn apply_hypothetical(&self, mut specs: SystemSpecs) -> SystemSpecs {
+        let what_if = &self.hypothetical;
+        if let Some(gb) = what_if.ram_gb {
+            specs = specs.with_ram_override(gb);
+        }
+        if let Some(gb) = what_if.vram_gb {
+            specs = specs.with_gpu_memory_override(gb);
+            specs.hypothetical = true;
+        }
+        if let Some(cores) = what_if.cores {
+            specs = specs.with_cpu_cores_override(cores);
+        }
+        specs
+    }
+
+    /// Apply `--gpu-wired-limit`, if given, to freshly detected specs.
+    fn apply_gpu_wired_limit(&self, specs: SystemSpecs) -> SystemSpecs {
+        match self.gpu_wired_limit_gb {
+            Some(gb) => specs.with_gpu_wired_limit(gb),
+            None => specs,
+        }
+    }
+
+    /// Replace the table/default RAM bandwidth with a measured one when available.
+    fn apply_measured_bandwidth(&self, specs: SystemSpecs) -> SystemSpecs {
+        if specs.loaded_from.is_some() {
+            return specs;
+        }
+        let measured = self.measured_bandwidth.get_or_init(|| {
+            if self.bench {
+                let gbps = llmfit_core::hardware::measure_mem_bandwidth_gbps(
+                    std::time::Duration::from_millis(500),
+                );
+                bench::save(&specs, gbps);
+                Some(gbps)
+            } else {
+                bench::load(&specs)
+            }
+        });
+        match *measured {
+            Some(gbps) => specs.with_mem_bandwidth(gbps),
+            None => specs,
+        }
+    }
+
+    /// Detect system specs with every user adjustment applied.
+    fn detect(&self) -> SystemSpecs {
+        let mut specs = self.apply_gpu_wired_limit(self.apply_hypothetical(self.base()));
+        if let Some(gb) = self.ram_reserve_gb {
+            specs = specs.with_ram_reserve(gb);
+        }
+        let mut specs = self.apply_measured_bandwidth(specs);
+        if let Some(mem_str) = &self.memory_override {
+            match llmfit_core::hardware::parse_memory_size(mem_str) {
+                Some(gb) => specs = specs.with_gpu_memory_override(gb),
+                None => eprintln!(
                     "Warning: could not parse --memory value '{}'. Expected format: 32G, 32000M, 1.5T",
                     mem_str
-                );
-                specs
+                ),
             }
         }
-    } else {
-        specs
+        specs.with_memory_headroom(self.headroom_gb)
+    }
+
+    /// `--dump-specs`: write the hardware profile to `path`, or stdout for "-".
+    /// Carve-outs the user chose (`--reserve`, `--headroom`, `--memory`) are
+    /// left out so they can be applied again when the profile is loaded.
+    fn dump(&self, path: &std::path::Path) {
+        let json = self
+            .apply_measured_bandwidth(self.apply_gpu_wired_limit(self.base()))
+            .to_profile_json();
+        if path.as_os_str() == "-" {
+            println!("{}", json);
+        } else if let Err(err) = std::fs::write(path, json + "\n") {
+            exit_with_error(format!("--dump-specs: {}: {}", path.display(), err));
+        } else {
+            eprintln!("Saved system profile to {}", path.display());
+        }
     }
 }
 
@@ -601,14 +1043,15 @@ fn run_fit(
     limit: Option<usize>,
     sort: SortColumn,
     json: bool,
-    memory_override: &Option<String>,
-    context_limit: Option<u32>,
+    dedupe_family: bool,
+    specs_source: &SpecsSource,
+    options: &FitOptions,
 ) {
-    let specs = detect_specs(memory_override);
-    let db = ModelDatabase::new();
+    let specs = specs_source.detect();
+    let db = catalog::database();
 
     if !json {
-        specs.display();
+        specs.display(options.units, &specs_source.models_dir);
     }
 
     let hidden: usize = db
@@ -617,25 +1060,32 @@ fn run_fit(
         .filter(|m| !backend_compatible(m, &specs))
         .count();
 
-    let mut fits: Vec<ModelFit> = db
-        .get_all_models()
-        .iter()
-        .filter(|m| backend_compatible(m, &specs))
-        .map(|m| ModelFit::analyze_with_context_limit(m, &specs, context_limit))
-        .collect();
+    let mut fits: Vec<ModelFit> =
+        llmfit_core::fit::analyze_compatible(db.get_all_models(), &specs, options);
 
     if perfect {
         fits.retain(|f| f.fit_level == llmfit_core::fit::FitLevel::Perfect);
     }
 
     fits = llmfit_core::fit::rank_models_by_fit_opts_col(fits, false, sort);
+    let family_quants = dedupe_family.then(|| llmfit_core::fit::collapse_families(&mut fits));
 
     if let Some(n) = limit {
         fits.truncate(n);
     }
 
     if json {
-        display::display_json_fits(&specs, &fits);
+        match &family_quants {
+            Some(quants) => display::display_json_family_fits(&specs, &fits, quants),
+            None => display::display_json_fits(&specs, &fits),
+        }
+        // Lets CI gate on "something runs on this runner"
+        if !fits
+            .iter()
+            .any(|f| f.fit_level != llmfit_core::fit::FitLevel::TooTight)
+        {
+            std::process::exit(2);
+        }
     } else {
         if hidden > 0 {
             eprintln!(
@@ -648,6 +1098,44 @@ fn run_fit(
     }
 }
 
+/// `fit --jsonl`: analyze compatible models one at a time in catalog order,
+/// printing each as soon as it's scored. Stops quietly once the reader goes
+/// away (e.g. `| head`).
+fn run_fit_jsonl(
+    perfect: bool,
+    limit: Option<usize>,
+    specs_source: &SpecsSource,
+    options: &FitOptions,
+) {
+    let specs = specs_source.detect();
+    let db = catalog::database();
+
+    let fits = db
+        .get_all_models()
+        .iter()
+        .filter(|m| backend_compatible(m, &specs))
+        .map(|m| ModelFit::analyze_with_options(m, &specs, options))
+        .filter(|f| !perfect || f.fit_level == llmfit_core::fit::FitLevel::Perfect)
+        .take(limit.unwrap_or(usize::MAX));
+
+    let mut out = std::io::stdout().lock();
+    for fit in fits {
+        if display::write_jsonl_fit(&mut out, &fit, &specs).is_err() {
+            break;
+        }
+    }
+}
+
+fn run_metrics(specs_source: &SpecsSource, options: &FitOptions) {
+    let specs = specs_source.detect();
+    let db = catalog::database();
+
+    let fits: Vec<ModelFit> =
+        llmfit_core::fit::analyze_compatible(db.get_all_models(), &specs, options);
+
+    print!("{}", display::prometheus_metrics(&specs, &fits));
+}
+
 fn fit_matches_filter(fit: &ModelFit, filter: FitArg) -> bool {
     match filter {
         FitArg::All => true,
@@ -656,7 +1144,92 @@ fn fit_matches_filter(fit: &ModelFit, filter: FitArg) -> bool {
         FitArg::Marginal => fit.fit_level == llmfit_core::fit::FitLevel::Marginal,
         FitArg::Tight => fit.fit_level == llmfit_core::fit::FitLevel::TooTight,
         FitArg::Runnable => fit.fit_level != llmfit_core::fit::FitLevel::TooTight,
+        FitArg::Gpu => {
+            fit.fit_level != llmfit_core::fit::FitLevel::TooTight
+                && fit.run_mode == llmfit_core::fit::RunMode::Gpu
+        }
+    }
+}
+
+fn query_requirement_met(fit: &ModelFit, require: QueryRequire) -> bool {
+    use llmfit_core::fit::FitLevel;
+    match require {
+        QueryRequire::Found => true,
+        QueryRequire::Runnable => fit_matches_filter(fit, FitArg::Runnable),
+        QueryRequire::Gpu => fit_matches_filter(fit, FitArg::Gpu),
+        QueryRequire::Good => matches!(fit.fit_level, FitLevel::Good | FitLevel::Perfect),
+        QueryRequire::Perfect => fit.fit_level == FitLevel::Perfect,
+    }
+}
+
+/// Resolve a query to one catalog entry: exact name, then Ollama tag, then
+/// substring, then substring ignoring punctuation. Among several partial
+/// matches the shortest name wins.
+fn resolve_query_index(models: &[llmfit_core::models::LlmModel], query: &str) -> Option<usize> {
+    let needle = query.trim().to_lowercase();
+    if needle.is_empty() {
+        return None;
+    }
+    if let Some(idx) = models.iter().position(|m| m.name.to_lowercase() == needle) {
+        return Some(idx);
+    }
+    if let Some(idx) = models.iter().position(|m| {
+        llmfit_core::providers::hf_name_to_ollama_candidates(&m.name).contains(&needle)
+    }) {
+        return Some(idx);
+    }
+
+    let shortest = |pred: &dyn Fn(&str) -> bool| {
+        models
+            .iter()
+            .enumerate()
+            .filter(|(_, m)| pred(&m.name.to_lowercase()))
+            .min_by_key(|(_, m)| m.name.len())
+            .map(|(i, _)| i)
+    };
+    let alnum = |s: &str| -> String { s.chars().filter(|c| c.is_alphanumeric()).collect() };
+    let compact_needle = alnum(&needle);
+    shortest(&|name| name.contains(&needle)).or_else(|| {
+        if compact_needle.is_empty() {
+            None
+        } else {
+            shortest(&|name| alnum(name).contains(&compact_needle))
+        }
+    })
+}
+
+fn run_query(
+    query: &str,
+    require: QueryRequire,
+    json: bool,
+    specs_source: &SpecsSource,
+    options: &FitOptions,
+) -> i32 {
+    let db = catalog::database();
+    let models = db.get_all_models();
+    let Some(idx) = resolve_query_index(models, query) else {
+        eprintln!("No model found matching '{}'", query);
+        return 1;
+    };
+
+    let specs = specs_source.detect();
+    let fit = ModelFit::analyze_with_options(&models[idx], &specs, options);
+    let ok = query_requirement_met(&fit, require);
+    if json {
+        display::display_json_fits(&specs, std::slice::from_ref(&fit));
+    } else {
+        println!(
+            "{}: {} ({}, {:.1}/{} = {:.0}%, ~{:.1} tok/s)",
+            fit.model.name,
+            fit.fit_text(),
+            fit.run_mode_text(),
+            options.units.convert(fit.memory_required_gb),
+            fmt_mem(fit.memory_available_gb, 1, options.units),
+            fit.utilization_pct,
+            fit.estimated_tps,
+        );
     }
+    if ok { 0 } else { 2 }
 }
 
 fn find_name_index_by_selector<T>(
@@ -719,28 +1292,22 @@ fn run_diff(
     sort: SortColumn,
     limit: usize,
     json: bool,
-    memory_override: &Option<String>,
-    context_limit: Option<u32>,
+    specs_source: &SpecsSource,
+    options: &FitOptions,
 ) {
     if limit < 2 {
-        eprintln!("Error: --limit must be at least 2 for diff");
-        std::process::exit(1);
+        exit_with_error("--limit must be at least 2 for diff");
     }
 
     if (model_a.is_some() && model_b.is_none()) || (model_a.is_none() && model_b.is_some()) {
-        eprintln!("Error: provide both model selectors, or neither to auto-compare top N");
-        std::process::exit(1);
+        exit_with_error("provide both model selectors, or neither to auto-compare top N");
     }
 
-    let specs = detect_specs(memory_override);
-    let db = ModelDatabase::new();
+    let specs = specs_source.detect();
+    let db = catalog::database();
 
-    let mut fits: Vec<ModelFit> = db
-        .get_all_models()
-        .iter()
-        .filter(|m| backend_compatible(m, &specs))
-        .map(|m| ModelFit::analyze_with_context_limit(m, &specs, context_limit))
-        .collect();
+    let mut fits: Vec<ModelFit> =
+        llmfit_core::fit::analyze_compatible(db.get_all_models(), &specs, options);
 
     fits.retain(|f| fit_matches_filter(f, fit_filter));
     fits = llmfit_core::fit::rank_models_by_fit_opts_col(fits, false, sort);
@@ -750,28 +1317,24 @@ fn run_diff(
             let a_idx = match find_fit_index_by_selector(&fits, a) {
                 Ok(i) => i,
                 Err(e) => {
-                    eprintln!("Error: {}", e);
-                    std::process::exit(1);
+                    exit_with_error(e);
                 }
             };
             let b_idx = match find_fit_index_by_selector(&fits, b) {
                 Ok(i) => i,
                 Err(e) => {
-                    eprintln!("Error: {}", e);
-                    std::process::exit(1);
+                    exit_with_error(e);
                 }
             };
 
             if a_idx == b_idx {
-                eprintln!("Error: both selectors resolved to the same model");
-                std::process::exit(1);
+                exit_with_error("both selectors resolved to the same model");
             }
 
             vec![fits[a_idx].clone(), fits[b_idx].clone()]
         } else {
             if fits.len() < 2 {
-                eprintln!("Error: need at least 2 models after filtering to compare");
-                std::process::exit(1);
+                exit_with_error("need at least 2 models after filtering to compare");
             }
             fits.into_iter().take(limit).collect()
         };
@@ -779,32 +1342,112 @@ fn run_diff(
     if json {
         display::display_json_diff_fits(&specs, &selected);
     } else {
-        specs.display();
+        specs.display(options.units, &specs_source.models_dir);
         display::display_model_diff(&selected, sort.label());
     }
 }
 
-fn run_tui(memory_override: &Option<String>, context_limit: Option<u32>) -> std::io::Result<()> {
+/// Leave raw mode and the alternate screen. Errors are ignored because this
+/// also runs from the panic hook, where there is nothing useful left to do.
+fn restore_terminal() {
+    let _ = crossterm::terminal::disable_raw_mode();
+    let _ = crossterm::execute!(
+        std::io::stdout(),
+        crossterm::terminal::LeaveAlternateScreen,
+        crossterm::event::DisableMouseCapture,
+        crossterm::cursor::Show
+    );
+}
+
+/// Restore the terminal before the default panic message prints, so a crash
+/// in draw code doesn't leave the shell in raw mode.
+fn install_panic_hook() {
+    let original = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        restore_terminal();
+        original(info);
+    }));
+}
+
+/// TUI-only settings resolved from the command line.
+struct TuiArgs {
+    catalog_url: Option<String>,
+    export_path: Option<std::path::PathBuf>,
+    mouse: bool,
+    theme: Option<theme::Theme>,
+    /// Draw fit indicators with ASCII characters (`--ascii`).
+    ascii: bool,
+    /// `--watch` interval.
+    watch: Option<std::time::Duration>,
+    /// Compare against and update the previous run's fit levels. Off for
+    /// runs that don't describe this machine as configured (`--no-config`,
+    /// `--specs-from`, hypothetical specs), so they can't skew the next run.
+    track_last_fit: bool,
+}
+
+fn run_tui(
+    specs_source: &SpecsSource,
+    options: &FitOptions<'static>,
+    args: TuiArgs,
+    config: Option<config::Config>,
+) -> std::io::Result<()> {
+    install_panic_hook();
+
     // Setup terminal
     crossterm::terminal::enable_raw_mode()?;
     let mut stdout = std::io::stdout();
-    crossterm::execute!(
-        stdout,
-        crossterm::terminal::EnterAlternateScreen,
-        crossterm::event::EnableMouseCapture
-    )?;
+    crossterm::execute!(stdout, crossterm::terminal::EnterAlternateScreen)?;
+    if args.mouse {
+        crossterm::execute!(stdout, crossterm::event::EnableMouseCapture)?;
+    }
 
     let backend = ratatui::backend::CrosstermBackend::new(stdout);
     let mut terminal = ratatui::Terminal::new(backend)?;
     draw_boot_screen(&mut terminal, "Detecting system hardware...")?;
 
     // Create app state
-    let specs = detect_specs(memory_override);
+    let specs = specs_source.detect();
     draw_boot_screen(&mut terminal, "Loading providers and models...")?;
-    let mut app = tui_app::App::with_specs_and_context(specs, context_limit);
+    let mut sources = catalog::sources();
+    let cached_catalog = args
+        .catalog_url
+        .as_deref()
+        .and_then(|url| catalog::load_cached_remote(url, &mut sources));
+    let mut app =
+        tui_app::App::with_sources(specs, *options, sources, specs_source.models_dir.clone());
+    let size = terminal.size()?;
+    app.on_resize(size.width, size.height);
+    if args.track_last_fit {
+        app.previous_fit = last_fit::load();
+    }
+    if let Some(path) = args.export_path {
+        app.export_path = path;
+    }
+    if let Some(config) = &config {
+        app.apply_config(config);
+    }
+    if let Some(theme) = args.theme {
+        app.theme = theme;
+    }
+    if let Some(url) = args.catalog_url {
+        app.fetch_remote_catalog(url, cached_catalog);
+    }
+    app.ascii = args.ascii;
+    if let Some(interval) = args.watch {
+        app.start_watch(interval);
+    }
+    // The app keeps the previous levels for this session; the next run
+    // compares against this one.
+    if args.track_last_fit {
+        last_fit::save(&app.all_fits);
+    }
 
     // Main loop
     loop {
+        if std::mem::take(&mut app.force_redraw) {
+            terminal.autoresize()?;
+            terminal.clear()?;
+        }
         terminal.draw(|frame| {
             tui_ui::draw(frame, &mut app);
         })?;
@@ -815,6 +1458,9 @@ fn run_tui(memory_override: &Option<String>, context_limit: Option<u32>) -> std:
             break;
         }
     }
+    if let Some(config) = &config {
+        config::save(&app.capture_config(config));
+    }
 
     // Restore terminal
     crossterm::terminal::disable_raw_mode()?;
@@ -869,18 +1515,15 @@ fn run_recommend(
     runtime_filter: String,
     capability: Option<String>,
     json: bool,
-    memory_override: &Option<String>,
-    context_limit: Option<u32>,
+    dedupe_family: bool,
+    specs_source: &SpecsSource,
+    options: &FitOptions,
 ) {
-    let specs = detect_specs(memory_override);
-    let db = ModelDatabase::new();
+    let specs = specs_source.detect();
+    let db = catalog::database();
 
-    let mut fits: Vec<ModelFit> = db
-        .get_all_models()
-        .iter()
-        .filter(|m| backend_compatible(m, &specs))
-        .map(|m| ModelFit::analyze_with_context_limit(m, &specs, context_limit))
-        .collect();
+    let mut fits: Vec<ModelFit> =
+        llmfit_core::fit::analyze_compatible(db.get_all_models(), &specs, options);
 
     // Filter by minimum fit level
     let min_level = match min_fit.to_lowercase().as_str() {
@@ -954,13 +1597,17 @@ fn run_recommend(
     }
 
     fits = llmfit_core::fit::rank_models_by_fit(fits);
+    let family_quants = dedupe_family.then(|| llmfit_core::fit::collapse_families(&mut fits));
     fits.truncate(limit);
 
     if json {
-        display::display_json_fits(&specs, &fits);
+        match &family_quants {
+            Some(quants) => display::display_json_family_fits(&specs, &fits, quants),
+            None => display::display_json_fits(&specs, &fits),
+        }
     } else {
         if !fits.is_empty() {
-            specs.display();
+            specs.display(options.units, &specs_source.models_dir);
         }
         display::display_model_fits(&fits);
     }
@@ -971,11 +1618,12 @@ fn run_download(
     quant: Option<&str>,
     budget: Option<f64>,
     list_only: bool,
-    memory_override: &Option<String>,
+    specs_source: &SpecsSource,
+    units: MemoryUnits,
 ) {
     use llmfit_core::providers::LlamaCppProvider;
 
-    let provider = LlamaCppProvider::new();
+    let provider = LlamaCppProvider::with_models_dir(specs_source.models_dir.clone());
 
     // Resolve repo ID: try known mapping, then treat as repo, then search
     let repo_id = if model.contains('/') {
@@ -1022,7 +1670,7 @@ fn run_download(
         println!("{}", "-".repeat(72));
         for (filename, size) in &files {
             let size_str = if *size > 1_073_741_824 {
-                format!("{:.1} GB", *size as f64 / 1_073_741_824.0)
+                fmt_mem(*size as f64 / 1_073_741_824.0, 1, units)
             } else {
                 format!("{:.0} MB", *size as f64 / 1_048_576.0)
             };
@@ -1047,7 +1695,7 @@ fn run_download(
             );
             eprintln!("\nAvailable files:");
             for (f, s) in &files {
-                let size_str = format!("{:.1} GB", *s as f64 / 1_073_741_824.0);
+                let size_str = fmt_mem(*s as f64 / 1_073_741_824.0, 1, units);
                 eprintln!("  {} ({})", f, size_str);
             }
             std::process::exit(1);
@@ -1057,7 +1705,7 @@ fn run_download(
         let mem_budget = if let Some(b) = budget {
             b
         } else {
-            let specs = detect_specs(memory_override);
+            let specs = specs_source.detect();
             specs
                 .total_gpu_vram_gb
                 .or(Some(specs.available_ram_gb))
@@ -1065,10 +1713,10 @@ fn run_download(
         };
         if let Some(result) = LlamaCppProvider::select_best_gguf(&files, mem_budget) {
             println!(
-                "Selected {} ({:.1} GB) for {:.0} GB memory budget",
+                "Selected {} ({}) for {} memory budget",
                 result.0,
-                result.1 as f64 / 1_073_741_824.0,
-                mem_budget
+                fmt_mem(result.1 as f64 / 1_073_741_824.0, 1, units),
+                fmt_mem(mem_budget, 0, units)
             );
             result
         } else {
@@ -1077,19 +1725,19 @@ fn run_download(
             sorted.sort_by_key(|(_, s)| *s);
             let (f, s) = sorted.first().expect("files list is not empty");
             println!(
-                "Warning: No quantization fits within {:.0} GB. Downloading smallest: {} ({:.1} GB)",
-                mem_budget,
+                "Warning: No quantization fits within {}. Downloading smallest: {} ({})",
+                fmt_mem(mem_budget, 0, units),
                 f,
-                *s as f64 / 1_073_741_824.0
+                fmt_mem(*s as f64 / 1_073_741_824.0, 1, units)
             );
             (f.clone(), *s)
         }
     };
 
     println!(
-        "\nDownloading {} ({:.1} GB) to {}",
+        "\nDownloading {} ({}) to {}",
         filename,
-        file_size as f64 / 1_073_741_824.0,
+        fmt_mem(file_size as f64 / 1_073_741_824.0, 1, units),
         provider.models_dir().display()
     );
 
@@ -1171,10 +1819,17 @@ fn run_hf_search(query: &str, limit: usize) {
     println!("To list files: llmfit download <repository> --list");
 }
 
-fn run_model(model: &str, server: bool, port: u16, ngl: i32, ctx_size: u32) {
+fn run_model(
+    model: &str,
+    server: bool,
+    port: u16,
+    ngl: i32,
+    ctx_size: u32,
+    models_dir: std::path::PathBuf,
+) {
     use llmfit_core::providers::LlamaCppProvider;
 
-    let provider = LlamaCppProvider::new();
+    let provider = LlamaCppProvider::with_models_dir(models_dir);
 
     // Find the model file
     let model_path = if std::path::Path::new(model).exists() {
@@ -1279,10 +1934,11 @@ fn run_plan(
     quant: Option<String>,
     target_tps: Option<f64>,
     json: bool,
-    memory_override: &Option<String>,
+    specs_source: &SpecsSource,
+    units: MemoryUnits,
 ) -> Result<(), String> {
-    let db = ModelDatabase::new();
-    let specs = detect_specs(memory_override);
+    let db = catalog::database();
+    let specs = specs_source.detect();
     let model = resolve_model_selector(db.get_all_models(), model_selector)?;
 
     let request = PlanRequest {
@@ -1290,36 +1946,317 @@ fn run_plan(
         quant,
         target_tps,
     };
-    let plan = estimate_model_plan(model, &request, &specs)?;
+    let plan = estimate_model_plan(model, &request, &specs, quants::table())?;
 
     if json {
         display::display_json_plan(&plan);
     } else {
-        specs.display();
-        display::display_model_plan(&plan);
+        specs.display(units, &specs_source.models_dir);
+        display::display_model_plan(&plan, units);
     }
 
     Ok(())
 }
 
+fn run_upgrade(limit: usize, json: bool, specs_source: &SpecsSource, options: &FitOptions) {
+    let specs = specs_source.detect();
+    let db = catalog::database();
+    let advice = llmfit_core::plan::advise_upgrades(
+        db.get_all_models(),
+        &specs,
+        options,
+        &llmfit_core::plan::default_upgrade_scenarios(),
+        limit,
+    );
+
+    if json {
+        display::display_json_upgrade_advice(&specs, &advice);
+    } else {
+        display::display_upgrade_advice(&specs, &advice, options.units);
+    }
+}
+
+/// `training` is `(batch, gradient_checkpointing)` when sizing a
+/// fine-tuning run instead of inference.
+fn run_hf(
+    path: &std::path::Path,
+    dtype: Option<String>,
+    context: Option<u32>,
+    training: Option<(u32, bool)>,
+    json: bool,
+    specs_source: &SpecsSource,
+    options: &FitOptions,
+) -> Result<(), String> {
+    let config = llmfit_core::HfConfig::from_path(path)?;
+    let (quants, units) = (options.quants, options.units);
+    let quant = match dtype {
+        Some(d) => llmfit_core::normalize_quant(&d, quants)
+            .ok_or_else(|| format!("Unsupported dtype/quantization '{}'", d))?,
+        None => config.dtype.clone().unwrap_or_else(|| "F16".to_string()),
+    };
+    let mut ctx = context.unwrap_or(config.max_position_embeddings);
+    if let Some(limit) = options.context_limit {
+        ctx = ctx.min(limit);
+    }
+
+    let specs = specs_source.detect();
+    let model = config.to_model(&quant, ctx, quants);
+
+    if let Some((batch_size, gradient_checkpointing)) = training {
+        let training = config.training_memory(
+            &quant,
+            llmfit_core::TrainingSetup {
+                batch_size,
+                seq_len: ctx,
+                gradient_checkpointing,
+            },
+            quants,
+        );
+        let fit = ModelFit::analyze_fixed_memory(&model, &specs, training.total_gb(), options);
+        if json {
+            display::display_json_training_fit(&specs, &fit, &training);
+        } else {
+            println!();
+            println!("  Architecture:  {} ({})", model.name, config.model_type);
+            println!(
+                "  Training:      batch {} x {} tokens, Adam, {}",
+                batch_size,
+                ctx,
+                if gradient_checkpointing {
+                    "gradient checkpointing"
+                } else {
+                    "no checkpointing"
+                }
+            );
+            println!(
+                "  Weights:       {}",
+                fmt_mem(training.weights_gb, 2, units)
+            );
+            println!(
+                "  Gradients:     {}",
+                fmt_mem(training.gradients_gb, 2, units)
+            );
+            println!(
+                "  Optimizer:     {} (2 fp32 moments)",
+                fmt_mem(training.optimizer_gb, 2, units)
+            );
+            println!(
+                "  Activations:   {}",
+                fmt_mem(training.activations_gb, 2, units)
+            );
+            println!(
+                "  Overhead:      {}",
+                fmt_mem(training.overhead_gb, 2, units)
+            );
+            println!(
+                "  Total:         {}",
+                fmt_mem(training.total_gb(), 2, units)
+            );
+            display::display_model_detail(&fit, units);
+        }
+        return Ok(());
+    }
+
+    let fit = ModelFit::analyze_fixed_memory(
+        &model,
+        &specs,
+        config.total_memory_gb(&quant, ctx, quants),
+        options,
+    );
+
+    if json {
+        display::display_json_fits(&specs, std::slice::from_ref(&fit));
+    } else {
+        println!();
+        println!("  Architecture:  {} ({})", model.name, config.model_type);
+        println!(
+            "  Layers/heads:  {} layers, {} heads ({} KV), hidden {}",
+            config.num_hidden_layers,
+            config.num_attention_heads,
+            config.num_key_value_heads,
+            config.hidden_size
+        );
+        println!(
+            "  Parameters:    {:.2}B{}",
+            config.parameter_count() as f64 / 1e9,
+            if model.is_moe {
+                format!(
+                    " ({:.2}B active)",
+                    config.active_parameter_count() as f64 / 1e9
+                )
+            } else {
+                String::new()
+            }
+        );
+        println!(
+            "  Weights:       {} at {}",
+            fmt_mem(config.weights_gb(&quant, quants), 2, units),
+            quant
+        );
+        println!(
+            "  KV cache:      {} at {} tokens (fp16)",
+            fmt_mem(config.kv_cache_gb(ctx), 2, units),
+            ctx
+        );
+        display::display_model_detail(&fit, units);
+    }
+    Ok(())
+}
+
 fn main() {
     let cli = Cli::parse();
+    let verbose = cli.verbose;
+    if let Some(path) = &cli.log_file
+        && let Err(e) = logging::init(path)
+    {
+        exit_with_error(format!("can't open log file {}: {}", path.display(), e));
+    }
+    quants::load();
+    run(cli);
+    quants::warn_unknown();
+    catalog::warn_errors();
+    if verbose {
+        for entry in llmfit_core::activity::entries() {
+            eprintln!("[{}] {}", entry.kind.label(), entry.message);
+        }
+    }
+}
+
+/// Report a fatal error on stderr and in the log file, then exit.
+fn exit_with_error(message: impl std::fmt::Display) -> ! {
+    log::error!("{}", message);
+    eprintln!("Error: {}", message);
+    std::process::exit(1);
+}
+
+fn run(cli: Cli) {
     let context_limit = resolve_context_limit(cli.max_context);
+    if let Some(gb) = cli.reserve
+        && (!gb.is_finite() || gb < 0.0)
+    {
+        exit_with_error("--reserve must be a non-negative number of GB");
+    }
+    let parse_size = |flag: &str, size: &Option<String>| {
+        size.as_deref().map(|size| {
+            llmfit_core::hardware::parse_memory_size(size)
+                .filter(|gb| *gb > 0.0)
+                .unwrap_or_else(|| {
+                    exit_with_error(format!(
+                        "{}: could not parse '{}'. Expected format: 64G, 64000M, 1.5T",
+                        flag, size
+                    ))
+                })
+        })
+    };
+    let what_if = Hypothetical {
+        ram_gb: parse_size("--ram", &cli.ram),
+        vram_gb: parse_size("--vram", &cli.vram),
+        cores: cli.cores,
+    };
+    if what_if.vram_gb.is_some() && cli.memory.is_some() {
+        exit_with_error("--vram and --memory both set the GPU's VRAM; pass only one");
+    }
+    if what_if.cores == Some(0) {
+        exit_with_error("--cores must be at least 1");
+    }
+    let gpu_wired_limit_gb = cli.gpu_wired_limit.as_deref().map(|size| {
+        llmfit_core::hardware::parse_memory_size(size)
+            .filter(|gb| *gb > 0.0)
+            .unwrap_or_else(|| {
+                exit_with_error(format!(
+                    "--gpu-wired-limit: could not parse '{}'. Expected format: 28G, 28000M",
+                    size
+                ))
+            })
+    });
+    let mut config = (!cli.no_config).then(config::load);
+    if let Some(gb) = cli.headroom
+        && (!gb.is_finite() || gb < 0.0)
+    {
+        exit_with_error("--headroom must be a non-negative number of GB");
+    }
+    let headroom = cli
+        .headroom
+        .or(config.as_ref().and_then(|c| c.headroom_gb))
+        .filter(|gb| gb.is_finite() && *gb >= 0.0)
+        .unwrap_or(llmfit_core::hardware::DEFAULT_MEMORY_HEADROOM_GB);
+    if cli.concurrency == 0 {
+        exit_with_error("--concurrency must be at least 1");
+    }
+    let fit_options = FitOptions {
+        context_limit,
+        run_mode: None,
+        target: cli.fit_target.into(),
+        concurrency: cli.concurrency,
+        quants: quants::table(),
+        units: cli.units.into(),
+    };
+
+    // Everything but the TUI gets detection warnings on stderr as they happen
+    let interactive = cli.command.is_none() && !(cli.metrics || cli.jsonl || cli.cli || cli.json);
+    llmfit_core::activity::set_stderr_warnings(!cli.quiet && !interactive);
+
+    let mut sources = CatalogSources::default();
+    catalog::load(&mut sources);
+    // The TUI starts from the cached copy and fetches in the background
+    if let Some(url) = &cli.catalog_url
+        && !interactive
+    {
+        catalog::load_remote(url, &mut sources);
+    }
+    if let Some(dir) = &cli.scan_dir {
+        if !dir.is_dir() {
+            exit_with_error(format!("--scan-dir: {} is not a directory", dir.display()));
+        }
+        catalog::scan_local(dir, &mut sources);
+    }
+    if cli.enrich {
+        catalog::enrich(cli.quiet, &mut sources);
+    }
+    catalog::set_sources(sources);
+    let loaded = cli.specs_from.as_ref().map(|path| {
+        let mut specs = std::fs::read_to_string(path)
+            .map_err(|err| err.to_string())
+            .and_then(|text| SystemSpecs::from_profile_json(&text))
+            .unwrap_or_else(|err| {
+                exit_with_error(format!("--specs-from: {}: {}", path.display(), err))
+            });
+        specs.loaded_from = Some(path.display().to_string());
+        specs
+    });
+    let specs_source = SpecsSource {
+        loaded,
+        hypothetical: what_if,
+        gpu_wired_limit_gb,
+        ram_reserve_gb: cli.reserve,
+        memory_override: cli.memory.clone(),
+        headroom_gb: headroom,
+        bench: cli.bench,
+        measured_bandwidth: std::sync::OnceLock::new(),
+        models_dir: cli
+            .models_dir
+            .clone()
+            .unwrap_or_else(llmfit_core::providers::llamacpp_models_dir),
+    };
+    if let Some(path) = &cli.dump_specs {
+        specs_source.dump(path);
+        return;
+    }
 
     // If a subcommand is given, use classic CLI mode
     if let Some(command) = cli.command {
         match command {
             Commands::System => {
-                let specs = detect_specs(&cli.memory);
+                let specs = specs_source.detect();
                 if cli.json {
                     display::display_json_system(&specs);
                 } else {
-                    specs.display();
+                    specs.display(fit_options.units, &specs_source.models_dir);
                 }
             }
 
             Commands::List => {
-                let db = ModelDatabase::new();
+                let db = catalog::database();
                 if cli.json {
                     println!(
                         "{}",
@@ -1331,30 +2268,47 @@ fn main() {
                 }
             }
 
+            Commands::Fit {
+                perfect,
+                limit,
+                sort: _,
+                jsonl: true,
+            } => {
+                if cli.dedupe_family {
+                    eprintln!(
+                        "Error: --dedupe-family needs ranked output and can't stream with --jsonl"
+                    );
+                    std::process::exit(1);
+                }
+                run_fit_jsonl(perfect, limit, &specs_source, &fit_options);
+            }
+
             Commands::Fit {
                 perfect,
                 limit,
                 sort,
+                jsonl: false,
             } => {
                 run_fit(
                     perfect,
                     limit,
                     sort.into(),
                     cli.json,
-                    &cli.memory,
-                    context_limit,
+                    cli.dedupe_family,
+                    &specs_source,
+                    &fit_options,
                 );
             }
 
             Commands::Search { query } => {
-                let db = ModelDatabase::new();
+                let db = catalog::database();
                 let results = db.find_model(&query);
                 display::display_search_results(&results, &query);
             }
 
             Commands::Info { model } => {
-                let db = ModelDatabase::new();
-                let specs = detect_specs(&cli.memory);
+                let db = catalog::database();
+                let specs = specs_source.detect();
                 let models = db.get_all_models();
 
                 let idx = match find_name_index_by_selector(models, &model, |m| m.name.as_str()) {
@@ -1365,11 +2319,18 @@ fn main() {
                     }
                 };
 
-                let fit = ModelFit::analyze_with_context_limit(&models[idx], &specs, context_limit);
+                let fit = ModelFit::analyze_with_options(&models[idx], &specs, &fit_options);
                 if cli.json {
                     display::display_json_fits(&specs, &[fit]);
                 } else {
-                    display::display_model_detail(&fit);
+                    display::display_model_detail(&fit, fit_options.units);
+                }
+            }
+
+            Commands::Query { model, require } => {
+                let code = run_query(&model, require, cli.json, &specs_source, &fit_options);
+                if code != 0 {
+                    std::process::exit(code);
                 }
             }
 
@@ -1387,8 +2348,8 @@ fn main() {
                     sort.into(),
                     limit,
                     cli.json,
-                    &cli.memory,
-                    context_limit,
+                    &specs_source,
+                    &fit_options,
                 );
             }
 
@@ -1398,14 +2359,23 @@ fn main() {
                 quant,
                 target_tps,
             } => {
-                if let Err(err) =
-                    run_plan(&model, context, quant, target_tps, cli.json, &cli.memory)
-                {
-                    eprintln!("Error: {}", err);
-                    std::process::exit(1);
+                if let Err(err) = run_plan(
+                    &model,
+                    context,
+                    quant,
+                    target_tps,
+                    cli.json,
+                    &specs_source,
+                    fit_options.units,
+                ) {
+                    exit_with_error(err);
                 }
             }
 
+            Commands::Upgrade { limit } => {
+                run_upgrade(limit, cli.json, &specs_source, &fit_options);
+            }
+
             Commands::Recommend {
                 limit,
                 use_case,
@@ -1421,8 +2391,9 @@ fn main() {
                     runtime,
                     capability,
                     json,
-                    &cli.memory,
-                    context_limit,
+                    cli.dedupe_family,
+                    &specs_source,
+                    &fit_options,
                 );
             }
 
@@ -1432,13 +2403,42 @@ fn main() {
                 budget,
                 list,
             } => {
-                run_download(&model, quant.as_deref(), budget, list, &cli.memory);
+                run_download(
+                    &model,
+                    quant.as_deref(),
+                    budget,
+                    list,
+                    &specs_source,
+                    fit_options.units,
+                );
             }
 
             Commands::HfSearch { query, limit } => {
                 run_hf_search(&query, limit);
             }
 
+            Commands::Hf {
+                path,
+                dtype,
+                context,
+                train,
+                batch,
+                checkpointing,
+            } => {
+                let training = train.then_some((batch, checkpointing));
+                if let Err(err) = run_hf(
+                    &path,
+                    dtype,
+                    context,
+                    training,
+                    cli.json,
+                    &specs_source,
+                    &fit_options,
+                ) {
+                    exit_with_error(err);
+                }
+            }
+
             Commands::Run {
                 model,
                 server,
@@ -1446,19 +2446,35 @@ fn main() {
                 ngl,
                 ctx_size,
             } => {
-                run_model(&model, server, port, ngl, ctx_size);
+                run_model(
+                    &model,
+                    server,
+                    port,
+                    ngl,
+                    ctx_size,
+                    specs_source.models_dir.clone(),
+                );
             }
 
             Commands::Serve { host, port } => {
-                if let Err(err) = serve_api::run_serve(&host, port, &cli.memory, context_limit) {
-                    eprintln!("Error: {}", err);
-                    std::process::exit(1);
+                if let Err(err) = serve_api::run_serve(&host, port, &specs_source, &fit_options) {
+                    exit_with_error(err);
                 }
             }
         }
         return;
     }
 
+    if cli.metrics {
+        run_metrics(&specs_source, &fit_options);
+        return;
+    }
+
+    if cli.jsonl {
+        run_fit_jsonl(cli.perfect, cli.limit, &specs_source, &fit_options);
+        return;
+    }
+
     // If --cli or --json flag, use classic fit output
     if cli.cli || cli.json {
         run_fit(
@@ -1466,14 +2482,52 @@ fn main() {
             cli.limit,
             cli.sort.into(),
             cli.json,
-            &cli.memory,
-            context_limit,
+            cli.dedupe_family,
+            &specs_source,
+            &fit_options,
         );
         return;
     }
 
     // Default: launch TUI
-    if let Err(e) = run_tui(&cli.memory, context_limit) {
+    let ascii = cli.ascii || config.as_ref().is_some_and(|c| c.ascii);
+    // Flags given this run become the saved preference
+    if let Some(config) = config.as_mut() {
+        config.ascii = ascii;
+        if cli.headroom.is_some() {
+            config.headroom_gb = cli.headroom;
+        }
+    }
+    let theme = cli.theme.as_deref().map(|name| {
+        theme::Theme::parse(name).unwrap_or_else(|| {
+            exit_with_error(format!(
+                "--theme: unknown theme '{}'. Try light, dark, dracula, nord or gruvbox",
+                name
+            ))
+        })
+    });
+    if cli.watch == Some(0) {
+        exit_with_error("--watch interval must be at least 1 second");
+    }
+    let track_last_fit = !cli.no_config
+        && cli.specs_from.is_none()
+        && cli.memory.is_none()
+        && cli.ram.is_none()
+        && cli.vram.is_none()
+        && cli.cores.is_none()
+        && cli.reserve.is_none()
+        && cli.headroom.is_none();
+    let args = TuiArgs {
+        catalog_url: cli.catalog_url,
+        export_path: cli.export_path,
+        mouse: !cli.no_mouse,
+        theme,
+        ascii,
+        watch: cli.watch.map(std::time::Duration::from_secs),
+        track_last_fit,
+    };
+    if let Err(e) = run_tui(&specs_source, &fit_options, args, config) {
+        log::error!("running TUI: {}", e);
         eprintln!("Error running TUI: {}", e);
         std::process::exit(1);
     }
@@ -1505,11 +2559,17 @@ mod tests {
                 release_date: Some("2025-01-01".to_string()),
                 gguf_sources: vec![],
                 capabilities: vec![],
+                tags: vec![],
                 format: llmfit_core::models::ModelFormat::default(),
+                num_layers: None,
+                kv_hidden_size: None,
+                hf_downloads: None,
+                gguf_file_sizes: Default::default(),
             },
             fit_level,
             run_mode: RunMode::Gpu,
             memory_required_gb: 4.0,
+            memory_required_low_gb: 4.0,
             memory_available_gb: 8.0,
             utilization_pct: 50.0,
             notes: vec![],
@@ -1523,9 +2583,12 @@ mod tests {
             },
             estimated_tps: 30.0,
             best_quant: "Q4_K_M".to_string(),
+            weights_gb: 4.0,
             use_case: llmfit_core::models::UseCase::General,
             runtime: InferenceRuntime::LlamaCpp,
             installed: false,
+            estimated_context: 8192,
+            concurrency: 1,
         }
     }
 
@@ -1537,6 +2600,42 @@ mod tests {
         assert!(!fit_matches_filter(&tight, FitArg::Runnable));
     }
 
+    #[test]
+    fn fit_filter_gpu_excludes_cpu_offload() {
+        let gpu = mock_fit("alpha/model", FitLevel::Good);
+        let mut offload = mock_fit("beta/model", FitLevel::Good);
+        offload.run_mode = RunMode::CpuOffload;
+        let mut tight = mock_fit("gamma/model", FitLevel::TooTight);
+        tight.run_mode = RunMode::Gpu;
+        assert!(fit_matches_filter(&gpu, FitArg::Gpu));
+        assert!(!fit_matches_filter(&offload, FitArg::Gpu));
+        assert!(!fit_matches_filter(&tight, FitArg::Gpu));
+        assert!(fit_matches_filter(&offload, FitArg::Runnable));
+    }
+
+    #[test]
+    fn query_resolves_closest_partial_match() {
+        let models: Vec<LlmModel> = ["org/Model-7B-Instruct", "org/Model-7B", "org/Other-7B"]
+            .iter()
+            .map(|n| mock_fit(n, FitLevel::Good).model)
+            .collect();
+        assert_eq!(resolve_query_index(&models, "org/other-7b"), Some(2));
+        assert_eq!(resolve_query_index(&models, "model-7b"), Some(1));
+        assert_eq!(resolve_query_index(&models, "model7b instruct"), Some(0));
+        assert_eq!(resolve_query_index(&models, "missing"), None);
+    }
+
+    #[test]
+    fn query_requirement_levels() {
+        let good = mock_fit("a", FitLevel::Good);
+        assert!(query_requirement_met(&good, QueryRequire::Runnable));
+        assert!(query_requirement_met(&good, QueryRequire::Good));
+        assert!(!query_requirement_met(&good, QueryRequire::Perfect));
+        let tight = mock_fit("b", FitLevel::TooTight);
+        assert!(query_requirement_met(&tight, QueryRequire::Found));
+        assert!(!query_requirement_met(&tight, QueryRequire::Runnable));
+    }
+
     #[test]
     fn selector_prefers_exact_match() {
         let fits = vec![
@@ -1578,7 +2677,12 @@ mod tests {
                 release_date: None,
                 gguf_sources: vec![],
                 capabilities: vec![],
+                tags: vec![],
                 format: llmfit_core::models::ModelFormat::default(),
+                num_layers: None,
+                kv_hidden_size: None,
+                hf_downloads: None,
+                gguf_file_sizes: Default::default(),
             },
             LlmModel {
                 name: "Qwen/Qwen3-Coder-Next".to_string(),
@@ -1598,7 +2702,12 @@ mod tests {
                 release_date: None,
                 gguf_sources: vec![],
                 capabilities: vec![],
+                tags: vec![],
                 format: llmfit_core::models::ModelFormat::default(),
+                num_layers: None,
+                kv_hidden_size: None,
+                hf_downloads: None,
+                gguf_file_sizes: Default::default(),
             },
         ];
 
@@ -0,0 +1,48 @@
+use llmfit_core::models::QuantTable;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::OnceLock;
+
+/// The table every estimate in this run uses; set once by [`load`].
+static TABLE: OnceLock<QuantTable> = OnceLock::new();
+
+/// Path to the custom quantization table: ~/.config/llmfit/quants
+fn config_path() -> Option<PathBuf> {
+    Some(llmfit_core::config_dir()?.join("quants"))
+}
+
+/// Load user bytes-per-weight entries (`NAME = BYTES` per line) on top of the
+/// built-in table. A malformed file is reported and ignored.
+pub fn load() {
+    let Some(path) = config_path() else {
+        return;
+    };
+    let Ok(text) = fs::read_to_string(&path) else {
+        return;
+    };
+    match QuantTable::parse(&text) {
+        Ok(table) => {
+            let _ = TABLE.set(table);
+        }
+        Err(err) => eprintln!("Warning: ignoring {}: {}", path.display(), err),
+    }
+}
+
+/// The loaded table, or the built-in one when there are no user entries.
+pub fn table() -> &'static QuantTable {
+    TABLE.get().unwrap_or_else(|| QuantTable::builtin())
+}
+
+/// Print a warning for every catalog quantization without a
+/// bytes-per-weight entry.
+pub fn warn_unknown() {
+    let db = crate::catalog::database();
+    for quant in table().unknown_quants(db.get_all_models()) {
+        log::warn!("unknown quantization '{}'", quant);
+        eprintln!(
+            "Warning: unknown quantization '{}', assumed {} bytes/weight (add it to ~/.config/llmfit/quants)",
+            quant,
+            llmfit_core::models::UNKNOWN_QUANT_BPP
+        );
+    }
+}
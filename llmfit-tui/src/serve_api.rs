@@ -7,11 +7,10 @@ use axum::response::{IntoResponse, Response};
 use axum::routing::get;
 use axum::{Json, Router};
 use llmfit_core::fit::{
-    FitLevel, InferenceRuntime, ModelFit, SortColumn, backend_compatible,
-    rank_models_by_fit_opts_col,
+    FitLevel, FitOptions, InferenceRuntime, ModelFit, SortColumn, rank_models_by_fit_opts_col,
 };
-use llmfit_core::hardware::{GpuBackend, SystemSpecs};
-use llmfit_core::models::{LlmModel, ModelDatabase, UseCase};
+use llmfit_core::hardware::{GpuBackend, MemoryUnits, SystemSpecs};
+use llmfit_core::models::{LlmModel, UseCase};
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone)]
@@ -20,7 +19,7 @@ struct AppState {
     os: String,
     specs: SystemSpecs,
     models: Vec<LlmModel>,
-    context_limit: Option<u32>,
+    fit_options: FitOptions<'static>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -94,16 +93,16 @@ type ApiResult<T> = Result<T, ApiError>;
 pub fn run_serve(
     host: &str,
     port: u16,
-    memory_override: &Option<String>,
-    context_limit: Option<u32>,
+    specs_source: &crate::SpecsSource,
+    fit_options: &FitOptions<'static>,
 ) -> Result<(), String> {
     let ip: IpAddr = host
         .parse()
         .map_err(|_| format!("invalid --host value: '{host}'"))?;
     let addr = SocketAddr::new(ip, port);
 
-    let specs = detect_specs(memory_override);
-    let db = ModelDatabase::new();
+    let specs = specs_source.detect();
+    let db = crate::catalog::database();
     let all_models = db.get_all_models().clone();
 
     let node_name = std::env::var("HOSTNAME")
@@ -116,7 +115,7 @@ pub fn run_serve(
         os: std::env::consts::OS.to_string(),
         specs,
         models: all_models,
-        context_limit,
+        fit_options: *fit_options,
     });
 
     let app = Router::new()
@@ -196,7 +195,7 @@ async fn models(
         total_models,
         returned_models: fits.len(),
         filters: active_filters_json(&query, false),
-        models: fits.iter().map(fit_to_json).collect(),
+        models: fits.iter().map(|f| fit_to_json(f, &state.specs)).collect(),
     };
 
     Ok(Json(envelope))
@@ -223,7 +222,7 @@ async fn top_models(
         total_models,
         returned_models: fits.len(),
         filters: active_filters_json(&query, true),
-        models: fits.iter().map(fit_to_json).collect(),
+        models: fits.iter().map(|f| fit_to_json(f, &state.specs)).collect(),
     };
 
     Ok(Json(envelope))
@@ -254,7 +253,7 @@ async fn model_by_name(
         total_models,
         returned_models: fits.len(),
         filters: active_filters_json(&scoped, false),
-        models: fits.iter().map(fit_to_json).collect(),
+        models: fits.iter().map(|f| fit_to_json(f, &state.specs)).collect(),
     };
 
     Ok(Json(envelope))
@@ -270,13 +269,12 @@ fn filtered_fits(
     let runtime_filter = parse_runtime(query.runtime.as_deref())?;
     let use_case_filter = parse_use_case(query.use_case.as_deref())?;
 
-    let context_limit = query.max_context.or(state.context_limit);
-    let mut fits: Vec<ModelFit> = state
-        .models
-        .iter()
-        .filter(|m| backend_compatible(m, &state.specs))
-        .map(|m| ModelFit::analyze_with_context_limit(m, &state.specs, context_limit))
-        .collect();
+    let fit_options = FitOptions {
+        context_limit: query.max_context.or(state.fit_options.context_limit),
+        ..state.fit_options
+    };
+    let mut fits: Vec<ModelFit> =
+        llmfit_core::fit::analyze_compatible(&state.models, &state.specs, &fit_options);
 
     let is_apple_silicon = state.specs.backend == GpuBackend::Metal && state.specs.unified_memory;
     if !is_apple_silicon {
@@ -482,8 +480,18 @@ fn system_json(specs: &SystemSpecs) -> serde_json::Value {
     serde_json::json!({
         "total_ram_gb": round2(specs.total_ram_gb),
         "available_ram_gb": round2(specs.available_ram_gb),
+        "swap_total_gb": round2(specs.swap_total_gb),
+        "swap_used_gb": round2(specs.swap_used_gb),
+        "gpu_wired_limit_gb": specs.gpu_wired_limit_gb.map(round2),
+        "hypothetical": specs.hypothetical,
+        "free_disk_gb": specs.free_disk_gb.map(round2),
+        "mem_bandwidth_gbps": round1(specs.mem_bandwidth_gbps()),
+        "mem_bandwidth_detected": specs.memory_bandwidth_gbps.is_some(),
         "cpu_cores": specs.total_cpu_cores,
         "cpu_name": specs.cpu_name,
+        "cpu_features": specs.cpu_features.labels(),
+        "has_npu": specs.has_npu,
+        "npu_name": specs.npu_name,
         "has_gpu": specs.has_gpu,
         "gpu_vram_gb": specs.gpu_vram_gb.map(round2),
         "gpu_name": specs.gpu_name,
@@ -494,12 +502,14 @@ fn system_json(specs: &SystemSpecs) -> serde_json::Value {
     })
 }
 
-fn fit_to_json(fit: &ModelFit) -> serde_json::Value {
+fn fit_to_json(fit: &ModelFit, specs: &SystemSpecs) -> serde_json::Value {
     serde_json::json!({
         "name": fit.model.name,
         "provider": fit.model.provider,
         "parameter_count": fit.model.parameter_count,
         "params_b": round2(fit.model.params_b()),
+        "active_params_b": fit.model.active_params_b().map(round2),
+        "weight_size_gb": round2(fit.weights_gb),
         "context_length": fit.model.context_length,
         "use_case": fit.model.use_case,
         "category": fit.use_case.label(),
@@ -526,6 +536,8 @@ fn fit_to_json(fit: &ModelFit) -> serde_json::Value {
         "total_memory_gb": round2(fit.memory_required_gb + fit.moe_offloaded_gb.unwrap_or(0.0)),
         "utilization_pct": round1(fit.utilization_pct),
         "notes": fit.notes,
+        // JSON sizes are always binary GB, so the explanation text is too
+        "explanation": fit.explain(specs, MemoryUnits::Binary),
         "gguf_sources": fit.model.gguf_sources,
     })
 }
@@ -537,16 +549,3 @@ fn round1(v: f64) -> f64 {
 fn round2(v: f64) -> f64 {
     (v * 100.0).round() / 100.0
 }
-
-/// Detect system specs with optional GPU memory override.
-fn detect_specs(memory_override: &Option<String>) -> SystemSpecs {
-    let specs = SystemSpecs::detect();
-    if let Some(mem_str) = memory_override {
-        match llmfit_core::hardware::parse_memory_size(mem_str) {
-            Some(gb) => specs.with_gpu_memory_override(gb),
-            None => specs,
-        }
-    } else {
-        specs
-    }
-}
@@ -6,6 +6,7 @@ use std::path::PathBuf;
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Theme {
     Default,
+    Light,
     Dracula,
     Solarized,
     Nord,
@@ -21,6 +22,7 @@ impl Theme {
     pub fn label(&self) -> &'static str {
         match self {
             Theme::Default => "Default",
+            Theme::Light => "Light",
             Theme::Dracula => "Dracula",
             Theme::Solarized => "Solarized",
             Theme::Nord => "Nord",
@@ -35,7 +37,8 @@ impl Theme {
 
     pub fn next(&self) -> Self {
         match self {
-            Theme::Default => Theme::Dracula,
+            Theme::Default => Theme::Light,
+            Theme::Light => Theme::Dracula,
             Theme::Dracula => Theme::Solarized,
             Theme::Solarized => Theme::Nord,
             Theme::Nord => Theme::Monokai,
@@ -51,6 +54,7 @@ impl Theme {
     pub fn colors(&self) -> ThemeColors {
         match self {
             Theme::Default => default_colors(),
+            Theme::Light => light_colors(),
             Theme::Dracula => dracula_colors(),
             Theme::Solarized => solarized_colors(),
             Theme::Nord => nord_colors(),
@@ -65,15 +69,7 @@ impl Theme {
 
     /// Path to the config file: ~/.config/llmfit/theme
     fn config_path() -> Option<PathBuf> {
-        let home = std::env::var("HOME")
-            .or_else(|_| std::env::var("USERPROFILE"))
-            .ok()?;
-        Some(
-            PathBuf::from(home)
-                .join(".config")
-                .join("llmfit")
-                .join("theme"),
-        )
+        Some(llmfit_core::config_dir()?.join("theme"))
     }
 
     /// Save the current theme to disk.
@@ -86,16 +82,46 @@ impl Theme {
         }
     }
 
-    /// Load the saved theme from disk, falling back to Default.
+    /// Load the saved theme from disk. Without one, pick Light when the
+    /// terminal reports a light background and Default otherwise.
     pub fn load() -> Self {
         Self::config_path()
             .and_then(|path| fs::read_to_string(path).ok())
             .map(|s| Self::from_label(s.trim()))
-            .unwrap_or(Theme::Default)
+            .unwrap_or_else(|| {
+                let colorfgbg = std::env::var("COLORFGBG").unwrap_or_default();
+                if is_light_background(&colorfgbg) {
+                    Theme::Light
+                } else {
+                    Theme::Default
+                }
+            })
+    }
+
+    /// Parse a `--theme` value: a theme's label in any case, or "dark" for
+    /// Default.
+    pub fn parse(name: &str) -> Option<Self> {
+        let name = name.trim().to_lowercase();
+        if name == "dark" {
+            return Some(Theme::Default);
+        }
+        let mut theme = Theme::Default;
+        loop {
+            if theme.label().to_lowercase() == name
+                || theme.label().to_lowercase().replace('é', "e") == name
+            {
+                return Some(theme);
+            }
+            theme = theme.next();
+            if theme == Theme::Default {
+                return None;
+            }
+        }
     }
 
     fn from_label(s: &str) -> Self {
         match s {
+            "Light" => Theme::Light,
             "Dracula" => Theme::Dracula,
             "Solarized" => Theme::Solarized,
             "Nord" => Theme::Nord,
@@ -110,6 +136,15 @@ impl Theme {
     }
 }
 
+/// Whether a `COLORFGBG` value ("fg;bg", set by rxvt, Konsole and others)
+/// names a light background: ANSI white (7) or bright white (15).
+fn is_light_background(colorfgbg: &str) -> bool {
+    matches!(
+        colorfgbg.rsplit(';').next().map(str::trim),
+        Some("7" | "15")
+    )
+}
+
 /// All semantic colors used throughout the TUI, mapped from each theme.
 pub struct ThemeColors {
     // General
@@ -150,6 +185,11 @@ pub struct ThemeColors {
     // Status bar
     pub status_bg: Color,
     pub status_fg: Color,
+
+    // Row backgrounds
+    pub pulling_bg: Color,
+    pub range_bg: Color,
+    pub stripe_bg: Color,
 }
 
 fn default_colors() -> ThemeColors {
@@ -188,6 +228,52 @@ fn default_colors() -> ThemeColors {
 
         status_bg: Color::Green,
         status_fg: Color::Black,
+
+        pulling_bg: Color::Rgb(50, 50, 0),
+        range_bg: Color::Rgb(40, 40, 80),
+        stripe_bg: Color::Rgb(25, 25, 35),
+    }
+}
+
+fn light_colors() -> ThemeColors {
+    // For light-background terminals: the default palette's yellow, cyan
+    // and gray wash out on white, so use darker shades throughout.
+    ThemeColors {
+        bg: Color::Reset,
+        fg: Color::Reset,
+        muted: Color::Rgb(96, 96, 96),
+        border: Color::Rgb(150, 150, 150),
+        title: Color::Rgb(0, 110, 0),
+        highlight_bg: Color::Rgb(196, 216, 250),
+
+        accent: Color::Rgb(0, 90, 140),
+        accent_secondary: Color::Rgb(150, 85, 0),
+
+        good: Color::Rgb(0, 120, 0),
+        warning: Color::Rgb(170, 95, 0),
+        error: Color::Rgb(190, 0, 0),
+        info: Color::Rgb(0, 90, 140),
+
+        score_high: Color::Rgb(0, 120, 0),
+        score_mid: Color::Rgb(170, 95, 0),
+        score_low: Color::Rgb(190, 0, 0),
+
+        fit_perfect: Color::Rgb(0, 120, 0),
+        fit_good: Color::Rgb(150, 110, 0),
+        fit_marginal: Color::Rgb(140, 0, 140),
+        fit_tight: Color::Rgb(190, 0, 0),
+
+        mode_gpu: Color::Rgb(0, 120, 0),
+        mode_moe: Color::Rgb(0, 90, 140),
+        mode_offload: Color::Rgb(170, 95, 0),
+        mode_cpu: Color::Rgb(96, 96, 96),
+
+        status_bg: Color::Rgb(0, 110, 0),
+        status_fg: Color::White,
+
+        pulling_bg: Color::Rgb(250, 238, 190),
+        range_bg: Color::Rgb(214, 222, 248),
+        stripe_bg: Color::Rgb(238, 239, 244),
     }
 }
 
@@ -225,6 +311,10 @@ fn dracula_colors() -> ThemeColors {
 
         status_bg: Color::Rgb(189, 147, 249),
         status_fg: Color::Rgb(40, 42, 54),
+
+        pulling_bg: Color::Rgb(50, 50, 0),
+        range_bg: Color::Rgb(40, 40, 80),
+        stripe_bg: Color::Rgb(25, 25, 35),
     }
 }
 
@@ -262,6 +352,10 @@ fn solarized_colors() -> ThemeColors {
 
         status_bg: Color::Rgb(38, 139, 210),
         status_fg: Color::Rgb(253, 246, 227),
+
+        pulling_bg: Color::Rgb(50, 50, 0),
+        range_bg: Color::Rgb(40, 40, 80),
+        stripe_bg: Color::Rgb(25, 25, 35),
     }
 }
 
@@ -299,6 +393,10 @@ fn nord_colors() -> ThemeColors {
 
         status_bg: Color::Rgb(129, 161, 193),
         status_fg: Color::Rgb(46, 52, 64),
+
+        pulling_bg: Color::Rgb(50, 50, 0),
+        range_bg: Color::Rgb(40, 40, 80),
+        stripe_bg: Color::Rgb(25, 25, 35),
     }
 }
 
@@ -336,6 +434,10 @@ fn monokai_colors() -> ThemeColors {
 
         status_bg: Color::Rgb(253, 151, 31),
         status_fg: Color::Rgb(39, 40, 34),
+
+        pulling_bg: Color::Rgb(50, 50, 0),
+        range_bg: Color::Rgb(40, 40, 80),
+        stripe_bg: Color::Rgb(25, 25, 35),
     }
 }
 
@@ -373,6 +475,10 @@ fn gruvbox_colors() -> ThemeColors {
 
         status_bg: Color::Rgb(214, 93, 14),
         status_fg: Color::Rgb(40, 40, 40),
+
+        pulling_bg: Color::Rgb(50, 50, 0),
+        range_bg: Color::Rgb(40, 40, 80),
+        stripe_bg: Color::Rgb(25, 25, 35),
     }
 }
 
@@ -411,6 +517,10 @@ fn catppuccin_latte_colors() -> ThemeColors {
 
         status_bg: Color::Rgb(136, 57, 239),  // Mauve
         status_fg: Color::Rgb(239, 241, 245), // Base
+
+        pulling_bg: Color::Rgb(250, 238, 190),
+        range_bg: Color::Rgb(214, 222, 248),
+        stripe_bg: Color::Rgb(238, 239, 244),
     }
 }
 
@@ -449,6 +559,10 @@ fn catppuccin_frappe_colors() -> ThemeColors {
 
         status_bg: Color::Rgb(186, 187, 241), // Lavender
         status_fg: Color::Rgb(35, 38, 52),    // Crust
+
+        pulling_bg: Color::Rgb(50, 50, 0),
+        range_bg: Color::Rgb(40, 40, 80),
+        stripe_bg: Color::Rgb(25, 25, 35),
     }
 }
 
@@ -487,6 +601,10 @@ fn catppuccin_macchiato_colors() -> ThemeColors {
 
         status_bg: Color::Rgb(183, 189, 248), // Lavender
         status_fg: Color::Rgb(24, 25, 38),    // Crust
+
+        pulling_bg: Color::Rgb(50, 50, 0),
+        range_bg: Color::Rgb(40, 40, 80),
+        stripe_bg: Color::Rgb(25, 25, 35),
     }
 }
 
@@ -525,5 +643,31 @@ fn catppuccin_mocha_colors() -> ThemeColors {
 
         status_bg: Color::Rgb(180, 190, 254), // Lavender
         status_fg: Color::Rgb(17, 17, 27),    // Crust
+
+        pulling_bg: Color::Rgb(50, 50, 0),
+        range_bg: Color::Rgb(40, 40, 80),
+        stripe_bg: Color::Rgb(25, 25, 35),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_light_background_detection_and_theme_names() {
+        assert!(is_light_background("0;15"));
+        assert!(is_light_background("0;default;7"));
+        assert!(!is_light_background("15;0"));
+        assert!(!is_light_background(""));
+
+        assert_eq!(Theme::parse("light"), Some(Theme::Light));
+        assert_eq!(Theme::parse("Dark"), Some(Theme::Default));
+        assert_eq!(
+            Theme::parse("catppuccin frappe"),
+            Some(Theme::CatppuccinFrappe)
+        );
+        assert_eq!(Theme::parse("neon"), None);
+        assert_eq!(Theme::from_label(Theme::Light.label()), Theme::Light);
     }
 }
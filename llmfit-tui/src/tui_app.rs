@@ -1,16 +1,29 @@
-use llmfit_core::fit::{FitLevel, ModelFit, SortColumn, backend_compatible};
-use llmfit_core::hardware::SystemSpecs;
-use llmfit_core::models::{Capability, ModelDatabase, UseCase};
+use llmfit_core::activity::{self, ActivityEntry};
+use llmfit_core::catalog::{CatalogError, CatalogSource, ModelCatalog};
+use llmfit_core::fit::{
+    FitLevel, FitOptions, FitTarget, ModelFit, RunMode, SortColumn, backend_compatible,
+};
+use llmfit_core::hardware::{DiskTier, MemoryReading, MemoryUnits, SystemSpecs, fmt_mem};
+use llmfit_core::models::{
+    Capability, CatalogSources, LlmModel, ModelDatabase, QuantTable, REQUANT_CYCLE, UseCase,
+};
 use llmfit_core::plan::{PlanEstimate, PlanRequest, estimate_model_plan};
 use llmfit_core::providers::{
     self, DockerModelRunnerProvider, LlamaCppProvider, LmStudioProvider, MlxProvider,
     ModelProvider, OllamaProvider, PullEvent, PullHandle,
 };
 
+use fuzzy_matcher::FuzzyMatcher;
+use fuzzy_matcher::skim::SkimMatcherV2;
+use ratatui::layout::{Position, Rect};
 use std::collections::{HashMap, HashSet};
+use std::path::PathBuf;
 use std::sync::mpsc;
+use std::time::{Duration, Instant, SystemTime};
 
+use crate::config::Config;
 use crate::theme::Theme;
+use crate::views::{self, SavedView};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum InputMode {
@@ -18,14 +31,34 @@ pub enum InputMode {
     Visual,
     Select,
     Search,
+    Jump,
     Plan,
     ProviderPopup,
     UseCasePopup,
     CapabilityPopup,
+    TagPopup,
+    ViewPopup,
     DownloadProviderPopup,
     QuantPopup,
     RunModePopup,
     ParamsBucketPopup,
+    Help,
+}
+
+/// Two clicks on the same row within this open the detail view.
+const DOUBLE_CLICK: Duration = Duration::from_millis(400);
+
+/// Where clickable parts of the UI are on screen, in screen cells, as laid
+/// out by [`crate::tui_ui::mouse_targets`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MouseTargets {
+    /// Model rows of the table or list, below the border and header.
+    pub table_rows: Rect,
+    /// The "Providers (P)" summary box in the filter bar.
+    pub provider_summary: Rect,
+    /// Entries of the provider popup, starting at `provider_offset`.
+    pub provider_rows: Rect,
+    pub provider_offset: usize,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -61,6 +94,7 @@ pub enum FitFilter {
     Marginal,
     TooTight,
     Runnable, // Perfect + Good + Marginal (excludes TooTight)
+    GpuOnly,  // Runnable entirely in VRAM (RunMode::Gpu, no CPU offload)
 }
 
 impl FitFilter {
@@ -72,13 +106,15 @@ impl FitFilter {
             FitFilter::Marginal => "Marginal",
             FitFilter::TooTight => "Too Tight",
             FitFilter::Runnable => "Runnable",
+            FitFilter::GpuOnly => "GPU Only",
         }
     }
 
     pub fn next(&self) -> Self {
         match self {
             FitFilter::All => FitFilter::Runnable,
-            FitFilter::Runnable => FitFilter::Perfect,
+            FitFilter::Runnable => FitFilter::GpuOnly,
+            FitFilter::GpuOnly => FitFilter::Perfect,
             FitFilter::Perfect => FitFilter::Good,
             FitFilter::Good => FitFilter::Marginal,
             FitFilter::Marginal => FitFilter::TooTight,
@@ -93,6 +129,8 @@ pub enum AvailabilityFilter {
     All,
     HasGguf,   // Has GGUF download sources (unsloth, bartowski, etc.)
     Installed, // Already installed in a local runtime
+    InOllama,  // Pulled into Ollama specifically
+    OnDisk,    // Weights present in the Ollama store or GGUF cache
 }
 
 impl AvailabilityFilter {
@@ -101,6 +139,8 @@ impl AvailabilityFilter {
             AvailabilityFilter::All => "All",
             AvailabilityFilter::HasGguf => "GGUF Avail",
             AvailabilityFilter::Installed => "Installed",
+            AvailabilityFilter::InOllama => "In Ollama",
+            AvailabilityFilter::OnDisk => "On Disk",
         }
     }
 
@@ -108,7 +148,52 @@ impl AvailabilityFilter {
         match self {
             AvailabilityFilter::All => AvailabilityFilter::HasGguf,
             AvailabilityFilter::HasGguf => AvailabilityFilter::Installed,
-            AvailabilityFilter::Installed => AvailabilityFilter::All,
+            AvailabilityFilter::Installed => AvailabilityFilter::InOllama,
+            AvailabilityFilter::InOllama => AvailabilityFilter::OnDisk,
+            AvailabilityFilter::OnDisk => AvailabilityFilter::All,
+        }
+    }
+}
+
+/// Table density. `Auto` switches to the compact column set when the
+/// terminal is too narrow to show every column legibly. `List` drops the
+/// table entirely for one dense line per model.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TableLayout {
+    Auto,
+    Full,
+    Compact,
+    List,
+}
+
+/// Below this width (in columns) `TableLayout::Auto` renders the compact table.
+pub const COMPACT_WIDTH_THRESHOLD: u16 = 130;
+
+impl TableLayout {
+    pub fn label(&self) -> &str {
+        match self {
+            TableLayout::Auto => "Auto",
+            TableLayout::Full => "Full",
+            TableLayout::Compact => "Compact",
+            TableLayout::List => "List",
+        }
+    }
+
+    pub fn next(&self) -> Self {
+        match self {
+            TableLayout::Auto => TableLayout::Full,
+            TableLayout::Full => TableLayout::Compact,
+            TableLayout::Compact => TableLayout::List,
+            TableLayout::List => TableLayout::Auto,
+        }
+    }
+
+    /// Whether the compact column set should be used for a table of `width` columns.
+    pub fn is_compact(&self, width: u16) -> bool {
+        match self {
+            TableLayout::Auto => width < COMPACT_WIDTH_THRESHOLD,
+            TableLayout::Full => false,
+            TableLayout::Compact | TableLayout::List => true,
         }
     }
 }
@@ -155,6 +240,81 @@ impl ActivePullProvider {
     }
 }
 
+/// One model in a download plan.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DownloadPlanEntry {
+    pub name: String,
+    pub quant: String,
+    /// Actual size if already on disk, otherwise the weights estimate, in
+    /// binary GB to compare with free disk space.
+    pub size_gb: f64,
+    pub on_disk: bool,
+}
+
+/// Total download size of several models against free disk space.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DownloadPlan {
+    pub entries: Vec<DownloadPlanEntry>,
+    /// Sum over entries not already on disk.
+    pub download_gb: f64,
+    pub free_gb: Option<f64>,
+}
+
+impl DownloadPlan {
+    /// Whether the downloads fit on the disk; `None` if free space is unknown.
+    pub fn fits(&self) -> Option<bool> {
+        self.free_gb.map(|free| self.download_gb <= free)
+    }
+}
+
+/// Maximum number of filter states kept for undo.
+const UNDO_DEPTH: usize = 32;
+
+/// Steps for the target-context filter (`[` / `]`).
+const CONTEXT_TARGETS: [u32; 6] = [4_096, 8_192, 16_384, 32_768, 65_536, 131_072];
+
+/// Steps for the minimum context-length filter (`W`), after "off".
+const MIN_CONTEXT_STEPS: [u32; 3] = [8_192, 32_768, 131_072];
+
+/// Most concurrent requests `+` goes up to.
+const MAX_CONCURRENCY: u32 = 256;
+
+/// Smallest change in available RAM that `--watch` refits for.
+const WATCH_MIN_CHANGE_GB: f64 = 0.1;
+
+/// Most models the Space compare set holds; more don't fit side by side.
+pub const COMPARE_SET_MAX: usize = 3;
+
+/// CSV export target when `--export-path` isn't given, relative to the
+/// working directory.
+const DEFAULT_EXPORT_PATH: &str = "llmfit-export.csv";
+
+/// Snapshot of every user-adjustable filter/sort input, used for undo.
+#[derive(Debug, Clone, PartialEq)]
+struct FilterState {
+    search_query: String,
+    /// Deselected providers by name, so a snapshot survives the provider
+    /// list changing underneath it.
+    hidden_providers: Vec<String>,
+    selected_use_cases: Vec<bool>,
+    selected_capabilities: Vec<bool>,
+    selected_tags: Vec<bool>,
+    tag_match_all: bool,
+    selected_quants: Vec<bool>,
+    selected_run_modes: Vec<bool>,
+    selected_params_buckets: Vec<bool>,
+    fit_filter: FitFilter,
+    availability_filter: AvailabilityFilter,
+    changed_only: bool,
+    known_vram_only: bool,
+    favorites_only: bool,
+    min_context: Option<u32>,
+    context_target: Option<u32>,
+    installed_first: bool,
+    sort_column: SortColumn,
+    sort_ascending: bool,
+}
+
 pub struct App {
     pub should_quit: bool,
     pub input_mode: InputMode,
@@ -163,6 +323,21 @@ pub struct App {
 
     // Data
     pub specs: SystemSpecs,
+    /// Context assumed for memory estimates (`{` / `}`); `None` uses each
+    /// model's full context.
+    pub context_limit: Option<u32>,
+    /// Execution path all fits are computed under; `None` picks per model.
+    pub forced_run_mode: Option<RunMode>,
+    /// Memory figure fits are scored against (`B`).
+    pub fit_target: FitTarget,
+    /// Requests every fit sizes its KV cache for (`+` / `-`).
+    pub concurrency: u32,
+    /// Bytes-per-parameter table every fit and requantize reads.
+    pub quant_table: &'static QuantTable,
+    /// Units memory sizes are shown in (`--units`).
+    pub units: MemoryUnits,
+    /// What the catalog is rebuilt from when a remote copy arrives.
+    catalog_sources: CatalogSources,
     pub all_fits: Vec<ModelFit>,
     pub filtered_fits: Vec<usize>, // indices into all_fits
     pub providers: Vec<String>,
@@ -171,6 +346,12 @@ pub struct App {
     pub selected_use_cases: Vec<bool>,
     pub capabilities: Vec<Capability>,
     pub selected_capabilities: Vec<bool>,
+    /// Every tag present in the catalog, sorted. Unlike the other filters,
+    /// no selection means "don't filter by tag".
+    pub tags: Vec<String>,
+    pub selected_tags: Vec<bool>,
+    /// Require all selected tags (true) or any of them (false).
+    pub tag_match_all: bool,
 
     // Filters
     pub fit_filter: FitFilter,
@@ -178,17 +359,47 @@ pub struct App {
     pub installed_first: bool,
     pub sort_column: SortColumn,
     pub sort_ascending: bool,
+    undo_stack: Vec<FilterState>,
 
     // Table state
     pub selected_row: usize,
+    /// Type-ahead prefix accumulated in Jump mode.
+    pub jump_buffer: String,
+    jump_last_key: Option<Instant>,
+    pub table_layout: TableLayout,
+    /// Draw fit indicators with ASCII characters (`--ascii`).
+    pub ascii: bool,
+    /// Where `e` writes the filtered list as CSV (`--export-path`).
+    pub export_path: PathBuf,
+    /// First row of `filtered_fits` shown in the table.
+    pub scroll_offset: usize,
+    /// Terminal size, as of the last resize.
+    pub screen: Rect,
+    /// Selected row when Ctrl+E/Ctrl+Y scrolled the view away from it; the
+    /// view snaps back once the selection moves.
+    peek_anchor: Option<usize>,
+    /// Set on terminal resize; the event loop clears the screen before the
+    /// next draw so nothing from the old layout lingers.
+    pub force_redraw: bool,
+    /// Time and row of the last table click, for double-click detection.
+    last_click: Option<(Instant, usize)>,
 
     // Detail view
     pub show_detail: bool,
+    /// Activity log overlay (`L`): snapshot of detection steps and fit decisions.
+    pub show_activity_log: bool,
+    pub activity_entries: Vec<ActivityEntry>,
+    pub activity_scroll: u16,
     pub show_compare: bool,
     pub compare_mark_model: Option<String>,
+    /// Models toggled with Space for side-by-side compare, by name so the
+    /// set survives re-sorting. Holds at most [`COMPARE_SET_MAX`].
+    pub compare_set: Vec<String>,
     pub show_multi_compare: bool,
     pub compare_models: Vec<usize>, // indices into all_fits
     pub compare_scroll: usize,      // horizontal scroll for multi-compare
+    pub show_download_plan: bool,
+    pub download_plan_models: Vec<usize>, // indices into all_fits
     pub show_plan: bool,
     plan_model_idx: Option<usize>,
     pub plan_field: PlanField,
@@ -203,6 +414,7 @@ pub struct App {
     pub provider_cursor: usize,
     pub use_case_cursor: usize,
     pub capability_cursor: usize,
+    pub tag_cursor: usize,
     pub download_provider_cursor: usize,
     pub download_provider_options: Vec<DownloadProvider>,
     pub download_provider_model: Option<String>,
@@ -212,6 +424,8 @@ pub struct App {
     pub ollama_binary_available: bool,
     pub ollama_installed: HashSet<String>,
     pub ollama_installed_count: usize,
+    /// Models Ollama has loaded in memory; refreshed with `r`.
+    pub ollama_running: HashSet<String>,
     ollama: OllamaProvider,
     pub mlx_available: bool,
     pub mlx_installed: HashSet<String>,
@@ -220,6 +434,12 @@ pub struct App {
     pub llamacpp_installed: HashSet<String>,
     pub llamacpp_installed_count: usize,
     llamacpp: LlamaCppProvider,
+    /// Bytes on disk per catalog model name (Ollama store + GGUF cache).
+    pub disk_sizes: HashMap<String, u64>,
+    /// Storage class of the GGUF cache disk, for load-time estimates.
+    pub disk_tier: DiskTier,
+    /// Free space on the GGUF cache disk, for the download plan.
+    pub disk_free_gb: Option<f64>,
     pub docker_mr_available: bool,
     pub docker_mr_installed: HashSet<String>,
     pub docker_mr_installed_count: usize,
@@ -239,6 +459,12 @@ pub struct App {
     download_capability_inflight: HashSet<String>,
     download_capability_tx: mpsc::Sender<(String, DownloadCapability)>,
     download_capability_rx: mpsc::Receiver<(String, DownloadCapability)>,
+    /// The `--catalog-url` catalog currently shown, for the system bar.
+    pub remote_catalog: Option<ModelCatalog>,
+    catalog_rx: Option<mpsc::Receiver<Result<ModelCatalog, CatalogError>>>,
+    /// `--watch` interval while a background thread re-reads free RAM.
+    pub watch_interval: Option<Duration>,
+    watch_rx: Option<mpsc::Receiver<MemoryReading>>,
     /// Animation frame counter, incremented every tick while pulling.
     pub tick_count: u64,
     /// When true, the next 'd' press will confirm and start the download.
@@ -268,6 +494,41 @@ pub struct App {
     // Theme
     pub theme: Theme,
 
+    /// Favorite model names, pinned to the top of the list and saved as
+    /// `favorites` in the config file.
+    pub pinned: HashSet<String>,
+    /// Preferences loaded at startup; pin changes are written back through
+    /// it straight away. `None` with `--no-config` and in tests.
+    config: Option<Config>,
+    /// Models shown at a quantization picked with `Q`, by name, with the
+    /// catalog entry they were rescaled from.
+    pub quant_overrides: HashMap<String, (LlmModel, &'static str)>,
+    /// Fit level of each model on the previous run, for highlighting changes.
+    /// Empty unless `main` loaded `last_fit.json` for this run.
+    pub previous_fit: HashMap<String, FitLevel>,
+    /// Only list models whose fit level differs from the previous run.
+    pub changed_only: bool,
+    /// Only list models whose catalog entry gives a `min_vram_gb`.
+    pub known_vram_only: bool,
+    /// Only list pinned (favorite) models.
+    pub favorites_only: bool,
+    /// Only list models whose context length is at least this many tokens.
+    pub min_context: Option<u32>,
+    /// Only list models that still fit with this many tokens of context.
+    pub context_target: Option<u32>,
+    /// Models that don't fit at `context_target`; refreshed when it or the
+    /// run mode changes.
+    context_target_misses: HashSet<String>,
+    /// Page of the help overlay being shown; `draw` clamps it to the pages
+    /// the current screen needs.
+    pub help_page: usize,
+    /// Built-in views followed by the user's saved ones.
+    pub views: Vec<SavedView>,
+    pub view_cursor: usize,
+    /// Name being typed for a new view; `Some` while the views popup is
+    /// prompting for it.
+    pub view_name_input: Option<String>,
+
     /// How many models we silently dropped because they can't run on this
     /// hardware — shown in the system bar so users aren't left wondering
     /// why the list looks shorter than expected.
@@ -275,21 +536,54 @@ pub struct App {
 }
 
 impl App {
-    pub fn with_specs_and_context(specs: SystemSpecs, context_limit: Option<u32>) -> Self {
-        let db = ModelDatabase::new();
+    /// Build the app over the catalog from `sources`, with GGUF downloads
+    /// going to `models_dir`.
+    pub fn with_sources(
+        specs: SystemSpecs,
+        options: FitOptions<'static>,
+        sources: CatalogSources,
+        models_dir: PathBuf,
+    ) -> Self {
+        let (db, _) = ModelDatabase::from_sources(&sources);
+        let llamacpp = LlamaCppProvider::with_models_dir(models_dir);
+        let mut app = Self::with_llamacpp(specs, options, db.get_all_models(), llamacpp);
+        app.catalog_sources = sources;
+        app
+    }
+
+    /// Build the app over an explicit model list instead of the embedded
+    /// catalog. Runtime providers are still detected.
+    #[cfg(test)]
+    pub fn with_catalog(
+        specs: SystemSpecs,
+        options: FitOptions<'static>,
+        models: &[LlmModel],
+    ) -> Self {
+        Self::with_llamacpp(specs, options, models, LlamaCppProvider::new())
+    }
 
+    fn with_llamacpp(
+        specs: SystemSpecs,
+        options: FitOptions<'static>,
+        models: &[LlmModel],
+        llamacpp: LlamaCppProvider,
+    ) -> Self {
         // Detect Ollama
         let ollama = OllamaProvider::new();
         let (ollama_available, ollama_installed, ollama_installed_count) =
             ollama.detect_with_installed();
         let ollama_binary_available = command_exists("ollama");
+        let ollama_running = if ollama_available {
+            ollama.running_models()
+        } else {
+            HashSet::new()
+        };
 
         // Detect MLX
         let mlx = MlxProvider::new();
         let (mlx_available, mlx_installed) = mlx.detect_with_installed();
 
         // Detect llama.cpp
-        let llamacpp = LlamaCppProvider::new();
         let llamacpp_available = llamacpp.is_available();
         let (llamacpp_installed, llamacpp_installed_count) = llamacpp.installed_models_counted();
 
@@ -304,41 +598,29 @@ impl App {
             lmstudio.detect_with_installed();
 
         // Track how many we're skipping so the UI can surface it.
-        let backend_hidden_count = db
-            .get_all_models()
+        let backend_hidden_count = models
             .iter()
             .filter(|m| !backend_compatible(m, &specs))
             .count();
 
         // Only analyze models that can actually run on this hardware.
-        let mut all_fits: Vec<ModelFit> = db
-            .get_all_models()
-            .iter()
-            .filter(|m| backend_compatible(m, &specs))
-            .map(|m| {
-                let mut fit = ModelFit::analyze_with_context_limit(m, &specs, context_limit);
-                fit.installed = providers::is_model_installed(&m.name, &ollama_installed)
-                    || providers::is_model_installed_mlx(&m.name, &mlx_installed)
-                    || providers::is_model_installed_llamacpp(&m.name, &llamacpp_installed)
-                    || providers::is_model_installed_docker_mr(&m.name, &docker_mr_installed)
-                    || providers::is_model_installed_lmstudio(&m.name, &lmstudio_installed);
-                fit
-            })
-            .collect();
+        let mut all_fits = llmfit_core::fit::analyze_compatible(models, &specs, &options);
+        for fit in all_fits.iter_mut() {
+            let name = &fit.model.name;
+            fit.installed = providers::is_model_installed(name, &ollama_installed)
+                || providers::is_model_installed_mlx(name, &mlx_installed)
+                || providers::is_model_installed_llamacpp(name, &llamacpp_installed)
+                || providers::is_model_installed_docker_mr(name, &docker_mr_installed)
+                || providers::is_model_installed_lmstudio(name, &lmstudio_installed);
+        }
+
+        let disk_sizes = compute_disk_sizes(&all_fits, &ollama, ollama_available, &llamacpp);
+        let disk_tier = DiskTier::detect(llamacpp.models_dir());
+        let disk_free_gb = llmfit_core::hardware::disk_free_gb(llamacpp.models_dir());
 
         // Sort by fit level then RAM usage
         all_fits = llmfit_core::fit::rank_models_by_fit(all_fits);
 
-        // Extract unique providers
-        let mut model_providers: Vec<String> = all_fits
-            .iter()
-            .map(|f| f.model.provider.clone())
-            .collect::<std::collections::BTreeSet<_>>()
-            .into_iter()
-            .collect();
-        model_providers.sort();
-
-        let selected_providers = vec![true; model_providers.len()];
         let model_use_cases = [
             UseCase::General,
             UseCase::Coding,
@@ -355,6 +637,14 @@ impl App {
         let model_capabilities = Capability::all().to_vec();
         let selected_capabilities = vec![true; model_capabilities.len()];
 
+        let mut model_tags: Vec<String> = all_fits
+            .iter()
+            .flat_map(|f| f.model.tags.iter().cloned())
+            .collect();
+        model_tags.sort();
+        model_tags.dedup();
+        let selected_tags = vec![false; model_tags.len()];
+
         // Extract unique quantizations
         let mut model_quants: Vec<String> = all_fits
             .iter()
@@ -392,29 +682,56 @@ impl App {
         let mut app = App {
             should_quit: false,
             input_mode: InputMode::Normal,
+            context_limit: options.context_limit,
+            forced_run_mode: options.run_mode,
+            fit_target: options.target,
+            concurrency: options.concurrency.max(1),
+            quant_table: options.quants,
+            units: options.units,
+            catalog_sources: CatalogSources::default(),
             search_query: String::new(),
             cursor_position: 0,
             specs,
             all_fits,
             filtered_fits: (0..filtered_count).collect(),
-            providers: model_providers,
-            selected_providers,
+            providers: Vec::new(),
+            selected_providers: Vec::new(),
             use_cases: model_use_cases,
             selected_use_cases,
             capabilities: model_capabilities,
             selected_capabilities,
+            tags: model_tags,
+            selected_tags,
+            tag_match_all: true,
             fit_filter: FitFilter::All,
             availability_filter: AvailabilityFilter::All,
             installed_first: false,
             sort_column: SortColumn::Score,
             sort_ascending: false,
+            undo_stack: Vec::new(),
             selected_row: 0,
+            jump_buffer: String::new(),
+            jump_last_key: None,
+            table_layout: TableLayout::Auto,
+            ascii: false,
+            export_path: PathBuf::from(DEFAULT_EXPORT_PATH),
+            scroll_offset: 0,
+            screen: Rect::default(),
+            peek_anchor: None,
+            force_redraw: false,
+            last_click: None,
             show_detail: false,
+            show_activity_log: false,
+            activity_entries: Vec::new(),
+            activity_scroll: 0,
             show_compare: false,
             compare_mark_model: None,
+            compare_set: Vec::new(),
             show_multi_compare: false,
             compare_models: Vec::new(),
             compare_scroll: 0,
+            show_download_plan: false,
+            download_plan_models: Vec::new(),
             show_plan: false,
             plan_model_idx: None,
             plan_field: PlanField::Context,
@@ -427,6 +744,7 @@ impl App {
             provider_cursor: 0,
             use_case_cursor: 0,
             capability_cursor: 0,
+            tag_cursor: 0,
             download_provider_cursor: 0,
             download_provider_options: Vec::new(),
             download_provider_model: None,
@@ -434,6 +752,7 @@ impl App {
             ollama_binary_available,
             ollama_installed,
             ollama_installed_count,
+            ollama_running,
             ollama,
             mlx_available,
             mlx_installed,
@@ -442,6 +761,9 @@ impl App {
             llamacpp_installed,
             llamacpp_installed_count,
             llamacpp,
+            disk_sizes,
+            disk_tier,
+            disk_free_gb,
             docker_mr_available,
             docker_mr_installed,
             docker_mr_installed_count,
@@ -459,6 +781,10 @@ impl App {
             download_capability_inflight: HashSet::new(),
             download_capability_tx,
             download_capability_rx,
+            remote_catalog: None,
+            catalog_rx: None,
+            watch_interval: None,
+            watch_rx: None,
             tick_count: 0,
             confirm_download: false,
             visual_anchor: None,
@@ -473,9 +799,24 @@ impl App {
             selected_params_buckets,
             params_bucket_cursor: 0,
             theme: Theme::load(),
+            pinned: HashSet::new(),
+            config: None,
+            quant_overrides: HashMap::new(),
+            previous_fit: HashMap::new(),
+            changed_only: false,
+            known_vram_only: false,
+            favorites_only: false,
+            min_context: None,
+            context_target: None,
+            context_target_misses: HashSet::new(),
+            views: views::builtin().into_iter().chain(views::load()).collect(),
+            help_page: 0,
+            view_cursor: 0,
+            view_name_input: None,
             backend_hidden_count,
         };
 
+        app.sync_providers();
         app.apply_filters();
         app.enqueue_capability_probes_for_visible(24);
         app
@@ -485,13 +826,16 @@ impl App {
         let query = self.search_query.to_lowercase();
         // Split query into space-separated terms for fuzzy matching
         let terms: Vec<&str> = query.split_whitespace().collect();
+        let matcher = SkimMatcherV2::default();
+        let mut search_scores: HashMap<usize, i64> = HashMap::new();
 
         self.filtered_fits = self
             .all_fits
             .iter()
             .enumerate()
-            .filter(|(_, fit)| {
-                // Search filter: all terms must match (fuzzy/AND logic)
+            .filter(|(i, fit)| {
+                // Search filter: every term must fuzzy-match the name ("l38"
+                // finds Llama-3-8B) or appear in another field (AND logic)
                 let matches_search = if terms.is_empty() {
                     true
                 } else {
@@ -512,14 +856,24 @@ impl App {
                         fit.use_case.label().to_lowercase(),
                         caps_text
                     );
-                    // All terms must be present (AND logic)
-                    terms.iter().all(|term| searchable.contains(term))
+                    let score: Option<i64> = terms
+                        .iter()
+                        .map(|term| {
+                            matcher
+                                .fuzzy_match(&fit.model.name, term)
+                                .or_else(|| searchable.contains(term).then_some(0))
+                        })
+                        .sum();
+                    if let Some(score) = score {
+                        search_scores.insert(*i, score);
+                    }
+                    score.is_some()
                 };
 
                 // Provider filter
                 let provider_idx = self.providers.iter().position(|p| p == &fit.model.provider);
                 let matches_provider = provider_idx
-                    .map(|idx| self.selected_providers[idx])
+                    .and_then(|idx| self.selected_providers.get(idx).copied())
                     .unwrap_or(true);
                 let use_case_idx = self.use_cases.iter().position(|uc| *uc == fit.use_case);
                 let matches_use_case = use_case_idx
@@ -534,6 +888,14 @@ impl App {
                     return false;
                 }
 
+                // Pinned models stay visible regardless of the other filters
+                if self.pinned.contains(&fit.model.name) {
+                    return matches_search;
+                }
+                if self.favorites_only {
+                    return false;
+                }
+
                 // Fit filter
                 let matches_fit = match self.fit_filter {
                     FitFilter::All => true,
@@ -542,13 +904,38 @@ impl App {
                     FitFilter::Marginal => fit.fit_level == FitLevel::Marginal,
                     FitFilter::TooTight => fit.fit_level == FitLevel::TooTight,
                     FitFilter::Runnable => fit.fit_level != FitLevel::TooTight,
+                    FitFilter::GpuOnly => {
+                        fit.fit_level != FitLevel::TooTight && fit.run_mode == RunMode::Gpu
+                    }
                 };
 
+                if self.changed_only && self.fit_change(fit).is_none() {
+                    return false;
+                }
+                if self.known_vram_only && fit.model.min_vram_gb.is_none() {
+                    return false;
+                }
+                if self
+                    .min_context
+                    .is_some_and(|min| fit.model.context_length < min)
+                {
+                    return false;
+                }
+                if self.context_target.is_some()
+                    && self.context_target_misses.contains(&fit.model.name)
+                {
+                    return false;
+                }
+
                 // Availability filter
                 let matches_availability = match self.availability_filter {
                     AvailabilityFilter::All => true,
                     AvailabilityFilter::HasGguf => !fit.model.gguf_sources.is_empty(),
                     AvailabilityFilter::Installed => fit.installed,
+                    AvailabilityFilter::InOllama => {
+                        providers::is_model_installed(&fit.model.name, &self.ollama_installed)
+                    }
+                    AvailabilityFilter::OnDisk => self.disk_sizes.contains_key(&fit.model.name),
                 };
 
                 // Capability filter
@@ -565,6 +952,24 @@ impl App {
                     }
                 };
 
+                // Tag filter
+                let matches_tags = {
+                    let mut wanted = self
+                        .tags
+                        .iter()
+                        .zip(self.selected_tags.iter())
+                        .filter(|(_, sel)| **sel)
+                        .map(|(tag, _)| tag)
+                        .peekable();
+                    if wanted.peek().is_none() {
+                        true
+                    } else if self.tag_match_all {
+                        wanted.all(|tag| fit.model.tags.contains(tag))
+                    } else {
+                        wanted.any(|tag| fit.model.tags.contains(tag))
+                    }
+                };
+
                 // Quant filter
                 let matches_quant = {
                     let all_selected = self.selected_quants.iter().all(|&s| s);
@@ -625,6 +1030,7 @@ impl App {
                     && matches_fit
                     && matches_availability
                     && matches_capability
+                    && matches_tags
                     && matches_quant
                     && matches_run_mode
                     && matches_params_bucket
@@ -632,6 +1038,20 @@ impl App {
             .map(|(i, _)| i)
             .collect();
 
+        // Best search matches first; ties keep the sort column's order
+        if !terms.is_empty() {
+            self.filtered_fits
+                .sort_by_key(|i| std::cmp::Reverse(search_scores.get(i).copied()));
+        }
+
+        // Float pinned models above the rest, keeping sort order within each group
+        let (mut pinned, rest): (Vec<usize>, Vec<usize>) = self
+            .filtered_fits
+            .iter()
+            .partition(|&&i| self.pinned.contains(&self.all_fits[i].model.name));
+        pinned.extend(rest);
+        self.filtered_fits = pinned;
+
         // Clamp selection
         if self.filtered_fits.is_empty() {
             self.selected_row = 0;
@@ -641,6 +1061,21 @@ impl App {
         self.enqueue_capability_probes_for_visible(24);
     }
 
+    /// Char positions in `name` that the search terms fuzzy-matched, for
+    /// highlighting. Empty when there's no search or it matched elsewhere.
+    pub fn search_highlights(&self, name: &str) -> Vec<usize> {
+        let query = self.search_query.to_lowercase();
+        let matcher = SkimMatcherV2::default();
+        let mut positions: Vec<usize> = query
+            .split_whitespace()
+            .filter_map(|term| matcher.fuzzy_indices(name, term))
+            .flat_map(|(_, indices)| indices)
+            .collect();
+        positions.sort_unstable();
+        positions.dedup();
+        positions
+    }
+
     pub fn selected_fit(&self) -> Option<&ModelFit> {
         self.filtered_fits
             .get(self.selected_row)
@@ -663,66 +1098,419 @@ impl App {
         self.enqueue_capability_probes_for_visible(24);
     }
 
-    pub fn page_up(&mut self) {
+    /// Move the selection by `delta` rows, clamped to `filtered_fits`.
+    fn jump_by(&mut self, delta: isize) {
         self.confirm_download = false;
-        self.selected_row = self.selected_row.saturating_sub(10);
+        let last = self.filtered_fits.len().saturating_sub(1);
+        self.selected_row = self.selected_row.saturating_add_signed(delta).min(last);
         self.enqueue_capability_probes_for_visible(24);
     }
 
+    /// Up one screenful of table rows.
+    pub fn page_up(&mut self) {
+        self.jump_by(-(self.table_rows() as isize));
+    }
+
     pub fn page_down(&mut self) {
-        self.confirm_download = false;
-        if !self.filtered_fits.is_empty() {
-            self.selected_row = (self.selected_row + 10).min(self.filtered_fits.len() - 1);
-        }
-        self.enqueue_capability_probes_for_visible(24);
+        self.jump_by(self.table_rows() as isize);
     }
 
     pub fn half_page_up(&mut self) {
-        self.selected_row = self.selected_row.saturating_sub(5);
-        self.enqueue_capability_probes_for_visible(24);
+        self.jump_by(-((self.table_rows() / 2).max(1) as isize));
     }
 
     pub fn half_page_down(&mut self) {
-        if !self.filtered_fits.is_empty() {
-            self.selected_row = (self.selected_row + 5).min(self.filtered_fits.len() - 1);
-        }
-        self.enqueue_capability_probes_for_visible(24);
+        self.jump_by((self.table_rows() / 2).max(1) as isize);
     }
 
     pub fn home(&mut self) {
-        self.selected_row = 0;
-        self.enqueue_capability_probes_for_visible(24);
+        self.jump_by(-(self.selected_row as isize));
     }
 
     pub fn end(&mut self) {
-        if !self.filtered_fits.is_empty() {
-            self.selected_row = self.filtered_fits.len() - 1;
-        }
-        self.enqueue_capability_probes_for_visible(24);
+        self.jump_by(self.filtered_fits.len() as isize);
     }
 
     pub fn cycle_fit_filter(&mut self) {
+        self.push_undo();
         self.fit_filter = self.fit_filter.next();
         self.apply_filters();
     }
 
     pub fn cycle_availability_filter(&mut self) {
+        self.push_undo();
         self.availability_filter = self.availability_filter.next();
         self.apply_filters();
     }
 
     pub fn cycle_sort_column(&mut self) {
+        self.push_undo();
         self.sort_column = self.sort_column.next();
         self.sort_ascending = false;
         self.re_sort();
     }
 
+    /// React to a terminal resize: keep the selection and scroll offset
+    /// inside the new visible range and request a full redraw. Compact vs
+    /// full columns are picked from the width on every draw.
+    pub fn on_resize(&mut self, width: u16, height: u16) {
+        self.screen = Rect::new(0, 0, width, height);
+        self.selected_row = self
+            .selected_row
+            .min(self.filtered_fits.len().saturating_sub(1));
+        self.sync_scroll();
+        self.force_redraw = true;
+    }
+
+    /// Model rows the table or list shows at the current terminal size.
+    pub fn table_rows(&self) -> usize {
+        crate::tui_ui::table_rows_for_height(self.screen.height, self.table_layout)
+    }
+
+    /// Handle a left click at screen cell (`column`, `row`): select a table
+    /// row (a double click opens its detail view), open the provider popup
+    /// from its summary box, or toggle a provider in the popup. A click
+    /// outside the popup's entries closes it.
+    pub fn click_at(&mut self, column: u16, row: u16, now: Instant) {
+        let pos = Position::new(column, row);
+        let targets = crate::tui_ui::mouse_targets(self);
+        match self.input_mode {
+            InputMode::Normal if targets.table_rows.contains(pos) => {
+                let idx = self.scroll_offset + (row - targets.table_rows.y) as usize;
+                if idx >= self.filtered_fits.len() {
+                    return;
+                }
+                let double = self.last_click.is_some_and(|(at, last)| {
+                    last == idx && now.duration_since(at) <= DOUBLE_CLICK
+                });
+                self.selected_row = idx;
+                if double {
+                    self.last_click = None;
+                    self.toggle_detail();
+                } else {
+                    self.last_click = Some((now, idx));
+                }
+            }
+            InputMode::Normal if targets.provider_summary.contains(pos) => {
+                self.open_provider_popup();
+            }
+            InputMode::ProviderPopup if targets.provider_rows.contains(pos) => {
+                let idx = targets.provider_offset + (row - targets.provider_rows.y) as usize;
+                if idx < self.providers.len() {
+                    self.provider_cursor = idx;
+                    self.toggle_provider(idx);
+                }
+            }
+            InputMode::ProviderPopup => self.close_provider_popup(),
+            _ => {}
+        }
+    }
+
+    /// Scroll wheel: move the selection, or the provider popup's cursor.
+    pub fn scroll_wheel(&mut self, down: bool) {
+        match (self.input_mode, down) {
+            (InputMode::Normal, true) => self.move_down(),
+            (InputMode::Normal, false) => self.move_up(),
+            (InputMode::ProviderPopup, true) => self.provider_popup_down(),
+            (InputMode::ProviderPopup, false) => self.provider_popup_up(),
+            _ => {}
+        }
+    }
+
+    /// Scroll the table by `delta` rows without moving the selection.
+    pub fn scroll_view(&mut self, delta: isize) {
+        let max_offset = self.filtered_fits.len().saturating_sub(self.table_rows());
+        self.scroll_offset = self
+            .scroll_offset
+            .saturating_add_signed(delta)
+            .min(max_offset);
+        self.peek_anchor.get_or_insert(self.selected_row);
+    }
+
+    /// Stop peeking and put the selection in the middle of the view.
+    pub fn recenter_view(&mut self) {
+        self.peek_anchor = None;
+        self.scroll_offset = self.selected_row.saturating_sub(self.table_rows() / 2);
+        self.sync_scroll();
+    }
+
+    /// Clamp the view to the visible rows and, unless peeking, scroll just
+    /// enough to keep the selection on screen. The event handlers call this
+    /// after every key, click, resize and background update.
+    pub fn sync_scroll(&mut self) {
+        let table_rows = self.table_rows();
+        if self
+            .peek_anchor
+            .is_some_and(|anchor| anchor != self.selected_row)
+        {
+            self.peek_anchor = None;
+        }
+        if self.peek_anchor.is_none() {
+            if self.selected_row < self.scroll_offset {
+                self.scroll_offset = self.selected_row;
+            } else if self.selected_row >= self.scroll_offset + table_rows {
+                self.scroll_offset = self.selected_row + 1 - table_rows;
+            }
+        }
+        let max_offset = self.filtered_fits.len().saturating_sub(table_rows);
+        self.scroll_offset = self.scroll_offset.min(max_offset);
+    }
+
+    /// The filtered list, in its current sort order, as a markdown table.
+    pub fn filtered_markdown(&self) -> String {
+        let fits: Vec<&ModelFit> = self
+            .filtered_fits
+            .iter()
+            .map(|&idx| &self.all_fits[idx])
+            .collect();
+        crate::display::markdown_fits_table(&fits)
+    }
+
+    /// `Y`: copy the filtered list as a markdown table.
+    pub fn copy_filtered_markdown(&mut self) {
+        if self.filtered_fits.is_empty() {
+            self.pull_status = Some("Nothing to copy: no models match".to_string());
+            return;
+        }
+        self.pull_status = Some(match crate::clipboard::copy(&self.filtered_markdown()) {
+            Ok(via) => format!(
+                "Copied {} models as markdown ({})",
+                self.filtered_fits.len(),
+                via
+            ),
+            Err(e) => format!("Copy failed: {}", e),
+        });
+    }
+
+    /// `e`: write the filtered list, in its current sort order, to
+    /// `export_path` as CSV.
+    pub fn export_filtered_csv(&mut self) {
+        if self.filtered_fits.is_empty() {
+            self.pull_status = Some("Nothing to export: no models match".to_string());
+            return;
+        }
+        let fits: Vec<&ModelFit> = self
+            .filtered_fits
+            .iter()
+            .map(|&idx| &self.all_fits[idx])
+            .collect();
+        let csv = crate::display::csv_fits_table(&fits);
+        self.pull_status = Some(match std::fs::write(&self.export_path, csv) {
+            Ok(()) => format!(
+                "Exported {} models to {}",
+                fits.len(),
+                self.export_path.display()
+            ),
+            Err(e) => format!("Export to {} failed: {}", self.export_path.display(), e),
+        });
+    }
+
+    pub fn cycle_table_layout(&mut self) {
+        self.table_layout = self.table_layout.next();
+        self.pull_status = Some(format!("Table layout: {}", self.table_layout.label()));
+    }
+
+    /// Step the target context up (`]`) or down (`[`). Stepping below the
+    /// smallest target turns the filter off.
+    pub fn step_context_target(&mut self, up: bool) {
+        let next = match (self.context_target, up) {
+            (None, true) => Some(CONTEXT_TARGETS[0]),
+            (None, false) => None,
+            (Some(current), true) => CONTEXT_TARGETS
+                .iter()
+                .copied()
+                .find(|&t| t > current)
+                .or(Some(current)),
+            (Some(current), false) => CONTEXT_TARGETS.iter().copied().rev().find(|&t| t < current),
+        };
+        if next == self.context_target {
+            return;
+        }
+        self.push_undo();
+        self.context_target = next;
+        self.refresh_context_target();
+        self.apply_filters();
+        self.pull_status = Some(match self.context_target {
+            Some(_) => format!(
+                "{} models fit at {} context",
+                self.filtered_fits.len(),
+                self.context_target_label()
+            ),
+            None => "Context filter off".to_string(),
+        });
+    }
+
+    /// Step the context length assumed for memory estimates down (`{`) or
+    /// up (`}`) and refit every model, each capped at its own maximum.
+    /// Stepping above the largest target goes back to each model's full
+    /// context.
+    pub fn step_context_limit(&mut self, up: bool) {
+        let next = match (self.context_limit, up) {
+            (None, true) => None,
+            (None, false) => CONTEXT_TARGETS.last().copied(),
+            (Some(current), true) => CONTEXT_TARGETS.iter().copied().find(|&t| t > current),
+            (Some(current), false) => CONTEXT_TARGETS
+                .iter()
+                .copied()
+                .rev()
+                .find(|&t| t < current)
+                .or(Some(current)),
+        };
+        if next == self.context_limit {
+            return;
+        }
+        self.context_limit = next;
+        self.refit_all();
+        self.pull_status = Some(match self.context_limit {
+            Some(limit) => format!("Estimating memory at up to {}k context", limit / 1024),
+            None => "Estimating memory at each model's full context".to_string(),
+        });
+    }
+
+    /// "32k" for the target context, or "Any" when the filter is off.
+    pub fn context_target_label(&self) -> String {
+        match self.context_target {
+            Some(target) => format!("{}k", target / 1024),
+            None => "Any".to_string(),
+        }
+    }
+
+    /// Recompute which models miss the target context under the current
+    /// run mode. Fits are re-estimated at exactly that context.
+    fn refresh_context_target(&mut self) {
+        self.context_target_misses = match self.context_target {
+            Some(target) => self
+                .all_fits
+                .iter()
+                .filter(|f| {
+                    !llmfit_core::fit::fits_at_context(
+                        &f.model,
+                        &self.specs,
+                        target,
+                        &self.fit_options(),
+                    )
+                })
+                .map(|f| f.model.name.clone())
+                .collect(),
+            None => HashSet::new(),
+        };
+    }
+
     pub fn cycle_theme(&mut self) {
         self.theme = self.theme.next();
         self.theme.save();
     }
 
+    fn filter_state(&self) -> FilterState {
+        FilterState {
+            search_query: self.search_query.clone(),
+            hidden_providers: self.hidden_providers(),
+            selected_use_cases: self.selected_use_cases.clone(),
+            selected_capabilities: self.selected_capabilities.clone(),
+            selected_tags: self.selected_tags.clone(),
+            tag_match_all: self.tag_match_all,
+            selected_quants: self.selected_quants.clone(),
+            selected_run_modes: self.selected_run_modes.clone(),
+            selected_params_buckets: self.selected_params_buckets.clone(),
+            fit_filter: self.fit_filter,
+            availability_filter: self.availability_filter,
+            changed_only: self.changed_only,
+            known_vram_only: self.known_vram_only,
+            favorites_only: self.favorites_only,
+            min_context: self.min_context,
+            context_target: self.context_target,
+            installed_first: self.installed_first,
+            sort_column: self.sort_column,
+            sort_ascending: self.sort_ascending,
+        }
+    }
+
+    /// Record the current filter state before a change so `undo` can restore it.
+    fn push_undo(&mut self) {
+        let state = self.filter_state();
+        if self.undo_stack.last() == Some(&state) {
+            return;
+        }
+        if self.undo_stack.len() >= UNDO_DEPTH {
+            self.undo_stack.remove(0);
+        }
+        self.undo_stack.push(state);
+    }
+
+    /// Clear search and every filter, and return to the default sort.
+    pub fn reset_filters(&mut self) {
+        self.push_undo();
+        self.search_query.clear();
+        self.cursor_position = 0;
+        for selections in [
+            &mut self.selected_providers,
+            &mut self.selected_use_cases,
+            &mut self.selected_capabilities,
+            &mut self.selected_quants,
+            &mut self.selected_run_modes,
+            &mut self.selected_params_buckets,
+        ] {
+            selections.iter_mut().for_each(|s| *s = true);
+        }
+        self.selected_tags.iter_mut().for_each(|s| *s = false);
+        self.tag_match_all = true;
+        self.fit_filter = FitFilter::All;
+        self.availability_filter = AvailabilityFilter::All;
+        self.changed_only = false;
+        self.known_vram_only = false;
+        self.favorites_only = false;
+        self.min_context = None;
+        self.context_target = None;
+        self.installed_first = false;
+        self.sort_column = SortColumn::Score;
+        self.sort_ascending = false;
+        self.re_sort();
+        self.pull_status = Some("Filters reset".to_string());
+    }
+
+    /// Restore the most recent filter state that differs from the current one.
+    pub fn undo(&mut self) {
+        let current = self.filter_state();
+        while let Some(state) = self.undo_stack.pop() {
+            if state == current {
+                continue;
+            }
+            self.search_query = state.search_query;
+            self.cursor_position = self.search_query.len();
+            self.selected_providers = self
+                .providers
+                .iter()
+                .map(|p| !state.hidden_providers.contains(p))
+                .collect();
+            self.selected_use_cases = state.selected_use_cases;
+            self.selected_capabilities = state.selected_capabilities;
+            self.selected_tags = state.selected_tags;
+            self.tag_match_all = state.tag_match_all;
+            self.selected_quants = state.selected_quants;
+            self.selected_run_modes = state.selected_run_modes;
+            self.selected_params_buckets = state.selected_params_buckets;
+            self.fit_filter = state.fit_filter;
+            self.availability_filter = state.availability_filter;
+            self.changed_only = state.changed_only;
+            self.known_vram_only = state.known_vram_only;
+            self.favorites_only = state.favorites_only;
+            self.min_context = state.min_context;
+            if self.context_target != state.context_target {
+                self.context_target = state.context_target;
+                self.refresh_context_target();
+            }
+            self.installed_first = state.installed_first;
+            self.sort_column = state.sort_column;
+            self.sort_ascending = state.sort_ascending;
+            self.re_sort();
+            self.pull_status = Some("Undid last filter change".to_string());
+            return;
+        }
+        self.pull_status = Some("Nothing to undo".to_string());
+    }
+
     pub fn enter_search(&mut self) {
+        self.push_undo();
         self.input_mode = InputMode::Search;
     }
 
@@ -757,12 +1545,185 @@ impl App {
         self.apply_filters();
     }
 
+    pub fn enter_jump(&mut self) {
+        self.jump_buffer.clear();
+        self.jump_last_key = None;
+        self.input_mode = InputMode::Jump;
+    }
+
+    pub fn exit_jump(&mut self) {
+        self.jump_buffer.clear();
+        self.input_mode = InputMode::Normal;
+    }
+
+    /// Type-ahead: extend the prefix (reset after a pause) and move to the
+    /// next model whose name starts with it. Repeating a single letter cycles
+    /// through the matches.
+    pub fn jump_input(&mut self, c: char) {
+        const JUMP_TIMEOUT: Duration = Duration::from_millis(1000);
+        let now = Instant::now();
+        if self
+            .jump_last_key
+            .is_none_or(|t| now.duration_since(t) > JUMP_TIMEOUT)
+        {
+            self.jump_buffer.clear();
+        }
+        self.jump_last_key = Some(now);
+
+        let c = c.to_ascii_lowercase();
+        let cycling = self.jump_buffer.len() == 1 && self.jump_buffer.starts_with(c);
+        if !cycling {
+            self.jump_buffer.push(c);
+        }
+
+        let len = self.filtered_fits.len();
+        if len == 0 {
+            return;
+        }
+        let start = if cycling {
+            self.selected_row + 1
+        } else {
+            self.selected_row
+        };
+        let order: Vec<usize> = (0..len).map(|i| (start + i) % len).collect();
+        let name_at = |row: usize| {
+            self.all_fits[self.filtered_fits[row]]
+                .model
+                .name
+                .to_lowercase()
+        };
+        let starts = |row: &usize| {
+            let name = name_at(*row);
+            let short = name.rsplit('/').next().unwrap_or(&name).to_string();
+            name.starts_with(&self.jump_buffer) || short.starts_with(&self.jump_buffer)
+        };
+        let found = order.iter().copied().find(starts).or_else(|| {
+            order
+                .iter()
+                .copied()
+                .find(|&row| name_at(row).contains(&self.jump_buffer))
+        });
+
+        match found {
+            Some(row) => {
+                self.selected_row = row;
+                self.enqueue_capability_probes_for_visible(24);
+            }
+            None => {
+                self.pull_status = Some(format!("No model matches '{}'", self.jump_buffer));
+            }
+        }
+    }
+
     pub fn toggle_detail(&mut self) {
         self.show_plan = false;
         self.show_compare = false;
         self.show_detail = !self.show_detail;
     }
 
+    /// Pin or unpin the selected model as a favorite, keeping the cursor on it.
+    pub fn toggle_pin_selected(&mut self) {
+        let Some(model_name) = self.selected_fit().map(|fit| fit.model.name.clone()) else {
+            return;
+        };
+        if self.pinned.remove(&model_name) {
+            self.pull_status = Some(format!("Unpinned '{}'", model_name));
+        } else {
+            self.pinned.insert(model_name.clone());
+            self.pull_status = Some(format!("Pinned '{}'", model_name));
+        }
+        if let Some(base) = &self.config {
+            crate::config::save(&self.capture_config(base));
+        }
+        self.apply_filters();
+        if let Some(row) = self
+            .filtered_fits
+            .iter()
+            .position(|&i| self.all_fits[i].model.name == model_name)
+        {
+            self.selected_row = row;
+        }
+    }
+
+    /// The fit level this model had on the previous run, if it was different.
+    pub fn fit_change(&self, fit: &ModelFit) -> Option<FitLevel> {
+        self.previous_fit
+            .get(&fit.model.name)
+            .copied()
+            .filter(|previous| *previous != fit.fit_level)
+    }
+
+    /// List only the favorites, to work through a shortlist.
+    pub fn toggle_favorites_only(&mut self) {
+        self.push_undo();
+        self.favorites_only = !self.favorites_only;
+        self.apply_filters();
+        self.pull_status = Some(if self.favorites_only {
+            format!("{} favorites", self.filtered_fits.len())
+        } else {
+            "Showing all models, favorites first".to_string()
+        });
+    }
+
+    /// Hide models with no stated VRAM requirement, for a strictly GPU view.
+    pub fn toggle_known_vram_only(&mut self) {
+        self.push_undo();
+        self.known_vram_only = !self.known_vram_only;
+        self.apply_filters();
+        self.pull_status = Some(if self.known_vram_only {
+            format!(
+                "{} models with a known VRAM requirement",
+                self.filtered_fits.len()
+            )
+        } else {
+            "Showing models with and without VRAM requirements".to_string()
+        });
+    }
+
+    /// Cycle the minimum context-length filter: off, 8k, 32k, 128k. Unlike
+    /// the target context (`[`/`]`) this only looks at what the model
+    /// supports, not whether it fits at that length.
+    pub fn cycle_min_context(&mut self) {
+        self.push_undo();
+        self.min_context = match self.min_context {
+            None => Some(MIN_CONTEXT_STEPS[0]),
+            Some(current) => MIN_CONTEXT_STEPS.iter().copied().find(|&s| s > current),
+        };
+        self.apply_filters();
+        self.pull_status = Some(match self.min_context {
+            Some(_) => format!(
+                "{} models with context ≥ {}",
+                self.filtered_fits.len(),
+                self.min_context_label()
+            ),
+            None => "Minimum context filter off".to_string(),
+        });
+    }
+
+    /// "≥32k" for the minimum context, or empty when the filter is off.
+    pub fn min_context_label(&self) -> String {
+        match self.min_context {
+            Some(min) => format!("≥{}k", min / 1024),
+            None => String::new(),
+        }
+    }
+
+    pub fn toggle_changed_only(&mut self) {
+        self.push_undo();
+        self.changed_only = !self.changed_only;
+        self.apply_filters();
+        self.pull_status = Some(if !self.changed_only {
+            "Showing all models".to_string()
+        } else if self.previous_fit.is_empty() {
+            "No previous run to compare against".to_string()
+        } else {
+            format!(
+                "{} models changed fit since the last run",
+                self.filtered_fits.len()
+            )
+        });
+    }
+
     pub fn mark_selected_for_compare(&mut self) {
         let Some(model_name) = self.selected_fit().map(|fit| fit.model.name.clone()) else {
             self.pull_status = Some("No selected model to mark".to_string());
@@ -772,8 +1733,32 @@ impl App {
         self.pull_status = Some(format!("Marked '{}' for compare", model_name));
     }
 
+    /// Space: add the selected model to the compare set, or remove it.
+    pub fn toggle_compare_set(&mut self) {
+        let Some(model_name) = self.selected_fit().map(|fit| fit.model.name.clone()) else {
+            return;
+        };
+        if let Some(pos) = self.compare_set.iter().position(|n| *n == model_name) {
+            self.compare_set.remove(pos);
+        } else if self.compare_set.len() >= COMPARE_SET_MAX {
+            self.pull_status = Some(format!(
+                "Compare set is full ({} models). Space on one removes it",
+                COMPARE_SET_MAX
+            ));
+            return;
+        } else {
+            self.compare_set.push(model_name);
+        }
+        self.pull_status = Some(match self.compare_set.len() {
+            0 => "Compare set cleared".to_string(),
+            1 => "Compare set: 1 model. Add another with Space".to_string(),
+            n => format!("Compare set: {} models. Press c to compare", n),
+        });
+    }
+
     pub fn clear_compare_mark(&mut self) {
         self.compare_mark_model = None;
+        self.compare_set.clear();
         self.show_compare = false;
         self.pull_status = Some("Cleared compare mark".to_string());
     }
@@ -793,6 +1778,22 @@ impl App {
             self.show_compare = false;
             return;
         }
+        if self.show_multi_compare {
+            self.close_multi_compare();
+            return;
+        }
+        if self.compare_set.len() >= 2 {
+            self.compare_models = self
+                .compare_set
+                .iter()
+                .filter_map(|name| self.all_fits.iter().position(|f| f.model.name == *name))
+                .collect();
+            self.compare_scroll = 0;
+            self.show_detail = false;
+            self.show_plan = false;
+            self.show_multi_compare = true;
+            return;
+        }
         if self.compare_mark_model.is_none() {
             self.pull_status = Some("No marked model. Press m to mark one first".to_string());
             return;
@@ -962,7 +1963,7 @@ impl App {
             target_tps,
         };
 
-        match estimate_model_plan(&fit.model, &request, &self.specs) {
+        match estimate_model_plan(&fit.model, &request, &self.specs, self.quant_table) {
             Ok(plan) => {
                 self.plan_estimate = Some(plan);
                 self.plan_error = None;
@@ -998,6 +1999,26 @@ impl App {
         self.input_mode = InputMode::Normal;
     }
 
+    fn hidden_providers(&self) -> Vec<String> {
+        self.providers
+            .iter()
+            .zip(&self.selected_providers)
+            .filter(|(_, sel)| !**sel)
+            .map(|(p, _)| p.clone())
+            .collect()
+    }
+
+    /// Rebuild the provider list from `all_fits`; call after the catalog
+    /// changes. Selections carry over by name and new providers start selected.
+    pub fn sync_providers(&mut self) {
+        let hidden = self.hidden_providers();
+        self.providers = distinct_providers(&self.all_fits);
+        self.selected_providers = self.providers.iter().map(|p| !hidden.contains(p)).collect();
+        self.provider_cursor = self
+            .provider_cursor
+            .min(self.providers.len().saturating_sub(1));
+    }
+
     pub fn provider_popup_up(&mut self) {
         if self.provider_cursor > 0 {
             self.provider_cursor -= 1;
@@ -1012,13 +2033,24 @@ impl App {
 
     pub fn provider_popup_toggle(&mut self) {
         if self.provider_cursor < self.selected_providers.len() {
+            self.push_undo();
             self.selected_providers[self.provider_cursor] =
                 !self.selected_providers[self.provider_cursor];
             self.apply_filters();
         }
     }
 
+    /// Toggle the provider at `idx` from the list view (the 1–9 quick keys).
+    pub fn toggle_provider(&mut self, idx: usize) {
+        if idx < self.selected_providers.len() {
+            self.push_undo();
+            self.selected_providers[idx] = !self.selected_providers[idx];
+            self.apply_filters();
+        }
+    }
+
     pub fn provider_popup_select_all(&mut self) {
+        self.push_undo();
         let all_selected = self.selected_providers.iter().all(|&s| s);
         let new_val = !all_selected;
         for s in &mut self.selected_providers {
@@ -1041,6 +2073,7 @@ impl App {
 
     pub fn use_case_popup_toggle(&mut self) {
         if self.use_case_cursor < self.selected_use_cases.len() {
+            self.push_undo();
             self.selected_use_cases[self.use_case_cursor] =
                 !self.selected_use_cases[self.use_case_cursor];
             self.apply_filters();
@@ -1048,6 +2081,7 @@ impl App {
     }
 
     pub fn use_case_popup_select_all(&mut self) {
+        self.push_undo();
         let all_selected = self.selected_use_cases.iter().all(|&s| s);
         let new_val = !all_selected;
         for s in &mut self.selected_use_cases {
@@ -1078,6 +2112,7 @@ impl App {
 
     pub fn capability_popup_toggle(&mut self) {
         if self.capability_cursor < self.selected_capabilities.len() {
+            self.push_undo();
             self.selected_capabilities[self.capability_cursor] =
                 !self.selected_capabilities[self.capability_cursor];
             self.apply_filters();
@@ -1085,6 +2120,7 @@ impl App {
     }
 
     pub fn capability_popup_select_all(&mut self) {
+        self.push_undo();
         let all_selected = self.selected_capabilities.iter().all(|&s| s);
         let new_val = !all_selected;
         for s in &mut self.selected_capabilities {
@@ -1093,6 +2129,435 @@ impl App {
         self.apply_filters();
     }
 
+    /// Cycle Auto → GPU only → CPU only → CPU+GPU offload and re-analyze
+    /// every model. Auto goes back to per-model path selection.
+    pub fn cycle_forced_run_mode(&mut self) {
+        self.forced_run_mode = match self.forced_run_mode {
+            None => Some(RunMode::Gpu),
+            Some(RunMode::Gpu) => Some(RunMode::CpuOnly),
+            Some(RunMode::CpuOnly) => Some(RunMode::CpuOffload),
+            Some(_) => None,
+        };
+        self.refit_all();
+        self.pull_status = Some(format!("Run mode: {}", self.forced_run_mode_label()));
+    }
+
+    /// Switch fits between the estimated minimum and the catalog's
+    /// recommended memory.
+    pub fn toggle_fit_target(&mut self) {
+        self.fit_target = match self.fit_target {
+            FitTarget::Minimum => FitTarget::Recommended,
+            FitTarget::Recommended => FitTarget::Minimum,
+        };
+        self.refit_all();
+        self.pull_status = Some(format!("Fit target: {} memory", self.fit_target.label()));
+    }
+
+    /// Double (`+`) or halve (`-`) the number of concurrent requests every
+    /// fit assumes, between 1 and [`MAX_CONCURRENCY`]. Each request adds its
+    /// own KV cache.
+    pub fn step_concurrency(&mut self, up: bool) {
+        let current = self.concurrency;
+        let next = if up {
+            (current * 2).min(MAX_CONCURRENCY)
+        } else {
+            (current / 2).max(1)
+        };
+        if next == current {
+            return;
+        }
+        self.concurrency = next;
+        self.refit_all();
+        self.pull_status = Some(format!(
+            "Concurrency: {} request{} (KV cache x{})",
+            next,
+            if next == 1 { "" } else { "s" },
+            next
+        ));
+    }
+
+    /// The assumptions every fit is currently computed under.
+    pub fn fit_options(&self) -> FitOptions<'static> {
+        FitOptions {
+            context_limit: self.context_limit,
+            run_mode: self.forced_run_mode,
+            target: self.fit_target,
+            concurrency: self.concurrency,
+            quants: self.quant_table,
+            units: self.units,
+        }
+    }
+
+    /// Re-analyze every model after an assumption changed, keeping the
+    /// selection on the same model.
+    fn refit_all(&mut self) {
+        let selected_name = self.selected_fit().map(|f| f.model.name.clone());
+        let models: Vec<&LlmModel> = self
+            .all_fits
+            .iter()
+            .map(|f| {
+                self.quant_overrides
+                    .get(&f.model.name)
+                    .map_or(&f.model, |(model, _)| model)
+            })
+            .collect();
+        let options = self.fit_options();
+        let refits = llmfit_core::fit::analyze_many(&models, &self.specs, &options);
+        for (fit, mut refit) in self.all_fits.iter_mut().zip(refits) {
+            if let Some((model, quant)) = self.quant_overrides.get(&fit.model.name) {
+                refit = ModelFit::analyze_at_quant(model, &self.specs, &options, quant);
+            }
+            refit.installed = fit.installed;
+            *fit = refit;
+        }
+        self.refresh_context_target();
+        self.re_sort();
+        if let Some(row) = selected_name.and_then(|name| {
+            self.filtered_fits
+                .iter()
+                .position(|&i| self.all_fits[i].model.name == name)
+        }) {
+            self.selected_row = row;
+        }
+    }
+
+    /// Show the selected model at the next quantization in
+    /// [`REQUANT_CYCLE`], then back at the automatically chosen one,
+    /// refitting just that row.
+    pub fn cycle_selected_quant(&mut self) {
+        let Some(&idx) = self.filtered_fits.get(self.selected_row) else {
+            return;
+        };
+        let name = self.all_fits[idx].model.name.clone();
+        let (model, current) = match self.quant_overrides.get(&name) {
+            Some((model, quant)) => (model.clone(), Some(*quant)),
+            None => (self.all_fits[idx].model.clone(), None),
+        };
+        if model.is_prequantized() || model.is_mlx_model() {
+            self.pull_status = Some(format!(
+                "{} ships as {}; it can't be requantized",
+                name, model.quantization
+            ));
+            return;
+        }
+        let next = match current {
+            None => REQUANT_CYCLE.first().copied(),
+            Some(quant) => REQUANT_CYCLE
+                .iter()
+                .position(|q| *q == quant)
+                .and_then(|i| REQUANT_CYCLE.get(i + 1))
+                .copied(),
+        };
+        let mut fit = match next {
+            Some(quant) => {
+                self.quant_overrides
+                    .insert(name.clone(), (model.clone(), quant));
+                ModelFit::analyze_at_quant(&model, &self.specs, &self.fit_options(), quant)
+            }
+            None => {
+                self.quant_overrides.remove(&name);
+                ModelFit::analyze_with_options(&model, &self.specs, &self.fit_options())
+            }
+        };
+        fit.installed = self.all_fits[idx].installed;
+        self.pull_status = Some(format!(
+            "{} at {}: {}, {}",
+            name,
+            next.unwrap_or("auto quant"),
+            fmt_mem(fit.memory_required_gb, 1, self.units),
+            fit.fit_text()
+        ));
+        self.all_fits[idx] = fit;
+        self.re_sort();
+        if let Some(row) = self
+            .filtered_fits
+            .iter()
+            .position(|&i| self.all_fits[i].model.name == name)
+        {
+            self.selected_row = row;
+        }
+    }
+
+    pub fn forced_run_mode_label(&self) -> &'static str {
+        match self.forced_run_mode {
+            None => "Auto",
+            Some(RunMode::Gpu) => "GPU only",
+            Some(RunMode::CpuOnly) => "CPU only",
+            Some(RunMode::CpuOffload) => "CPU+GPU offload",
+            Some(_) => "Auto",
+        }
+    }
+
+    // ── Help overlay ─────────────────────────────────────────────
+
+    pub fn open_help(&mut self) {
+        self.help_page = 0;
+        self.input_mode = InputMode::Help;
+    }
+
+    pub fn help_page_down(&mut self) {
+        if self.help_page + 1 < crate::tui_ui::help_pages(self.screen) {
+            self.help_page += 1;
+        }
+    }
+
+    pub fn help_page_up(&mut self) {
+        self.help_page = self.help_page.saturating_sub(1);
+    }
+
+    pub fn close_help(&mut self) {
+        self.input_mode = InputMode::Normal;
+    }
+
+    // ── Saved views ──────────────────────────────────────────────
+
+    pub fn open_view_popup(&mut self) {
+        self.view_name_input = None;
+        self.input_mode = InputMode::ViewPopup;
+    }
+
+    pub fn close_view_popup(&mut self) {
+        self.view_name_input = None;
+        self.input_mode = InputMode::Normal;
+    }
+
+    pub fn view_popup_up(&mut self) {
+        if self.view_cursor > 0 {
+            self.view_cursor -= 1;
+        }
+    }
+
+    pub fn view_popup_down(&mut self) {
+        if self.view_cursor + 1 < self.views.len() {
+            self.view_cursor += 1;
+        }
+    }
+
+    /// Current search, filters and sort as a view called `name`.
+    pub fn capture_view(&self, name: &str) -> SavedView {
+        SavedView {
+            name: name.to_string(),
+            search: self.search_query.clone(),
+            providers: selected_labels(self.providers.iter().cloned(), &self.selected_providers),
+            use_cases: selected_labels(
+                self.use_cases.iter().map(|u| u.label().to_string()),
+                &self.selected_use_cases,
+            ),
+            capabilities: selected_labels(
+                self.capabilities.iter().map(|c| c.label().to_string()),
+                &self.selected_capabilities,
+            ),
+            quants: selected_labels(self.quants.iter().cloned(), &self.selected_quants),
+            run_modes: selected_labels(self.run_modes.iter().cloned(), &self.selected_run_modes),
+            params_buckets: selected_labels(
+                self.params_buckets.iter().cloned(),
+                &self.selected_params_buckets,
+            ),
+            tags: self.active_tags().into_iter().map(str::to_string).collect(),
+            tag_match_all: self.tag_match_all,
+            fit: self.fit_filter.label().to_string(),
+            availability: self.availability_filter.label().to_string(),
+            min_context: self.min_context,
+            known_vram_only: self.known_vram_only,
+            changed_only: self.changed_only,
+            favorites_only: self.favorites_only,
+            installed_first: self.installed_first,
+            sort: self.sort_column.label().to_string(),
+            sort_ascending: self.sort_ascending,
+            builtin: false,
+        }
+    }
+
+    /// Replace search, filters and sort with the highlighted view.
+    pub fn apply_selected_view(&mut self) {
+        let Some(view) = self.views.get(self.view_cursor).cloned() else {
+            return;
+        };
+        self.push_undo();
+        self.search_query = view.search.clone();
+        self.cursor_position = self.search_query.len();
+        self.selected_providers = select_labels(self.providers.iter().cloned(), &view.providers);
+        self.selected_use_cases = select_labels(
+            self.use_cases.iter().map(|u| u.label().to_string()),
+            &view.use_cases,
+        );
+        self.selected_capabilities = select_labels(
+            self.capabilities.iter().map(|c| c.label().to_string()),
+            &view.capabilities,
+        );
+        self.selected_quants = select_labels(self.quants.iter().cloned(), &view.quants);
+        self.selected_run_modes = select_labels(self.run_modes.iter().cloned(), &view.run_modes);
+        self.selected_params_buckets =
+            select_labels(self.params_buckets.iter().cloned(), &view.params_buckets);
+        self.selected_tags = self.tags.iter().map(|t| view.tags.contains(t)).collect();
+        self.tag_match_all = view.tag_match_all;
+        self.fit_filter = find_by_label(FitFilter::All, FitFilter::next, |f| f.label() == view.fit);
+        self.availability_filter =
+            find_by_label(AvailabilityFilter::All, AvailabilityFilter::next, |a| {
+                a.label() == view.availability
+            });
+        self.min_context = view.min_context;
+        self.known_vram_only = view.known_vram_only;
+        self.changed_only = view.changed_only;
+        self.favorites_only = view.favorites_only;
+        self.installed_first = view.installed_first;
+        self.sort_column = find_by_label(SortColumn::Score, SortColumn::next, |c| {
+            c.label() == view.sort
+        });
+        self.sort_ascending = view.sort_ascending;
+        self.re_sort();
+        self.selected_row = 0;
+        self.input_mode = InputMode::Normal;
+        self.pull_status = Some(format!("View: {}", view.name));
+    }
+
+    /// Restore the provider filter, fit filter and sort order saved in the
+    /// config file.
+    pub fn apply_config(&mut self, config: &Config) {
+        self.selected_providers = select_labels(self.providers.iter().cloned(), &config.providers);
+        self.fit_filter =
+            find_by_label(FitFilter::All, FitFilter::next, |f| f.label() == config.fit);
+        self.sort_column = find_by_label(SortColumn::Score, SortColumn::next, |c| {
+            c.label() == config.sort
+        });
+        self.sort_ascending = config.sort_ascending;
+        self.pinned = config.favorites.iter().cloned().collect();
+        self.config = Some(config.clone());
+        self.re_sort();
+        self.selected_row = 0;
+    }
+
+    /// `base` with the current provider filter, fit filter, sort order and
+    /// favorites.
+    pub fn capture_config(&self, base: &Config) -> Config {
+        let mut favorites: Vec<String> = self.pinned.iter().cloned().collect();
+        favorites.sort_unstable();
+        Config {
+            providers: selected_labels(self.providers.iter().cloned(), &self.selected_providers),
+            fit: self.fit_filter.label().to_string(),
+            sort: self.sort_column.label().to_string(),
+            sort_ascending: self.sort_ascending,
+            favorites,
+            ..base.clone()
+        }
+    }
+
+    /// Start typing a name for saving the current state as a view.
+    pub fn start_view_name(&mut self) {
+        self.view_name_input = Some(String::new());
+    }
+
+    pub fn view_name_push(&mut self, c: char) {
+        if let Some(name) = self.view_name_input.as_mut() {
+            name.push(c);
+        }
+    }
+
+    pub fn view_name_backspace(&mut self) {
+        if let Some(name) = self.view_name_input.as_mut() {
+            name.pop();
+        }
+    }
+
+    pub fn cancel_view_name(&mut self) {
+        self.view_name_input = None;
+    }
+
+    /// Save the current state under the typed name, replacing a user view of
+    /// the same name. Built-in names can't be overwritten.
+    pub fn confirm_view_name(&mut self) {
+        let Some(name) = self.view_name_input.take() else {
+            return;
+        };
+        let name = name.trim().to_string();
+        if name.is_empty() {
+            return;
+        }
+        if self.views.iter().any(|v| v.builtin && v.name == name) {
+            self.pull_status = Some(format!("'{}' is a built-in view", name));
+            return;
+        }
+        let view = self.capture_view(&name);
+        match self.views.iter().position(|v| v.name == name) {
+            Some(i) => {
+                self.views[i] = view;
+                self.view_cursor = i;
+            }
+            None => {
+                self.views.push(view);
+                self.view_cursor = self.views.len() - 1;
+            }
+        }
+        views::save(&self.views);
+        self.pull_status = Some(format!("Saved view '{}'", name));
+    }
+
+    pub fn delete_selected_view(&mut self) {
+        match self.views.get(self.view_cursor) {
+            Some(view) if view.builtin => {
+                self.pull_status = Some("Built-in views can't be deleted".to_string());
+            }
+            Some(_) => {
+                let view = self.views.remove(self.view_cursor);
+                self.view_cursor = self.view_cursor.min(self.views.len().saturating_sub(1));
+                views::save(&self.views);
+                self.pull_status = Some(format!("Deleted view '{}'", view.name));
+            }
+            None => {}
+        }
+    }
+
+    pub fn open_tag_popup(&mut self) {
+        self.input_mode = InputMode::TagPopup;
+    }
+
+    pub fn close_tag_popup(&mut self) {
+        self.input_mode = InputMode::Normal;
+    }
+
+    pub fn tag_popup_up(&mut self) {
+        if self.tag_cursor > 0 {
+            self.tag_cursor -= 1;
+        }
+    }
+
+    pub fn tag_popup_down(&mut self) {
+        if self.tag_cursor + 1 < self.tags.len() {
+            self.tag_cursor += 1;
+        }
+    }
+
+    pub fn tag_popup_toggle(&mut self) {
+        if self.tag_cursor < self.selected_tags.len() {
+            self.push_undo();
+            self.selected_tags[self.tag_cursor] = !self.selected_tags[self.tag_cursor];
+            self.apply_filters();
+        }
+    }
+
+    /// Deselect every tag, which turns the tag filter off.
+    pub fn tag_popup_clear(&mut self) {
+        self.push_undo();
+        self.selected_tags.iter_mut().for_each(|s| *s = false);
+        self.apply_filters();
+    }
+
+    pub fn toggle_tag_match_mode(&mut self) {
+        self.push_undo();
+        self.tag_match_all = !self.tag_match_all;
+        self.apply_filters();
+    }
+
+    /// Selected tags in catalog order.
+    pub fn active_tags(&self) -> Vec<&str> {
+        self.tags
+            .iter()
+            .zip(self.selected_tags.iter())
+            .filter(|(_, sel)| **sel)
+            .map(|(tag, _)| tag.as_str())
+            .collect()
+    }
+
     // ── Visual mode ──────────────────────────────────────────────
 
     pub fn enter_visual_mode(&mut self) {
@@ -1160,6 +2625,62 @@ impl App {
         }
     }
 
+    /// In visual mode, total up the download size of the selected models.
+    pub fn visual_download_plan(&mut self) {
+        let Some(range) = self.visual_range() else {
+            return;
+        };
+        self.download_plan_models = range
+            .filter_map(|row| self.filtered_fits.get(row).copied())
+            .collect();
+        self.exit_visual_mode();
+        self.show_detail = false;
+        self.show_plan = false;
+        self.show_compare = false;
+        self.show_multi_compare = false;
+        self.show_download_plan = true;
+    }
+
+    pub fn close_download_plan(&mut self) {
+        self.show_download_plan = false;
+        self.download_plan_models.clear();
+    }
+
+    /// Estimated on-disk size of each planned model against free disk space.
+    /// Models already on disk are listed but don't count toward the total.
+    pub fn download_plan(&self) -> DownloadPlan {
+        let entries: Vec<DownloadPlanEntry> = self
+            .download_plan_models
+            .iter()
+            .filter_map(|&idx| self.all_fits.get(idx))
+            .map(|fit| {
+                let on_disk = self.disk_sizes.get(&fit.model.name);
+                DownloadPlanEntry {
+                    name: fit.model.name.clone(),
+                    quant: fit.best_quant.clone(),
+                    size_gb: on_disk.map_or_else(
+                        || {
+                            fit.model
+                                .weights_file_gib(&fit.best_quant, self.quant_table)
+                        },
+                        |&bytes| bytes as f64 / (1024.0 * 1024.0 * 1024.0),
+                    ),
+                    on_disk: on_disk.is_some(),
+                }
+            })
+            .collect();
+        let download_gb = entries
+            .iter()
+            .filter(|e| !e.on_disk)
+            .map(|e| e.size_gb)
+            .sum();
+        DownloadPlan {
+            entries,
+            download_gb,
+            free_gb: self.disk_free_gb,
+        }
+    }
+
     // ── Select mode ─────────────────────────────────────────────
 
     pub fn enter_select_mode(&mut self) {
@@ -1177,7 +2698,7 @@ impl App {
     }
 
     pub fn select_column_right(&mut self) {
-        if self.select_column < 13 {
+        if self.select_column < 14 {
             self.select_column += 1;
         }
     }
@@ -1186,28 +2707,27 @@ impl App {
     pub fn activate_select_column_filter(&mut self) {
         match self.select_column {
             1 => self.cycle_availability_filter(), // Inst
-            2 => {
-                self.input_mode = InputMode::Search;
-            } // Model → search
+            2 => self.enter_search(),              // Model → search
             3 => {
                 self.input_mode = InputMode::ProviderPopup;
             } // Provider
             4 => {
                 self.input_mode = InputMode::ParamsBucketPopup;
             } // Params
-            5 => self.set_or_toggle_sort(SortColumn::Score), // Score
-            6 => self.set_or_toggle_sort(SortColumn::Tps), // tok/s
-            7 => {
+            5 => self.set_or_toggle_sort(SortColumn::Params), // Size
+            6 => self.set_or_toggle_sort(SortColumn::Score), // Score
+            7 => self.set_or_toggle_sort(SortColumn::Tps), // tok/s
+            8 => {
                 self.input_mode = InputMode::QuantPopup;
             } // Quant
-            8 => {
+            9 => {
                 self.input_mode = InputMode::RunModePopup;
             } // Mode
-            9 => self.set_or_toggle_sort(SortColumn::MemPct), // Mem%
-            10 => self.set_or_toggle_sort(SortColumn::Ctx), // Ctx
-            11 => self.set_or_toggle_sort(SortColumn::ReleaseDate), // Date
-            12 => self.cycle_fit_filter(),         // Fit
-            13 => {
+            10 => self.set_or_toggle_sort(SortColumn::MemPct), // Mem%
+            11 => self.set_or_toggle_sort(SortColumn::Ctx), // Ctx
+            12 => self.set_or_toggle_sort(SortColumn::ReleaseDate), // Date
+            13 => self.cycle_fit_filter(),         // Fit
+            14 => {
                 self.input_mode = InputMode::UseCasePopup;
             } // Use Case
             _ => {}
@@ -1216,6 +2736,7 @@ impl App {
 
     /// Set sort column, or toggle ascending/descending if already on that column.
     fn set_or_toggle_sort(&mut self, col: SortColumn) {
+        self.push_undo();
         if self.sort_column == col {
             self.sort_ascending = !self.sort_ascending;
         } else {
@@ -1245,12 +2766,14 @@ impl App {
 
     pub fn quant_popup_toggle(&mut self) {
         if self.quant_cursor < self.selected_quants.len() {
+            self.push_undo();
             self.selected_quants[self.quant_cursor] = !self.selected_quants[self.quant_cursor];
             self.apply_filters();
         }
     }
 
     pub fn quant_popup_select_all(&mut self) {
+        self.push_undo();
         let all_selected = self.selected_quants.iter().all(|&s| s);
         let new_val = !all_selected;
         for s in &mut self.selected_quants {
@@ -1279,6 +2802,7 @@ impl App {
 
     pub fn run_mode_popup_toggle(&mut self) {
         if self.run_mode_cursor < self.selected_run_modes.len() {
+            self.push_undo();
             self.selected_run_modes[self.run_mode_cursor] =
                 !self.selected_run_modes[self.run_mode_cursor];
             self.apply_filters();
@@ -1286,6 +2810,7 @@ impl App {
     }
 
     pub fn run_mode_popup_select_all(&mut self) {
+        self.push_undo();
         let all_selected = self.selected_run_modes.iter().all(|&s| s);
         let new_val = !all_selected;
         for s in &mut self.selected_run_modes {
@@ -1314,6 +2839,7 @@ impl App {
 
     pub fn params_bucket_popup_toggle(&mut self) {
         if self.params_bucket_cursor < self.selected_params_buckets.len() {
+            self.push_undo();
             self.selected_params_buckets[self.params_bucket_cursor] =
                 !self.selected_params_buckets[self.params_bucket_cursor];
             self.apply_filters();
@@ -1321,6 +2847,7 @@ impl App {
     }
 
     pub fn params_bucket_popup_select_all(&mut self) {
+        self.push_undo();
         let all_selected = self.selected_params_buckets.iter().all(|&s| s);
         let new_val = !all_selected;
         for s in &mut self.selected_params_buckets {
@@ -1330,6 +2857,7 @@ impl App {
     }
 
     pub fn toggle_installed_first(&mut self) {
+        self.push_undo();
         self.installed_first = !self.installed_first;
         self.re_sort();
     }
@@ -1514,6 +3042,8 @@ impl App {
     pub fn tick_pull(&mut self) {
         self.enqueue_capability_probes_for_visible(24);
         self.tick_download_capability();
+        self.tick_catalog();
+        self.tick_watch();
         if self.pull_active.is_some() {
             self.tick_count = self.tick_count.wrapping_add(1);
         }
@@ -1642,11 +3172,68 @@ impl App {
         self.start_download_with_provider(model_name, provider);
     }
 
+    /// Model counts per fit level across all analyzed models, ignoring filters.
+    pub fn fit_distribution(&self) -> [(FitLevel, usize); 4] {
+        let mut counts = [
+            (FitLevel::Perfect, 0),
+            (FitLevel::Good, 0),
+            (FitLevel::Marginal, 0),
+            (FitLevel::TooTight, 0),
+        ];
+        for fit in &self.all_fits {
+            if let Some(slot) = counts.iter_mut().find(|(level, _)| *level == fit.fit_level) {
+                slot.1 += 1;
+            }
+        }
+        counts
+    }
+
+    /// Open the activity log overlay with a fresh snapshot, or close it.
+    pub fn toggle_activity_log(&mut self) {
+        self.show_activity_log = !self.show_activity_log;
+        if self.show_activity_log {
+            self.activity_entries = activity::entries();
+            self.activity_scroll = 0;
+        }
+    }
+
+    pub fn activity_scroll_up(&mut self) {
+        self.activity_scroll = self.activity_scroll.saturating_sub(1);
+    }
+
+    pub fn activity_scroll_down(&mut self) {
+        self.activity_scroll = self.activity_scroll.saturating_add(1);
+    }
+
+    /// Directory scanned for cached GGUF files (`LLMFIT_MODELS_DIR` or the default).
+    pub fn gguf_cache_dir(&self) -> &std::path::Path {
+        self.llamacpp.models_dir()
+    }
+
+    /// `r`: re-read free RAM and refit, then refresh installed models when a
+    /// runtime provider is available. GPUs aren't probed again.
+    pub fn refresh(&mut self) {
+        self.specs.refresh(self.llamacpp.models_dir());
+        self.refit_all();
+        if self.ollama_available
+            || self.mlx_available
+            || self.llamacpp_available
+            || self.lmstudio_available
+        {
+            self.refresh_installed();
+        }
+        self.pull_status = Some(format!(
+            "Refreshed: {} RAM available",
+            fmt_mem(self.specs.available_ram_gb, 1, self.units)
+        ));
+    }
+
     /// Re-query all providers for installed models and update all_fits.
     pub fn refresh_installed(&mut self) {
         let (ollama_set, ollama_count) = self.ollama.installed_models_counted();
         self.ollama_installed = ollama_set;
         self.ollama_installed_count = ollama_count;
+        self.ollama_running = self.ollama.running_models();
         self.mlx_installed = self.mlx.installed_models();
         let (llamacpp_set, llamacpp_count) = self.llamacpp.installed_models_counted();
         self.llamacpp_installed = llamacpp_set;
@@ -1657,6 +3244,19 @@ impl App {
         let (lmstudio_set, lmstudio_count) = self.lmstudio.installed_models_counted();
         self.lmstudio_installed = lmstudio_set;
         self.lmstudio_installed_count = lmstudio_count;
+        self.mark_installed();
+        self.disk_sizes = compute_disk_sizes(
+            &self.all_fits,
+            &self.ollama,
+            self.ollama_available,
+            &self.llamacpp,
+        );
+        self.re_sort();
+        self.enqueue_capability_probes_for_visible(24);
+    }
+
+    /// Set each fit's installed flag from the providers' installed lists.
+    fn mark_installed(&mut self) {
         for fit in &mut self.all_fits {
             fit.installed = providers::is_model_installed(&fit.model.name, &self.ollama_installed)
                 || providers::is_model_installed_mlx(&fit.model.name, &self.mlx_installed)
@@ -1673,8 +3273,6 @@ impl App {
                     &self.lmstudio_installed,
                 );
         }
-        self.re_sort();
-        self.enqueue_capability_probes_for_visible(24);
     }
 
     pub fn download_capability_for(&self, model_name: &str) -> DownloadCapability {
@@ -1741,6 +3339,155 @@ impl App {
         });
     }
 
+    /// Fetch the `--catalog-url` catalog on a background thread, showing
+    /// `cached` (its last download) until it arrives.
+    pub fn fetch_remote_catalog(&mut self, url: String, cached: Option<ModelCatalog>) {
+        self.remote_catalog = cached;
+        let (tx, rx) = mpsc::channel();
+        std::thread::spawn(move || {
+            let _ = tx.send(ModelCatalog::fetch_remote(&url));
+        });
+        self.catalog_rx = Some(rx);
+    }
+
+    fn tick_catalog(&mut self) {
+        // Hold a finished fetch until views holding indices into all_fits close
+        if self.input_mode != InputMode::Normal
+            || self.show_plan
+            || self.show_multi_compare
+            || self.show_download_plan
+        {
+            return;
+        }
+        let Some(rx) = &self.catalog_rx else {
+            return;
+        };
+        let result = match rx.try_recv() {
+            Ok(result) => result,
+            Err(mpsc::TryRecvError::Empty) => return,
+            Err(mpsc::TryRecvError::Disconnected) => {
+                self.catalog_rx = None;
+                return;
+            }
+        };
+        self.catalog_rx = None;
+        match result {
+            Ok(catalog) => {
+                // A 304 or offline fallback is the cached copy already shown
+                if catalog.source == CatalogSource::Downloaded || self.remote_catalog.is_none() {
+                    self.catalog_sources.base = Some(catalog.models.clone());
+                    let (db, _) = ModelDatabase::from_sources(&self.catalog_sources);
+                    self.swap_catalog(db.get_all_models());
+                    self.pull_status = Some(format!(
+                        "Loaded remote {}",
+                        catalog.summary(SystemTime::now())
+                    ));
+                }
+                self.remote_catalog = Some(catalog);
+            }
+            Err(err) => {
+                log::warn!("{}", err);
+                self.pull_status = Some(format!("Remote catalog: {}", err));
+            }
+        }
+    }
+
+    /// `--watch`: re-read free RAM every `interval` on a background thread
+    /// and refit when it moves. Only `System::refresh_memory()` runs each
+    /// tick; GPUs aren't probed again. Loaded and hypothetical specs don't
+    /// describe this machine's RAM, so they aren't watched.
+    pub fn start_watch(&mut self, interval: Duration) {
+        if self.specs.loaded_from.is_some() || self.specs.hypothetical {
+            self.pull_status =
+                Some("--watch ignored: specs aren't this machine's live RAM".to_string());
+            return;
+        }
+        let (tx, rx) = mpsc::channel();
+        std::thread::spawn(move || {
+            loop {
+                std::thread::sleep(interval);
+                if tx.send(MemoryReading::take()).is_err() {
+                    break;
+                }
+            }
+        });
+        self.watch_interval = Some(interval);
+        self.watch_rx = Some(rx);
+    }
+
+    fn tick_watch(&mut self) {
+        // Hold readings while views holding indices into all_fits are open
+        if self.input_mode != InputMode::Normal
+            || self.show_plan
+            || self.show_multi_compare
+            || self.show_download_plan
+        {
+            return;
+        }
+        let Some(rx) = &self.watch_rx else {
+            return;
+        };
+        if let Some(reading) = rx.try_iter().last() {
+            self.apply_memory_reading(reading);
+        }
+    }
+
+    /// Apply a reading from the watch thread, refitting only when available RAM moved
+    /// by at least [`WATCH_MIN_CHANGE_GB`] so the table doesn't churn.
+    pub fn apply_memory_reading(&mut self, reading: MemoryReading) {
+        let before = self.specs.available_ram_gb;
+        let mut specs = self.specs.clone();
+        specs.apply_memory_reading(reading);
+        if (specs.available_ram_gb - before).abs() < WATCH_MIN_CHANGE_GB {
+            return;
+        }
+        activity::detect(format!(
+            "Watch: {} RAM available (was {})",
+            fmt_mem(specs.available_ram_gb, 1, self.units),
+            fmt_mem(before, 1, self.units)
+        ));
+        self.specs = specs;
+        self.refit_all();
+    }
+
+    /// Replace the model list, e.g. with a remote catalog that finished
+    /// downloading. Filters and the selected model carry over.
+    fn swap_catalog(&mut self, models: &[LlmModel]) {
+        let selected_name = self.selected_fit().map(|f| f.model.name.clone());
+        let compatible: Vec<&LlmModel> = models
+            .iter()
+            .filter(|m| backend_compatible(m, &self.specs))
+            .collect();
+        self.backend_hidden_count = models.len() - compatible.len();
+        // Pinned quants were rescaled from the old catalog's figures
+        self.quant_overrides.clear();
+        self.all_fits =
+            llmfit_core::fit::analyze_many(&compatible, &self.specs, &self.fit_options());
+        self.mark_installed();
+        self.disk_sizes = compute_disk_sizes(
+            &self.all_fits,
+            &self.ollama,
+            self.ollama_available,
+            &self.llamacpp,
+        );
+        self.sync_providers();
+        let tags = self.all_fits.iter().flat_map(|f| f.model.tags.clone());
+        resync_options(&mut self.tags, &mut self.selected_tags, tags, false);
+        let quants = self.all_fits.iter().map(|f| f.best_quant.clone());
+        resync_options(&mut self.quants, &mut self.selected_quants, quants, true);
+        self.tag_cursor = self.tag_cursor.min(self.tags.len().saturating_sub(1));
+        self.quant_cursor = self.quant_cursor.min(self.quants.len().saturating_sub(1));
+        self.refresh_context_target();
+        self.re_sort();
+        if let Some(row) = selected_name.and_then(|name| {
+            self.filtered_fits
+                .iter()
+                .position(|&i| self.all_fits[i].model.name == name)
+        }) {
+            self.selected_row = row;
+        }
+    }
+
     fn tick_download_capability(&mut self) {
         loop {
             match self.download_capability_rx.try_recv() {
@@ -1780,3 +3527,99 @@ fn command_exists(name: &str) -> bool {
         .map(|s| s.success())
         .unwrap_or(false)
 }
+
+/// Distinct providers in the catalog, sorted by name.
+fn distinct_providers(fits: &[ModelFit]) -> Vec<String> {
+    fits.iter()
+        .map(|f| f.model.provider.clone())
+        .collect::<std::collections::BTreeSet<_>>()
+        .into_iter()
+        .collect()
+}
+
+/// Labels of the selected entries, or `None` when everything is selected.
+fn selected_labels(labels: impl Iterator<Item = String>, selected: &[bool]) -> Option<Vec<String>> {
+    if selected.iter().all(|s| *s) {
+        return None;
+    }
+    Some(
+        labels
+            .zip(selected.iter())
+            .filter(|(_, sel)| **sel)
+            .map(|(label, _)| label)
+            .collect(),
+    )
+}
+
+/// Inverse of [`selected_labels`]; unknown labels are ignored.
+fn select_labels(labels: impl Iterator<Item = String>, wanted: &Option<Vec<String>>) -> Vec<bool> {
+    match wanted {
+        Some(wanted) => labels.map(|l| wanted.contains(&l)).collect(),
+        None => labels.map(|_| true).collect(),
+    }
+}
+
+/// Walk a `next()` cycle from `start` until `matches`, falling back to `start`.
+fn find_by_label<T: Copy + PartialEq>(
+    start: T,
+    next: impl Fn(&T) -> T,
+    matches: impl Fn(&T) -> bool,
+) -> T {
+    let mut value = start;
+    loop {
+        if matches(&value) {
+            return value;
+        }
+        value = next(&value);
+        if value == start {
+            return start;
+        }
+    }
+}
+
+/// Rebuild a filter's sorted option list from `values`. Options that remain
+/// keep their selection; new ones get `default`.
+fn resync_options(
+    options: &mut Vec<String>,
+    selected: &mut Vec<bool>,
+    values: impl Iterator<Item = String>,
+    default: bool,
+) {
+    let previous: HashMap<String, bool> = options.drain(..).zip(selected.drain(..)).collect();
+    *options = values
+        .collect::<std::collections::BTreeSet<_>>()
+        .into_iter()
+        .collect();
+    *selected = options
+        .iter()
+        .map(|o| previous.get(o).copied().unwrap_or(default))
+        .collect();
+}
+
+/// Map catalog model names to the bytes their weights occupy on disk,
+/// combining the Ollama store and the llama.cpp GGUF cache.
+fn compute_disk_sizes(
+    fits: &[ModelFit],
+    ollama: &OllamaProvider,
+    ollama_available: bool,
+    llamacpp: &LlamaCppProvider,
+) -> HashMap<String, u64> {
+    let ollama_sizes = if ollama_available {
+        ollama.installed_sizes()
+    } else {
+        HashMap::new()
+    };
+    let gguf_sizes = llamacpp.installed_sizes();
+    if ollama_sizes.is_empty() && gguf_sizes.is_empty() {
+        return HashMap::new();
+    }
+    fits.iter()
+        .filter_map(|fit| {
+            let name = &fit.model.name;
+            let ollama = providers::ollama_disk_size(name, &ollama_sizes).unwrap_or(0);
+            let gguf = providers::llamacpp_disk_size(name, &gguf_sizes).unwrap_or(0);
+            let total = ollama + gguf;
+            (total > 0).then(|| (name.clone(), total))
+        })
+        .collect()
+}
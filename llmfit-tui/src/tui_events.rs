@@ -1,5 +1,8 @@
-use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
-use std::time::Duration;
+use crossterm::event::{
+    self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers, MouseButton, MouseEvent,
+    MouseEventKind,
+};
+use std::time::{Duration, Instant};
 
 use crate::tui_app::{App, InputMode};
 
@@ -7,38 +10,96 @@ use crate::tui_app::{App, InputMode};
 pub fn handle_events(app: &mut App) -> std::io::Result<bool> {
     // Always tick the pull progress (non-blocking)
     app.tick_pull();
+    // Background refits and catalog updates can shrink the list
+    app.sync_scroll();
 
-    if event::poll(Duration::from_millis(50))?
-        && let Event::Key(key) = event::read()?
-    {
+    if !event::poll(Duration::from_millis(50))? {
+        return Ok(false);
+    }
+    match event::read()? {
         // Only handle Press events (ignore Release on some platforms)
-        if key.kind != KeyEventKind::Press {
-            return Ok(false);
+        Event::Key(key) if key.kind == KeyEventKind::Press => {
+            handle_key(app, key);
+            Ok(true)
+        }
+        Event::Resize(width, height) => {
+            app.on_resize(width, height);
+            Ok(true)
         }
-        match app.input_mode {
-            InputMode::Normal => handle_normal_mode(app, key),
-            InputMode::Visual => handle_visual_mode(app, key),
-            InputMode::Select => handle_select_mode(app, key),
-            InputMode::Search => handle_search_mode(app, key),
-            InputMode::Plan => handle_plan_mode(app, key),
-            InputMode::ProviderPopup => handle_provider_popup_mode(app, key),
-            InputMode::UseCasePopup => handle_use_case_popup_mode(app, key),
-            InputMode::CapabilityPopup => handle_capability_popup_mode(app, key),
-            InputMode::DownloadProviderPopup => handle_download_provider_popup_mode(app, key),
-            InputMode::QuantPopup => handle_quant_popup_mode(app, key),
-            InputMode::RunModePopup => handle_run_mode_popup_mode(app, key),
-            InputMode::ParamsBucketPopup => handle_params_bucket_popup_mode(app, key),
+        Event::Mouse(mouse) => Ok(handle_mouse(app, mouse)),
+        _ => Ok(false),
+    }
+}
+
+/// Clicks and the scroll wheel. Only arrives when mouse capture is on
+/// (i.e. without `--no-mouse`). Returns true if the event did anything.
+pub fn handle_mouse(app: &mut App, mouse: MouseEvent) -> bool {
+    if app.show_activity_log {
+        return false;
+    }
+    match mouse.kind {
+        MouseEventKind::Down(MouseButton::Left) => {
+            app.click_at(mouse.column, mouse.row, Instant::now())
         }
-        return Ok(true);
+        MouseEventKind::ScrollDown => app.scroll_wheel(true),
+        MouseEventKind::ScrollUp => app.scroll_wheel(false),
+        _ => return false,
+    }
+    app.sync_scroll();
+    true
+}
+
+/// Dispatch one key press to the handler for the current input mode.
+pub fn handle_key(app: &mut App, key: KeyEvent) {
+    match app.input_mode {
+        InputMode::Normal => handle_normal_mode(app, key),
+        InputMode::Visual => handle_visual_mode(app, key),
+        InputMode::Select => handle_select_mode(app, key),
+        InputMode::Search => handle_search_mode(app, key),
+        InputMode::Jump => handle_jump_mode(app, key),
+        InputMode::Plan => handle_plan_mode(app, key),
+        InputMode::ProviderPopup => handle_provider_popup_mode(app, key),
+        InputMode::UseCasePopup => handle_use_case_popup_mode(app, key),
+        InputMode::CapabilityPopup => handle_capability_popup_mode(app, key),
+        InputMode::TagPopup => handle_tag_popup_mode(app, key),
+        InputMode::ViewPopup => handle_view_popup_mode(app, key),
+        InputMode::DownloadProviderPopup => handle_download_provider_popup_mode(app, key),
+        InputMode::QuantPopup => handle_quant_popup_mode(app, key),
+        InputMode::RunModePopup => handle_run_mode_popup_mode(app, key),
+        InputMode::ParamsBucketPopup => handle_params_bucket_popup_mode(app, key),
+        InputMode::Help => handle_help_mode(app, key),
+    }
+    app.sync_scroll();
+}
+
+/// j/k page through the help overlay; any other key dismisses it.
+fn handle_help_mode(app: &mut App, key: KeyEvent) {
+    match key.code {
+        KeyCode::Down | KeyCode::Char('j') | KeyCode::PageDown | KeyCode::Char(' ') => {
+            app.help_page_down()
+        }
+        KeyCode::Up | KeyCode::Char('k') | KeyCode::PageUp => app.help_page_up(),
+        _ => app.close_help(),
     }
-    Ok(false)
 }
 
 fn handle_normal_mode(app: &mut App, key: KeyEvent) {
+    if app.show_activity_log {
+        match key.code {
+            KeyCode::Char('L') | KeyCode::Char('q') | KeyCode::Esc => app.toggle_activity_log(),
+            KeyCode::Up | KeyCode::Char('k') => app.activity_scroll_up(),
+            KeyCode::Down | KeyCode::Char('j') => app.activity_scroll_down(),
+            _ => {}
+        }
+        return;
+    }
+
     match key.code {
         // Quit
         KeyCode::Char('q') | KeyCode::Esc => {
-            if app.show_multi_compare {
+            if app.show_download_plan {
+                app.close_download_plan();
+            } else if app.show_multi_compare {
                 app.close_multi_compare();
             } else if app.show_detail {
                 app.show_detail = false;
@@ -54,9 +115,15 @@ fn handle_normal_mode(app: &mut App, key: KeyEvent) {
         KeyCode::Char('l') if app.show_multi_compare => app.multi_compare_scroll_right(),
         KeyCode::Left if app.show_multi_compare => app.multi_compare_scroll_left(),
         KeyCode::Right if app.show_multi_compare => app.multi_compare_scroll_right(),
+        // In the detail view, h/l step to the previous/next model
+        KeyCode::Char('h') | KeyCode::Left if app.show_detail => app.move_up(),
+        KeyCode::Char('l') | KeyCode::Right if app.show_detail => app.move_down(),
 
         KeyCode::Char('u') if key.modifiers.contains(KeyModifiers::CONTROL) => app.half_page_up(),
         KeyCode::Char('d') if key.modifiers.contains(KeyModifiers::CONTROL) => app.half_page_down(),
+        // Scroll the view without moving the selection (vim Ctrl+E / Ctrl+Y)
+        KeyCode::Char('e') if key.modifiers.contains(KeyModifiers::CONTROL) => app.scroll_view(1),
+        KeyCode::Char('y') if key.modifiers.contains(KeyModifiers::CONTROL) => app.scroll_view(-1),
         KeyCode::Up | KeyCode::Char('k') => app.move_up(),
         KeyCode::Down | KeyCode::Char('j') => app.move_down(),
         KeyCode::PageUp => app.page_up(),
@@ -73,6 +140,9 @@ fn handle_normal_mode(app: &mut App, key: KeyEvent) {
         // Search
         KeyCode::Char('/') => app.enter_search(),
 
+        // Type-ahead jump
+        KeyCode::Char('\'') => app.enter_jump(),
+
         // Fit filter
         KeyCode::Char('f') => app.cycle_fit_filter(),
 
@@ -82,16 +152,56 @@ fn handle_normal_mode(app: &mut App, key: KeyEvent) {
         // Sort column
         KeyCode::Char('s') => app.cycle_sort_column(),
 
+        // Activity log overlay
+        KeyCode::Char('L') => app.toggle_activity_log(),
+
+        // Undo last filter/search/sort change
+        KeyCode::Char('u') => app.undo(),
+
+        // Reset all filters
+        KeyCode::Char('R') => app.reset_filters(),
+
         // Theme
         KeyCode::Char('t') => app.cycle_theme(),
 
+        // Put the selection in the middle of the view (vim zz)
+        KeyCode::Char('z') => app.recenter_view(),
+
+        // Full / compact table layout
+        KeyCode::Char('o') => app.cycle_table_layout(),
+
+        // Copy the filtered list as a markdown table
+        KeyCode::Char('Y') => app.copy_filtered_markdown(),
+
+        // Write the filtered list to a CSV file
+        KeyCode::Char('e') => app.export_filtered_csv(),
+
         // Plan view
         KeyCode::Char('p') => app.open_plan_mode(),
 
-        // Provider popup
+        // Provider popup; 1-9 toggle the first nine providers directly
         KeyCode::Char('P') => app.open_provider_popup(),
+        KeyCode::Char(c @ '1'..='9') => app.toggle_provider(c as usize - '1' as usize),
         KeyCode::Char('U') => app.open_use_case_popup(),
         KeyCode::Char('C') => app.open_capability_popup(),
+        KeyCode::Char('T') => app.open_tag_popup(),
+        KeyCode::Char('w') => app.open_view_popup(),
+        KeyCode::Char('M') => app.cycle_forced_run_mode(),
+        KeyCode::Char('Q') => app.cycle_selected_quant(),
+        KeyCode::Char('B') => app.toggle_fit_target(),
+        KeyCode::Char('+' | '=') => app.step_concurrency(true),
+        KeyCode::Char('-') => app.step_concurrency(false),
+
+        // Only models whose fit changed since the last run
+        KeyCode::Char('D') => app.toggle_changed_only(),
+        KeyCode::Char('K') => app.toggle_known_vram_only(),
+        KeyCode::Char('W') => app.cycle_min_context(),
+
+        // Only models that fit at a target context
+        KeyCode::Char(']') => app.step_context_target(true),
+        KeyCode::Char('[') => app.step_context_target(false),
+        KeyCode::Char('}') => app.step_context_limit(true),
+        KeyCode::Char('{') => app.step_context_limit(false),
 
         // Installed-first sort toggle (any provider)
         KeyCode::Char('i')
@@ -115,24 +225,25 @@ fn handle_normal_mode(app: &mut App, key: KeyEvent) {
             }
         }
 
-        // Refresh installed models
-        KeyCode::Char('r')
-            if app.ollama_available
-                || app.mlx_available
-                || app.llamacpp_available
-                || app.lmstudio_available =>
-        {
-            app.refresh_installed()
-        }
+        // Refresh free RAM and installed models
+        KeyCode::Char('r') => app.refresh(),
 
         // Detail view
         KeyCode::Enter => app.toggle_detail(),
 
+        // Pin / unpin favorite
+        KeyCode::Char('*') | KeyCode::Char('b') => app.toggle_pin_selected(),
+        KeyCode::Char('F') => app.toggle_favorites_only(),
+
         // Compare view
         KeyCode::Char('m') => app.mark_selected_for_compare(),
         KeyCode::Char('c') => app.toggle_compare_view(),
+        KeyCode::Char(' ') => app.toggle_compare_set(),
         KeyCode::Char('x') => app.clear_compare_mark(),
 
+        // Key bindings overlay
+        KeyCode::Char('?') => app.open_help(),
+
         _ => {}
     }
 }
@@ -158,6 +269,9 @@ fn handle_visual_mode(app: &mut App, key: KeyEvent) {
         // Compare first and last in visual selection
         KeyCode::Char('c') => app.visual_compare(),
 
+        // Total download size of the selection against free disk
+        KeyCode::Char('d') => app.visual_download_plan(),
+
         _ => {}
     }
 }
@@ -203,6 +317,19 @@ fn handle_search_mode(app: &mut App, key: KeyEvent) {
     }
 }
 
+fn handle_jump_mode(app: &mut App, key: KeyEvent) {
+    match key.code {
+        KeyCode::Esc | KeyCode::Enter => app.exit_jump(),
+
+        KeyCode::Char(c) if c.is_alphanumeric() || c == '-' || c == '.' => app.jump_input(c),
+
+        KeyCode::Up => app.move_up(),
+        KeyCode::Down => app.move_down(),
+
+        _ => {}
+    }
+}
+
 fn handle_provider_popup_mode(app: &mut App, key: KeyEvent) {
     match key.code {
         KeyCode::Esc | KeyCode::Char('P') | KeyCode::Char('q') => app.close_provider_popup(),
@@ -265,6 +392,48 @@ fn handle_capability_popup_mode(app: &mut App, key: KeyEvent) {
     }
 }
 
+fn handle_tag_popup_mode(app: &mut App, key: KeyEvent) {
+    match key.code {
+        KeyCode::Esc | KeyCode::Char('T') | KeyCode::Char('q') => app.close_tag_popup(),
+
+        KeyCode::Up | KeyCode::Char('k') => app.tag_popup_up(),
+        KeyCode::Down | KeyCode::Char('j') => app.tag_popup_down(),
+
+        KeyCode::Char(' ') | KeyCode::Enter => app.tag_popup_toggle(),
+
+        KeyCode::Char('a') => app.tag_popup_clear(),
+        KeyCode::Char('m') => app.toggle_tag_match_mode(),
+
+        _ => {}
+    }
+}
+
+fn handle_view_popup_mode(app: &mut App, key: KeyEvent) {
+    if app.view_name_input.is_some() {
+        match key.code {
+            KeyCode::Esc => app.cancel_view_name(),
+            KeyCode::Enter => app.confirm_view_name(),
+            KeyCode::Backspace => app.view_name_backspace(),
+            KeyCode::Char(c) => app.view_name_push(c),
+            _ => {}
+        }
+        return;
+    }
+
+    match key.code {
+        KeyCode::Esc | KeyCode::Char('w') | KeyCode::Char('q') => app.close_view_popup(),
+
+        KeyCode::Up | KeyCode::Char('k') => app.view_popup_up(),
+        KeyCode::Down | KeyCode::Char('j') => app.view_popup_down(),
+
+        KeyCode::Enter => app.apply_selected_view(),
+        KeyCode::Char('n') => app.start_view_name(),
+        KeyCode::Char('d') => app.delete_selected_view(),
+
+        _ => {}
+    }
+}
+
 fn handle_download_provider_popup_mode(app: &mut App, key: KeyEvent) {
     match key.code {
         KeyCode::Esc | KeyCode::Char('q') => app.close_download_provider_popup(),
@@ -319,3 +488,894 @@ fn handle_params_bucket_popup_mode(app: &mut App, key: KeyEvent) {
         _ => {}
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tui_app::{AvailabilityFilter, FitFilter};
+    use llmfit_core::fit::{FitLevel, FitOptions, ModelFit, RunMode};
+    use llmfit_core::hardware::{GpuBackend, SystemSpecs};
+    use llmfit_core::models::{LlmModel, ModelFormat};
+    use ratatui::{Terminal, backend::TestBackend};
+
+    fn model(name: &str, provider: &str, params: &str, min_ram: f64) -> LlmModel {
+        LlmModel {
+            name: name.to_string(),
+            provider: provider.to_string(),
+            parameter_count: params.to_string(),
+            parameters_raw: None,
+            min_ram_gb: min_ram,
+            recommended_ram_gb: min_ram * 2.0,
+            min_vram_gb: Some(min_ram),
+            quantization: "Q4_K_M".to_string(),
+            context_length: 4096,
+            use_case: "General".to_string(),
+            is_moe: false,
+            num_experts: None,
+            active_experts: None,
+            active_parameters: None,
+            release_date: None,
+            gguf_sources: vec![],
+            capabilities: vec![],
+            tags: vec![],
+            format: ModelFormat::default(),
+            num_layers: None,
+            kv_hidden_size: None,
+            hf_downloads: None,
+            gguf_file_sizes: Default::default(),
+        }
+    }
+
+    /// Draw one frame of `app` on a `width` x `height` test terminal and
+    /// return the buffer's debug dump.
+    fn render_to_string(app: &mut App, width: u16, height: u16) -> String {
+        let mut terminal = Terminal::new(TestBackend::new(width, height)).unwrap();
+        terminal
+            .draw(|frame| crate::tui_ui::draw(frame, app))
+            .unwrap();
+        format!("{:?}", terminal.backend().buffer())
+    }
+
+    /// CPU-only 16 GB box with a four-model catalog; the 70B model is too tight.
+    fn test_app() -> App {
+        let specs = SystemSpecs {
+            total_ram_gb: 16.0,
+            available_ram_gb: 14.0,
+            total_cpu_cores: 8,
+            cpu_name: "Test CPU".to_string(),
+            has_gpu: false,
+            gpu_vram_gb: None,
+            total_gpu_vram_gb: None,
+            gpu_name: None,
+            gpu_count: 0,
+            unified_memory: false,
+            backend: GpuBackend::CpuX86,
+            gpus: vec![],
+            memory_bandwidth_gbps: None,
+            reserved_ram_gb: 0.0,
+            headroom_gb: 0.0,
+            gpu_wired_limit_gb: None,
+            swap_total_gb: 0.0,
+            swap_used_gb: 0.0,
+            process_mem_limit_gb: None,
+            driver_version: None,
+            cuda_version: None,
+            cpu_features: Default::default(),
+            has_npu: false,
+            npu_name: None,
+            loaded_from: None,
+            hypothetical: false,
+            free_disk_gb: None,
+        };
+        let models = vec![
+            model("alpha-coder-7b", "Acme", "7B", 4.0),
+            model("beta-chat-1b", "Acme", "1B", 1.0),
+            model("gamma-70b", "Zeta", "70B", 40.0),
+            model("delta-3b", "Zeta", "3B", 2.0),
+        ];
+        App::with_catalog(specs, FitOptions::default(), &models)
+    }
+
+    fn press(app: &mut App, keys: &str) {
+        for c in keys.chars() {
+            handle_key(app, KeyEvent::from(KeyCode::Char(c)));
+        }
+    }
+
+    fn press_code(app: &mut App, code: KeyCode) {
+        handle_key(app, KeyEvent::from(code));
+    }
+
+    fn visible_names(app: &App) -> Vec<String> {
+        let mut names: Vec<String> = app
+            .filtered_fits
+            .iter()
+            .map(|&i| app.all_fits[i].model.name.clone())
+            .collect();
+        names.sort();
+        names
+    }
+
+    #[test]
+    fn test_search_entry_typing_and_exit() {
+        let mut app = test_app();
+        assert_eq!(app.filtered_fits.len(), 4);
+
+        press(&mut app, "/");
+        assert_eq!(app.input_mode, InputMode::Search);
+        press(&mut app, "coder");
+        assert_eq!(app.search_query, "coder");
+        assert_eq!(visible_names(&app), vec!["alpha-coder-7b"]);
+
+        press_code(&mut app, KeyCode::Enter);
+        assert_eq!(app.input_mode, InputMode::Normal);
+        assert_eq!(app.filtered_fits.len(), 1);
+
+        // Undo restores the pre-search state
+        press(&mut app, "u");
+        assert!(app.search_query.is_empty());
+        assert_eq!(app.filtered_fits.len(), 4);
+    }
+
+    #[test]
+    fn test_navigation_moves_selection_within_bounds() {
+        let mut app = test_app();
+        assert_eq!(app.selected_row, 0);
+        press(&mut app, "jj");
+        assert_eq!(app.selected_row, 2);
+        press(&mut app, "G");
+        assert_eq!(app.selected_row, 3);
+        press(&mut app, "j");
+        assert_eq!(app.selected_row, 3);
+        press(&mut app, "kkkkk");
+        assert_eq!(app.selected_row, 0);
+    }
+
+    #[test]
+    fn test_provider_popup_toggle_hides_provider() {
+        let mut app = test_app();
+        press(&mut app, "P");
+        assert_eq!(app.input_mode, InputMode::ProviderPopup);
+        assert_eq!(app.providers, vec!["Acme", "Zeta"]);
+
+        // Cursor starts on "Acme"; toggle it off
+        press(&mut app, " ");
+        assert_eq!(visible_names(&app), vec!["delta-3b", "gamma-70b"]);
+
+        press_code(&mut app, KeyCode::Esc);
+        assert_eq!(app.input_mode, InputMode::Normal);
+        assert_eq!(app.filtered_fits.len(), 2);
+    }
+
+    #[test]
+    fn test_digit_keys_toggle_providers() {
+        let mut app = test_app();
+        press(&mut app, "2");
+        assert_eq!(visible_names(&app), vec!["alpha-coder-7b", "beta-chat-1b"]);
+
+        // Digits past the provider count do nothing
+        press(&mut app, "9");
+        assert_eq!(app.selected_providers, vec![true, false]);
+
+        press(&mut app, "2");
+        assert_eq!(app.filtered_fits.len(), 4);
+    }
+
+    #[test]
+    fn test_brace_keys_step_assumed_context_and_refit() {
+        let mut long = model("long-8b", "Acme", "8B", 5.0);
+        long.context_length = 131_072;
+        long.num_layers = Some(32);
+        long.kv_hidden_size = Some(1024);
+        let mut app = App::with_catalog(test_app().specs, FitOptions::default(), &[long]);
+        let full = app.all_fits[0].memory_required_gb;
+        let full_utilization = app.all_fits[0].utilization_pct;
+        // 17 GB of KV cache at 128k only squeezes in at a lower quant
+        assert_eq!(app.all_fits[0].fit_level, FitLevel::Marginal);
+        assert_ne!(app.all_fits[0].best_quant, "Q8_0");
+
+        press(&mut app, "}");
+        assert_eq!(app.context_limit, None);
+        press(&mut app, "{");
+        assert_eq!(app.context_limit, Some(131_072));
+        press(&mut app, "{{{{{{");
+        assert_eq!(app.context_limit, Some(4096));
+        assert!(app.all_fits[0].memory_required_gb < full);
+        assert!(app.all_fits[0].utilization_pct < full_utilization);
+        assert_eq!(app.all_fits[0].best_quant, "Q8_0");
+
+        press(&mut app, "}}}}}}");
+        assert_eq!(app.context_limit, None);
+        assert_eq!(app.all_fits[0].memory_required_gb, full);
+    }
+
+    #[test]
+    fn test_bracket_keys_step_context_target() {
+        let mut app = test_app();
+        press(&mut app, "]");
+        assert_eq!(app.context_target, Some(4096));
+        assert_eq!(
+            visible_names(&app),
+            vec!["alpha-coder-7b", "beta-chat-1b", "delta-3b"]
+        );
+
+        // Every test model tops out at 4k context
+        press(&mut app, "]");
+        assert_eq!(app.context_target, Some(8192));
+        assert!(app.filtered_fits.is_empty());
+
+        press(&mut app, "u");
+        assert_eq!(app.context_target, Some(4096));
+        press(&mut app, "[");
+        assert_eq!(app.context_target, None);
+        assert_eq!(app.filtered_fits.len(), 4);
+    }
+
+    #[test]
+    fn test_detail_view_pages_with_h_and_l() {
+        let mut app = test_app();
+        press_code(&mut app, KeyCode::Enter);
+        assert!(app.show_detail);
+
+        press(&mut app, "ll");
+        assert_eq!(app.selected_row, 2);
+        press_code(&mut app, KeyCode::Left);
+        assert_eq!(app.selected_row, 1);
+        press(&mut app, "hhh");
+        assert_eq!(app.selected_row, 0);
+        press(&mut app, "llllll");
+        assert_eq!(app.selected_row, 3);
+        assert!(app.show_detail);
+    }
+
+    #[test]
+    fn test_known_vram_only_hides_unspecified_models() {
+        let mut app = test_app();
+        for fit in app.all_fits.iter_mut() {
+            if fit.model.name == "beta-chat-1b" {
+                fit.model.min_vram_gb = None;
+            }
+        }
+        press(&mut app, "K");
+        assert!(app.known_vram_only);
+        assert_eq!(
+            visible_names(&app),
+            vec!["alpha-coder-7b", "delta-3b", "gamma-70b"]
+        );
+
+        press(&mut app, "u");
+        assert!(!app.known_vram_only);
+        assert_eq!(app.filtered_fits.len(), 4);
+    }
+
+    #[test]
+    fn test_page_jumps_move_by_visible_rows() {
+        let models: Vec<LlmModel> = (0..60)
+            .map(|i| model(&format!("model-{:02}", i), "Acme", "1B", 1.0))
+            .collect();
+        let mut app = App::with_catalog(test_app().specs, FitOptions::default(), &models);
+        app.on_resize(160, 30);
+        let page = app.table_rows();
+        assert!(page > 1 && page < 60);
+
+        press_code(&mut app, KeyCode::PageDown);
+        assert_eq!(app.selected_row, page);
+        handle_key(
+            &mut app,
+            KeyEvent::new(KeyCode::Char('u'), KeyModifiers::CONTROL),
+        );
+        assert_eq!(app.selected_row, page - page / 2);
+        press(&mut app, "G");
+        assert_eq!(app.selected_row, 59);
+        press_code(&mut app, KeyCode::PageDown);
+        assert_eq!(app.selected_row, 59, "clamped to the last row");
+        assert_eq!(app.scroll_offset, 60 - page);
+        press_code(&mut app, KeyCode::Home);
+        assert_eq!(app.selected_row, 0);
+        press_code(&mut app, KeyCode::PageUp);
+        assert_eq!(app.selected_row, 0);
+    }
+
+    #[test]
+    fn test_hypothetical_machine_marked_in_system_bar() {
+        let specs = test_app().specs.with_ram_override(64.0);
+        let models = [
+            model("gamma-70b", "Zeta", "70B", 40.0),
+            model("beta-chat-1b", "Acme", "1B", 1.0),
+        ];
+        let mut app = App::with_catalog(specs, FitOptions::default(), &models);
+        let gamma = app
+            .all_fits
+            .iter()
+            .find(|f| f.model.name == "gamma-70b")
+            .unwrap();
+        assert_ne!(gamma.fit_level, FitLevel::TooTight);
+
+        let screen = render_to_string(&mut app, 160, 30);
+        assert!(screen.contains("(hypothetical)"));
+    }
+
+    #[test]
+    fn test_memory_reading_refits_when_ram_moves() {
+        let models = [
+            model("beta-chat-1b", "Acme", "1B", 1.0),
+            model("delta-13b", "Acme", "13B", 10.0),
+        ];
+        let mut app = App::with_catalog(test_app().specs, FitOptions::default(), &models);
+        let level = |app: &App| {
+            app.all_fits
+                .iter()
+                .find(|f| f.model.name == "delta-13b")
+                .unwrap()
+                .fit_level
+        };
+        assert_ne!(level(&app), FitLevel::TooTight);
+
+        // A wobble below the threshold leaves the fits alone
+        let available = app.specs.available_ram_gb;
+        app.apply_memory_reading(llmfit_core::MemoryReading {
+            available_ram_gb: available - 0.05,
+            swap_used_gb: 0.0,
+        });
+        assert_eq!(app.specs.available_ram_gb, available);
+
+        app.apply_memory_reading(llmfit_core::MemoryReading {
+            available_ram_gb: 4.0,
+            swap_used_gb: 0.0,
+        });
+        assert_eq!(app.specs.available_ram_gb, 4.0);
+        assert_eq!(level(&app), FitLevel::TooTight);
+    }
+
+    #[test]
+    fn test_free_disk_shown_in_system_bar() {
+        let mut app = test_app();
+        let render = |app: &mut App| render_to_string(app, 160, 30);
+        assert!(!render(&mut app).contains("Disk: "));
+
+        app.specs.free_disk_gb = Some(40.0);
+        let screen = render(&mut app);
+        assert!(screen.contains("Disk: 40"));
+        assert!(screen.contains("B free"));
+    }
+
+    #[test]
+    fn test_forced_run_mode_cycle_and_auto_restores() {
+        let mut specs = test_app().specs;
+        specs.has_gpu = true;
+        specs.gpu_vram_gb = Some(8.0);
+        specs.total_gpu_vram_gb = Some(8.0);
+        specs.gpu_count = 1;
+        specs.backend = GpuBackend::Cuda;
+        let models = [
+            model("alpha-coder-7b", "Acme", "7B", 4.0),
+            model("beta-chat-1b", "Acme", "1B", 1.0),
+        ];
+        let mut app = App::with_catalog(specs, FitOptions::default(), &models);
+        let run_modes = |app: &App| -> Vec<RunMode> {
+            let mut fits: Vec<_> = app.all_fits.iter().collect();
+            fits.sort_by(|a, b| a.model.name.cmp(&b.model.name));
+            fits.iter().map(|f| f.run_mode).collect()
+        };
+        let auto = run_modes(&app);
+        assert_eq!(auto, vec![RunMode::Gpu, RunMode::Gpu]);
+
+        press(&mut app, "M");
+        assert_eq!(app.forced_run_mode_label(), "GPU only");
+        press(&mut app, "M");
+        assert_eq!(run_modes(&app), vec![RunMode::CpuOnly, RunMode::CpuOnly]);
+        press(&mut app, "M");
+        assert_eq!(app.forced_run_mode, Some(RunMode::CpuOffload));
+        assert_eq!(
+            run_modes(&app),
+            vec![RunMode::CpuOffload, RunMode::CpuOffload]
+        );
+
+        let screen = render_to_string(&mut app, 160, 30);
+        assert!(screen.contains("RUN: CPU+GPU offload"));
+
+        press(&mut app, "M");
+        assert_eq!(app.forced_run_mode, None);
+        assert_eq!(run_modes(&app), auto);
+    }
+
+    #[test]
+    fn test_in_ollama_filter_and_glyphs() {
+        let models = [
+            model("Qwen/Qwen2.5-Coder-7B-Instruct", "Alibaba", "7B", 5.0),
+            model("meta-llama/Llama-3.1-8B-Instruct", "Meta", "8B", 6.0),
+            model("beta-chat-1b", "Acme", "1B", 1.0),
+        ];
+        let mut app = App::with_catalog(test_app().specs, FitOptions::default(), &models);
+        app.ollama_installed = ["qwen2.5-coder:7b-instruct-q4_k_m", "llama3.1:8b"]
+            .map(str::to_string)
+            .into();
+        app.ollama_running = ["llama3.1:8b".to_string()].into();
+        for fit in app.all_fits.iter_mut() {
+            fit.installed = fit.model.name != "beta-chat-1b";
+        }
+
+        press(&mut app, "aaa");
+        assert_eq!(app.availability_filter, AvailabilityFilter::InOllama);
+        assert_eq!(
+            visible_names(&app),
+            vec![
+                "Qwen/Qwen2.5-Coder-7B-Instruct",
+                "meta-llama/Llama-3.1-8B-Instruct"
+            ]
+        );
+
+        let screen = render_to_string(&mut app, 160, 30);
+        assert!(screen.contains("O●"));
+        assert!(screen.contains("O✓"));
+    }
+
+    #[test]
+    fn test_favorites_filter_and_persist_in_config() {
+        use crate::config::Config;
+
+        let mut app = test_app();
+        app.pinned.clear();
+        app.apply_filters();
+        let name = app.selected_fit().unwrap().model.name.clone();
+        press(&mut app, "b");
+        assert!(app.pinned.contains(&name));
+
+        press(&mut app, "F");
+        assert_eq!(visible_names(&app), vec![name.clone()]);
+        press(&mut app, "u");
+        assert_eq!(app.filtered_fits.len(), 4);
+
+        let saved = app.capture_config(&Config::default());
+        assert_eq!(saved.favorites, vec![name.clone()]);
+        assert_eq!(Config::from_toml(&saved.to_toml()).unwrap(), saved);
+
+        let mut restored = test_app();
+        restored.apply_config(&saved);
+        assert_eq!(restored.pinned, app.pinned);
+    }
+
+    #[test]
+    fn test_sync_providers_keeps_selection_by_name() {
+        let mut app = test_app();
+        press(&mut app, "P ");
+        press_code(&mut app, KeyCode::Esc);
+        assert_eq!(app.selected_providers, vec![false, true]);
+
+        // "Aardvark" sorts before "Acme", shifting every index by one
+        let extra = model("ant-2b", "Aardvark", "2B", 1.5);
+        app.all_fits.push(ModelFit::analyze(&extra, &app.specs));
+        app.sync_providers();
+        app.apply_filters();
+        assert_eq!(app.providers, vec!["Aardvark", "Acme", "Zeta"]);
+        assert_eq!(app.selected_providers, vec![true, false, true]);
+        assert_eq!(visible_names(&app), vec!["ant-2b", "delta-3b", "gamma-70b"]);
+
+        // Undo still restores by name after the list moved
+        press(&mut app, "u");
+        assert_eq!(app.selected_providers, vec![true, true, true]);
+        assert_eq!(app.filtered_fits.len(), 5);
+    }
+
+    #[test]
+    fn test_changed_only_lists_models_whose_fit_moved() {
+        let mut app = test_app();
+        app.previous_fit.clear();
+        app.previous_fit
+            .insert("gamma-70b".to_string(), FitLevel::Good);
+        let beta_level = app
+            .all_fits
+            .iter()
+            .find(|f| f.model.name == "beta-chat-1b")
+            .map(|f| f.fit_level)
+            .unwrap();
+        app.previous_fit
+            .insert("beta-chat-1b".to_string(), beta_level);
+
+        press(&mut app, "D");
+        assert_eq!(visible_names(&app), vec!["gamma-70b"]);
+
+        press(&mut app, "u");
+        assert!(!app.changed_only);
+        assert_eq!(app.filtered_fits.len(), 4);
+    }
+
+    #[test]
+    fn test_fit_filter_cycle() {
+        let mut app = test_app();
+        assert_eq!(app.fit_filter, FitFilter::All);
+
+        press(&mut app, "f");
+        assert_eq!(app.fit_filter, FitFilter::Runnable);
+        assert!(!visible_names(&app).contains(&"gamma-70b".to_string()));
+
+        press(&mut app, "ffffff");
+        assert_eq!(app.fit_filter, FitFilter::All);
+        assert_eq!(app.filtered_fits.len(), 4);
+    }
+
+    #[test]
+    fn test_ctrl_e_scrolls_view_without_moving_selection() {
+        let mut app = test_app();
+        // Two model rows
+        app.on_resize(80, 12);
+        let ctrl = |c| KeyEvent::new(KeyCode::Char(c), KeyModifiers::CONTROL);
+
+        handle_key(&mut app, ctrl('e'));
+        handle_key(&mut app, ctrl('e'));
+        handle_key(&mut app, ctrl('e'));
+        // Clamped so the last page stays full
+        assert_eq!(app.scroll_offset, 2);
+        assert_eq!(app.selected_row, 0);
+
+        // z recenters and ends the peek
+        press(&mut app, "z");
+        assert_eq!(app.scroll_offset, 0);
+
+        // Moving the selection snaps the view back to it
+        handle_key(&mut app, ctrl('e'));
+        handle_key(&mut app, ctrl('y'));
+        press(&mut app, "jjj");
+        assert_eq!(app.scroll_offset, 2);
+        // zz outside a peek recenters too and leaves the layout alone
+        press(&mut app, "zz");
+        assert_eq!(app.scroll_offset, 2);
+        assert_eq!(app.table_layout, crate::tui_app::TableLayout::Auto);
+    }
+
+    #[test]
+    fn test_visual_download_plan_totals_against_free_disk() {
+        let mut app = test_app();
+        app.disk_sizes.clear();
+        app.disk_free_gb = Some(1.0);
+        press(&mut app, "vjjd");
+        assert!(app.show_download_plan);
+        assert_eq!(app.input_mode, InputMode::Normal);
+
+        let plan = app.download_plan();
+        assert_eq!(plan.entries.len(), 3);
+        let total: f64 = plan.entries.iter().map(|e| e.size_gb).sum();
+        assert!((plan.download_gb - total).abs() < 1e-9);
+        assert_eq!(plan.fits(), Some(false));
+
+        // Models already on disk don't need downloading
+        let first = &plan.entries[0];
+        app.disk_sizes
+            .insert(first.name.clone(), 2 * 1024 * 1024 * 1024);
+        let replanned = app.download_plan();
+        assert!(replanned.entries[0].on_disk);
+        assert_eq!(replanned.entries[0].size_gb, 2.0);
+        assert!((replanned.download_gb - (total - first.size_gb)).abs() < 1e-9);
+
+        press_code(&mut app, KeyCode::Esc);
+        assert!(!app.show_download_plan);
+        assert!(!app.should_quit);
+    }
+
+    #[test]
+    fn test_resize_clamps_scroll_to_new_height() {
+        let mut app = test_app();
+        app.on_resize(80, 50);
+        press(&mut app, "G");
+        assert_eq!(app.selected_row, 3);
+
+        // 12 rows leaves room for two models; the selection stays on screen
+        app.on_resize(80, 12);
+        assert_eq!(app.table_rows(), 2);
+        assert_eq!(app.scroll_offset, 2);
+        assert!(app.force_redraw);
+
+        app.on_resize(80, 40);
+        assert_eq!(app.scroll_offset, 0);
+    }
+
+    #[test]
+    fn test_render_table_and_too_small_guard() {
+        let mut app = test_app();
+
+        let screen = render_to_string(&mut app, 160, 30);
+        assert!(screen.contains("alpha-coder-7b"));
+        assert!(!screen.contains("Terminal too small"));
+
+        for (width, height) in [(40, 10), (60, 16)] {
+            let screen = render_to_string(&mut app, width, height);
+            assert!(screen.contains("Terminal too small"));
+        }
+
+        // The full layout comes back once the terminal is big enough again
+        let screen = render_to_string(&mut app, 60, 17);
+        assert!(!screen.contains("Terminal too small"));
+        assert!(screen.contains("alpha-coder-7b"));
+    }
+
+    #[test]
+    fn test_help_overlay_swallows_keys_and_fits_small_terminals() {
+        let mut app = test_app();
+        press(&mut app, "?");
+        assert_eq!(app.input_mode, InputMode::Help);
+
+        let screen = render_to_string(&mut app, 160, 40);
+        assert!(screen.contains("Keys (any key to close)"));
+        assert!(screen.contains("Navigate models"));
+        assert!(screen.contains("Cycle table layout"));
+
+        // A small terminal pages through the list instead of cutting it off
+        app.on_resize(60, 17);
+        let pages = crate::tui_ui::help_pages(app.screen);
+        assert!(pages > 1);
+        let screen = render_to_string(&mut app, 60, 17);
+        assert!(screen.contains(&format!("Keys 1/{}", pages)));
+        assert!(screen.contains("Navigate models"));
+        assert!(screen.contains("more…"));
+        press(&mut app, &"j".repeat(pages + 1));
+        assert_eq!(app.help_page, pages - 1);
+        let screen = render_to_string(&mut app, 60, 17);
+        assert!(screen.contains("Quit"));
+        assert!(!screen.contains("Navigate models"));
+        press(&mut app, "k");
+        assert_eq!(app.input_mode, InputMode::Help);
+
+        // The dismissing key isn't also handled as a command
+        press(&mut app, "f");
+        assert_eq!(app.input_mode, InputMode::Normal);
+        assert_eq!(app.fit_filter, FitFilter::All);
+    }
+
+    #[test]
+    fn test_mouse_selects_rows_and_toggles_providers() {
+        use std::time::{Duration, Instant};
+
+        let mut app = test_app();
+        app.on_resize(160, 30);
+        // Clicks land where draw puts the rows
+        let screen = render_to_string(&mut app, 160, 30);
+        let rows = crate::tui_ui::mouse_targets(&app).table_rows;
+        // The debug dump has three lines before the first screen row
+        let first_row = screen.lines().nth(rows.y as usize + 3).unwrap();
+        assert!(first_row.contains(&app.all_fits[app.filtered_fits[0]].model.name));
+
+        let now = Instant::now();
+        app.click_at(rows.x + 5, rows.y + 2, now);
+        assert_eq!(app.selected_row, 2);
+        assert!(!app.show_detail);
+        app.click_at(rows.x + 5, rows.y + 2, now + Duration::from_millis(100));
+        assert!(app.show_detail);
+        app.toggle_detail();
+
+        app.scroll_wheel(true);
+        assert_eq!(app.selected_row, 3);
+        // Below the last model: nothing to select
+        app.click_at(rows.x + 5, rows.y + 10, now + Duration::from_secs(1));
+        assert_eq!(app.selected_row, 3);
+
+        let summary = crate::tui_ui::mouse_targets(&app).provider_summary;
+        app.click_at(summary.x + 2, summary.y + 1, now);
+        assert_eq!(app.input_mode, InputMode::ProviderPopup);
+        let entries = crate::tui_ui::mouse_targets(&app).provider_rows;
+        app.click_at(entries.x + 2, entries.y, now);
+        assert!(!app.selected_providers[0]);
+        assert_eq!(visible_names(&app), vec!["delta-3b", "gamma-70b"]);
+        app.click_at(0, 0, now);
+        assert_eq!(app.input_mode, InputMode::Normal);
+    }
+
+    #[test]
+    fn test_quant_cycle_refits_selected_row() {
+        let mut app = test_app();
+        let name = app.selected_fit().unwrap().model.name.clone();
+
+        press(&mut app, "Q");
+        let q4 = app.selected_fit().unwrap().clone();
+        assert_eq!(q4.model.name, name);
+        assert_eq!(q4.best_quant, "Q4_K_M");
+        press(&mut app, "QQQQ");
+        let f16 = app.selected_fit().unwrap().clone();
+        assert_eq!(f16.best_quant, "F16");
+        assert!(f16.memory_required_gb > q4.memory_required_gb);
+        assert!(app.pull_status.as_deref().unwrap().contains(" at F16: "));
+
+        // The pinned quant survives a refit of every row
+        press(&mut app, "B");
+        assert_eq!(app.selected_fit().unwrap().best_quant, "F16");
+        press(&mut app, "B");
+        assert_eq!(app.quant_overrides.len(), 1);
+
+        press(&mut app, "Q");
+        assert!(app.quant_overrides.is_empty());
+        assert_eq!(app.selected_fit().unwrap().model.name, name);
+    }
+
+    #[test]
+    fn test_min_context_filter_cycles_and_undoes() {
+        let mut long = model("long-ctx-70b", "Acme", "70B", 40.0);
+        long.context_length = 131_072;
+        let mut mid = model("mid-ctx-3b", "Acme", "3B", 2.0);
+        mid.context_length = 32_768;
+        let short = model("short-ctx-1b", "Acme", "1B", 1.0);
+        let mut app =
+            App::with_catalog(test_app().specs, FitOptions::default(), &[long, mid, short]);
+
+        press(&mut app, "W");
+        assert_eq!(app.filtered_fits.len(), 2);
+        press(&mut app, "W");
+        assert_eq!(visible_names(&app), vec!["long-ctx-70b", "mid-ctx-3b"]);
+        assert_eq!(app.min_context_label(), "≥32k");
+        // Context length alone counts, not whether the model fits at it
+        press(&mut app, "W");
+        assert_eq!(visible_names(&app), vec!["long-ctx-70b"]);
+        press(&mut app, "W");
+        assert_eq!(app.min_context, None);
+        assert_eq!(app.filtered_fits.len(), 3);
+
+        press(&mut app, "u");
+        assert_eq!(app.min_context, Some(131_072));
+    }
+
+    #[test]
+    fn test_filtered_markdown_follows_filters_and_sort() {
+        let mut app = test_app();
+        press(&mut app, "/");
+        press(&mut app, "-7");
+        press_code(&mut app, KeyCode::Enter);
+
+        let markdown = app.filtered_markdown();
+        let lines: Vec<&str> = markdown.lines().collect();
+        assert_eq!(lines.len(), 2 + app.filtered_fits.len());
+        assert!(lines[0].starts_with("| Model | Provider |"));
+        assert!(!markdown.contains("beta-chat-1b"));
+        for (line, &idx) in lines[2..].iter().zip(&app.filtered_fits) {
+            assert!(line.starts_with(&format!("| {} |", app.all_fits[idx].model.name)));
+        }
+    }
+
+    #[test]
+    fn test_saved_view_round_trips_toggle_filters() {
+        let mut app = test_app();
+        press(&mut app, "WKDF");
+        let view = app.capture_view("mine");
+        assert!(view.min_context.is_some());
+        assert!(view.known_vram_only);
+        assert!(view.changed_only);
+        assert!(view.favorites_only);
+
+        app.min_context = None;
+        app.known_vram_only = false;
+        app.changed_only = false;
+        app.favorites_only = false;
+        app.views.push(view.clone());
+        app.view_cursor = app.views.len() - 1;
+        app.apply_selected_view();
+        assert_eq!(app.min_context, view.min_context);
+        assert!(app.known_vram_only);
+        assert!(app.changed_only);
+        assert!(app.favorites_only);
+    }
+
+    #[test]
+    fn test_space_builds_compare_set_for_side_by_side_view() {
+        let mut app = test_app();
+        let names: Vec<String> = (0..4)
+            .map(|row| app.all_fits[app.filtered_fits[row]].model.name.clone())
+            .collect();
+        press(&mut app, " j j j ");
+        assert_eq!(app.compare_set, names[..3]);
+        assert!(app.pull_status.as_deref().unwrap().contains("full"));
+
+        // The set holds names, so it survives a re-sort
+        press(&mut app, "s");
+        press(&mut app, "c");
+        assert!(app.show_multi_compare);
+        let compared: Vec<&str> = app
+            .compare_models
+            .iter()
+            .map(|&i| app.all_fits[i].model.name.as_str())
+            .collect();
+        assert_eq!(compared, names[..3]);
+
+        press(&mut app, "c");
+        assert!(!app.show_multi_compare);
+        press(&mut app, "x");
+        assert!(app.compare_set.is_empty());
+    }
+
+    #[test]
+    fn test_search_is_fuzzy_on_name_and_ranks_best_match_first() {
+        let models = [
+            model("org/Llama-3.1-70B", "Meta", "70B", 40.0),
+            model("org/Llama-3-8B", "Meta", "8B", 5.0),
+            model("org/Mistral-7B", "Mistral", "7B", 4.0),
+        ];
+        let mut app = App::with_catalog(test_app().specs, FitOptions::default(), &models);
+        press(&mut app, "/");
+        press(&mut app, "l38");
+        press_code(&mut app, KeyCode::Enter);
+
+        let top = &app.all_fits[app.filtered_fits[0]];
+        assert_eq!(top.model.name, "org/Llama-3-8B");
+        assert!(!visible_names(&app).contains(&"org/Mistral-7B".to_string()));
+        let highlights = app.search_highlights("org/Llama-3-8B");
+        assert_eq!(highlights.len(), 3);
+
+        // Other fields still match as plain substrings, ANDed with the name
+        press(&mut app, "/");
+        press_code(&mut app, KeyCode::Backspace);
+        press_code(&mut app, KeyCode::Backspace);
+        press_code(&mut app, KeyCode::Backspace);
+        press(&mut app, "mistral");
+        press_code(&mut app, KeyCode::Enter);
+        assert_eq!(visible_names(&app), vec!["org/Mistral-7B".to_string()]);
+    }
+
+    #[test]
+    fn test_export_writes_filtered_list_as_csv() {
+        let mut quoted = model("gamma-chat-3b", "Gamma", "3B", 2.0);
+        quoted.use_case = "Chat, instruction following".to_string();
+        let mut app = App::with_catalog(test_app().specs, FitOptions::default(), &[quoted]);
+        app.export_path =
+            std::env::temp_dir().join(format!("llmfit-export-test-{}.csv", std::process::id()));
+
+        press(&mut app, "e");
+        let csv = std::fs::read_to_string(&app.export_path).unwrap();
+        let _ = std::fs::remove_file(&app.export_path);
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines.len(), 2);
+        assert!(lines[0].starts_with("Installed,Model,Provider,Params,"));
+        assert!(lines[1].starts_with("no,gamma-chat-3b,Gamma,3B,"));
+        assert!(lines[1].ends_with(",\"Chat, instruction following\""));
+        assert!(
+            app.pull_status
+                .as_deref()
+                .unwrap()
+                .starts_with("Exported 1 models to ")
+        );
+    }
+
+    #[test]
+    fn test_config_round_trips_and_restores_filters() {
+        use crate::config::Config;
+
+        let mut app = test_app();
+        app.apply_config(&Config {
+            providers: Some(vec!["Zeta".to_string()]),
+            fit: "Runnable".to_string(),
+            sort: "Params".to_string(),
+            sort_ascending: true,
+            ..Config::default()
+        });
+        assert_eq!(app.fit_filter, FitFilter::Runnable);
+        assert_eq!(visible_names(&app), vec!["delta-3b".to_string()]);
+
+        let saved = app.capture_config(&Config {
+            headroom_gb: Some(2.0),
+            ..Config::default()
+        });
+        assert_eq!(saved.providers, Some(vec!["Zeta".to_string()]));
+        assert_eq!(saved.sort, "Params");
+        assert_eq!(Config::from_toml(&saved.to_toml()).unwrap(), saved);
+
+        // Missing keys take defaults; a malformed file is an error
+        assert_eq!(Config::from_toml("").unwrap(), Config::default());
+        assert!(Config::from_toml("fit = [").is_err());
+    }
+
+    #[test]
+    fn test_list_layout_renders_one_line_per_model() {
+        let mut app = test_app();
+        press(&mut app, "ooo");
+        assert_eq!(app.table_layout, crate::tui_app::TableLayout::List);
+
+        app.on_resize(100, 20);
+        press(&mut app, "j");
+        let screen = render_to_string(&mut app, 100, 20);
+        assert!(screen.contains("alpha-coder-7b"));
+        let selected = &app.all_fits[app.filtered_fits[1]].model.name;
+        assert!(
+            screen
+                .lines()
+                .any(|line| line.contains('▶') && line.contains(selected.as_str()))
+        );
+
+        press(&mut app, "o");
+        assert_eq!(app.table_layout, crate::tui_app::TableLayout::Auto);
+    }
+}
@@ -1,6 +1,6 @@
 use ratatui::{
     Frame,
-    layout::{Constraint, Direction, Layout, Rect},
+    layout::{Alignment, Constraint, Direction, Layout, Margin, Rect},
     style::{Color, Modifier, Style},
     text::{Line, Span},
     widgets::{
@@ -12,11 +12,136 @@ use ratatui::{
 use crate::theme::ThemeColors;
 use crate::tui_app::{
     App, AvailabilityFilter, DL_DOCKER, DL_LLAMACPP, DL_LMSTUDIO, DL_OLLAMA, DownloadCapability,
-    DownloadProvider, FitFilter, InputMode, PlanField,
+    DownloadProvider, FitFilter, InputMode, MouseTargets, PlanField, TableLayout,
 };
-use llmfit_core::fit::{FitLevel, ModelFit, SortColumn};
-use llmfit_core::hardware::is_running_in_wsl;
+use llmfit_core::activity::ActivityKind;
+use llmfit_core::fit::{
+    FitExplanation, FitLevel, FitTarget, ModelFit, RunMode, SortColumn, memory_deficit_gb,
+    offload_ram_deficit_gb,
+};
+use llmfit_core::hardware::{MemoryUnits, SystemSpecs, fmt_mem, is_running_in_wsl};
 use llmfit_core::providers;
+use std::rc::Rc;
+
+/// Rows the main table (or detail/compare pane) gets at the very least.
+const MIN_TABLE_ROWS: u16 = 10;
+
+/// Smallest terminal the fixed layout renders cleanly in: below this height
+/// the bars and the table's minimum can't all be satisfied.
+const MIN_WIDTH: u16 = 60;
+const MIN_HEIGHT: u16 = 3 + 3 + MIN_TABLE_ROWS + 1;
+
+/// Rows around the main pane: system bar, filter bar and status bar.
+const SCREEN_CHROME_ROWS: u16 = 3 + 3 + 1;
+
+/// Rows of the main pane that aren't model rows: the table's borders and
+/// header, or the list's single top rule.
+fn table_chrome_rows(layout: TableLayout) -> u16 {
+    if layout == TableLayout::List { 1 } else { 3 }
+}
+
+/// Model rows visible in a terminal `height` rows tall.
+pub fn table_rows_for_height(height: u16, layout: TableLayout) -> usize {
+    height
+        .saturating_sub(SCREEN_CHROME_ROWS + table_chrome_rows(layout))
+        .max(1) as usize
+}
+
+/// Whether `area` is too small for the full layout.
+fn too_small(area: Rect) -> bool {
+    area.width < MIN_WIDTH || area.height < MIN_HEIGHT
+}
+
+/// System bar, filter bar, main pane and status bar, top to bottom.
+fn screen_layout(area: Rect) -> Rc<[Rect]> {
+    Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3),           // system info bar
+            Constraint::Length(3),           // search + filters
+            Constraint::Min(MIN_TABLE_ROWS), // main table
+            Constraint::Length(1),           // status bar
+        ])
+        .split(area)
+}
+
+/// Search box and filter summaries, left to right.
+fn filter_bar_layout(area: Rect) -> Rc<[Rect]> {
+    Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Min(30),    // search
+            Constraint::Length(18), // provider summary
+            Constraint::Length(18), // use-case summary
+            Constraint::Length(16), // capability summary
+            Constraint::Length(22), // tag summary
+            Constraint::Length(18), // sort column
+            Constraint::Length(20), // fit filter
+            Constraint::Length(16), // target context / assumed context
+            Constraint::Length(20), // availability filter
+            Constraint::Length(16), // theme
+        ])
+        .split(area)
+}
+
+/// Model rows of the table or list drawn in `area`, below its border and
+/// header (or the list's top rule).
+fn table_rows_area(area: Rect, layout: TableLayout) -> Rect {
+    let rows = area.height.saturating_sub(table_chrome_rows(layout)).max(1);
+    let rect = if layout == TableLayout::List {
+        Rect::new(area.x, area.y + 1, area.width, rows)
+    } else {
+        Rect::new(area.x + 1, area.y + 2, area.width.saturating_sub(2), rows)
+    };
+    rect.intersection(area)
+}
+
+/// The provider popup, centered in `area`.
+fn provider_popup_area(app: &App, area: Rect) -> Rect {
+    let max_name_len = app.providers.iter().map(|p| p.len()).max().unwrap_or(10);
+    let popup_width = (max_name_len as u16 + 10).min(area.width.saturating_sub(4));
+    let popup_height = (app.providers.len() as u16 + 2).min(area.height.saturating_sub(4));
+
+    let x = area.x + (area.width.saturating_sub(popup_width)) / 2;
+    let y = area.y + (area.height.saturating_sub(popup_height)) / 2;
+    Rect::new(x, y, popup_width, popup_height)
+}
+
+/// First entry shown in a popup list `inner_height` rows tall, so that
+/// `cursor` stays visible.
+fn popup_scroll(cursor: usize, inner_height: usize) -> usize {
+    if cursor >= inner_height {
+        cursor - inner_height + 1
+    } else {
+        0
+    }
+}
+
+/// Where `draw` puts the clickable parts of the UI for `app` on its current
+/// screen. Anything not on screen gets an empty `Rect`.
+pub fn mouse_targets(app: &App) -> MouseTargets {
+    let mut targets = MouseTargets::default();
+    if too_small(app.screen) {
+        return targets;
+    }
+    let outer = screen_layout(app.screen);
+    targets.provider_summary = filter_bar_layout(outer[1])[1];
+    if !(app.show_plan
+        || app.show_download_plan
+        || app.show_multi_compare
+        || app.show_compare
+        || app.show_detail)
+    {
+        targets.table_rows = table_rows_area(outer[2], app.table_layout);
+    }
+    if app.input_mode == InputMode::ProviderPopup {
+        let popup = provider_popup_area(app, app.screen);
+        targets.provider_rows = popup.inner(Margin::new(1, 1));
+        targets.provider_offset =
+            popup_scroll(app.provider_cursor, targets.provider_rows.height as usize);
+    }
+    targets
+}
 
 pub fn draw(frame: &mut Frame, app: &mut App) {
     let tc = app.theme.colors();
@@ -27,21 +152,21 @@ pub fn draw(frame: &mut Frame, app: &mut App) {
         frame.render_widget(bg_block, frame.area());
     }
 
-    let outer = Layout::default()
-        .direction(Direction::Vertical)
-        .constraints([
-            Constraint::Length(3), // system info bar
-            Constraint::Length(3), // search + filters
-            Constraint::Min(10),   // main table
-            Constraint::Length(1), // status bar
-        ])
-        .split(frame.area());
+    let area = frame.area();
+    if too_small(area) {
+        draw_too_small(frame, area, &tc);
+        return;
+    }
+
+    let outer = screen_layout(area);
 
     draw_system_bar(frame, app, outer[0], &tc);
     draw_search_and_filters(frame, app, outer[1], &tc);
 
     if app.show_plan {
         draw_plan(frame, app, outer[2], &tc);
+    } else if app.show_download_plan {
+        draw_download_plan(frame, app, outer[2], &tc);
     } else if app.show_multi_compare {
         draw_multi_compare(frame, app, outer[2], &tc);
     } else if app.show_compare {
@@ -61,6 +186,10 @@ pub fn draw(frame: &mut Frame, app: &mut App) {
         draw_use_case_popup(frame, app, &tc);
     } else if app.input_mode == InputMode::CapabilityPopup {
         draw_capability_popup(frame, app, &tc);
+    } else if app.input_mode == InputMode::TagPopup {
+        draw_tag_popup(frame, app, &tc);
+    } else if app.input_mode == InputMode::ViewPopup {
+        draw_view_popup(frame, app, &tc);
     } else if app.input_mode == InputMode::DownloadProviderPopup {
         draw_download_provider_popup(frame, app, &tc);
     } else if app.input_mode == InputMode::QuantPopup {
@@ -69,20 +198,60 @@ pub fn draw(frame: &mut Frame, app: &mut App) {
         draw_run_mode_popup(frame, app, &tc);
     } else if app.input_mode == InputMode::ParamsBucketPopup {
         draw_params_bucket_popup(frame, app, &tc);
+    } else if app.input_mode == InputMode::Help {
+        draw_help(frame, app, &tc);
+    }
+
+    if app.show_activity_log {
+        draw_activity_log(frame, app, &tc);
     }
 }
 
+/// Shown instead of the normal layout until the terminal is resized.
+fn draw_too_small(frame: &mut Frame, area: Rect, tc: &ThemeColors) {
+    let lines = vec![
+        Line::from(Span::styled(
+            format!(
+                "Terminal too small — resize to at least {}x{}",
+                MIN_WIDTH, MIN_HEIGHT
+            ),
+            Style::default().fg(tc.warning).add_modifier(Modifier::BOLD),
+        )),
+        Line::from(Span::styled(
+            format!("current: {}x{}", area.width, area.height),
+            Style::default().fg(tc.muted),
+        )),
+    ];
+    // Center vertically, leaving room for the message to wrap on narrow widths.
+    let text_height = 4.min(area.height);
+    let y = area.y + area.height.saturating_sub(text_height) / 2;
+    let text_area = Rect::new(area.x, y, area.width, text_height);
+    frame.render_widget(
+        Paragraph::new(lines)
+            .alignment(Alignment::Center)
+            .wrap(Wrap { trim: true }),
+        text_area,
+    );
+}
+
+/// Swap in use below this is normal housekeeping, not memory pressure, so
+/// the system bar leaves it out.
+const SWAP_SHOWN_GB: f64 = 1.0;
+
 fn draw_system_bar(frame: &mut Frame, app: &App, area: Rect, tc: &ThemeColors) {
     let gpu_info = if app.specs.gpus.is_empty() {
         format!("GPU: none ({})", app.specs.backend.label())
     } else {
         let primary = &app.specs.gpus[0];
-        let backend = primary.backend.label();
+        let backend = match (&app.specs.cuda_version, primary.backend) {
+            (Some(cuda), llmfit_core::GpuBackend::Cuda) => format!("CUDA {}", cuda),
+            _ => primary.backend.label().to_string(),
+        };
         let primary_str = if primary.unified_memory {
             format!(
-                "{} ({:.1} GB shared, {})",
+                "{} ({} shared, {})",
                 primary.name,
-                primary.vram_gb.unwrap_or(0.0),
+                fmt_mem(primary.vram_gb.unwrap_or(0.0), 1, app.units),
                 backend
             )
         } else {
@@ -91,22 +260,47 @@ fn draw_system_bar(frame: &mut Frame, app: &App, area: Rect, tc: &ThemeColors) {
                     if primary.count > 1 {
                         let total_vram = vram * primary.count as f64;
                         format!(
-                            "{} x{} ({:.1} GB each = {:.0} GB total, {})",
-                            primary.name, primary.count, vram, total_vram, backend
+                            "{} x{} ({} each = {} total, {})",
+                            primary.name,
+                            primary.count,
+                            fmt_mem(vram, 1, app.units),
+                            fmt_mem(total_vram, 0, app.units),
+                            backend
                         )
                     } else {
-                        format!("{} ({:.1} GB, {})", primary.name, vram, backend)
+                        format!(
+                            "{} ({}, {})",
+                            primary.name,
+                            fmt_mem(vram, 1, app.units),
+                            backend
+                        )
                     }
                 }
                 Some(_) => format!("{} (shared, {})", primary.name, backend),
                 None => format!("{} ({})", primary.name, backend),
             }
         };
-        let extra = app.specs.gpus.len() - 1;
-        if extra > 0 {
-            format!("GPU: {} +{} more", primary_str, extra)
-        } else {
+        // Secondary cards aren't pooled with the primary group; list each one
+        let extras: Vec<String> = app.specs.gpus[1..]
+            .iter()
+            .map(|gpu| {
+                let count = if gpu.count > 1 {
+                    format!(" x{}", gpu.count)
+                } else {
+                    String::new()
+                };
+                match gpu.vram_gb {
+                    Some(vram) if vram > 0.0 => {
+                        format!("{}{} ({})", gpu.name, count, fmt_mem(vram, 0, app.units))
+                    }
+                    _ => format!("{}{}", gpu.name, count),
+                }
+            })
+            .collect();
+        if extras.is_empty() {
             format!("GPU: {}", primary_str)
+        } else {
+            format!("GPU: {} + {}", primary_str, extras.join(" + "))
         }
     };
 
@@ -173,7 +367,17 @@ fn draw_system_bar(frame: &mut Frame, app: &App, area: Rect, tc: &ThemeColors) {
         tc.muted
     };
 
-    let mut spans = vec![
+    let mut spans = Vec::new();
+    if let Some(path) = &app.specs.loaded_from {
+        let file = std::path::Path::new(path)
+            .file_name()
+            .map_or(path.clone(), |f| f.to_string_lossy().into_owned());
+        spans.push(Span::styled(
+            format!(" PROFILE: {} ", file),
+            Style::default().fg(tc.status_fg).bg(tc.warning).bold(),
+        ));
+    }
+    spans.extend([
         Span::styled(" CPU: ", Style::default().fg(tc.muted)),
         Span::styled(
             format!(
@@ -182,19 +386,89 @@ fn draw_system_bar(frame: &mut Frame, app: &App, area: Rect, tc: &ThemeColors) {
             ),
             Style::default().fg(tc.fg),
         ),
+        Span::styled(
+            app.specs
+                .cpu_features
+                .badge()
+                .map(|badge| format!(" {}", badge))
+                .unwrap_or_default(),
+            Style::default().fg(tc.accent),
+        ),
         Span::styled("  │  ", Style::default().fg(tc.muted)),
         Span::styled("RAM: ", Style::default().fg(tc.muted)),
         Span::styled(
             format!(
-                "{:.1} GB avail / {:.1} GB total{}",
-                app.specs.available_ram_gb,
-                app.specs.total_ram_gb,
+                "{} avail / {} total{}{}{}",
+                fmt_mem(app.specs.available_ram_gb, 1, app.units),
+                fmt_mem(app.specs.total_ram_gb, 1, app.units),
+                if app.specs.reserved_ram_gb + app.specs.headroom_gb > 0.0 {
+                    let units = app.units;
+                    format!(
+                        " ({} {} reserved)",
+                        fmt_reserve(
+                            units.convert(app.specs.reserved_ram_gb + app.specs.headroom_gb)
+                        ),
+                        units.label()
+                    )
+                } else {
+                    String::new()
+                },
+                if app.specs.process_mem_limit_gb.is_some() {
+                    " (ulimit)"
+                } else {
+                    ""
+                },
                 if is_running_in_wsl() { " (WSL)" } else { "" }
             ),
             Style::default().fg(tc.accent),
         ),
+    ]);
+    if let Some(interval) = app.watch_interval {
+        spans.push(Span::styled(
+            format!(" (live, {}s)", interval.as_secs()),
+            Style::default().fg(tc.muted),
+        ));
+    }
+    if app.specs.hypothetical {
+        spans.push(Span::styled(
+            " (hypothetical)",
+            Style::default().fg(tc.warning).bold(),
+        ));
+    }
+    if app.specs.swap_used_gb >= SWAP_SHOWN_GB {
+        spans.push(Span::styled(
+            format!(
+                " swap {} / {}",
+                fmt_mem(app.specs.swap_used_gb, 1, app.units),
+                fmt_mem(app.specs.swap_total_gb, 1, app.units)
+            ),
+            Style::default().fg(tc.warning),
+        ));
+    }
+    spans.extend([
         Span::styled("  │  ", Style::default().fg(tc.muted)),
         Span::styled(gpu_info, Style::default().fg(tc.accent_secondary)),
+    ]);
+    if let Some(npu) = &app.specs.npu_name {
+        spans.extend([
+            Span::styled("  │  ", Style::default().fg(tc.muted)),
+            Span::styled(
+                format!("NPU: {}", npu),
+                Style::default().fg(tc.accent_secondary),
+            ),
+        ]);
+    }
+    if let Some(free) = app.specs.free_disk_gb {
+        spans.extend([
+            Span::styled("  │  ", Style::default().fg(tc.muted)),
+            Span::styled("Disk: ", Style::default().fg(tc.muted)),
+            Span::styled(
+                format!("{} free", fmt_mem(free, 0, app.units)),
+                Style::default().fg(tc.accent),
+            ),
+        ]);
+    }
+    spans.extend([
         Span::styled("  │  ", Style::default().fg(tc.muted)),
         Span::styled(ollama_info, Style::default().fg(ollama_color)),
         Span::styled("  │  ", Style::default().fg(tc.muted)),
@@ -205,7 +479,7 @@ fn draw_system_bar(frame: &mut Frame, app: &App, area: Rect, tc: &ThemeColors) {
         Span::styled(docker_mr_info, Style::default().fg(docker_mr_color)),
         Span::styled("  │  ", Style::default().fg(tc.muted)),
         Span::styled(lmstudio_info, Style::default().fg(lmstudio_color)),
-    ];
+    ]);
 
     if app.backend_hidden_count > 0 {
         spans.push(Span::styled("  │  ", Style::default().fg(tc.muted)));
@@ -223,47 +497,104 @@ fn draw_system_bar(frame: &mut Frame, app: &App, area: Rect, tc: &ThemeColors) {
         ));
     }
 
+    if let Some(catalog) = &app.remote_catalog {
+        spans.push(Span::styled("  │  ", Style::default().fg(tc.muted)));
+        spans.push(Span::styled(
+            catalog.summary(std::time::SystemTime::now()),
+            Style::default().fg(tc.muted),
+        ));
+    }
+
     let text = Line::from(spans);
 
     let block = Block::default()
         .borders(Borders::ALL)
         .border_style(Style::default().fg(tc.border))
         .title(" llmfit ")
-        .title_style(Style::default().fg(tc.title).add_modifier(Modifier::BOLD));
+        .title_style(Style::default().fg(tc.title).add_modifier(Modifier::BOLD))
+        .title_bottom(fit_histogram_line(app, tc).right_aligned());
 
     let paragraph = Paragraph::new(text).block(block);
     frame.render_widget(paragraph, area);
 }
 
-fn draw_search_and_filters(frame: &mut Frame, app: &App, area: Rect, tc: &ThemeColors) {
-    let chunks = Layout::default()
-        .direction(Direction::Horizontal)
-        .constraints([
-            Constraint::Min(30),    // search
-            Constraint::Length(18), // provider summary
-            Constraint::Length(18), // use-case summary
-            Constraint::Length(16), // capability summary
-            Constraint::Length(18), // sort column
-            Constraint::Length(20), // fit filter
-            Constraint::Length(20), // availability filter
-            Constraint::Length(16), // theme
-        ])
-        .split(area);
+/// "7B → 4.1 GiB (Q4_K_M)": parameters and the weights' size at the chosen
+/// quantization. MoE models list total and active parameters.
+fn weight_size_text(fit: &ModelFit, units: MemoryUnits) -> String {
+    format!(
+        "{} → {} ({})",
+        fit.model.params_label(),
+        fmt_mem(fit.weights_gb, 1, units),
+        fit.best_quant
+    )
+}
+
+/// "4" for whole gigabytes, "2.5" otherwise.
+fn fmt_reserve(gb: f64) -> String {
+    if gb.fract() == 0.0 {
+        format!("{:.0}", gb)
+    } else {
+        format!("{:.1}", gb)
+    }
+}
+
+/// Colored bar per fit level over the whole catalog, e.g. "Perfect ████ 40".
+fn fit_histogram_line(app: &App, tc: &ThemeColors) -> Line<'static> {
+    const MAX_BAR: usize = 10;
+    let counts = app.fit_distribution();
+    let max = counts.iter().map(|(_, n)| *n).max().unwrap_or(0).max(1);
+
+    let mut spans = Vec::new();
+    for (i, (level, count)) in counts.into_iter().enumerate() {
+        spans.push(Span::raw(if i == 0 { " " } else { "  " }));
+        let bar_len = if count == 0 {
+            0
+        } else {
+            (count * MAX_BAR).div_ceil(max)
+        };
+        let label = match level {
+            FitLevel::Perfect => "Perfect",
+            FitLevel::Good => "Good",
+            FitLevel::Marginal => "Marginal",
+            FitLevel::TooTight => "Too Tight",
+        };
+        spans.push(Span::styled(
+            format!("{} {} ", fit_indicator(level, app.ascii), label),
+            Style::default().fg(tc.muted),
+        ));
+        spans.push(Span::styled(
+            "█".repeat(bar_len),
+            Style::default().fg(fit_color(level, tc)),
+        ));
+        spans.push(Span::styled(
+            format!(" {} ", count),
+            Style::default().fg(tc.fg),
+        ));
+    }
+    Line::from(spans)
+}
+
+fn draw_search_and_filters(frame: &mut Frame, app: &mut App, area: Rect, tc: &ThemeColors) {
+    let chunks = filter_bar_layout(area);
 
     // Search box
     let search_style = match app.input_mode {
         InputMode::Search => Style::default().fg(tc.accent_secondary),
         InputMode::Normal
+        | InputMode::Jump
         | InputMode::Plan
         | InputMode::ProviderPopup
         | InputMode::UseCasePopup
         | InputMode::CapabilityPopup
+        | InputMode::TagPopup
+        | InputMode::ViewPopup
         | InputMode::DownloadProviderPopup
         | InputMode::Visual
         | InputMode::Select
         | InputMode::QuantPopup
         | InputMode::RunModePopup
-        | InputMode::ParamsBucketPopup => Style::default().fg(tc.muted),
+        | InputMode::ParamsBucketPopup
+        | InputMode::Help => Style::default().fg(tc.muted),
     };
 
     let search_text = if app.search_query.is_empty() && app.input_mode == InputMode::Normal {
@@ -378,6 +709,26 @@ fn draw_search_and_filters(frame: &mut Frame, app: &App, area: Rect, tc: &ThemeC
     .block(cap_block);
     frame.render_widget(caps, chunks[3]);
 
+    // Tag filter summary
+    let active_tags = app.active_tags();
+    let (tag_text, tag_color) = if active_tags.is_empty() {
+        ("Any".to_string(), tc.good)
+    } else {
+        let joiner = if app.tag_match_all { "+" } else { "|" };
+        (active_tags.join(joiner), tc.warning)
+    };
+    let tag_block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(tc.border))
+        .title(" Tags (T) ")
+        .title_style(Style::default().fg(tc.muted));
+    let tags = Paragraph::new(Line::from(Span::styled(
+        format!(" {}", tag_text),
+        Style::default().fg(tag_color),
+    )))
+    .block(tag_block);
+    frame.render_widget(tags, chunks[4]);
+
     // Sort column
     let sort_block = Block::default()
         .borders(Borders::ALL)
@@ -390,12 +741,13 @@ fn draw_search_and_filters(frame: &mut Frame, app: &App, area: Rect, tc: &ThemeC
         Style::default().fg(tc.accent),
     )))
     .block(sort_block);
-    frame.render_widget(sort_text, chunks[4]);
+    frame.render_widget(sort_text, chunks[5]);
 
     // Fit filter
     let fit_style = match app.fit_filter {
         FitFilter::All => Style::default().fg(tc.fg),
         FitFilter::Runnable => Style::default().fg(tc.good),
+        FitFilter::GpuOnly => Style::default().fg(tc.mode_gpu),
         FitFilter::Perfect => Style::default().fg(tc.good),
         FitFilter::Good => Style::default().fg(tc.warning),
         FitFilter::Marginal => Style::default().fg(tc.fit_marginal),
@@ -405,18 +757,56 @@ fn draw_search_and_filters(frame: &mut Frame, app: &App, area: Rect, tc: &ThemeC
     let fit_block = Block::default()
         .borders(Borders::ALL)
         .border_style(Style::default().fg(tc.border))
-        .title(" Fit [f] ")
+        .title(" Fit [f/K/W] ")
         .title_style(Style::default().fg(tc.muted));
 
-    let fit_text = Paragraph::new(Line::from(Span::styled(app.fit_filter.label(), fit_style)))
-        .block(fit_block);
-    frame.render_widget(fit_text, chunks[5]);
+    let mut fit_spans = vec![Span::styled(app.fit_filter.label(), fit_style)];
+    if app.known_vram_only {
+        fit_spans.push(Span::styled(" +VRAM", Style::default().fg(tc.warning)));
+    }
+    if app.favorites_only {
+        fit_spans.push(Span::styled(" ★", Style::default().fg(tc.accent)));
+    }
+    if app.min_context.is_some() {
+        fit_spans.push(Span::styled(
+            format!(" {}", app.min_context_label()),
+            Style::default().fg(tc.warning),
+        ));
+    }
+    let fit_text = Paragraph::new(Line::from(fit_spans)).block(fit_block);
+    frame.render_widget(fit_text, chunks[6]);
+
+    // Target context filter
+    let ctx_color = if app.context_target.is_some() {
+        tc.warning
+    } else {
+        tc.fg
+    };
+    let ctx_block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(tc.border))
+        .title(" Ctx [] {} ")
+        .title_style(Style::default().fg(tc.muted));
+    let mut ctx_spans = vec![Span::styled(
+        format!(" {}", app.context_target_label()),
+        Style::default().fg(ctx_color),
+    )];
+    if let Some(limit) = app.context_limit {
+        ctx_spans.push(Span::styled(
+            format!(" ≤{}k", limit / 1024),
+            Style::default().fg(tc.accent),
+        ));
+    }
+    let ctx_text = Paragraph::new(Line::from(ctx_spans)).block(ctx_block);
+    frame.render_widget(ctx_text, chunks[7]);
 
     // Availability filter
     let avail_style = match app.availability_filter {
         AvailabilityFilter::All => Style::default().fg(tc.fg),
         AvailabilityFilter::HasGguf => Style::default().fg(tc.info),
         AvailabilityFilter::Installed => Style::default().fg(tc.good),
+        AvailabilityFilter::InOllama => Style::default().fg(tc.good),
+        AvailabilityFilter::OnDisk => Style::default().fg(tc.accent),
     };
 
     let avail_block = Block::default()
@@ -430,7 +820,7 @@ fn draw_search_and_filters(frame: &mut Frame, app: &App, area: Rect, tc: &ThemeC
         avail_style,
     )))
     .block(avail_block);
-    frame.render_widget(avail_text, chunks[6]);
+    frame.render_widget(avail_text, chunks[8]);
 
     // Theme indicator
     let theme_block = Block::default()
@@ -444,7 +834,7 @@ fn draw_search_and_filters(frame: &mut Frame, app: &App, area: Rect, tc: &ThemeC
         Style::default().fg(tc.info),
     )))
     .block(theme_block);
-    frame.render_widget(theme_text, chunks[7]);
+    frame.render_widget(theme_text, chunks[9]);
 }
 
 fn fit_color(level: FitLevel, tc: &ThemeColors) -> Color {
@@ -456,12 +846,189 @@ fn fit_color(level: FitLevel, tc: &ThemeColors) -> Color {
     }
 }
 
-fn fit_indicator(level: FitLevel) -> &'static str {
+/// Rough quality tier of a quantization: green for 6+ bits per weight,
+/// yellow for 4-5 bits, red for 3 bits and below.
+fn quant_color(quant: &str, tc: &ThemeColors) -> Color {
+    let bytes = crate::quants::table().bpp(quant);
+    if bytes >= 0.75 {
+        tc.good
+    } else if bytes >= 0.5 {
+        tc.warning
+    } else {
+        tc.error
+    }
+}
+
+/// Ordering used to tell an improved fit from a worse one.
+fn fit_rank(level: FitLevel) -> u8 {
     match level {
-        FitLevel::Perfect => "●",
-        FitLevel::Good => "●",
-        FitLevel::Marginal => "●",
-        FitLevel::TooTight => "●",
+        FitLevel::Perfect => 3,
+        FitLevel::Good => 2,
+        FitLevel::Marginal => 1,
+        FitLevel::TooTight => 0,
+    }
+}
+
+fn change_arrow(previous: FitLevel, current: FitLevel) -> &'static str {
+    if fit_rank(current) > fit_rank(previous) {
+        "↑"
+    } else {
+        "↓"
+    }
+}
+
+/// "↑ now Good (was Marginal)" for a model whose fit changed since the last run.
+fn fit_change_line(app: &App, fit: &ModelFit, tc: &ThemeColors) -> Option<Line<'static>> {
+    let previous = app.fit_change(fit)?;
+    let color = if fit_rank(fit.fit_level) > fit_rank(previous) {
+        tc.good
+    } else {
+        tc.error
+    };
+    let was = match previous {
+        FitLevel::Perfect => "Perfect",
+        FitLevel::Good => "Good",
+        FitLevel::Marginal => "Marginal",
+        FitLevel::TooTight => "Too Tight",
+    };
+    Some(Line::from(vec![
+        Span::styled("  Last run:    ", Style::default().fg(tc.muted)),
+        Span::styled(
+            format!(
+                "{} now {} (was {})",
+                change_arrow(previous, fit.fit_level),
+                fit.fit_text(),
+                was
+            ),
+            Style::default().fg(color),
+        ),
+    ]))
+}
+
+/// The memory upgrade that would lift a Marginal or Too Tight fit to Good,
+/// plus the RAM route when a model too big for the GPU could spill instead.
+fn upgrade_lines(
+    fit: &ModelFit,
+    specs: &SystemSpecs,
+    units: MemoryUnits,
+    tc: &ThemeColors,
+) -> Vec<Line<'static>> {
+    let mut lines = Vec::new();
+    if let Some(gb) = memory_deficit_gb(fit) {
+        let pool = fit.explain(specs, units).pool;
+        lines.push(Line::from(vec![
+            Span::styled("  Upgrade:     ", Style::default().fg(tc.muted)),
+            Span::styled(
+                format!(
+                    "Needs +{} {} for {} mode",
+                    fmt_mem(gb, 1, units),
+                    pool.label(),
+                    fit.run_mode_text()
+                ),
+                Style::default().fg(tc.warning),
+            ),
+            Span::styled(
+                format!("  (at {})", fit.best_quant),
+                Style::default().fg(tc.muted),
+            ),
+        ]));
+    }
+    if let Some(gb) = offload_ram_deficit_gb(fit, specs) {
+        lines.push(Line::from(vec![
+            Span::styled("               ", Style::default().fg(tc.muted)),
+            Span::styled(
+                format!("or +{} RAM to run it CPU+GPU", fmt_mem(gb, 1, units)),
+                Style::default().fg(tc.warning),
+            ),
+        ]));
+    }
+    lines
+}
+
+/// "Bound by", memory terms and the run-mode decision path for the detail view.
+fn explanation_lines(
+    why: &FitExplanation,
+    units: MemoryUnits,
+    tc: &ThemeColors,
+) -> Vec<Line<'static>> {
+    let mut lines = vec![Line::from(vec![
+        Span::styled("  Bound by:    ", Style::default().fg(tc.muted)),
+        Span::styled(
+            why.binding.label().to_string(),
+            Style::default().fg(tc.fg).bold(),
+        ),
+        Span::styled(
+            format!(
+                "  ({} of {})",
+                fmt_mem(why.memory_required_gb, 1, units),
+                fmt_mem(why.memory_available_gb, 1, units)
+            ),
+            Style::default().fg(tc.muted),
+        ),
+    ])];
+    lines.push(Line::from(vec![
+        Span::styled("  Margin:      ", Style::default().fg(tc.muted)),
+        Span::styled(
+            why.margin_text(units),
+            Style::default().fg(if why.margin_gb >= 0.0 {
+                tc.good
+            } else {
+                tc.error
+            }),
+        ),
+    ]));
+    if let Some(b) = why.breakdown {
+        lines.push(Line::from(vec![
+            Span::styled("  Memory:      ", Style::default().fg(tc.muted)),
+            Span::styled(
+                format!(
+                    "weights {:.1} + KV {:.1} + overhead {:.1} {}",
+                    units.convert(b.weights_gb),
+                    units.convert(b.kv_cache_gb),
+                    units.convert(b.overhead_gb),
+                    units.label()
+                ),
+                Style::default().fg(tc.fg),
+            ),
+            Span::styled(
+                if b.requests > 1 {
+                    format!(
+                        "  ({}, {} ctx, KV x{} requests)",
+                        why.quant, b.context_tokens, b.requests
+                    )
+                } else {
+                    format!("  ({}, {} ctx)", why.quant, b.context_tokens)
+                },
+                Style::default().fg(tc.muted),
+            ),
+        ]));
+    }
+    for (i, step) in why.run_mode_path.iter().enumerate() {
+        let label = if i == 0 {
+            "  Decision:    "
+        } else {
+            "               "
+        };
+        lines.push(Line::from(vec![
+            Span::styled(label, Style::default().fg(tc.muted)),
+            Span::styled(step.clone(), Style::default().fg(tc.fg)),
+        ]));
+    }
+    lines
+}
+
+/// Fit glyph; the shape alone tells the levels apart, for colorblind users
+/// and monochrome terminals. `ascii` picks the `--ascii` set.
+fn fit_indicator(level: FitLevel, ascii: bool) -> &'static str {
+    match (level, ascii) {
+        (FitLevel::Perfect, false) => "✔",
+        (FitLevel::Good, false) => "●",
+        (FitLevel::Marginal, false) => "◐",
+        (FitLevel::TooTight, false) => "✘",
+        (FitLevel::Perfect, true) => "+",
+        (FitLevel::Good, true) => "o",
+        (FitLevel::Marginal, true) => "~",
+        (FitLevel::TooTight, true) => "x",
     }
 }
 
@@ -487,23 +1054,70 @@ fn pull_indicator(percent: Option<f64>, tick: u64) -> String {
     }
 }
 
+/// Fit indicator for a row, followed by the fit-change arrow, the compare
+/// mark and the model's position in the compare set when they apply.
+/// Inst-column glyph for a model Ollama has: `O●` while it's loaded,
+/// `O✓` once pulled. Other runtimes share the plain ` ✓`.
+fn ollama_glyph(app: &App, name: &str) -> Option<&'static str> {
+    if providers::is_model_installed(name, &app.ollama_running) {
+        Some("O●")
+    } else if providers::is_model_installed(name, &app.ollama_installed) {
+        Some("O✓")
+    } else {
+        None
+    }
+}
+
+fn row_marker(app: &App, fit: &ModelFit) -> String {
+    let mut marker = fit_indicator(fit.fit_level, app.ascii).to_string();
+    if let Some(previous) = app.fit_change(fit) {
+        marker.push_str(change_arrow(previous, fit.fit_level));
+    }
+    if app.compare_mark_model.as_deref() == Some(fit.model.name.as_str()) {
+        marker.push('*');
+    }
+    if let Some(pos) = app.compare_set.iter().position(|n| *n == fit.model.name) {
+        marker.push_str(&(pos + 1).to_string());
+    }
+    marker
+}
+
+/// Columns (indices into the full header list) kept in the compact table:
+/// indicator, Inst, Model, Score, tok/s, Mode, Mem and Fit.
+const COMPACT_COLUMNS: [usize; 8] = [0, 1, 2, 6, 7, 9, 10, 13];
+
 fn draw_table(frame: &mut Frame, app: &mut App, area: Rect, tc: &ThemeColors) {
+    if app.table_layout == TableLayout::List {
+        draw_list(frame, app, area, tc);
+        return;
+    }
     let sort_col = app.sort_column;
     let header_names = [
-        "", "Inst", "Model", "Provider", "Params", "Score", "tok/s*", "Quant", "Mode", "Mem %",
-        "Ctx", "Date", "Fit", "Use Case",
+        "", "Inst", "Model", "Provider", "Params", "Size", "Score", "tok/s*", "Quant", "Mode",
+        "Mem %", "Ctx", "Date", "Fit", "Use Case",
     ];
     let sort_col_idx: Option<usize> = match sort_col {
-        SortColumn::Score => Some(5),
-        SortColumn::Tps => Some(6),
+        SortColumn::Score => Some(6),
+        SortColumn::Tps => Some(7),
         SortColumn::Params => Some(4),
-        SortColumn::MemPct => Some(9),
-        SortColumn::Ctx => Some(10),
-        SortColumn::ReleaseDate => Some(11),
-        SortColumn::UseCase => Some(13),
+        SortColumn::MemPct => Some(10),
+        SortColumn::Ctx => Some(11),
+        SortColumn::ReleaseDate => Some(12),
+        SortColumn::UseCase => Some(14),
+    };
+    let compact = app.table_layout.is_compact(area.width);
+    let columns: Vec<usize> = if compact {
+        COMPACT_COLUMNS.to_vec()
+    } else {
+        (0..header_names.len()).collect()
     };
     let in_select_mode = app.input_mode == InputMode::Select;
-    let header_cells = header_names.iter().enumerate().map(|(i, h)| {
+    let header_cells = columns.iter().map(|&i| {
+        let h = if compact && i == 10 {
+            &"Mem"
+        } else {
+            &header_names[i]
+        };
         if in_select_mode && app.select_column == i {
             Cell::from(format!("▸{}◂", h)).style(
                 Style::default()
@@ -524,13 +1138,12 @@ fn draw_table(frame: &mut Frame, app: &mut App, area: Rect, tc: &ThemeColors) {
     });
     let header = Row::new(header_cells).height(1);
 
-    let visible_rows = (area.height as usize).saturating_sub(3).max(1);
+    let visible_rows = area
+        .height
+        .saturating_sub(table_chrome_rows(app.table_layout))
+        .max(1) as usize;
     let total_rows = app.filtered_fits.len();
-    let viewport_start = if total_rows <= visible_rows || app.selected_row < visible_rows {
-        0
-    } else {
-        app.selected_row + 1 - visible_rows
-    };
+    let viewport_start = app.scroll_offset;
     let viewport_end = (viewport_start + visible_rows).min(total_rows);
 
     let visual_range = app.visual_range();
@@ -573,7 +1186,9 @@ fn draw_table(frame: &mut Frame, app: &mut App, area: Rect, tc: &ThemeColors) {
             let capability = app.download_capability_for(&fit.model.name);
 
             let installed_icon = if fit.installed {
-                " ✓".to_string()
+                ollama_glyph(app, &fit.model.name)
+                    .unwrap_or(" ✓")
+                    .to_string()
             } else if is_pulling {
                 pull_indicator(app.pull_percent, app.tick_count)
             } else {
@@ -618,31 +1233,45 @@ fn draw_table(frame: &mut Frame, app: &mut App, area: Rect, tc: &ThemeColors) {
                 .map(|r| r.contains(&row_idx))
                 .unwrap_or(false);
             let row_style = if is_pulling {
-                Style::default().bg(Color::Rgb(50, 50, 0))
+                Style::default().bg(tc.pulling_bg)
             } else if in_visual_range {
-                Style::default().bg(Color::Rgb(40, 40, 80))
+                Style::default().bg(tc.range_bg)
             } else {
                 Style::default()
             };
 
-            let marker = if app.compare_mark_model.as_deref() == Some(fit.model.name.as_str()) {
-                format!("{}*", fit_indicator(fit.fit_level))
+            let marker = row_marker(app, fit);
+
+            let name_cell = if app.pinned.contains(&fit.model.name) {
+                Cell::from(format!("★ {}", fit.model.name))
+                    .style(Style::default().fg(tc.accent).add_modifier(Modifier::BOLD))
             } else {
-                fit_indicator(fit.fit_level).to_string()
+                let highlights = app.search_highlights(&fit.model.name);
+                Cell::from(highlighted_name(&fit.model.name, &highlights, tc))
             };
 
-            Row::new(vec![
+            let mem_cell = if compact {
+                Cell::from(format!(
+                    "{:.1}G {:.0}%",
+                    fit.memory_required_gb, fit.utilization_pct
+                ))
+            } else {
+                Cell::from(format!("{:.0}%", fit.utilization_pct))
+            };
+
+            let mut cells = vec![
                 Cell::from(marker).style(Style::default().fg(color)),
                 Cell::from(installed_icon).style(Style::default().fg(installed_color)),
-                Cell::from(fit.model.name.clone()).style(Style::default().fg(tc.fg)),
+                name_cell,
                 Cell::from(fit.model.provider.clone()).style(Style::default().fg(tc.muted)),
                 Cell::from(fit.model.parameter_count.clone()).style(Style::default().fg(tc.fg)),
+                Cell::from(format!("{:.1}G", fit.weights_gb)).style(Style::default().fg(tc.muted)),
                 Cell::from(format!("{:.0}", fit.score)).style(Style::default().fg(score_color)),
                 Cell::from(tps_text).style(Style::default().fg(tc.fg)),
-                Cell::from(fit.best_quant.clone()).style(Style::default().fg(tc.muted)),
+                Cell::from(fit.best_quant.clone())
+                    .style(Style::default().fg(quant_color(&fit.best_quant, tc))),
                 Cell::from(fit.run_mode_text().to_string()).style(Style::default().fg(mode_color)),
-                Cell::from(format!("{:.0}%", fit.utilization_pct))
-                    .style(Style::default().fg(color)),
+                mem_cell.style(Style::default().fg(color)),
                 Cell::from(format!("{}k", fit.model.context_length / 1000))
                     .style(Style::default().fg(tc.muted)),
                 Cell::from(
@@ -656,17 +1285,31 @@ fn draw_table(frame: &mut Frame, app: &mut App, area: Rect, tc: &ThemeColors) {
                 .style(Style::default().fg(tc.muted)),
                 Cell::from(fit.fit_text().to_string()).style(Style::default().fg(color)),
                 Cell::from(fit.use_case.label().to_string()).style(Style::default().fg(tc.muted)),
-            ])
-            .style(row_style)
+            ];
+            if compact {
+                cells = columns.iter().map(|&i| cells[i].clone()).collect();
+            }
+            Row::new(cells).style(row_style)
         })
         .collect();
 
-    let widths = [
-        Constraint::Length(2),  // indicator
+    let compact_widths = [
+        Constraint::Length(3),  // indicator (+ change arrow)
+        Constraint::Length(5),  // installed / pull %
+        Constraint::Min(16),    // model name
+        Constraint::Length(6),  // score
+        Constraint::Length(6),  // tok/s
+        Constraint::Length(7),  // mode
+        Constraint::Length(11), // mem (GB + %)
+        Constraint::Length(10), // fit
+    ];
+    let full_widths = [
+        Constraint::Length(3),  // indicator (+ change arrow)
         Constraint::Length(5),  // installed / pull %
         Constraint::Min(20),    // model name
         Constraint::Length(12), // provider
         Constraint::Length(8),  // params
+        Constraint::Length(7),  // size (weights at the chosen quant)
         Constraint::Length(6),  // score
         Constraint::Length(6),  // tok/s
         Constraint::Length(10), // quant (AWQ-4bit, GPTQ-Int4, GPTQ-Int8)
@@ -677,6 +1320,11 @@ fn draw_table(frame: &mut Frame, app: &mut App, area: Rect, tc: &ThemeColors) {
         Constraint::Length(10), // fit
         Constraint::Min(10),    // use case
     ];
+    let widths: &[Constraint] = if compact {
+        &compact_widths
+    } else {
+        &full_widths
+    };
 
     let count_text = format!(
         " Models ({}/{}) ",
@@ -700,17 +1348,19 @@ fn draw_table(frame: &mut Frame, app: &mut App, area: Rect, tc: &ThemeColors) {
         )
         .highlight_symbol("▶ ");
 
+    // While peeking the selection may be off screen; highlight nothing then
     let mut state = TableState::default();
-    if !app.filtered_fits.is_empty() {
-        state.select(Some(app.selected_row.saturating_sub(viewport_start)));
+    if (viewport_start..viewport_end).contains(&app.selected_row) {
+        state.select(Some(app.selected_row - viewport_start));
     }
 
     frame.render_stateful_widget(table, area, &mut state);
 
-    // Scrollbar
-    if app.filtered_fits.len() > (area.height as usize).saturating_sub(3) {
-        let mut scrollbar_state =
-            ScrollbarState::new(app.filtered_fits.len()).position(app.selected_row);
+    // Scrollbar, following the view so page jumps move the thumb by a page
+    if total_rows > visible_rows {
+        let mut scrollbar_state = ScrollbarState::new(total_rows - visible_rows + 1)
+            .position(viewport_start)
+            .viewport_content_length(visible_rows);
         frame.render_stateful_widget(
             Scrollbar::new(ScrollbarOrientation::VerticalRight)
                 .begin_symbol(Some("↑"))
@@ -721,6 +1371,84 @@ fn draw_table(frame: &mut Frame, app: &mut App, area: Rect, tc: &ThemeColors) {
     }
 }
 
+/// `TableLayout::List`: one unaligned line per model, e.g.
+/// `✔ llama3:8b  Perfect  6.8/24.0G  GPU`, under a single top rule and no
+/// header, so more models fit on screen.
+fn draw_list(frame: &mut Frame, app: &mut App, area: Rect, tc: &ThemeColors) {
+    let visible_rows = area
+        .height
+        .saturating_sub(table_chrome_rows(app.table_layout))
+        .max(1) as usize;
+    let total_rows = app.filtered_fits.len();
+    let viewport_start = app.scroll_offset;
+    let viewport_end = (viewport_start + visible_rows).min(total_rows);
+    let visual_range = app.visual_range();
+
+    let lines: Vec<Line> = (viewport_start..viewport_end)
+        .map(|row_idx| {
+            let fit = &app.all_fits[app.filtered_fits[row_idx]];
+            let color = fit_color(fit.fit_level, tc);
+            let selected = row_idx == app.selected_row;
+            let mut spans = vec![
+                Span::raw(if selected { "▶ " } else { "  " }),
+                Span::styled(row_marker(app, fit), Style::default().fg(color)),
+                Span::raw(" "),
+            ];
+            if app.pinned.contains(&fit.model.name) {
+                spans.push(Span::styled(
+                    format!("★ {}", fit.model.name),
+                    Style::default().fg(tc.accent).add_modifier(Modifier::BOLD),
+                ));
+            } else {
+                spans.push(Span::styled(
+                    fit.model.name.clone(),
+                    Style::default().fg(tc.fg),
+                ));
+            }
+            spans.push(Span::styled(
+                format!("  {}", fit.fit_text()),
+                Style::default().fg(color),
+            ));
+            spans.push(Span::styled(
+                format!(
+                    "  {:.1}/{:.1}G",
+                    fit.memory_required_gb, fit.memory_available_gb
+                ),
+                Style::default().fg(tc.muted),
+            ));
+            spans.push(Span::styled(
+                format!("  {}", fit.run_mode_text()),
+                Style::default().fg(tc.muted),
+            ));
+            if fit.installed {
+                spans.push(Span::styled("  ✓", Style::default().fg(tc.good)));
+            }
+
+            let style = if selected {
+                Style::default()
+                    .bg(tc.highlight_bg)
+                    .add_modifier(Modifier::BOLD)
+            } else if visual_range.as_ref().is_some_and(|r| r.contains(&row_idx)) {
+                Style::default().bg(tc.range_bg)
+            } else {
+                Style::default()
+            };
+            Line::from(spans).style(style)
+        })
+        .collect();
+
+    let block = Block::default()
+        .borders(Borders::TOP)
+        .border_style(Style::default().fg(tc.border))
+        .title(format!(
+            " Models ({}/{}) ",
+            app.filtered_fits.len(),
+            app.all_fits.len()
+        ))
+        .title_style(Style::default().fg(tc.fg));
+    frame.render_widget(Paragraph::new(lines).block(block), area);
+}
+
 fn draw_compare(frame: &mut Frame, app: &App, area: Rect, tc: &ThemeColors) {
     let Some((left, right)) = app.selected_compare_pair() else {
         let block = Block::default()
@@ -888,6 +1616,7 @@ fn draw_compare(frame: &mut Frame, app: &App, area: Rect, tc: &ThemeColors) {
         " Marked (baseline) ",
         left,
         &left_metrics,
+        app.ascii,
     );
     render_compare_panel(
         frame,
@@ -896,6 +1625,7 @@ fn draw_compare(frame: &mut Frame, app: &App, area: Rect, tc: &ThemeColors) {
         " Selected (delta vs baseline) ",
         right,
         &right_metrics,
+        app.ascii,
     );
 }
 
@@ -937,6 +1667,7 @@ fn render_compare_panel(
     title: &str,
     fit: &ModelFit,
     metrics: &CompareMetrics,
+    ascii: bool,
 ) {
     let lines = vec![
         Line::from(""),
@@ -975,7 +1706,7 @@ fn render_compare_panel(
         Line::from(vec![
             Span::styled("  Fit:   ", Style::default().fg(tc.muted)),
             Span::styled(
-                format!("{} {}", fit_indicator(fit.fit_level), fit.fit_text()),
+                format!("{} {}", fit_indicator(fit.fit_level, ascii), fit.fit_text()),
                 Style::default().fg(fit_color(fit.fit_level, tc)),
             ),
         ]),
@@ -1031,6 +1762,79 @@ fn render_compare_panel(
     );
 }
 
+fn draw_download_plan(frame: &mut Frame, app: &App, area: Rect, tc: &ThemeColors) {
+    let plan = app.download_plan();
+    let rows: Vec<Row> = plan
+        .entries
+        .iter()
+        .map(|e| {
+            let (status, color) = if e.on_disk {
+                ("on disk", tc.good)
+            } else {
+                ("download", tc.fg)
+            };
+            Row::new(vec![
+                Cell::from(e.name.clone()),
+                Cell::from(e.quant.clone()).style(Style::default().fg(quant_color(&e.quant, tc))),
+                Cell::from(fmt_mem(e.size_gb, 1, app.units)),
+                Cell::from(status).style(Style::default().fg(color)),
+            ])
+        })
+        .collect();
+    let header = Row::new(["Model", "Quant", "Size", "Status"])
+        .style(Style::default().fg(tc.accent).add_modifier(Modifier::BOLD));
+
+    let (summary, summary_color) = match (plan.free_gb, plan.fits()) {
+        (Some(free), Some(true)) => (
+            format!(
+                " {} to download, {} free — fits ",
+                fmt_mem(plan.download_gb, 1, app.units),
+                fmt_mem(free, 1, app.units)
+            ),
+            tc.good,
+        ),
+        (Some(free), _) => (
+            format!(
+                " {} to download, only {} free — {} short ",
+                fmt_mem(plan.download_gb, 1, app.units),
+                fmt_mem(free, 1, app.units),
+                fmt_mem(plan.download_gb - free, 1, app.units)
+            ),
+            tc.error,
+        ),
+        (None, _) => (
+            format!(
+                " {} to download, free space unknown ",
+                fmt_mem(plan.download_gb, 1, app.units)
+            ),
+            tc.warning,
+        ),
+    };
+
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Min(30),
+            Constraint::Length(10),
+            Constraint::Length(10),
+            Constraint::Length(10),
+        ],
+    )
+    .header(header)
+    .block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(tc.border))
+            .title(format!(" Download plan ({} models) ", plan.entries.len()))
+            .title_style(Style::default().fg(tc.title).add_modifier(Modifier::BOLD))
+            .title_bottom(Line::from(Span::styled(
+                summary,
+                Style::default().fg(summary_color).bold(),
+            ))),
+    );
+    frame.render_widget(table, area);
+}
+
 fn draw_multi_compare(frame: &mut Frame, app: &App, area: Rect, tc: &ThemeColors) {
     if app.compare_models.is_empty() {
         let block = Block::default()
@@ -1098,6 +1902,24 @@ fn draw_multi_compare(frame: &mut Frame, app: &App, area: Rect, tc: &ThemeColors
         .map(|m| m.model.context_length)
         .max()
         .unwrap_or(0);
+    let best_fit = models.iter().map(|m| fit_rank(m.fit_level)).max();
+    let best_params = models
+        .iter()
+        .map(|m| m.model.params_b())
+        .fold(f64::MIN, f64::max);
+    let best_quant = models
+        .iter()
+        .map(|m| crate::quants::table().bpp(&m.best_quant))
+        .fold(f64::MIN, f64::max);
+    let best_vram = models
+        .iter()
+        .filter_map(|m| m.model.min_vram_gb)
+        .fold(f64::MAX, f64::min); // lower is better
+    let best_ram = models
+        .iter()
+        .map(|m| m.model.min_ram_gb)
+        .fold(f64::MAX, f64::min); // lower is better
+    let best_style = Style::default().fg(tc.good).add_modifier(Modifier::BOLD);
 
     let mut rows: Vec<AttrRow> = Vec::new();
 
@@ -1168,11 +1990,18 @@ fn draw_multi_compare(frame: &mut Frame, app: &App, area: Rect, tc: &ThemeColors
         label: "Fit",
         values: visible_models
             .iter()
-            .map(|m| format!("{} {}", fit_indicator(m.fit_level), m.fit_text()))
+            .map(|m| format!("{} {}", fit_indicator(m.fit_level, app.ascii), m.fit_text()))
             .collect(),
         styles: visible_models
             .iter()
-            .map(|m| Style::default().fg(fit_color(m.fit_level, tc)))
+            .map(|m| {
+                let style = Style::default().fg(fit_color(m.fit_level, tc));
+                if Some(fit_rank(m.fit_level)) == best_fit {
+                    style.add_modifier(Modifier::BOLD)
+                } else {
+                    style
+                }
+            })
             .collect(),
     });
 
@@ -1195,6 +2024,46 @@ fn draw_multi_compare(frame: &mut Frame, app: &App, area: Rect, tc: &ThemeColors
             .collect(),
     });
 
+    // Min VRAM
+    rows.push(AttrRow {
+        label: "Min VRAM",
+        values: visible_models
+            .iter()
+            .map(|m| {
+                m.model
+                    .min_vram_gb
+                    .map(|v| fmt_mem(v, 1, app.units))
+                    .unwrap_or_else(|| "\u{2014}".to_string())
+            })
+            .collect(),
+        styles: visible_models
+            .iter()
+            .map(|m| match m.model.min_vram_gb {
+                Some(v) if (v - best_vram).abs() < 0.05 => best_style,
+                _ => Style::default().fg(tc.fg),
+            })
+            .collect(),
+    });
+
+    // Min RAM
+    rows.push(AttrRow {
+        label: "Min RAM",
+        values: visible_models
+            .iter()
+            .map(|m| fmt_mem(m.model.min_ram_gb, 1, app.units))
+            .collect(),
+        styles: visible_models
+            .iter()
+            .map(|m| {
+                if (m.model.min_ram_gb - best_ram).abs() < 0.05 {
+                    best_style
+                } else {
+                    Style::default().fg(tc.fg)
+                }
+            })
+            .collect(),
+    });
+
     // Params
     rows.push(AttrRow {
         label: "Params",
@@ -1202,7 +2071,16 @@ fn draw_multi_compare(frame: &mut Frame, app: &App, area: Rect, tc: &ThemeColors
             .iter()
             .map(|m| m.model.parameter_count.clone())
             .collect(),
-        styles: vec![Style::default().fg(tc.fg); n],
+        styles: visible_models
+            .iter()
+            .map(|m| {
+                if (m.model.params_b() - best_params).abs() < 0.05 {
+                    best_style
+                } else {
+                    Style::default().fg(tc.fg)
+                }
+            })
+            .collect(),
     });
 
     // Mode
@@ -1252,7 +2130,17 @@ fn draw_multi_compare(frame: &mut Frame, app: &App, area: Rect, tc: &ThemeColors
             .iter()
             .map(|m| m.best_quant.clone())
             .collect(),
-        styles: vec![Style::default().fg(tc.muted); n],
+        styles: visible_models
+            .iter()
+            .map(|m| {
+                let bytes = crate::quants::table().bpp(&m.best_quant);
+                if (bytes - best_quant).abs() < 1e-9 {
+                    best_style
+                } else {
+                    Style::default().fg(quant_color(&m.best_quant, tc))
+                }
+            })
+            .collect(),
     });
 
     // Use Case
@@ -1303,7 +2191,7 @@ fn draw_multi_compare(frame: &mut Frame, app: &App, area: Rect, tc: &ThemeColors
             let bg = if row_idx % 2 == 0 {
                 Style::default()
             } else {
-                Style::default().bg(Color::Rgb(25, 25, 35))
+                Style::default().bg(tc.stripe_bg)
             };
             Row::new(cells).style(bg)
         })
@@ -1335,6 +2223,30 @@ fn draw_multi_compare(frame: &mut Frame, app: &App, area: Rect, tc: &ThemeColors
     frame.render_widget(table, area);
 }
 
+/// `name` with the chars at `highlights` (char positions) in bold accent.
+fn highlighted_name(name: &str, highlights: &[usize], tc: &ThemeColors) -> Line<'static> {
+    if highlights.is_empty() {
+        return Line::styled(name.to_string(), Style::default().fg(tc.fg));
+    }
+    let plain = Style::default().fg(tc.fg);
+    let matched = Style::default().fg(tc.accent).add_modifier(Modifier::BOLD);
+    let mut spans: Vec<Span<'static>> = Vec::new();
+    let mut run = String::new();
+    let mut run_matched = false;
+    for (i, c) in name.chars().enumerate() {
+        let is_matched = highlights.binary_search(&i).is_ok();
+        if is_matched != run_matched && !run.is_empty() {
+            let style = if run_matched { matched } else { plain };
+            spans.push(Span::styled(std::mem::take(&mut run), style));
+        }
+        run_matched = is_matched;
+        run.push(c);
+    }
+    let style = if run_matched { matched } else { plain };
+    spans.push(Span::styled(run, style));
+    Line::from(spans)
+}
+
 fn truncate_str(s: &str, max_len: usize) -> String {
     if s.len() <= max_len {
         s.to_string()
@@ -1343,6 +2255,38 @@ fn truncate_str(s: &str, max_len: usize) -> String {
     }
 }
 
+/// "~load: 25 s (NVMe)", from the downloaded size when known, otherwise the
+/// weights size at the chosen quant.
+fn load_time_line(app: &App, fit: &ModelFit, tc: &ThemeColors) -> Line<'static> {
+    // Disk throughput is in 10^9-byte GB/s; the size is shown in `app.units`
+    let bytes = match app.disk_sizes.get(&fit.model.name) {
+        Some(&bytes) => bytes as f64,
+        None => fit.weights_gb * 1e9,
+    };
+    let secs = app.disk_tier.load_seconds(bytes / 1e9);
+    let time = if secs < 60.0 {
+        format!("~{:.0} s", secs.max(1.0))
+    } else {
+        format!("~{:.1} min", secs / 60.0)
+    };
+    let mut spans = vec![
+        Span::styled("  Load time:   ", Style::default().fg(tc.muted)),
+        Span::styled(
+            format!(
+                "{} ({} from {})",
+                time,
+                fmt_mem(bytes / (1024.0 * 1024.0 * 1024.0), 1, app.units),
+                app.disk_tier.label()
+            ),
+            Style::default().fg(tc.fg),
+        ),
+    ];
+    if app.disk_tier.is_slow() {
+        spans.push(Span::styled("  slow disk", Style::default().fg(tc.warning)));
+    }
+    Line::from(spans)
+}
+
 fn draw_detail(frame: &mut Frame, app: &App, area: Rect, tc: &ThemeColors) {
     let fit = match app.selected_fit() {
         Some(f) => f,
@@ -1369,7 +2313,7 @@ fn draw_detail(frame: &mut Frame, app: &App, area: Rect, tc: &ThemeColors) {
         ]),
         Line::from(vec![
             Span::styled("  Parameters:  ", Style::default().fg(tc.muted)),
-            Span::styled(&fit.model.parameter_count, Style::default().fg(tc.fg)),
+            Span::styled(weight_size_text(fit, app.units), Style::default().fg(tc.fg)),
         ]),
         Line::from(vec![
             Span::styled("  Quantization:", Style::default().fg(tc.muted)),
@@ -1445,7 +2389,9 @@ fn draw_detail(frame: &mut Frame, app: &App, area: Rect, tc: &ThemeColors) {
             Span::styled("  Installed:   ", Style::default().fg(tc.muted)),
             {
                 let mut installed_providers = Vec::new();
-                if providers::is_model_installed(&fit.model.name, &app.ollama_installed) {
+                if providers::is_model_installed(&fit.model.name, &app.ollama_running) {
+                    installed_providers.push("Ollama (loaded)");
+                } else if providers::is_model_installed(&fit.model.name, &app.ollama_installed) {
                     installed_providers.push("Ollama");
                 }
                 if providers::is_model_installed_mlx(&fit.model.name, &app.mlx_installed) {
@@ -1485,6 +2431,24 @@ fn draw_detail(frame: &mut Frame, app: &App, area: Rect, tc: &ThemeColors) {
                 }
             },
         ]),
+        Line::from(vec![
+            Span::styled("  On disk:     ", Style::default().fg(tc.muted)),
+            match app.disk_sizes.get(&fit.model.name) {
+                Some(&bytes) => Span::styled(
+                    format!(
+                        "downloaded ✓ ({})",
+                        fmt_mem(bytes as f64 / 1_073_741_824.0, 1, app.units)
+                    ),
+                    Style::default().fg(tc.good),
+                ),
+                None => Span::styled("not downloaded", Style::default().fg(tc.muted)),
+            },
+            Span::styled(
+                format!("  GGUF cache: {}", app.gguf_cache_dir().display()),
+                Style::default().fg(tc.muted),
+            ),
+        ]),
+        load_time_line(app, fit, tc),
     ];
 
     // Scoring section
@@ -1534,7 +2498,13 @@ fn draw_detail(frame: &mut Frame, app: &App, area: Rect, tc: &ThemeColors) {
         Line::from(vec![
             Span::styled("  Baseline Est:", Style::default().fg(tc.muted)),
             Span::styled(
-                format!("{:.1} tok/s", fit.estimated_tps),
+                match fit.run_mode {
+                    RunMode::CpuOnly => format!("~{:.1} tok/s (CPU)", fit.estimated_tps),
+                    RunMode::CpuOffload => {
+                        format!("~{:.1} tok/s (CPU offload)", fit.estimated_tps)
+                    }
+                    _ => format!("{:.1} tok/s", fit.estimated_tps),
+                },
                 Style::default().fg(tc.fg),
             ),
         ]),
@@ -1564,17 +2534,17 @@ fn draw_detail(frame: &mut Frame, app: &App, area: Rect, tc: &ThemeColors) {
             ]));
         }
 
-        if let Some(active_vram) = fit.model.moe_active_vram_gb() {
+        if let Some(active_vram) = fit.model.moe_active_vram_gb(crate::quants::table()) {
             lines.push(Line::from(vec![
                 Span::styled("  Active VRAM: ", Style::default().fg(tc.muted)),
                 Span::styled(
-                    format!("{:.1} GB", active_vram),
+                    fmt_mem(active_vram, 1, app.units),
                     Style::default().fg(tc.accent),
                 ),
                 Span::styled(
                     format!(
-                        "  (vs {:.1} GB full model)",
-                        fit.model.min_vram_gb.unwrap_or(0.0)
+                        "  (vs {} full model)",
+                        fmt_mem(fit.model.min_vram_gb.unwrap_or(0.0), 1, app.units)
                     ),
                     Style::default().fg(tc.muted),
                 ),
@@ -1585,7 +2555,10 @@ fn draw_detail(frame: &mut Frame, app: &App, area: Rect, tc: &ThemeColors) {
             lines.push(Line::from(vec![
                 Span::styled("  Offloaded:   ", Style::default().fg(tc.muted)),
                 Span::styled(
-                    format!("{:.1} GB inactive experts in RAM", offloaded),
+                    format!(
+                        "{} inactive experts in RAM",
+                        fmt_mem(offloaded, 1, app.units)
+                    ),
                     Style::default().fg(tc.warning),
                 ),
             ]));
@@ -1620,14 +2593,35 @@ fn draw_detail(frame: &mut Frame, app: &App, area: Rect, tc: &ThemeColors) {
         Line::from(vec![
             Span::styled("  Fit Level:   ", Style::default().fg(tc.muted)),
             Span::styled(
-                format!("{} {}", fit_indicator(fit.fit_level), fit.fit_text()),
+                format!(
+                    "{} {}",
+                    fit_indicator(fit.fit_level, app.ascii),
+                    fit.fit_text()
+                ),
                 Style::default().fg(color).bold(),
             ),
         ]),
-        Line::from(vec![
-            Span::styled("  Run Mode:    ", Style::default().fg(tc.muted)),
-            Span::styled(fit.run_mode_text(), Style::default().fg(tc.fg).bold()),
-        ]),
+    ]);
+    lines.extend(fit_change_line(app, fit, tc));
+    lines.extend_from_slice(&[Line::from(vec![
+        Span::styled("  Run Mode:    ", Style::default().fg(tc.muted)),
+        Span::styled(fit.run_mode_text(), Style::default().fg(tc.fg).bold()),
+    ])]);
+    lines.extend_from_slice(&[
+        Line::from(""),
+        Line::from(Span::styled(
+            "  -- Reasoning --",
+            Style::default().fg(tc.accent),
+        )),
+        Line::from(""),
+    ]);
+    lines.extend(explanation_lines(
+        &fit.explain(&app.specs, app.units),
+        app.units,
+        tc,
+    ));
+    lines.extend(upgrade_lines(fit, &app.specs, app.units, tc));
+    lines.extend_from_slice(&[
         Line::from(""),
         Line::from(Span::styled(
             "  -- Memory --",
@@ -1636,16 +2630,37 @@ fn draw_detail(frame: &mut Frame, app: &App, area: Rect, tc: &ThemeColors) {
         Line::from(""),
     ]);
 
+    // Effective figures: what's left after --reserve and --headroom.
+    let held_back = |gb: f64| {
+        if gb > 0.0 {
+            format!(", {} reserved", fmt_mem(gb, 1, app.units))
+        } else {
+            String::new()
+        }
+    };
     if let Some(vram) = fit.model.min_vram_gb {
         let vram_label = if app.specs.has_gpu {
+            let vram_held = if app.specs.unified_memory {
+                app.specs.reserved_ram_gb + app.specs.headroom_gb
+            } else {
+                app.specs.headroom_gb
+            };
             if app.specs.unified_memory {
                 if let Some(sys_vram) = app.specs.gpu_vram_gb {
-                    format!("  (shared: {:.1} GB)", sys_vram)
+                    format!(
+                        "  (shared: {} avail{})",
+                        fmt_mem(sys_vram, 1, app.units),
+                        held_back(vram_held)
+                    )
                 } else {
                     "  (shared memory)".to_string()
                 }
             } else if let Some(sys_vram) = app.specs.gpu_vram_gb {
-                format!("  (system: {:.1} GB)", sys_vram)
+                format!(
+                    "  (system: {} avail{})",
+                    fmt_mem(sys_vram, 1, app.units),
+                    held_back(vram_held)
+                )
             } else {
                 "  (system: unknown)".to_string()
             }
@@ -1654,7 +2669,7 @@ fn draw_detail(frame: &mut Frame, app: &App, area: Rect, tc: &ThemeColors) {
         };
         lines.push(Line::from(vec![
             Span::styled("  Min VRAM:    ", Style::default().fg(tc.muted)),
-            Span::styled(format!("{:.1} GB", vram), Style::default().fg(tc.fg)),
+            Span::styled(fmt_mem(vram, 1, app.units), Style::default().fg(tc.fg)),
             Span::styled(vram_label, Style::default().fg(tc.muted)),
         ]));
     }
@@ -1663,20 +2678,48 @@ fn draw_detail(frame: &mut Frame, app: &App, area: Rect, tc: &ThemeColors) {
         Line::from(vec![
             Span::styled("  Min RAM:     ", Style::default().fg(tc.muted)),
             Span::styled(
-                format!("{:.1} GB", fit.model.min_ram_gb),
+                fmt_mem(fit.model.min_ram_gb, 1, app.units),
                 Style::default().fg(tc.fg),
             ),
             Span::styled(
-                format!("  (system: {:.1} GB avail)", app.specs.available_ram_gb),
+                format!(
+                    "  (system: {} avail{})",
+                    fmt_mem(app.specs.available_ram_gb, 1, app.units),
+                    held_back(app.specs.reserved_ram_gb + app.specs.headroom_gb)
+                ),
                 Style::default().fg(tc.muted),
             ),
         ]),
         Line::from(vec![
             Span::styled("  Rec RAM:     ", Style::default().fg(tc.muted)),
             Span::styled(
-                format!("{:.1} GB", fit.model.recommended_ram_gb),
+                fmt_mem(fit.model.recommended_ram_gb, 1, app.units),
+                Style::default().fg(tc.fg),
+            ),
+        ]),
+        Line::from(vec![
+            Span::styled("  Mem Needed:  ", Style::default().fg(tc.muted)),
+            Span::styled(
+                if fit.memory_required_gb - fit.memory_required_low_gb >= 0.05 {
+                    let units = app.units;
+                    format!(
+                        "{:.1}–{:.1} {}",
+                        units.convert(fit.memory_required_low_gb),
+                        units.convert(fit.memory_required_gb),
+                        units.label()
+                    )
+                } else {
+                    fmt_mem(fit.memory_required_gb, 1, app.units)
+                },
                 Style::default().fg(tc.fg),
             ),
+            Span::styled(
+                format!(
+                    "  ({}k ctx typical – full ctx; fit uses the high end)",
+                    llmfit_core::fit::TYPICAL_CONTEXT_TOKENS / 1024
+                ),
+                Style::default().fg(tc.muted),
+            ),
         ]),
         Line::from(vec![
             Span::styled("  Mem Usage:   ", Style::default().fg(tc.muted)),
@@ -1686,14 +2729,45 @@ fn draw_detail(frame: &mut Frame, app: &App, area: Rect, tc: &ThemeColors) {
             ),
             Span::styled(
                 format!(
-                    "  ({:.1} / {:.1} GB)",
-                    fit.memory_required_gb, fit.memory_available_gb
+                    "  ({} / {})",
+                    fmt_mem(fit.memory_required_gb, 1, app.units),
+                    fmt_mem(fit.memory_available_gb, 1, app.units)
                 ),
                 Style::default().fg(tc.muted),
             ),
         ]),
     ]);
 
+    if let (Some(group), Some(placement)) = (
+        app.specs.gpu_group_label(app.units),
+        fit.gpu_placement(&app.specs),
+    ) {
+        lines.push(Line::from(vec![
+            Span::styled("  GPUs:        ", Style::default().fg(tc.muted)),
+            Span::styled(group, Style::default().fg(tc.fg)),
+            Span::styled(
+                format!("  {}", placement.label(app.units)),
+                Style::default().fg(tc.muted),
+            ),
+        ]));
+    }
+
+    if let Some(split) = fit.tensor_parallel_split(&app.specs) {
+        lines.push(Line::from(vec![
+            Span::styled("  TP Split:    ", Style::default().fg(tc.muted)),
+            Span::styled(
+                split.label(app.units),
+                Style::default().fg(if split.fits { tc.good } else { tc.error }),
+            ),
+        ]));
+        if split.fits {
+            lines.push(Line::from(Span::styled(
+                format!("               --tensor-parallel-size {}", split.gpus),
+                Style::default().fg(tc.muted),
+            )));
+        }
+    }
+
     // Build right-pane content (GGUF sources + notes)
     let has_right_pane = !fit.model.gguf_sources.is_empty() || !fit.notes.is_empty();
 
@@ -1904,13 +2978,13 @@ fn draw_plan(frame: &mut Frame, app: &App, area: Rect, tc: &ThemeColors) {
             Span::styled(
                 plan.minimum
                     .vram_gb
-                    .map(|v| format!("{v:.1} GB"))
+                    .map(|v| fmt_mem(v, 1, app.units))
                     .unwrap_or_else(|| "n/a".to_string()),
                 Style::default().fg(tc.fg),
             ),
             Span::styled("   RAM: ", Style::default().fg(tc.muted)),
             Span::styled(
-                format!("{:.1} GB", plan.minimum.ram_gb),
+                fmt_mem(plan.minimum.ram_gb, 1, app.units),
                 Style::default().fg(tc.fg),
             ),
             Span::styled("   CPU: ", Style::default().fg(tc.muted)),
@@ -1929,13 +3003,13 @@ fn draw_plan(frame: &mut Frame, app: &App, area: Rect, tc: &ThemeColors) {
             Span::styled(
                 plan.recommended
                     .vram_gb
-                    .map(|v| format!("{v:.1} GB"))
+                    .map(|v| fmt_mem(v, 1, app.units))
                     .unwrap_or_else(|| "n/a".to_string()),
                 Style::default().fg(tc.fg),
             ),
             Span::styled("   RAM: ", Style::default().fg(tc.muted)),
             Span::styled(
-                format!("{:.1} GB", plan.recommended.ram_gb),
+                fmt_mem(plan.recommended.ram_gb, 1, app.units),
                 Style::default().fg(tc.fg),
             ),
             Span::styled("   CPU: ", Style::default().fg(tc.muted)),
@@ -2012,27 +3086,95 @@ fn draw_plan(frame: &mut Frame, app: &App, area: Rect, tc: &ThemeColors) {
     frame.render_widget(paragraph, area);
 }
 
-fn draw_provider_popup(frame: &mut Frame, app: &App, tc: &ThemeColors) {
+/// Overlay listing detection steps and the fit decision for the selected model.
+fn draw_activity_log(frame: &mut Frame, app: &App, tc: &ThemeColors) {
     let area = frame.area();
-
-    let max_name_len = app.providers.iter().map(|p| p.len()).max().unwrap_or(10);
-    let popup_width = (max_name_len as u16 + 10).min(area.width.saturating_sub(4));
-    let popup_height = (app.providers.len() as u16 + 2).min(area.height.saturating_sub(4));
-
+    let popup_width = area.width.saturating_sub(8).min(120);
+    let popup_height = area.height.saturating_sub(4);
     let x = area.x + (area.width.saturating_sub(popup_width)) / 2;
     let y = area.y + (area.height.saturating_sub(popup_height)) / 2;
     let popup_area = Rect::new(x, y, popup_width, popup_height);
+    frame.render_widget(Clear, popup_area);
+
+    let heading = |text: &str| {
+        Line::from(Span::styled(
+            text.to_string(),
+            Style::default().fg(tc.accent).add_modifier(Modifier::BOLD),
+        ))
+    };
+    let mut lines = vec![heading("Detection")];
+    lines.extend(
+        app.activity_entries
+            .iter()
+            .filter(|e| matches!(e.kind, ActivityKind::Detect | ActivityKind::Warning))
+            .map(|e| {
+                let color = if e.kind == ActivityKind::Warning {
+                    tc.warning
+                } else {
+                    tc.fg
+                };
+                Line::from(Span::styled(
+                    format!("  {}", e.message),
+                    Style::default().fg(color),
+                ))
+            }),
+    );
+
+    if let Some(fit) = app.selected_fit() {
+        lines.push(Line::from(""));
+        lines.push(heading("Selected model"));
+        let decision = app.activity_entries.iter().rev().find(|e| {
+            e.kind == ActivityKind::Fit && e.subject.as_deref() == Some(fit.model.name.as_str())
+        });
+        match decision {
+            Some(e) => lines.push(Line::from(Span::styled(
+                format!("  {}", e.message),
+                Style::default().fg(fit_color(fit.fit_level, tc)),
+            ))),
+            None => lines.push(Line::from(Span::styled(
+                "  (no decision recorded)",
+                Style::default().fg(tc.muted),
+            ))),
+        }
+        lines.extend(fit.notes.iter().map(|note| {
+            Line::from(Span::styled(
+                format!("    {}", note),
+                Style::default().fg(tc.muted),
+            ))
+        }));
+    }
+
+    let fit_count = app
+        .activity_entries
+        .iter()
+        .filter(|e| e.kind == ActivityKind::Fit)
+        .count();
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(tc.accent_secondary))
+        .title(format!(
+            " Activity log ({} fit decisions) — j/k scroll, L/Esc close ",
+            fit_count
+        ))
+        .title_style(Style::default().fg(tc.fg).bold());
+
+    let paragraph = Paragraph::new(lines)
+        .block(block)
+        .wrap(Wrap { trim: false })
+        .scroll((app.activity_scroll, 0));
+    frame.render_widget(paragraph, popup_area);
+}
+
+fn draw_provider_popup(frame: &mut Frame, app: &mut App, tc: &ThemeColors) {
+    let popup_area = provider_popup_area(app, frame.area());
+    let popup_height = popup_area.height;
 
     frame.render_widget(Clear, popup_area);
 
     let inner_height = popup_height.saturating_sub(2) as usize;
     let total = app.providers.len();
 
-    let scroll_offset = if app.provider_cursor >= inner_height {
-        app.provider_cursor - inner_height + 1
-    } else {
-        0
-    };
+    let scroll_offset = popup_scroll(app.provider_cursor, inner_height);
 
     let lines: Vec<Line> = app
         .providers
@@ -2253,6 +3395,136 @@ fn draw_capability_popup(frame: &mut Frame, app: &App, tc: &ThemeColors) {
     frame.render_widget(paragraph, popup_area);
 }
 
+fn draw_tag_popup(frame: &mut Frame, app: &App, tc: &ThemeColors) {
+    let area = frame.area();
+
+    let max_name_len = app.tags.iter().map(|t| t.len()).max().unwrap_or(10);
+    let popup_width = (max_name_len as u16 + 10)
+        .max(28)
+        .min(area.width.saturating_sub(4));
+    let popup_height = (app.tags.len() as u16 + 2).min(area.height.saturating_sub(4));
+
+    let x = area.x + (area.width.saturating_sub(popup_width)) / 2;
+    let y = area.y + (area.height.saturating_sub(popup_height)) / 2;
+    let popup_area = Rect::new(x, y, popup_width, popup_height);
+
+    frame.render_widget(Clear, popup_area);
+
+    let inner_height = popup_height.saturating_sub(2) as usize;
+    let scroll_offset = if app.tag_cursor >= inner_height {
+        app.tag_cursor - inner_height + 1
+    } else {
+        0
+    };
+
+    let lines: Vec<Line> = app
+        .tags
+        .iter()
+        .enumerate()
+        .skip(scroll_offset)
+        .take(inner_height)
+        .map(|(i, tag)| {
+            let selected = app.selected_tags[i];
+            let checkbox = if selected { "[x]" } else { "[ ]" };
+            let mut style = if selected {
+                Style::default().fg(tc.good)
+            } else {
+                Style::default().fg(tc.muted)
+            };
+            if i == app.tag_cursor {
+                style = style
+                    .fg(if selected { tc.good } else { tc.fg })
+                    .add_modifier(Modifier::BOLD)
+                    .bg(tc.highlight_bg);
+            }
+            Line::from(Span::styled(format!(" {} {}", checkbox, tag), style))
+        })
+        .collect();
+
+    let title = format!(
+        " Tags (match {}) ",
+        if app.tag_match_all { "all" } else { "any" }
+    );
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(tc.accent_secondary))
+        .title(title)
+        .title_style(
+            Style::default()
+                .fg(tc.accent_secondary)
+                .add_modifier(Modifier::BOLD),
+        );
+
+    let paragraph = Paragraph::new(lines).block(block);
+    frame.render_widget(paragraph, popup_area);
+}
+
+fn draw_view_popup(frame: &mut Frame, app: &App, tc: &ThemeColors) {
+    let area = frame.area();
+
+    let max_name_len = app.views.iter().map(|v| v.name.len()).max().unwrap_or(10);
+    let popup_width = (max_name_len as u16 + 16)
+        .max(36)
+        .min(area.width.saturating_sub(4));
+    let prompt_rows = if app.view_name_input.is_some() { 2 } else { 0 };
+    let popup_height =
+        (app.views.len() as u16 + 2 + prompt_rows).min(area.height.saturating_sub(4));
+
+    let x = area.x + (area.width.saturating_sub(popup_width)) / 2;
+    let y = area.y + (area.height.saturating_sub(popup_height)) / 2;
+    let popup_area = Rect::new(x, y, popup_width, popup_height);
+
+    frame.render_widget(Clear, popup_area);
+
+    let inner_height =
+        (popup_height.saturating_sub(2) as usize).saturating_sub(prompt_rows as usize);
+    let scroll_offset = if app.view_cursor >= inner_height {
+        app.view_cursor - inner_height + 1
+    } else {
+        0
+    };
+
+    let mut lines: Vec<Line> = app
+        .views
+        .iter()
+        .enumerate()
+        .skip(scroll_offset)
+        .take(inner_height)
+        .map(|(i, view)| {
+            let mut style = Style::default().fg(tc.fg);
+            if i == app.view_cursor {
+                style = style.add_modifier(Modifier::BOLD).bg(tc.highlight_bg);
+            }
+            let mut spans = vec![Span::styled(format!(" {}", view.name), style)];
+            if view.builtin {
+                spans.push(Span::styled(" (built-in)", Style::default().fg(tc.muted)));
+            }
+            Line::from(spans)
+        })
+        .collect();
+
+    if let Some(name) = &app.view_name_input {
+        lines.push(Line::from(""));
+        lines.push(Line::from(vec![
+            Span::styled(" Name: ", Style::default().fg(tc.muted)),
+            Span::styled(format!("{}_", name), Style::default().fg(tc.accent)),
+        ]));
+    }
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(tc.accent_secondary))
+        .title(" Views ")
+        .title_style(
+            Style::default()
+                .fg(tc.accent_secondary)
+                .add_modifier(Modifier::BOLD),
+        );
+
+    let paragraph = Paragraph::new(lines).block(block);
+    frame.render_widget(paragraph, popup_area);
+}
+
 fn draw_download_provider_popup(frame: &mut Frame, app: &App, tc: &ThemeColors) {
     let area = frame.area();
     let popup_width = 44.min(area.width.saturating_sub(4));
@@ -2311,6 +3583,197 @@ fn draw_download_provider_popup(frame: &mut Frame, app: &App, tc: &ThemeColors)
     frame.render_widget(paragraph, popup_area);
 }
 
+/// Normal-mode key bindings listed by the `?` overlay.
+const HELP_BINDINGS: &[(&str, &str)] = &[
+    ("↑↓ / jk", "Navigate models"),
+    ("PgUp / PgDn", "Page up / down"),
+    ("Ctrl-U / Ctrl-D", "Half page up / down"),
+    ("g / G", "Jump to top / bottom"),
+    ("Ctrl-E / Ctrl-Y", "Scroll without moving the selection"),
+    ("Enter", "Toggle detail view"),
+    ("h / l", "Detail view: previous / next model"),
+    ("/", "Search (fuzzy on name)"),
+    ("'", "Jump to a name prefix"),
+    ("f", "Cycle fit filter"),
+    ("a", "Cycle availability filter"),
+    ("s", "Cycle sort column"),
+    ("1-9", "Toggle one of the first nine providers"),
+    ("P", "Provider filter popup"),
+    ("U", "Use-case filter popup"),
+    ("C", "Capability filter popup"),
+    ("T", "Tag filter popup"),
+    ("w", "Saved views"),
+    ("M", "Cycle run-mode assumption"),
+    ("Q", "Cycle selected model's quantization"),
+    ("B", "Toggle fit target (minimum / recommended)"),
+    ("+ / -", "Double / halve concurrent requests (KV cache)"),
+    ("D", "Only models whose fit changed"),
+    ("K", "Only models with a known VRAM need"),
+    ("W", "Minimum context: off, 8k, 32k, 128k"),
+    ("[ / ]", "Lower / raise target context"),
+    ("{ / }", "Lower / raise context for estimates"),
+    ("u", "Undo last filter, search or sort change"),
+    ("R", "Reset search, filters and sort"),
+    ("b / *", "Toggle favorite (pin) on selected model"),
+    ("F", "Only favorites"),
+    ("m", "Mark selected model for compare"),
+    ("Space", "Add / remove in the compare set"),
+    ("c", "Compare view"),
+    ("x", "Clear compare mark and set"),
+    ("v", "Visual mode (select a range)"),
+    ("V", "Select mode (filter by column)"),
+    ("p", "Plan mode for selected model"),
+    ("i", "Toggle installed-first sorting"),
+    ("d", "Download selected model"),
+    ("r", "Refresh RAM and installed models"),
+    ("Y", "Copy filtered list as markdown"),
+    ("e", "Export filtered list as CSV"),
+    ("t", "Cycle color theme"),
+    ("z", "Recenter the view on the selection"),
+    ("o", "Cycle table layout"),
+    ("L", "Activity log"),
+    ("?", "This help"),
+    ("q / Esc", "Quit"),
+];
+
+const HELP_KEY_WIDTH: usize = 17;
+const HELP_COLUMN_WIDTH: u16 = 60;
+
+/// Rows one help entry takes in a column `width` wide: the description
+/// moves under its key when both don't fit on one line.
+fn help_entry_height(desc: &str, width: usize) -> u16 {
+    if 1 + HELP_KEY_WIDTH + desc.chars().count() <= width {
+        1
+    } else {
+        2
+    }
+}
+
+/// Where the help overlay goes on a screen of `area`, and how
+/// [`HELP_BINDINGS`] splits into pages of side-by-side columns.
+struct HelpLayout {
+    popup: Rect,
+    columns: Rc<[Rect]>,
+    /// Width the entry heights were worked out for.
+    column_width: usize,
+    /// Per page, the bindings shown in each column.
+    pages: Vec<Vec<std::ops::Range<usize>>>,
+}
+
+fn help_layout(area: Rect) -> HelpLayout {
+    let max_height = area.height.saturating_sub(2).max(3);
+    let max_width = area.width.saturating_sub(2).max(10);
+    let column_count = (HELP_BINDINGS.len() as u16)
+        .div_ceil(max_height.saturating_sub(2).max(1))
+        .clamp(1, (max_width / HELP_COLUMN_WIDTH).max(1));
+    let popup_width = (HELP_COLUMN_WIDTH * column_count + 2).min(max_width);
+    let column_width = (popup_width.saturating_sub(2) / column_count) as usize;
+    let heights: Vec<u16> = HELP_BINDINGS
+        .iter()
+        .map(|(_, desc)| help_entry_height(desc, column_width))
+        .collect();
+
+    // Even columns when everything fits on one page; otherwise fill each
+    // column to the full height and page through them.
+    let max_rows = max_height.saturating_sub(2).max(1);
+    let mut rows = heights
+        .iter()
+        .sum::<u16>()
+        .div_ceil(column_count)
+        .min(max_rows);
+    let columns = loop {
+        let mut columns = Vec::new();
+        let (mut start, mut used) = (0, 0);
+        for (i, &height) in heights.iter().enumerate() {
+            if used + height > rows && i > start {
+                columns.push(start..i);
+                (start, used) = (i, 0);
+            }
+            used += height;
+        }
+        columns.push(start..heights.len());
+        if columns.len() <= column_count as usize || rows >= max_rows {
+            break columns;
+        }
+        rows += 1;
+    };
+    let pages: Vec<Vec<_>> = columns
+        .chunks(column_count as usize)
+        .map(<[_]>::to_vec)
+        .collect();
+
+    let popup_height = rows + 2;
+    let x = area.x + (area.width.saturating_sub(popup_width)) / 2;
+    let y = area.y + (area.height.saturating_sub(popup_height)) / 2;
+    let popup = Rect::new(x, y, popup_width, popup_height);
+    let columns = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints(vec![
+            Constraint::Ratio(1, column_count as u32);
+            column_count as usize
+        ])
+        .split(popup.inner(Margin::new(1, 1)));
+    HelpLayout {
+        popup,
+        columns,
+        column_width,
+        pages,
+    }
+}
+
+/// Pages the help overlay needs on a screen of `area`.
+pub fn help_pages(area: Rect) -> usize {
+    help_layout(area).pages.len()
+}
+
+/// Key bindings overlay. The list splits into side-by-side columns when it
+/// doesn't fit the height, and into pages when the columns don't fit the
+/// width either.
+fn draw_help(frame: &mut Frame, app: &App, tc: &ThemeColors) {
+    let layout = help_layout(frame.area());
+    let page_count = layout.pages.len();
+    let page = app.help_page.min(page_count - 1);
+
+    frame.render_widget(Clear, layout.popup);
+    let mut block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(tc.accent))
+        .title_style(Style::default().fg(tc.accent).add_modifier(Modifier::BOLD));
+    if page_count == 1 {
+        block = block.title(" Keys (any key to close) ");
+    } else {
+        block = block
+            .title(format!(" Keys {}/{} ", page + 1, page_count))
+            .title_bottom(Line::from(Span::styled(
+                if page + 1 < page_count {
+                    " more… j:next k:prev "
+                } else {
+                    " k:prev, any other key closes "
+                },
+                Style::default().fg(tc.muted),
+            )));
+    }
+    frame.render_widget(block, layout.popup);
+
+    for (chunk, range) in layout.columns.iter().zip(&layout.pages[page]) {
+        let mut lines: Vec<Line> = Vec::new();
+        for (key, desc) in &HELP_BINDINGS[range.clone()] {
+            let key = Span::styled(
+                format!(" {:<width$}", key, width = HELP_KEY_WIDTH),
+                Style::default().fg(tc.accent_secondary).bold(),
+            );
+            let desc_style = Style::default().fg(tc.fg);
+            if help_entry_height(desc, layout.column_width) == 1 {
+                lines.push(Line::from(vec![key, Span::styled(*desc, desc_style)]));
+            } else {
+                lines.push(Line::from(key));
+                lines.push(Line::from(Span::styled(format!("   {}", desc), desc_style)));
+            }
+        }
+        frame.render_widget(Paragraph::new(lines), *chunk);
+    }
+}
+
 fn status_keys_and_mode(app: &App) -> (String, String) {
     match app.input_mode {
         InputMode::Normal => {
@@ -2321,7 +3784,7 @@ fn status_keys_and_mode(app: &App) -> (String, String) {
                 );
             }
             let detail_key = if app.show_detail {
-                "Enter:table"
+                "hl:prev/next  Enter:table"
             } else {
                 "Enter:detail"
             };
@@ -2338,11 +3801,12 @@ fn status_keys_and_mode(app: &App) -> (String, String) {
                 };
                 format!("  {}  d:pull  r:refresh", installed_key)
             } else {
-                String::new()
+                "  r:refresh".to_string()
             };
+            // The essentials only; `?` lists everything else.
             (
                 format!(
-                    " ↑↓/jk:nav  {}  /:search  f:fit  s:sort  v:visual  V:select  t:theme  p:plan  m:mark  c:compare  x:clear mark{}  P:providers  U:use cases  C:caps  q:quit  tok/s*:est",
+                    " ↑↓/jk:nav  {}  /:search  f:fit  s:sort  p:plan  c:compare{}  ?:help  q:quit",
                     detail_key, ollama_keys,
                 ),
                 "NORMAL".to_string(),
@@ -2352,7 +3816,7 @@ fn status_keys_and_mode(app: &App) -> (String, String) {
             let count = app.visual_selection_count();
             (
                 format!(
-                    " ↑↓/jk:extend  c:compare  m:mark  Esc:exit  ({} selected)",
+                    " ↑↓/jk:extend  c:compare  d:download plan  m:mark  Esc:exit  ({} selected)",
                     count
                 ),
                 "VISUAL".to_string(),
@@ -2360,8 +3824,8 @@ fn status_keys_and_mode(app: &App) -> (String, String) {
         }
         InputMode::Select => {
             let header_names = [
-                "", "Inst", "Model", "Provider", "Params", "Score", "tok/s*", "Quant", "Mode",
-                "Mem %", "Ctx", "Date", "Fit", "Use Case",
+                "", "Inst", "Model", "Provider", "Params", "Size", "Score", "tok/s*", "Quant",
+                "Mode", "Mem %", "Ctx", "Date", "Fit", "Use Case",
             ];
             let col_name = header_names.get(app.select_column).unwrap_or(&"");
             (
@@ -2373,6 +3837,13 @@ fn status_keys_and_mode(app: &App) -> (String, String) {
             "  Type to search  Esc:done  Ctrl-U:clear".to_string(),
             "SEARCH".to_string(),
         ),
+        InputMode::Jump => (
+            format!(
+                "  Type a name prefix (repeat a letter to cycle)  Enter/Esc:done  [{}]",
+                app.jump_buffer
+            ),
+            "JUMP".to_string(),
+        ),
         InputMode::Plan => (
             "  Tab/jk:field  ←/→:cursor  type:edit  Backspace/Delete  Ctrl-U:clear  Esc:close"
                 .to_string(),
@@ -2390,6 +3861,18 @@ fn status_keys_and_mode(app: &App) -> (String, String) {
             "  ↑↓/jk:navigate  Space:toggle  a:all/none  Esc:close".to_string(),
             "CAPABILITIES".to_string(),
         ),
+        InputMode::TagPopup => (
+            "  ↑↓/jk:navigate  Space:toggle  m:match all/any  a:clear  Esc:close".to_string(),
+            "TAGS".to_string(),
+        ),
+        InputMode::ViewPopup if app.view_name_input.is_some() => (
+            "  type a name  Enter:save  Esc:cancel".to_string(),
+            "VIEWS".to_string(),
+        ),
+        InputMode::ViewPopup => (
+            "  ↑↓/jk:navigate  Enter:apply  n:save current  d:delete  Esc:close".to_string(),
+            "VIEWS".to_string(),
+        ),
         InputMode::DownloadProviderPopup => (
             "  ↑↓/jk:choose  Enter:download  Esc:cancel".to_string(),
             "DOWNLOAD".to_string(),
@@ -2406,7 +3889,44 @@ fn status_keys_and_mode(app: &App) -> (String, String) {
             "  ↑↓/jk:navigate  Space:toggle  a:all/none  Esc:close".to_string(),
             "PARAMS".to_string(),
         ),
+        InputMode::Help => (
+            "  j/k:page  any other key:close".to_string(),
+            "HELP".to_string(),
+        ),
+    }
+}
+
+/// " RUN: CPU only " badge while a run mode is forced, empty otherwise.
+fn forced_run_mode_span(app: &App, tc: &ThemeColors) -> Span<'static> {
+    if app.forced_run_mode.is_none() {
+        return Span::raw("");
+    }
+    Span::styled(
+        format!(" RUN: {} ", app.forced_run_mode_label()),
+        Style::default().fg(tc.status_fg).bg(tc.warning).bold(),
+    )
+}
+
+/// " FIT: recommended " badge while fits target recommended memory.
+fn fit_target_span(app: &App, tc: &ThemeColors) -> Span<'static> {
+    if app.fit_target == FitTarget::Minimum {
+        return Span::raw("");
+    }
+    Span::styled(
+        format!(" FIT: {} ", app.fit_target.label()),
+        Style::default().fg(tc.status_fg).bg(tc.warning).bold(),
+    )
+}
+
+/// " x8 requests " badge while fits assume concurrent requests.
+fn concurrency_span(app: &App, tc: &ThemeColors) -> Span<'static> {
+    if app.concurrency == 1 {
+        return Span::raw("");
     }
+    Span::styled(
+        format!(" x{} requests ", app.concurrency),
+        Style::default().fg(tc.status_fg).bg(tc.warning).bold(),
+    )
 }
 
 fn draw_status_bar(frame: &mut Frame, app: &App, area: Rect, tc: &ThemeColors) {
@@ -2433,6 +3953,9 @@ fn draw_status_bar(frame: &mut Frame, app: &App, area: Rect, tc: &ThemeColors) {
                 format!(" {} ", mode_text),
                 Style::default().fg(tc.status_fg).bg(tc.status_bg).bold(),
             ),
+            forced_run_mode_span(app, tc),
+            fit_target_span(app, tc),
+            concurrency_span(app, tc),
             Span::styled(keys, Style::default().fg(tc.muted)),
         ]);
         frame.render_widget(Paragraph::new(status_line), chunks[0]);
@@ -2457,6 +3980,9 @@ fn draw_status_bar(frame: &mut Frame, app: &App, area: Rect, tc: &ThemeColors) {
             format!(" {} ", mode_text),
             Style::default().fg(tc.status_fg).bg(tc.status_bg).bold(),
         ),
+        forced_run_mode_span(app, tc),
+        fit_target_span(app, tc),
+        concurrency_span(app, tc),
         Span::styled(keys, Style::default().fg(tc.muted)),
     ]);
 
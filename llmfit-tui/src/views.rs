@@ -0,0 +1,147 @@
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+
+/// A named snapshot of search, filters and sort order.
+///
+/// Multi-select filters are stored by label. `None` means every value is
+/// selected, so a view keeps working when new providers or quants show up.
+/// Labels that no longer exist are ignored on apply.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SavedView {
+    pub name: String,
+    #[serde(default)]
+    pub search: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub providers: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub use_cases: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub capabilities: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub quants: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub run_modes: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub params_buckets: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    #[serde(default = "default_true")]
+    pub tag_match_all: bool,
+    #[serde(default = "default_all")]
+    pub fit: String,
+    #[serde(default = "default_all")]
+    pub availability: String,
+    /// Minimum context length in tokens, if the filter is on.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_context: Option<u32>,
+    /// Only models whose catalog entry gives a VRAM requirement.
+    #[serde(default)]
+    pub known_vram_only: bool,
+    /// Only models whose fit level changed since the last run.
+    #[serde(default)]
+    pub changed_only: bool,
+    /// Only favorite (pinned) models.
+    #[serde(default)]
+    pub favorites_only: bool,
+    #[serde(default)]
+    pub installed_first: bool,
+    #[serde(default = "default_sort")]
+    pub sort: String,
+    #[serde(default)]
+    pub sort_ascending: bool,
+    /// Shipped with llmfit; never written to disk and can't be deleted.
+    #[serde(skip)]
+    pub builtin: bool,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+fn default_all() -> String {
+    "All".to_string()
+}
+
+fn default_sort() -> String {
+    "Score".to_string()
+}
+
+impl SavedView {
+    fn builtin(name: &str) -> Self {
+        SavedView {
+            name: name.to_string(),
+            search: String::new(),
+            providers: None,
+            use_cases: None,
+            capabilities: None,
+            quants: None,
+            run_modes: None,
+            params_buckets: None,
+            tags: Vec::new(),
+            tag_match_all: true,
+            fit: default_all(),
+            availability: default_all(),
+            min_context: None,
+            known_vram_only: false,
+            changed_only: false,
+            favorites_only: false,
+            installed_first: false,
+            sort: default_sort(),
+            sort_ascending: false,
+            builtin: true,
+        }
+    }
+}
+
+/// Views available out of the box, listed before the user's own.
+pub fn builtin() -> Vec<SavedView> {
+    vec![
+        SavedView {
+            use_cases: Some(vec!["Coding".to_string()]),
+            run_modes: Some(vec!["CPU".to_string()]),
+            fit: "Runnable".to_string(),
+            ..SavedView::builtin("CPU-runnable coding models")
+        },
+        SavedView {
+            fit: "GPU Only".to_string(),
+            ..SavedView::builtin("GPU models by quality")
+        },
+        SavedView {
+            fit: "Runnable".to_string(),
+            sort: "tok/s".to_string(),
+            ..SavedView::builtin("Fastest runnable")
+        },
+        SavedView {
+            availability: "Installed".to_string(),
+            ..SavedView::builtin("Installed")
+        },
+    ]
+}
+
+/// Path to the saved-views file: ~/.config/llmfit/views.json
+fn config_path() -> Option<PathBuf> {
+    Some(llmfit_core::config_dir()?.join("views.json"))
+}
+
+/// Load the user's saved views, returning an empty list if none are saved
+/// or the file can't be parsed.
+pub fn load() -> Vec<SavedView> {
+    config_path()
+        .and_then(|path| fs::read_to_string(path).ok())
+        .and_then(|s| serde_json::from_str(&s).ok())
+        .unwrap_or_default()
+}
+
+/// Save the user's views (built-ins are skipped).
+pub fn save(views: &[SavedView]) {
+    if let Some(path) = config_path() {
+        if let Some(parent) = path.parent() {
+            let _ = fs::create_dir_all(parent);
+        }
+        let user: Vec<&SavedView> = views.iter().filter(|v| !v.builtin).collect();
+        if let Ok(json) = serde_json::to_string_pretty(&user) {
+            let _ = fs::write(&path, json + "\n");
+        }
+    }
+}
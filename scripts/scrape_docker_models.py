@@ -217,7 +217,7 @@ def main():
     # Load the HF model database to get all model names
     hf_models_file = os.path.join(project_root, "llmfit-core", "data", "hf_models.json")
     with open(hf_models_file) as f:
-        hf_models = json.load(f)
+        hf_models = json.load(f)["models"]
 
     print(f"Loaded {len(hf_models)} models from HF database")
 
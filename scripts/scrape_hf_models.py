@@ -20,6 +20,10 @@ import urllib.error
 
 HF_API = "https://huggingface.co/api/models"
 
+# Catalog format version written to hf_models.json. Must stay within
+# MIN_CATALOG_SCHEMA_VERSION..=MAX_CATALOG_SCHEMA_VERSION in llmfit-core.
+CATALOG_SCHEMA_VERSION = 1
+
 # Global auth token, set from --token flag or HF_TOKEN / HUGGING_FACE_HUB_TOKEN env var
 _hf_token: str | None = None
 
@@ -1956,7 +1960,7 @@ def main():
     for output_path in output_paths:
         os.makedirs(os.path.dirname(output_path), exist_ok=True)
         with open(output_path, "w") as f:
-            json.dump(results, f, indent=2)
+            json.dump({"schema_version": CATALOG_SCHEMA_VERSION, "models": results}, f, indent=2)
 
     print(f"\n✅ Wrote {len(results)} models to {', '.join(output_paths)}")
     print(f"   Curated: {len(TARGET_MODELS)}, Fallbacks: {fallback_count}, "
@@ -65,7 +65,7 @@ if ! python3 -m json.tool "$DATA_FILE" > /dev/null 2>&1; then
     exit 1
 fi
 
-MODEL_COUNT=$(python3 -c "import json; print(len(json.load(open('$DATA_FILE'))))")
+MODEL_COUNT=$(python3 -c "import json; print(len(json.load(open('$DATA_FILE'))['models']))")
 echo -e "${GREEN}✓ Valid JSON with $MODEL_COUNT models${NC}"
 echo
 
@@ -47,7 +47,7 @@ def load_hf_models() -> list[str]:
     """Return list of HF repo names from hf_models.json."""
     with open(HF_MODELS_PATH) as f:
         data = json.load(f)
-    return [m["name"] for m in data]
+    return [m["name"] for m in data["models"]]
 
 
 def verify_hf(models: list[str]) -> list[str]: